@@ -186,7 +186,7 @@ class LanceDBPlugin : Plugin() {
 
         scope.launch {
             try {
-                h.delete(key)
+                h.delete(key, null)
                 call.resolve()
             } catch (e: Exception) {
                 call.reject("delete failed: ${e.message}", e)
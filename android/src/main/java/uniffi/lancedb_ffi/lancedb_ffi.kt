@@ -661,6 +661,184 @@ internal open class UniffiForeignFutureStructVoid(
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
     fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
+internal interface UniffiCallbackInterfaceProgressReporterMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`done`: Long,`total`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceReembedderMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`text`: RustBuffer.ByValue,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+@Structure.FieldOrder("onProgress", "uniffiFree")
+internal open class UniffiVTableCallbackInterfaceProgressReporter(
+    @JvmField internal var `onProgress`: UniffiCallbackInterfaceProgressReporterMethod0? = null,
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+) : Structure() {
+    class UniffiByValue(
+        `onProgress`: UniffiCallbackInterfaceProgressReporterMethod0? = null,
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    ): UniffiVTableCallbackInterfaceProgressReporter(`onProgress`,`uniffiFree`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceProgressReporter) {
+        `onProgress` = other.`onProgress`
+        `uniffiFree` = other.`uniffiFree`
+    }
+
+}
+@Structure.FieldOrder("embed", "uniffiFree")
+internal open class UniffiVTableCallbackInterfaceReembedder(
+    @JvmField internal var `embed`: UniffiCallbackInterfaceReembedderMethod0? = null,
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+) : Structure() {
+    class UniffiByValue(
+        `embed`: UniffiCallbackInterfaceReembedderMethod0? = null,
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    ): UniffiVTableCallbackInterfaceReembedder(`embed`,`uniffiFree`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceReembedder) {
+        `embed` = other.`embed`
+        `uniffiFree` = other.`uniffiFree`
+    }
+
+}
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
 
 
 
@@ -745,6 +923,8 @@ internal interface UniffiLib : Library {
             .also { lib: UniffiLib ->
                 uniffiCheckContractApiVersion(lib)
                 uniffiCheckApiChecksums(lib)
+                uniffiCallbackInterfaceProgressReporter.register(lib)
+                uniffiCallbackInterfaceReembedder.register(lib)
                 }
         }
         
@@ -760,18 +940,160 @@ internal interface UniffiLib : Library {
     ): Unit
     fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open(`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,
     ): Long
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_memory(`embeddingDim`: Int,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_read_only(`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_scoped(`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,`agentId`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_columns(`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,`extraColumns`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_options(`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_centroid(`ptr`: Pointer,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_cleanup_old_versions(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`olderThanSeconds`: Long,
+    ): Long
     fun uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
     ): Long
-    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(`ptr`: Pointer,`key`: RustBuffer.ByValue,
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_clear_all(`ptr`: Pointer,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_close_handle(`ptr`: Pointer,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_copy_collection(`ptr`: Pointer,`from`: RustBuffer.ByValue,`to`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_count(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`filter`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_counts_by_agent(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_create_index(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`numPartitions`: RustBuffer.ByValue,`numSubVectors`: RustBuffer.ByValue,`progress`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_create_scalar_index(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`column`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_by_agent(`ptr`: Pointer,`agentId`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_many(`ptr`: Pointer,`keys`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_where(`ptr`: Pointer,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,`dryRun`: Byte,`deleteAll`: Byte,`maintainIndex`: Byte,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_describe(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_drop_index(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`column`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_exists(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_explain_search(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_export_json(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_export_parquet(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`outPath`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_flush(`ptr`: Pointer,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_get(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,`includeEmbedding`: Byte,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_get_many(`ptr`: Pointer,`keys`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_health_check(`ptr`: Pointer,
     ): Long
     fun uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`queryText`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`rrfK`: RustBuffer.ByValue,`vectorLimit`: RustBuffer.ByValue,
     ): Long
-    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_list(`ptr`: Pointer,`prefix`: RustBuffer.ByValue,`limit`: RustBuffer.ByValue,
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_import_arrow_file(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`path`: RustBuffer.ByValue,`upsert`: Byte,`progress`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_import_json(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`json`: RustBuffer.ByValue,`mode`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_import_parquet(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`inPath`: RustBuffer.ByValue,`mode`: RustBuffer.ByValue,`progress`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_index_info(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_list(`ptr`: Pointer,`prefix`: RustBuffer.ByValue,`limit`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_list_agents(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_list_detailed(`ptr`: Pointer,`prefix`: RustBuffer.ByValue,`limit`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_list_versions(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_migrate_dimension(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`newDim`: Int,`reembed`: Long,`progress`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_nearest(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_optimize(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_rename_collection(`ptr`: Pointer,`from`: RustBuffer.ByValue,`to`: RustBuffer.ByValue,
     ): Long
-    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_restore(`ptr`: Pointer,`collection`: RustBuffer.ByValue,`version`: Long,
     ): Long
-    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store(`ptr`: Pointer,`key`: RustBuffer.ByValue,`agentId`: RustBuffer.ByValue,`text`: RustBuffer.ByValue,`embedding`: RustBuffer.ByValue,`metadata`: RustBuffer.ByValue,
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_restore_key(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
     ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_binary(`ptr`: Pointer,`code`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_bytes(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_detailed(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_hybrid(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`queryText`: RustBuffer.ByValue,`limit`: Int,`alpha`: Double,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_many(`ptr`: Pointer,`queryVectors`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_multi(`ptr`: Pointer,`collections`: RustBuffer.ByValue,`queryVector`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_paged(`ptr`: Pointer,`queryVector`: RustBuffer.ByValue,`limit`: Int,`offset`: Int,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_similar(`ptr`: Pointer,`key`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`excludeSelf`: Byte,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_search_text(`ptr`: Pointer,`query`: RustBuffer.ByValue,`limit`: Int,`filter`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_soft_delete(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_stats(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store(`ptr`: Pointer,`key`: RustBuffer.ByValue,`agentId`: RustBuffer.ByValue,`text`: RustBuffer.ByValue,`embedding`: RustBuffer.ByValue,`metadata`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store_binary(`ptr`: Pointer,`key`: RustBuffer.ByValue,`agentId`: RustBuffer.ByValue,`text`: RustBuffer.ByValue,`code`: RustBuffer.ByValue,`metadata`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store_bytes(`ptr`: Pointer,`key`: RustBuffer.ByValue,`agentId`: RustBuffer.ByValue,`text`: RustBuffer.ByValue,`embedding`: RustBuffer.ByValue,`metadata`: RustBuffer.ByValue,`options`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store_many(`ptr`: Pointer,`entries`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,`chunkSize`: RustBuffer.ByValue,`progress`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_store_with_meta(`ptr`: Pointer,`key`: RustBuffer.ByValue,`agentId`: RustBuffer.ByValue,`text`: RustBuffer.ByValue,`embedding`: RustBuffer.ByValue,`meta`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_touch(`ptr`: Pointer,`key`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_update_embeddings(`ptr`: Pointer,`updates`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_update_metadata(`ptr`: Pointer,`key`: RustBuffer.ByValue,`metadata`: RustBuffer.ByValue,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbhandle_verify(`ptr`: Pointer,`collection`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_clone_lancedbmanager(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Pointer
+    fun uniffi_lancedb_ffi_fn_free_lancedbmanager(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_lancedb_ffi_fn_constructor_lancedbmanager_new(uniffi_out_err: UniffiRustCallStatus, 
+    ): Pointer
+    fun uniffi_lancedb_ffi_fn_method_lancedbmanager_close(`ptr`: Pointer,`id`: RustBuffer.ByValue,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbmanager_close_all(`ptr`: Pointer,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_method_lancedbmanager_get(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_lancedb_ffi_fn_method_lancedbmanager_ids(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_lancedb_ffi_fn_method_lancedbmanager_open(`ptr`: Pointer,`id`: RustBuffer.ByValue,`dbPath`: RustBuffer.ByValue,`embeddingDim`: Int,
+    ): Long
+    fun uniffi_lancedb_ffi_fn_init_callback_vtable_progressreporter(`vtable`: UniffiVTableCallbackInterfaceProgressReporter,
+    ): Unit
+    fun uniffi_lancedb_ffi_fn_init_callback_vtable_reembedder(`vtable`: UniffiVTableCallbackInterfaceReembedder,
+    ): Unit
+    fun uniffi_lancedb_ffi_fn_func_lancedb_version(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_lancedb_ffi_fn_func_meta_filter_sql(`filters`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun ffi_lancedb_ffi_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
     fun ffi_lancedb_ffi_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
@@ -884,19 +1206,157 @@ internal interface UniffiLib : Library {
     ): Unit
     fun ffi_lancedb_ffi_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_lancedb_ffi_checksum_func_lancedb_version(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_func_meta_filter_sql(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_centroid(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_cleanup_old_versions(
+    ): Short
     fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear(
     ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear_all(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_close_handle(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_copy_collection(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_count(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_counts_by_agent(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_index(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_scalar_index(
+    ): Short
     fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete(
     ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_by_agent(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_many(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_where(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_describe(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_drop_index(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_exists(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_explain_search(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_json(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_parquet(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_flush(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_get(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_get_many(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_health_check(
+    ): Short
     fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_hybrid_search(
     ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_arrow_file(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_json(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_parquet(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_index_info(
+    ): Short
     fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_list(
     ): Short
-    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search(
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_agents(
     ): Short
-    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store(
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_detailed(
     ): Short
-    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open(
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_versions(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_migrate_dimension(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_nearest(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_optimize(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_rename_collection(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore_key(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_binary(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_bytes(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_detailed(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_hybrid(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_many(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_multi(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_paged(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_similar(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_text(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_soft_delete(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_stats(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_binary(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_bytes(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_many(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_with_meta(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_touch(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_embeddings(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_metadata(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbhandle_verify(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbmanager_close(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbmanager_close_all(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbmanager_get(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbmanager_ids(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_lancedbmanager_open(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_memory(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_read_only(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_scoped(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_columns(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_options(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_constructor_lancedbmanager_new(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_progressreporter_on_progress(
+    ): Short
+    fun uniffi_lancedb_ffi_checksum_method_reembedder_embed(
     ): Short
     fun ffi_lancedb_ffi_uniffi_contract_version(
     ): Int
@@ -915,25 +1375,232 @@ private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
 
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: UniffiLib) {
+    if (lib.uniffi_lancedb_ffi_checksum_func_lancedb_version() != 8508.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_func_meta_filter_sql() != 25967.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_centroid() != 13849.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_cleanup_old_versions() != 62346.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear() != 62802.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete() != 18738.toShort()) {
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear_all() != 40720.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_close_handle() != 43058.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_copy_collection() != 20394.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_count() != 41206.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_counts_by_agent() != 13099.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_index() != 47246.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_scalar_index() != 8198.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete() != 51313.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_by_agent() != 30348.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_many() != 24190.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_where() != 7246.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_describe() != 51022.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_drop_index() != 22298.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_exists() != 39757.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_explain_search() != 40581.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_json() != 60901.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_parquet() != 35510.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_flush() != 13475.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_get() != 36780.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_get_many() != 60650.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_health_check() != 35972.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_hybrid_search() != 51031.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_list() != 30004.toShort()) {
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_arrow_file() != 1893.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_json() != 26566.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_parquet() != 38027.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_index_info() != 46571.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_list() != 48228.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_agents() != 5983.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_detailed() != 25291.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_versions() != 57995.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_migrate_dimension() != 23958.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_nearest() != 18440.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_optimize() != 17977.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_rename_collection() != 39069.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore() != 28753.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore_key() != 22086.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search() != 30283.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_binary() != 40342.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_bytes() != 22944.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_detailed() != 533.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_hybrid() != 43508.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search() != 42957.toShort()) {
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_many() != 62245.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store() != 43577.toShort()) {
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_multi() != 59773.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open() != 20925.toShort()) {
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_paged() != 27549.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_similar() != 18702.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_text() != 19530.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_soft_delete() != 53334.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_stats() != 6880.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store() != 32837.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_binary() != 51332.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_bytes() != 61032.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_many() != 31302.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_with_meta() != 50419.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_touch() != 43871.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_embeddings() != 56752.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_metadata() != 63496.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbhandle_verify() != 11124.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbmanager_close() != 2415.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbmanager_close_all() != 2264.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbmanager_get() != 49138.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbmanager_ids() != 13633.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_lancedbmanager_open() != 53275.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open() != 39229.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_memory() != 5971.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_read_only() != 3901.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_scoped() != 25237.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_columns() != 34491.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_options() != 59604.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_constructor_lancedbmanager_new() != 12261.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_progressreporter_on_progress() != 693.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_lancedb_ffi_checksum_method_reembedder_embed() != 11632.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
 }
@@ -1069,6 +1736,52 @@ public object FfiConverterInt: FfiConverter<Int, Int> {
     }
 }
 
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterLong: FfiConverter<Long, Long> {
+    override fun lift(value: Long): Long {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Long {
+        return buf.getLong()
+    }
+
+    override fun lower(value: Long): Long {
+        return value
+    }
+
+    override fun allocationSize(value: Long) = 8UL
+
+    override fun write(value: Long, buf: ByteBuffer) {
+        buf.putLong(value)
+    }
+}
+
 /**
  * @suppress
  */
@@ -1115,6 +1828,29 @@ public object FfiConverterDouble: FfiConverter<Double, Double> {
     }
 }
 
+/**
+ * @suppress
+ */
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
 /**
  * @suppress
  */
@@ -1172,6 +1908,25 @@ public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
     }
 }
 
+/**
+ * @suppress
+ */
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
 
 // This template implements a class for working with a Rust struct via a Pointer/Arc<T>
 // to the live Rust struct on the other side of the FFI.
@@ -1336,49 +2091,513 @@ private class JavaLangRefCleanable(
 }
 public interface LanceDbHandleInterface {
     
+    /**
+     * Mean of the embeddings in `collection` matching `filter` (all rows when
+     * `None`), including staged entries and leaving out soft-deleted ones.
+     * Errors when no rows match, since the centroid is then undefined.
+     */
+    suspend fun `centroid`(`filter`: kotlin.String?, `collection`: kotlin.String?): List<kotlin.Float>
+    
+    /**
+     * Delete versions of `collection` older than `older_than_seconds`, and
+     * the files only they reference, to reclaim disk. The latest version is
+     * always kept, whatever its age, and pruned versions can no longer be
+     * read or restored. A missing collection removes nothing.
+     */
+    suspend fun `cleanupOldVersions`(`collection`: kotlin.String?, `olderThanSeconds`: kotlin.ULong): CleanupStats
+    
     /**
      * Drop all data. If `collection` is None, drops the default table.
      */
     suspend fun `clear`(`collection`: kotlin.String?)
     
     /**
-     * Delete a memory entry by key.
+     * Drop every collection, including staged entries. A no-op on an empty
+     * database.
      */
-    suspend fun `delete`(`key`: kotlin.String)
+    suspend fun `clearAll`()
     
     /**
-     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
-     * `query_vector`: embedding for ANN search.
-     * `query_text`: text query for BM25 scoring.
-     * `limit`: number of results to return.
-     * `filter`: optional SQL-like predicate.
-     * `rrf_k`: RRF constant (default 60).
-     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     * Flush staged entries, then release the connection and cached tables so
+     * their file handles close. Every later call on this handle, including
+     * another `close`, fails with `ConnectionFailed`. If the flush fails the
+     * handle stays open and the error is returned. Exported as
+     * `closeHandle`, since Kotlin bindings already give every object a
+     * `close()` that frees it.
      */
-    suspend fun `hybridSearch`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `rrfK`: kotlin.UInt?, `vectorLimit`: kotlin.UInt?): List<HybridSearchResult>
+    suspend fun `closeHandle`()
     
     /**
-     * List memory keys, optionally filtered by prefix.
+     * Copy every row of collection `from` into a new collection `to`, with
+     * the same schema. Staged entries are flushed first so they are copied.
+     * `from` must exist and `to` must not (`TableError` otherwise).
      */
-    suspend fun `list`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?): List<kotlin.String>
+    suspend fun `copyCollection`(`from`: kotlin.String, `to`: kotlin.String)
     
     /**
-     * Search for the `limit` nearest neighbours to `query_vector`.
-     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     * Number of rows in `collection` matching `filter` (all rows when `None`),
+     * including staged entries and leaving out soft-deleted ones. Returns 0
+     * when the collection doesn't exist.
      */
-    suspend fun `search`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?): List<SearchResult>
+    suspend fun `count`(`collection`: kotlin.String?, `filter`: kotlin.String?): kotlin.ULong
     
     /**
-     * Store a memory entry. Overwrites if `key` already exists.
+     * Entries per `agent_id` in `collection`, including staged entries,
+     * sorted by `agent_id`. Empty when the collection doesn't exist.
      */
-    suspend fun `store`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `metadata`: kotlin.String?)
+    suspend fun `countsByAgent`(`collection`: kotlin.String?): List<AgentCount>
     
-    companion object
-}
-
-open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
-
-    constructor(pointer: Pointer) {
+    /**
+     * Build an IVF_PQ index on `embedding` in `collection`, using the handle's
+     * distance metric. Omitted parameters default to `sqrt(rows)` partitions
+     * and `embedding_dim / 16` sub-vectors (or the next value that divides
+     * the dimension). PQ training needs at least 256 rows. A no-op when the
+     * collection already has a vector index or doesn't exist. IVF_PQ only
+     * indexes float embeddings, so a handle opened with
+     * `EmbeddingDtype::Int8` or `EmbeddingDtype::Binary` gets a
+     * `SchemaError`; its searches scan every row instead. The engine
+     * reports no progress while training, so `progress` only hears (0, 1)
+     * when the build starts and (1, 1) once it is done.
+     */
+    suspend fun `createIndex`(`collection`: kotlin.String?, `numPartitions`: kotlin.UInt?, `numSubVectors`: kotlin.UInt?, `progress`: ProgressReporter?)
+    
+    /**
+     * Build a BTree index on the scalar `column` of `collection` (e.g.
+     * `key`, `agent_id` or `created_at`), so equality and range filters on
+     * it, such as the `key = ...` lookups behind `get`, `exists`, `delete`
+     * and upserts, no longer scan every row. Optional: every method works
+     * without it. `optimize` keeps it up to date with later writes. A no-op
+     * when the index already exists or the collection doesn't; an unknown
+     * or non-scalar column is a `SchemaError`.
+     */
+    suspend fun `createScalarIndex`(`collection`: kotlin.String?, `column`: kotlin.String)
+    
+    /**
+     * Delete a memory entry by key from `collection`.
+     */
+    suspend fun `delete`(`key`: kotlin.String, `collection`: kotlin.String?)
+    
+    /**
+     * Delete every entry stored by `agent_id` in `collection` and return how
+     * many were removed. No-op returning 0 when the collection doesn't exist.
+     */
+    suspend fun `deleteByAgent`(`agentId`: kotlin.String, `collection`: kotlin.String?): kotlin.ULong
+    
+    /**
+     * Delete the entries for `keys` from `collection` with a single commit and
+     * return how many rows were removed. Staged entries are flushed first.
+     * Keys that aren't stored are ignored, and a missing collection is a
+     * no-op returning 0.
+     */
+    suspend fun `deleteMany`(`keys`: List<kotlin.String>, `collection`: kotlin.String?): kotlin.ULong
+    
+    /**
+     * Delete every row in `collection` matching the SQL predicate `filter`
+     * (e.g. `"created_at < 1700000000000 AND agent_id = 'old'"`) and return
+     * how many rows matched. The predicate is checked against the table schema
+     * before anything is deleted, and `dry_run` stops after counting.
+     * An empty filter is refused unless `delete_all` is set, so a missing
+     * predicate can't wipe the table by accident. Staged entries are flushed
+     * first so the predicate sees them.
+     *
+     * Deleted rows stay in any index until it is rebuilt, only masked at query
+     * time. `maintain_index` fixes that incrementally after the delete: data
+     * files holding deleted rows are rewritten without them, existing indexes
+     * are remapped to the rewritten rows (no retraining), and remaining
+     * unindexed rows are added. The cost is proportional to the affected
+     * files, not the table. A full index rebuild is still preferable once a
+     * large share of the table has changed, since remapping keeps the
+     * original partitioning. No-op when the table has no index.
+     */
+    suspend fun `deleteWhere`(`filter`: kotlin.String, `collection`: kotlin.String?, `dryRun`: kotlin.Boolean, `deleteAll`: kotlin.Boolean, `maintainIndex`: kotlin.Boolean): kotlin.ULong
+    
+    /**
+     * The columns of `collection` as stored, which may differ from the
+     * handle's own schema (e.g. a database created with another
+     * `embedding_dim`). A missing collection is a `TableError`.
+     */
+    suspend fun `describe`(`collection`: kotlin.String?): SchemaInfo
+    
+    /**
+     * Drop every index on `column` (default `embedding`) in `collection`, e.g.
+     * to rebuild it with other parameters or to go back to flat scans. The
+     * index files stay on disk until the next `optimize`. A no-op when the
+     * column has no index or the collection doesn't exist.
+     */
+    suspend fun `dropIndex`(`collection`: kotlin.String?, `column`: kotlin.String?)
+    
+    /**
+     * Whether `collection` holds an entry for `key`, including a staged one,
+     * without reading the row. False when the collection doesn't exist or the
+     * entry is soft-deleted.
+     */
+    suspend fun `exists`(`key`: kotlin.String, `collection`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * The query plan `search` would run for these arguments with default
+     * options, for checking whether a slow search used the vector index or
+     * fell back to a flat scan. Nothing is executed, and staged entries
+     * (merged in memory) do not appear in the plan.
+     */
+    suspend fun `explainSearch`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?): kotlin.String
+    
+    /**
+     * Every live row of `collection`, staged entries included, as a JSON
+     * array of objects with `key`, `agent_id`, `text`, `embedding` (an array
+     * of floats), `metadata` (the stored string or null), `created_at` and,
+     * on tagged tables, `tags`, plus `embedding2` (null when unset) on
+     * collections with a second vector column, and on handles opened with
+     * extra columns a `columns` object of each set value by name. Meant for
+     * debugging and small datasets; use
+     * `export_parquet` for backups. A missing collection gives `[]`.
+     */
+    suspend fun `exportJson`(`collection`: kotlin.String?): kotlin.String
+    
+    /**
+     * Write every row of `collection`, with all of its columns, to a Parquet
+     * file at `out_path`, replacing any file already there. Rows are streamed
+     * a batch at a time. Staged entries are flushed first; a missing
+     * collection writes an empty file with the handle's schema. Returns the
+     * number of rows written.
+     */
+    suspend fun `exportParquet`(`collection`: kotlin.String?, `outPath`: kotlin.String): kotlin.ULong
+    
+    /**
+     * Write all staged entries to the table as a single batch.
+     * No-op when staging is disabled or nothing is pending.
+     */
+    suspend fun `flush`()
+    
+    /**
+     * Fetch the stored entry for `key` in `collection`, including a staged
+     * one unless `options.committed_only` is set, or `None`. A soft-deleted
+     * entry is `None`.
+     * There is no query vector, so `score` is 0 and `distance` is `None`.
+     * `include_embedding` also returns the stored embedding.
+     */
+    suspend fun `get`(`key`: kotlin.String, `collection`: kotlin.String?, `includeEmbedding`: kotlin.Boolean, `options`: GetOptions? = null): SearchResult?
+    
+    /**
+     * `get` for several keys with a single scan. Results follow the order of
+     * `keys`; missing and soft-deleted keys are left out and repeated keys
+     * returned once.
+     */
+    suspend fun `getMany`(`keys`: List<kotlin.String>, `collection`: kotlin.String?, `options`: GetOptions? = null): List<SearchResult>
+    
+    /**
+     * Cheap probe that the database can be reached: connects and lists the
+     * collections. A failed (or closed) connection is reported as
+     * `can_connect: false` rather than an error; an empty database is healthy.
+     */
+    suspend fun `healthCheck`(): HealthStatus
+    
+    /**
+     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
+     * `query_vector`: embedding for ANN search.
+     * `query_text`: text query for BM25 scoring.
+     * `limit`: number of results to return.
+     * `filter`: optional SQL-like predicate.
+     * `rrf_k`: RRF constant (default 60).
+     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     */
+    suspend fun `hybridSearch`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `rrfK`: kotlin.UInt?, `vectorLimit`: kotlin.UInt?): List<HybridSearchResult>
+    
+    /**
+     * Stream the Arrow IPC file at `path` into `collection` one record batch
+     * at a time, so the file never has to fit in memory. The file schema is
+     * checked once against the table (or, for a new table, the handle's
+     * schema) before anything is written. With `upsert`, rows replace
+     * existing rows with the same `key`; otherwise they are appended.
+     * Each batch is its own commit: if one fails, the error names it and the
+     * batches before it stay imported. `progress` is called with
+     * (batches done, total batches). Returns the number of rows imported.
+     */
+    suspend fun `importArrowFile`(`collection`: kotlin.String?, `path`: kotlin.String, `upsert`: kotlin.Boolean, `progress`: ProgressReporter?): kotlin.ULong
+    
+    /**
+     * Load records in the format of `export_json` into `collection` with
+     * `store_many`, so `Append` upserts by key. `columns` values are read
+     * as the types of the handle's extra columns. `Overwrite` replaces the
+     * collection's rows, and any of its staged entries, with the records in
+     * a single commit once every record has passed `store_many`'s checks; a
+     * bad record leaves the collection untouched. `Overwrite` is not
+     * available on an agent-scoped handle. Returns the number of rows
+     * written.
+     */
+    suspend fun `importJson`(`collection`: kotlin.String?, `json`: kotlin.String, `mode`: ImportMode): kotlin.ULong
+    
+    /**
+     * Load the rows of the Parquet file at `in_path` (as written by
+     * `export_parquet`) into `collection` in a single commit. `Append`
+     * upserts by key, as `import_json` does, except on an append-only handle,
+     * where rows are added as they are. The file schema
+     * must match the table's for `Append` to an existing collection, and the
+     * handle's (including `embedding_dim`) otherwise; a mismatch is a
+     * `SchemaError` and nothing is written. Rows are read
+     * `DEFAULT_CHUNK_ROWS` at a time and `progress` is called with (rows
+     * read, total rows) after each chunk. Returns the number of rows
+     * imported.
+     */
+    suspend fun `importParquet`(`collection`: kotlin.String?, `inPath`: kotlin.String, `mode`: ImportMode, `progress`: ProgressReporter?): kotlin.ULong
+    
+    /**
+     * The indexes of `collection`, sorted by column, with how many rows each
+     * covers. Empty when the collection has none or doesn't exist.
+     */
+    suspend fun `indexInfo`(`collection`: kotlin.String?): List<IndexInfo>
+    
+    /**
+     * List memory keys in `options.collection`, optionally filtered by
+     * prefix, returning at most `limit`. See `ListOptions` for paging, time
+     * windows, versions and ordering. Soft-deleted keys are left out unless
+     * `options.include_deleted` is set.
+     */
+    suspend fun `list`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?, `options`: ListOptions? = null): List<kotlin.String>
+    
+    /**
+     * Distinct `agent_id`s with at least one entry in `collection`, including
+     * staged entries, sorted. Empty when the collection doesn't exist.
+     */
+    suspend fun `listAgents`(`collection`: kotlin.String?): List<kotlin.String>
+    
+    /**
+     * Like `list`, but returns each entry's text, metadata and timestamps as
+     * `SearchResult`s. There is no query vector, so `score` is 0 and
+     * `distance` is None. `options` are as for `list`.
+     */
+    suspend fun `listDetailed`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?, `options`: ListOptions? = null): List<SearchResult>
+    
+    /**
+     * Committed versions of `collection`, oldest first. Every write creates
+     * one; pass its number as `version` to `search` or `list` to read the
+     * collection as it was then. A missing collection has no versions.
+     */
+    suspend fun `listVersions`(`collection`: kotlin.String?): List<VersionInfo>
+    
+    /**
+     * Re-embed every row of `collection` with `reembed` and rewrite it at
+     * `new_dim`, e.g. after switching embedding models. Rows are streamed
+     * batch by batch into a single overwrite commit, so the new table is
+     * swapped in atomically and a failure part-way leaves the old one intact.
+     * `progress` is called with (rows done, total rows) after each batch.
+     * Indexes are not carried over. The handle keeps its original
+     * `embedding_dim`; reopen with `new_dim` to use the migrated collection.
+     * Returns the number of rows migrated.
+     */
+    suspend fun `migrateDimension`(`collection`: kotlin.String?, `newDim`: kotlin.Int, `reembed`: Reembedder, `progress`: ProgressReporter?): kotlin.ULong
+    
+    /**
+     * The single nearest neighbour to `query_vector` in `collection`, or
+     * `None` when nothing matches `filter` or the collection doesn't exist.
+     * A cheaper `search` with `limit = 1` that decodes only the winning row.
+     */
+    suspend fun `nearest`(`queryVector`: List<kotlin.Float>, `filter`: kotlin.String?, `collection`: kotlin.String?): SearchResult?
+    
+    /**
+     * Compact `collection`: merge small data files and rewrite away rows
+     * that upserts and deletes left behind, then bring any vector or text
+     * index up to date with the rewritten rows and any rows written since.
+     * Old versions are kept, so earlier versions remain readable. No-op when
+     * the collection doesn't exist or has nothing to compact.
+     */
+    suspend fun `optimize`(`collection`: kotlin.String?)
+    
+    /**
+     * Rename collection `from` to `to`. The engine has no native rename for
+     * local databases, so this is `copy_collection` followed by dropping
+     * `from`; large collections take a while and briefly need twice the disk.
+     */
+    suspend fun `renameCollection`(`from`: kotlin.String, `to`: kotlin.String)
+    
+    /**
+     * Roll `collection` back to `version` by committing a copy of it as the
+     * new latest version; later versions stay listed and can be restored in
+     * turn. Entries still staged are kept and land on top at the next flush.
+     * A missing collection or unknown version is a `TableError`.
+     */
+    suspend fun `restore`(`collection`: kotlin.String?, `version`: kotlin.ULong)
+    
+    /**
+     * Undo `soft_delete` for `key` in `collection`. Returns whether a
+     * soft-deleted entry matched.
+     */
+    suspend fun `restoreKey`(`key`: kotlin.String, `collection`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * Search `options.collection` (default `"memories"`) for the `limit`
+     * nearest neighbours to `query_vector`.
+     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     */
+    suspend fun `search`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions? = null): List<SearchResult>
+    
+    /**
+     * `search` by Hamming distance to the packed binary `code` (see
+     * `store_binary`); the nearest codes come first.
+     */
+    suspend fun `searchBinary`(`code`: kotlin.ByteArray, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?): List<SearchResult>
+    
+    /**
+     * `search` with `query_vector` passed as little-endian f32 bytes (see
+     * `store_bytes`): `embedding_dim * 4` bytes (`embedding2_dim * 4` when
+     * searching `embedding2`), or any non-empty multiple of 4 up to that
+     * with `prefix_search`.
+     */
+    suspend fun `searchBytes`(`queryVector`: kotlin.ByteArray, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?): List<SearchResult>
+    
+    /**
+     * Like `search`, but also reports whether the results are approximate.
+     */
+    suspend fun `searchDetailed`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?): SearchResponse
+    
+    /**
+     * Blend `search` and `search_text` over `collection`. Each side fetches
+     * `limit * 4` candidates and its scores are min-max normalised to
+     * `[0, 1]` (a side whose candidates all score alike gives them 1). The
+     * fused score is a weighted sum, `alpha * vector + (1 - alpha) * text`,
+     * with 0 for a side that didn't return the key, so `alpha = 1` ranks by
+     * vector similarity only and `alpha = 0` by keyword relevance only.
+     * Results are unique by key, best first, with `score` set to the fused
+     * value and `distance` kept from the vector side.
+     */
+    suspend fun `searchHybrid`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `alpha`: kotlin.Double, `collection`: kotlin.String?): List<SearchResult>
+    
+    /**
+     * Run `search` for each of `query_vectors` and return one result list
+     * per query, in input order. Every vector is validated before any query
+     * runs, and the queries share one connection and one snapshot of the
+     * staged entries.
+     */
+    suspend fun `searchMany`(`queryVectors`: List<List<kotlin.Float>>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?): List<List<SearchResult>>
+    
+    /**
+     * `search` over each of `collections`, merged into one list of the
+     * global top `limit` by score. `SearchResult::collection` tells which
+     * collection a result came from. Collections that don't exist, and
+     * repeats in `collections`, are skipped.
+     */
+    suspend fun `searchMulti`(`collections`: List<kotlin.String>, `queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?): List<SearchResult>
+    
+    /**
+     * The page of `limit` results after the nearest `offset`, plus the
+     * number of rows matching `filter`, for rendering page controls.
+     * `total_matching` is an exact count of the filtered rows, staged entries
+     * included. Without a vector index, paging through it reaches every
+     * one; with an index, each page is cut from an approximate ranking
+     * (see `SearchOptions::offset`) and can't be relied on to reach all
+     * `total_matching` rows.
+     */
+    suspend fun `searchPaged`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `offset`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?): SearchPage
+    
+    /**
+     * `search` using the stored embedding of `key` as the query, i.e. "more
+     * like this". `exclude_self` leaves `key` itself out of the results.
+     * A missing `key` is a `QueryError`.
+     */
+    suspend fun `searchSimilar`(`key`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `excludeSelf`: kotlin.Boolean, `collection`: kotlin.String?): List<SearchResult>
+    
+    /**
+     * Keyword search over `text` in `collection` using the engine's
+     * full-text (BM25) index, which is built on the first call. `score` is
+     * the BM25 relevance and `distance` is `None`. Only committed rows that
+     * aren't soft-deleted are searched; rows written after the index was built are still matched by
+     * a slower unindexed pass until `optimize` indexes them. Returns no
+     * results when the collection doesn't exist.
+     */
+    suspend fun `searchText`(`query`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?): List<SearchResult>
+    
+    /**
+     * Move `key` in `collection` to the trash: `search` and `list` skip it
+     * until `restore_key` (or storing the key again) brings it back. Staged
+     * entries are flushed first. Returns whether a live entry matched.
+     */
+    suspend fun `softDelete`(`key`: kotlin.String, `collection`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * Row count, version history and on-disk size of `collection` in one
+     * call. A missing collection reports all zeros.
+     */
+    suspend fun `stats`(`collection`: kotlin.String?): CollectionStats
+    
+    /**
+     * Store a memory entry in `options.collection` (default `"memories"`).
+     * Overwrites if `key` already exists, unless the handle is append-only.
+     * With staging enabled, entries for the default collection are buffered
+     * until the next flush and the returned stats are zero. Passing `None`
+     * for `options` uses the defaults (see `StoreOptions`).
+     */
+    suspend fun `store`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `metadata`: kotlin.String?, `options`: StoreOptions? = null): WriteStats
+    
+    /**
+     * `store` for a handle opened with `EmbeddingDtype::Binary`, taking the
+     * packed code as `embedding_dim` bytes.
+     */
+    suspend fun `storeBinary`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `code`: kotlin.ByteArray, `metadata`: kotlin.String?, `collection`: kotlin.String?): WriteStats
+    
+    /**
+     * `store` with the embedding passed as `embedding_dim * 4` bytes of
+     * little-endian f32, which crosses the FFI boundary far more cheaply
+     * than a list of floats. `options` are as for `store`.
+     */
+    suspend fun `storeBytes`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: kotlin.ByteArray, `metadata`: kotlin.String?, `options`: StoreOptions? = null): WriteStats
+    
+    /**
+     * Store many entries with one upsert: every embedding is validated first
+     * and the rows are written in a single commit, so a bad entry fails the
+     * whole call and nothing is written. When a key repeats within `entries`
+     * the last occurrence wins. Staged entries are flushed first and the
+     * batch bypasses staging. The rows are converted and written
+     * `chunk_size` at a time (default `DEFAULT_CHUNK_ROWS`) rather than as
+     * one large record batch. `progress` is called with (rows handed to the
+     * engine, total rows) once per chunk; it starts over if a conflicting
+     * commit is retried. Returns the number of rows written.
+     */
+    suspend fun `storeMany`(`entries`: List<MemoryEntry>, `collection`: kotlin.String?, `chunkSize`: kotlin.UInt?, `progress`: ProgressReporter?): kotlin.ULong
+    
+    /**
+     * `store` with `meta` written to `metadata` as a JSON object of strings,
+     * so it reads back as `SearchResult::meta`. When a key repeats, the last
+     * pair wins.
+     */
+    suspend fun `storeWithMeta`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `meta`: List<MetaPair>, `collection`: kotlin.String?): WriteStats
+    
+    /**
+     * Mark `key` in `collection` as just used by setting its `created_at` to
+     * now, without rewriting its text or embedding, so apps can evict
+     * least-recently-used entries by `created_at`. A staged entry is updated
+     * in place. Returns whether an entry matched.
+     */
+    suspend fun `touch`(`key`: kotlin.String, `collection`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * Overwrite the `embedding` of each listed key in `collection`, e.g. after
+     * switching embedding models, leaving text, metadata and timestamps
+     * untouched. Every vector is checked before anything is written, keys
+     * not stored are skipped, and a key listed twice takes its last vector.
+     * Returns how many entries were updated.
+     */
+    suspend fun `updateEmbeddings`(`updates`: List<KeyEmbedding>, `collection`: kotlin.String?): kotlin.ULong
+    
+    /**
+     * Set only the `metadata` of the entry stored under `key` in
+     * `collection`, leaving its text, embedding and timestamp untouched.
+     * A staged entry is updated in place. Returns whether an entry matched.
+     */
+    suspend fun `updateMetadata`(`key`: kotlin.String, `metadata`: kotlin.String?, `collection`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * Audit `collection` for rows sharing a `key`, which concurrent upserts
+     * can leave behind. Read-only: only the `key` column of committed rows is
+     * scanned, and a missing collection reports zero rows. An agent-scoped
+     * handle audits only its agent's rows.
+     */
+    suspend fun `verify`(`collection`: kotlin.String?): VerifyReport
+    
+    companion object
+}
+
+open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
+
+    constructor(pointer: Pointer) {
         this.pointer = pointer
         this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
     }
@@ -1459,24 +2678,25 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * Drop all data. If `collection` is None, drops the default table.
+     * Mean of the embeddings in `collection` matching `filter` (all rows when
+     * `None`), including staged entries and leaving out soft-deleted ones.
+     * Errors when no rows match, since the centroid is then undefined.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `clear`(`collection`: kotlin.String?) {
+    override suspend fun `centroid`(`filter`: kotlin.String?, `collection`: kotlin.String?) : List<kotlin.Float> {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_centroid(
                 thisPtr,
-                FfiConverterOptionalString.lower(`collection`),
+                FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),
             )
         },
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
-        
+        { FfiConverterSequenceFloat.lift(it) },
         // Error FFI converter
         LanceException.ErrorHandler,
     )
@@ -1484,24 +2704,26 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * Delete a memory entry by key.
+     * Delete versions of `collection` older than `older_than_seconds`, and
+     * the files only they reference, to reclaim disk. The latest version is
+     * always kept, whatever its age, and pruned versions can no longer be
+     * read or restored. A missing collection removes nothing.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `delete`(`key`: kotlin.String) {
+    override suspend fun `cleanupOldVersions`(`collection`: kotlin.String?, `olderThanSeconds`: kotlin.ULong) : CleanupStats {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_cleanup_old_versions(
                 thisPtr,
-                FfiConverterString.lower(`key`),
+                FfiConverterOptionalString.lower(`collection`),FfiConverterULong.lower(`olderThanSeconds`),
             )
         },
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
-        
+        { FfiConverterTypeCleanupStats.lift(it) },
         // Error FFI converter
         LanceException.ErrorHandler,
     )
@@ -1509,29 +2731,24 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
-     * `query_vector`: embedding for ANN search.
-     * `query_text`: text query for BM25 scoring.
-     * `limit`: number of results to return.
-     * `filter`: optional SQL-like predicate.
-     * `rrf_k`: RRF constant (default 60).
-     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     * Drop all data. If `collection` is None, drops the default table.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `hybridSearch`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `rrfK`: kotlin.UInt?, `vectorLimit`: kotlin.UInt?) : List<HybridSearchResult> {
+    override suspend fun `clear`(`collection`: kotlin.String?) {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(
                 thisPtr,
-                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterString.lower(`queryText`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalUInt.lower(`rrfK`),FfiConverterOptionalUInt.lower(`vectorLimit`),
+                FfiConverterOptionalString.lower(`collection`),
             )
         },
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
         // lift function
-        { FfiConverterSequenceTypeHybridSearchResult.lift(it) },
+        { Unit },
+        
         // Error FFI converter
         LanceException.ErrorHandler,
     )
@@ -1539,23 +2756,25 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * List memory keys, optionally filtered by prefix.
+     * Drop every collection, including staged entries. A no-op on an empty
+     * database.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `list`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?) : List<kotlin.String> {
+    override suspend fun `clearAll`() {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_list(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_clear_all(
                 thisPtr,
-                FfiConverterOptionalString.lower(`prefix`),FfiConverterOptionalUInt.lower(`limit`),
+                
             )
         },
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
         // lift function
-        { FfiConverterSequenceString.lift(it) },
+        { Unit },
+        
         // Error FFI converter
         LanceException.ErrorHandler,
     )
@@ -1563,24 +2782,29 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * Search for the `limit` nearest neighbours to `query_vector`.
-     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     * Flush staged entries, then release the connection and cached tables so
+     * their file handles close. Every later call on this handle, including
+     * another `close`, fails with `ConnectionFailed`. If the flush fails the
+     * handle stays open and the error is returned. Exported as
+     * `closeHandle`, since Kotlin bindings already give every object a
+     * `close()` that frees it.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `search`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?) : List<SearchResult> {
+    override suspend fun `closeHandle`() {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_close_handle(
                 thisPtr,
-                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),
+                
             )
         },
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
         // lift function
-        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        { Unit },
+        
         // Error FFI converter
         LanceException.ErrorHandler,
     )
@@ -1588,16 +2812,18 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
 
     
     /**
-     * Store a memory entry. Overwrites if `key` already exists.
+     * Copy every row of collection `from` into a new collection `to`, with
+     * the same schema. Staged entries are flushed first so they are copied.
+     * `from` must exist and `to` must not (`TableError` otherwise).
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `store`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `metadata`: kotlin.String?) {
+    override suspend fun `copyCollection`(`from`: kotlin.String, `to`: kotlin.String) {
         return uniffiRustCallAsync(
         callWithPointer { thisPtr ->
-            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store(
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_copy_collection(
                 thisPtr,
-                FfiConverterString.lower(`key`),FfiConverterString.lower(`agentId`),FfiConverterString.lower(`text`),FfiConverterSequenceFloat.lower(`embedding`),FfiConverterOptionalString.lower(`metadata`),
+                FfiConverterString.lower(`from`),FfiConverterString.lower(`to`),
             )
         },
         { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
@@ -1612,312 +2838,5591 @@ open class LanceDbHandle: Disposable, AutoCloseable, LanceDbHandleInterface {
     }
 
     
-
-    
-    companion object {
-        
     /**
-     * Open (or create) a LanceDB database at `db_path`.
-     * `embedding_dim` is the fixed vector dimension (e.g. 1536 for OpenAI ada-002).
+     * Number of rows in `collection` matching `filter` (all rows when `None`),
+     * including staged entries and leaving out soft-deleted ones. Returns 0
+     * when the collection doesn't exist.
      */
     @Throws(LanceException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-     suspend fun `open`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int) : LanceDbHandle {
+    override suspend fun `count`(`collection`: kotlin.String?, `filter`: kotlin.String?) : kotlin.ULong {
         return uniffiRustCallAsync(
-        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),),
-        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
-        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_count(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterOptionalString.lower(`filter`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
         // lift function
-        { FfiConverterTypeLanceDBHandle.lift(it) },
+        { FfiConverterULong.lift(it) },
         // Error FFI converter
         LanceException.ErrorHandler,
     )
     }
 
-        
-    }
     
-}
+    /**
+     * Entries per `agent_id` in `collection`, including staged entries,
+     * sorted by `agent_id`. Empty when the collection doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `countsByAgent`(`collection`: kotlin.String?) : List<AgentCount> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_counts_by_agent(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeAgentCount.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Build an IVF_PQ index on `embedding` in `collection`, using the handle's
+     * distance metric. Omitted parameters default to `sqrt(rows)` partitions
+     * and `embedding_dim / 16` sub-vectors (or the next value that divides
+     * the dimension). PQ training needs at least 256 rows. A no-op when the
+     * collection already has a vector index or doesn't exist. IVF_PQ only
+     * indexes float embeddings, so a handle opened with
+     * `EmbeddingDtype::Int8` or `EmbeddingDtype::Binary` gets a
+     * `SchemaError`; its searches scan every row instead. The engine
+     * reports no progress while training, so `progress` only hears (0, 1)
+     * when the build starts and (1, 1) once it is done.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `createIndex`(`collection`: kotlin.String?, `numPartitions`: kotlin.UInt?, `numSubVectors`: kotlin.UInt?, `progress`: ProgressReporter?) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_create_index(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterOptionalUInt.lower(`numPartitions`),FfiConverterOptionalUInt.lower(`numSubVectors`),FfiConverterOptionalTypeProgressReporter.lower(`progress`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Build a BTree index on the scalar `column` of `collection` (e.g.
+     * `key`, `agent_id` or `created_at`), so equality and range filters on
+     * it, such as the `key = ...` lookups behind `get`, `exists`, `delete`
+     * and upserts, no longer scan every row. Optional: every method works
+     * without it. `optimize` keeps it up to date with later writes. A no-op
+     * when the index already exists or the collection doesn't; an unknown
+     * or non-scalar column is a `SchemaError`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `createScalarIndex`(`collection`: kotlin.String?, `column`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_create_scalar_index(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterString.lower(`column`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Delete a memory entry by key from `collection`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `delete`(`key`: kotlin.String, `collection`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Delete every entry stored by `agent_id` in `collection` and return how
+     * many were removed. No-op returning 0 when the collection doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `deleteByAgent`(`agentId`: kotlin.String, `collection`: kotlin.String?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_by_agent(
+                thisPtr,
+                FfiConverterString.lower(`agentId`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Delete the entries for `keys` from `collection` with a single commit and
+     * return how many rows were removed. Staged entries are flushed first.
+     * Keys that aren't stored are ignored, and a missing collection is a
+     * no-op returning 0.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `deleteMany`(`keys`: List<kotlin.String>, `collection`: kotlin.String?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_many(
+                thisPtr,
+                FfiConverterSequenceString.lower(`keys`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Delete every row in `collection` matching the SQL predicate `filter`
+     * (e.g. `"created_at < 1700000000000 AND agent_id = 'old'"`) and return
+     * how many rows matched. The predicate is checked against the table schema
+     * before anything is deleted, and `dry_run` stops after counting.
+     * An empty filter is refused unless `delete_all` is set, so a missing
+     * predicate can't wipe the table by accident. Staged entries are flushed
+     * first so the predicate sees them.
+     *
+     * Deleted rows stay in any index until it is rebuilt, only masked at query
+     * time. `maintain_index` fixes that incrementally after the delete: data
+     * files holding deleted rows are rewritten without them, existing indexes
+     * are remapped to the rewritten rows (no retraining), and remaining
+     * unindexed rows are added. The cost is proportional to the affected
+     * files, not the table. A full index rebuild is still preferable once a
+     * large share of the table has changed, since remapping keeps the
+     * original partitioning. No-op when the table has no index.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `deleteWhere`(`filter`: kotlin.String, `collection`: kotlin.String?, `dryRun`: kotlin.Boolean, `deleteAll`: kotlin.Boolean, `maintainIndex`: kotlin.Boolean) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_where(
+                thisPtr,
+                FfiConverterString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),FfiConverterBoolean.lower(`dryRun`),FfiConverterBoolean.lower(`deleteAll`),FfiConverterBoolean.lower(`maintainIndex`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The columns of `collection` as stored, which may differ from the
+     * handle's own schema (e.g. a database created with another
+     * `embedding_dim`). A missing collection is a `TableError`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `describe`(`collection`: kotlin.String?) : SchemaInfo {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_describe(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeSchemaInfo.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Drop every index on `column` (default `embedding`) in `collection`, e.g.
+     * to rebuild it with other parameters or to go back to flat scans. The
+     * index files stay on disk until the next `optimize`. A no-op when the
+     * column has no index or the collection doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `dropIndex`(`collection`: kotlin.String?, `column`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_drop_index(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterOptionalString.lower(`column`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Whether `collection` holds an entry for `key`, including a staged one,
+     * without reading the row. False when the collection doesn't exist or the
+     * entry is soft-deleted.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `exists`(`key`: kotlin.String, `collection`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_exists(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The query plan `search` would run for these arguments with default
+     * options, for checking whether a slow search used the vector index or
+     * fell back to a flat scan. Nothing is executed, and staged entries
+     * (merged in memory) do not appear in the plan.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `explainSearch`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_explain_search(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Every live row of `collection`, staged entries included, as a JSON
+     * array of objects with `key`, `agent_id`, `text`, `embedding` (an array
+     * of floats), `metadata` (the stored string or null), `created_at` and,
+     * on tagged tables, `tags`, plus `embedding2` (null when unset) on
+     * collections with a second vector column, and on handles opened with
+     * extra columns a `columns` object of each set value by name. Meant for
+     * debugging and small datasets; use
+     * `export_parquet` for backups. A missing collection gives `[]`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `exportJson`(`collection`: kotlin.String?) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_export_json(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Write every row of `collection`, with all of its columns, to a Parquet
+     * file at `out_path`, replacing any file already there. Rows are streamed
+     * a batch at a time. Staged entries are flushed first; a missing
+     * collection writes an empty file with the handle's schema. Returns the
+     * number of rows written.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `exportParquet`(`collection`: kotlin.String?, `outPath`: kotlin.String) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_export_parquet(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterString.lower(`outPath`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Write all staged entries to the table as a single batch.
+     * No-op when staging is disabled or nothing is pending.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `flush`() {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_flush(
+                thisPtr,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Fetch the stored entry for `key` in `collection`, including a staged
+     * one unless `options.committed_only` is set, or `None`. A soft-deleted
+     * entry is `None`.
+     * There is no query vector, so `score` is 0 and `distance` is `None`.
+     * `include_embedding` also returns the stored embedding.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `get`(`key`: kotlin.String, `collection`: kotlin.String?, `includeEmbedding`: kotlin.Boolean, `options`: GetOptions?) : SearchResult? {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_get(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),FfiConverterBoolean.lower(`includeEmbedding`),FfiConverterOptionalTypeGetOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterOptionalTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `get` for several keys with a single scan. Results follow the order of
+     * `keys`; missing and soft-deleted keys are left out and repeated keys
+     * returned once.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `getMany`(`keys`: List<kotlin.String>, `collection`: kotlin.String?, `options`: GetOptions?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_get_many(
+                thisPtr,
+                FfiConverterSequenceString.lower(`keys`),FfiConverterOptionalString.lower(`collection`),FfiConverterOptionalTypeGetOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Cheap probe that the database can be reached: connects and lists the
+     * collections. A failed (or closed) connection is reported as
+     * `can_connect: false` rather than an error; an empty database is healthy.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `healthCheck`() : HealthStatus {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_health_check(
+                thisPtr,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeHealthStatus.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
+     * `query_vector`: embedding for ANN search.
+     * `query_text`: text query for BM25 scoring.
+     * `limit`: number of results to return.
+     * `filter`: optional SQL-like predicate.
+     * `rrf_k`: RRF constant (default 60).
+     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `hybridSearch`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `rrfK`: kotlin.UInt?, `vectorLimit`: kotlin.UInt?) : List<HybridSearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterString.lower(`queryText`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalUInt.lower(`rrfK`),FfiConverterOptionalUInt.lower(`vectorLimit`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeHybridSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Stream the Arrow IPC file at `path` into `collection` one record batch
+     * at a time, so the file never has to fit in memory. The file schema is
+     * checked once against the table (or, for a new table, the handle's
+     * schema) before anything is written. With `upsert`, rows replace
+     * existing rows with the same `key`; otherwise they are appended.
+     * Each batch is its own commit: if one fails, the error names it and the
+     * batches before it stay imported. `progress` is called with
+     * (batches done, total batches). Returns the number of rows imported.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `importArrowFile`(`collection`: kotlin.String?, `path`: kotlin.String, `upsert`: kotlin.Boolean, `progress`: ProgressReporter?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_import_arrow_file(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterString.lower(`path`),FfiConverterBoolean.lower(`upsert`),FfiConverterOptionalTypeProgressReporter.lower(`progress`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Load records in the format of `export_json` into `collection` with
+     * `store_many`, so `Append` upserts by key. `columns` values are read
+     * as the types of the handle's extra columns. `Overwrite` replaces the
+     * collection's rows, and any of its staged entries, with the records in
+     * a single commit once every record has passed `store_many`'s checks; a
+     * bad record leaves the collection untouched. `Overwrite` is not
+     * available on an agent-scoped handle. Returns the number of rows
+     * written.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `importJson`(`collection`: kotlin.String?, `json`: kotlin.String, `mode`: ImportMode) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_import_json(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterString.lower(`json`),FfiConverterTypeImportMode.lower(`mode`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Load the rows of the Parquet file at `in_path` (as written by
+     * `export_parquet`) into `collection` in a single commit. `Append`
+     * upserts by key, as `import_json` does, except on an append-only handle,
+     * where rows are added as they are. The file schema
+     * must match the table's for `Append` to an existing collection, and the
+     * handle's (including `embedding_dim`) otherwise; a mismatch is a
+     * `SchemaError` and nothing is written. Rows are read
+     * `DEFAULT_CHUNK_ROWS` at a time and `progress` is called with (rows
+     * read, total rows) after each chunk. Returns the number of rows
+     * imported.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `importParquet`(`collection`: kotlin.String?, `inPath`: kotlin.String, `mode`: ImportMode, `progress`: ProgressReporter?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_import_parquet(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterString.lower(`inPath`),FfiConverterTypeImportMode.lower(`mode`),FfiConverterOptionalTypeProgressReporter.lower(`progress`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The indexes of `collection`, sorted by column, with how many rows each
+     * covers. Empty when the collection has none or doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `indexInfo`(`collection`: kotlin.String?) : List<IndexInfo> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_index_info(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeIndexInfo.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * List memory keys in `options.collection`, optionally filtered by
+     * prefix, returning at most `limit`. See `ListOptions` for paging, time
+     * windows, versions and ordering. Soft-deleted keys are left out unless
+     * `options.include_deleted` is set.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `list`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?, `options`: ListOptions?) : List<kotlin.String> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_list(
+                thisPtr,
+                FfiConverterOptionalString.lower(`prefix`),FfiConverterOptionalUInt.lower(`limit`),FfiConverterOptionalTypeListOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceString.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Distinct `agent_id`s with at least one entry in `collection`, including
+     * staged entries, sorted. Empty when the collection doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `listAgents`(`collection`: kotlin.String?) : List<kotlin.String> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_list_agents(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceString.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Like `list`, but returns each entry's text, metadata and timestamps as
+     * `SearchResult`s. There is no query vector, so `score` is 0 and
+     * `distance` is None. `options` are as for `list`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `listDetailed`(`prefix`: kotlin.String?, `limit`: kotlin.UInt?, `options`: ListOptions?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_list_detailed(
+                thisPtr,
+                FfiConverterOptionalString.lower(`prefix`),FfiConverterOptionalUInt.lower(`limit`),FfiConverterOptionalTypeListOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Committed versions of `collection`, oldest first. Every write creates
+     * one; pass its number as `version` to `search` or `list` to read the
+     * collection as it was then. A missing collection has no versions.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `listVersions`(`collection`: kotlin.String?) : List<VersionInfo> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_list_versions(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeVersionInfo.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Re-embed every row of `collection` with `reembed` and rewrite it at
+     * `new_dim`, e.g. after switching embedding models. Rows are streamed
+     * batch by batch into a single overwrite commit, so the new table is
+     * swapped in atomically and a failure part-way leaves the old one intact.
+     * `progress` is called with (rows done, total rows) after each batch.
+     * Indexes are not carried over. The handle keeps its original
+     * `embedding_dim`; reopen with `new_dim` to use the migrated collection.
+     * Returns the number of rows migrated.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `migrateDimension`(`collection`: kotlin.String?, `newDim`: kotlin.Int, `reembed`: Reembedder, `progress`: ProgressReporter?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_migrate_dimension(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterInt.lower(`newDim`),FfiConverterTypeReembedder.lower(`reembed`),FfiConverterOptionalTypeProgressReporter.lower(`progress`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The single nearest neighbour to `query_vector` in `collection`, or
+     * `None` when nothing matches `filter` or the collection doesn't exist.
+     * A cheaper `search` with `limit = 1` that decodes only the winning row.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `nearest`(`queryVector`: List<kotlin.Float>, `filter`: kotlin.String?, `collection`: kotlin.String?) : SearchResult? {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_nearest(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterOptionalTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Compact `collection`: merge small data files and rewrite away rows
+     * that upserts and deletes left behind, then bring any vector or text
+     * index up to date with the rewritten rows and any rows written since.
+     * Old versions are kept, so earlier versions remain readable. No-op when
+     * the collection doesn't exist or has nothing to compact.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `optimize`(`collection`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_optimize(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Rename collection `from` to `to`. The engine has no native rename for
+     * local databases, so this is `copy_collection` followed by dropping
+     * `from`; large collections take a while and briefly need twice the disk.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `renameCollection`(`from`: kotlin.String, `to`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_rename_collection(
+                thisPtr,
+                FfiConverterString.lower(`from`),FfiConverterString.lower(`to`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Roll `collection` back to `version` by committing a copy of it as the
+     * new latest version; later versions stay listed and can be restored in
+     * turn. Entries still staged are kept and land on top at the next flush.
+     * A missing collection or unknown version is a `TableError`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `restore`(`collection`: kotlin.String?, `version`: kotlin.ULong) {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_restore(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),FfiConverterULong.lower(`version`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Undo `soft_delete` for `key` in `collection`. Returns whether a
+     * soft-deleted entry matched.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `restoreKey`(`key`: kotlin.String, `collection`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_restore_key(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Search `options.collection` (default `"memories"`) for the `limit`
+     * nearest neighbours to `query_vector`.
+     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `search`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalTypeSearchOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `search` by Hamming distance to the packed binary `code` (see
+     * `store_binary`); the nearest codes come first.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchBinary`(`code`: kotlin.ByteArray, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_binary(
+                thisPtr,
+                FfiConverterByteArray.lower(`code`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalTypeSearchOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `search` with `query_vector` passed as little-endian f32 bytes (see
+     * `store_bytes`): `embedding_dim * 4` bytes (`embedding2_dim * 4` when
+     * searching `embedding2`), or any non-empty multiple of 4 up to that
+     * with `prefix_search`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchBytes`(`queryVector`: kotlin.ByteArray, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_bytes(
+                thisPtr,
+                FfiConverterByteArray.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalTypeSearchOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Like `search`, but also reports whether the results are approximate.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchDetailed`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?) : SearchResponse {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_detailed(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalTypeSearchOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeSearchResponse.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Blend `search` and `search_text` over `collection`. Each side fetches
+     * `limit * 4` candidates and its scores are min-max normalised to
+     * `[0, 1]` (a side whose candidates all score alike gives them 1). The
+     * fused score is a weighted sum, `alpha * vector + (1 - alpha) * text`,
+     * with 0 for a side that didn't return the key, so `alpha = 1` ranks by
+     * vector similarity only and `alpha = 0` by keyword relevance only.
+     * Results are unique by key, best first, with `score` set to the fused
+     * value and `distance` kept from the vector side.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchHybrid`(`queryVector`: List<kotlin.Float>, `queryText`: kotlin.String, `limit`: kotlin.UInt, `alpha`: kotlin.Double, `collection`: kotlin.String?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_hybrid(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterString.lower(`queryText`),FfiConverterUInt.lower(`limit`),FfiConverterDouble.lower(`alpha`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Run `search` for each of `query_vectors` and return one result list
+     * per query, in input order. Every vector is validated before any query
+     * runs, and the queries share one connection and one snapshot of the
+     * staged entries.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchMany`(`queryVectors`: List<List<kotlin.Float>>, `limit`: kotlin.UInt, `filter`: kotlin.String?, `options`: SearchOptions?) : List<List<SearchResult>> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_many(
+                thisPtr,
+                FfiConverterSequenceSequenceFloat.lower(`queryVectors`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalTypeSearchOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `search` over each of `collections`, merged into one list of the
+     * global top `limit` by score. `SearchResult::collection` tells which
+     * collection a result came from. Collections that don't exist, and
+     * repeats in `collections`, are skipped.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchMulti`(`collections`: List<kotlin.String>, `queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `filter`: kotlin.String?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_multi(
+                thisPtr,
+                FfiConverterSequenceString.lower(`collections`),FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The page of `limit` results after the nearest `offset`, plus the
+     * number of rows matching `filter`, for rendering page controls.
+     * `total_matching` is an exact count of the filtered rows, staged entries
+     * included. Without a vector index, paging through it reaches every
+     * one; with an index, each page is cut from an approximate ranking
+     * (see `SearchOptions::offset`) and can't be relied on to reach all
+     * `total_matching` rows.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchPaged`(`queryVector`: List<kotlin.Float>, `limit`: kotlin.UInt, `offset`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?) : SearchPage {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_paged(
+                thisPtr,
+                FfiConverterSequenceFloat.lower(`queryVector`),FfiConverterUInt.lower(`limit`),FfiConverterUInt.lower(`offset`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeSearchPage.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `search` using the stored embedding of `key` as the query, i.e. "more
+     * like this". `exclude_self` leaves `key` itself out of the results.
+     * A missing `key` is a `QueryError`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchSimilar`(`key`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `excludeSelf`: kotlin.Boolean, `collection`: kotlin.String?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_similar(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterBoolean.lower(`excludeSelf`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Keyword search over `text` in `collection` using the engine's
+     * full-text (BM25) index, which is built on the first call. `score` is
+     * the BM25 relevance and `distance` is `None`. Only committed rows that
+     * aren't soft-deleted are searched; rows written after the index was built are still matched by
+     * a slower unindexed pass until `optimize` indexes them. Returns no
+     * results when the collection doesn't exist.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `searchText`(`query`: kotlin.String, `limit`: kotlin.UInt, `filter`: kotlin.String?, `collection`: kotlin.String?) : List<SearchResult> {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_search_text(
+                thisPtr,
+                FfiConverterString.lower(`query`),FfiConverterUInt.lower(`limit`),FfiConverterOptionalString.lower(`filter`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSearchResult.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Move `key` in `collection` to the trash: `search` and `list` skip it
+     * until `restore_key` (or storing the key again) brings it back. Staged
+     * entries are flushed first. Returns whether a live entry matched.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `softDelete`(`key`: kotlin.String, `collection`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_soft_delete(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Row count, version history and on-disk size of `collection` in one
+     * call. A missing collection reports all zeros.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `stats`(`collection`: kotlin.String?) : CollectionStats {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_stats(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeCollectionStats.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Store a memory entry in `options.collection` (default `"memories"`).
+     * Overwrites if `key` already exists, unless the handle is append-only.
+     * With staging enabled, entries for the default collection are buffered
+     * until the next flush and the returned stats are zero. Passing `None`
+     * for `options` uses the defaults (see `StoreOptions`).
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `store`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `metadata`: kotlin.String?, `options`: StoreOptions?) : WriteStats {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterString.lower(`agentId`),FfiConverterString.lower(`text`),FfiConverterSequenceFloat.lower(`embedding`),FfiConverterOptionalString.lower(`metadata`),FfiConverterOptionalTypeStoreOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeWriteStats.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `store` for a handle opened with `EmbeddingDtype::Binary`, taking the
+     * packed code as `embedding_dim` bytes.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `storeBinary`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `code`: kotlin.ByteArray, `metadata`: kotlin.String?, `collection`: kotlin.String?) : WriteStats {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store_binary(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterString.lower(`agentId`),FfiConverterString.lower(`text`),FfiConverterByteArray.lower(`code`),FfiConverterOptionalString.lower(`metadata`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeWriteStats.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `store` with the embedding passed as `embedding_dim * 4` bytes of
+     * little-endian f32, which crosses the FFI boundary far more cheaply
+     * than a list of floats. `options` are as for `store`.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `storeBytes`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: kotlin.ByteArray, `metadata`: kotlin.String?, `options`: StoreOptions?) : WriteStats {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store_bytes(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterString.lower(`agentId`),FfiConverterString.lower(`text`),FfiConverterByteArray.lower(`embedding`),FfiConverterOptionalString.lower(`metadata`),FfiConverterOptionalTypeStoreOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeWriteStats.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Store many entries with one upsert: every embedding is validated first
+     * and the rows are written in a single commit, so a bad entry fails the
+     * whole call and nothing is written. When a key repeats within `entries`
+     * the last occurrence wins. Staged entries are flushed first and the
+     * batch bypasses staging. The rows are converted and written
+     * `chunk_size` at a time (default `DEFAULT_CHUNK_ROWS`) rather than as
+     * one large record batch. `progress` is called with (rows handed to the
+     * engine, total rows) once per chunk; it starts over if a conflicting
+     * commit is retried. Returns the number of rows written.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `storeMany`(`entries`: List<MemoryEntry>, `collection`: kotlin.String?, `chunkSize`: kotlin.UInt?, `progress`: ProgressReporter?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store_many(
+                thisPtr,
+                FfiConverterSequenceTypeMemoryEntry.lower(`entries`),FfiConverterOptionalString.lower(`collection`),FfiConverterOptionalUInt.lower(`chunkSize`),FfiConverterOptionalTypeProgressReporter.lower(`progress`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * `store` with `meta` written to `metadata` as a JSON object of strings,
+     * so it reads back as `SearchResult::meta`. When a key repeats, the last
+     * pair wins.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `storeWithMeta`(`key`: kotlin.String, `agentId`: kotlin.String, `text`: kotlin.String, `embedding`: List<kotlin.Float>, `meta`: List<MetaPair>, `collection`: kotlin.String?) : WriteStats {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_store_with_meta(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterString.lower(`agentId`),FfiConverterString.lower(`text`),FfiConverterSequenceFloat.lower(`embedding`),FfiConverterSequenceTypeMetaPair.lower(`meta`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeWriteStats.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Mark `key` in `collection` as just used by setting its `created_at` to
+     * now, without rewriting its text or embedding, so apps can evict
+     * least-recently-used entries by `created_at`. A staged entry is updated
+     * in place. Returns whether an entry matched.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `touch`(`key`: kotlin.String, `collection`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_touch(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Overwrite the `embedding` of each listed key in `collection`, e.g. after
+     * switching embedding models, leaving text, metadata and timestamps
+     * untouched. Every vector is checked before anything is written, keys
+     * not stored are skipped, and a key listed twice takes its last vector.
+     * Returns how many entries were updated.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateEmbeddings`(`updates`: List<KeyEmbedding>, `collection`: kotlin.String?) : kotlin.ULong {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_update_embeddings(
+                thisPtr,
+                FfiConverterSequenceTypeKeyEmbedding.lower(`updates`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_u64(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_u64(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_u64(future) },
+        // lift function
+        { FfiConverterULong.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Set only the `metadata` of the entry stored under `key` in
+     * `collection`, leaving its text, embedding and timestamp untouched.
+     * A staged entry is updated in place. Returns whether an entry matched.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateMetadata`(`key`: kotlin.String, `metadata`: kotlin.String?, `collection`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_update_metadata(
+                thisPtr,
+                FfiConverterString.lower(`key`),FfiConverterOptionalString.lower(`metadata`),FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Audit `collection` for rows sharing a `key`, which concurrent upserts
+     * can leave behind. Read-only: only the `key` column of committed rows is
+     * scanned, and a missing collection reports zero rows. An agent-scoped
+     * handle audits only its agent's rows.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `verify`(`collection`: kotlin.String?) : VerifyReport {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbhandle_verify(
+                thisPtr,
+                FfiConverterOptionalString.lower(`collection`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeVerifyReport.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+
+    
+    companion object {
+        
+    /**
+     * Open (or create) a LanceDB database at `db_path`.
+     * `embedding_dim` is the fixed vector dimension (e.g. 1536 for OpenAI ada-002).
+     * A `memory://` path keeps the database in process memory instead.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `open`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    /**
+     * Open a fresh database held only in process memory, for caches and
+     * tests. It behaves like an on-disk one and its data is freed when this
+     * handle is closed or dropped. Other handles opened on its `db_path`
+     * share the store but don't free it.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `openMemory`(`embeddingDim`: kotlin.Int) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_memory(FfiConverterInt.lower(`embeddingDim`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    /**
+     * Like `open`, but every method that writes fails with `TableError`
+     * (see `OpenOptions::read_only`); reads work as usual. `search_text`
+     * doesn't build the missing text index it would otherwise create.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `openReadOnly`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_read_only(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    /**
+     * Like `open`, but confined to the entries of `agent_id`, for isolating
+     * tenants: every read, update and delete only matches rows with that
+     * `agent_id` (ANDed with any caller filter), and `store` writes it
+     * whatever `agent_id` is passed. A key already stored by another agent
+     * is left untouched by writes through this handle. Methods acting on
+     * whole collections regardless of agent (`clear_all`, copies, renames,
+     * `restore`, `migrate_dimension` and the Arrow/Parquet import and
+     * export) fail with `TableError`; `clear` deletes only the agent's rows.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `openScoped`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int, `agentId`: kotlin.String) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_scoped(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),FfiConverterString.lower(`agentId`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    /**
+     * Like `open`, with `extra_columns` added to the schema of every
+     * collection (see `OpenOptions::extra_columns`). Values are passed to
+     * `store` through its `columns` argument and can be filtered on by name,
+     * e.g. `"importance >= 3"`. Collections created without a column gain it
+     * on their next write, null for existing rows.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `openWithColumns`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int, `extraColumns`: List<ColumnSpec>) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_columns(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),FfiConverterSequenceTypeColumnSpec.lower(`extraColumns`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    /**
+     * Like `open`, but with handle-level `options` (see `OpenOptions`).
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+     suspend fun `openWithOptions`(`dbPath`: kotlin.String, `embeddingDim`: kotlin.Int, `options`: OpenOptions) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_options(FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),FfiConverterTypeOpenOptions.lower(`options`),),
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+        
+    }
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLanceDBHandle: FfiConverter<LanceDbHandle, Pointer> {
+
+    override fun lower(value: LanceDbHandle): Pointer {
+        return value.uniffiClonePointer()
+    }
+
+    override fun lift(value: Pointer): LanceDbHandle {
+        return LanceDbHandle(value)
+    }
+
+    override fun read(buf: ByteBuffer): LanceDbHandle {
+        // The Rust code always writes pointers as 8 bytes, and will
+        // fail to compile if they don't fit.
+        return lift(Pointer(buf.getLong()))
+    }
+
+    override fun allocationSize(value: LanceDbHandle) = 8UL
+
+    override fun write(value: LanceDbHandle, buf: ByteBuffer) {
+        // The Rust code always expects pointers written as 8 bytes,
+        // and will fail to compile if they don't fit.
+        buf.putLong(Pointer.nativeValue(lower(value)))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a Pointer/Arc<T>
+// to the live Rust struct on the other side of the FFI.
+//
+// Each instance implements core operations for working with the Rust `Arc<T>` and the
+// Kotlin Pointer to work with the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque pointer to the underlying Rust struct.
+//     Method calls need to read this pointer from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its pointer should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the pointer, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the pointer, but is interrupted
+//      before it can pass the pointer over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read pointer value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+/**
+ * Opens `LanceDBHandle`s under app-chosen ids so several independent
+ * databases can be looked up, listed and closed together. The handles run
+ * on the shared runtime like any other.
+ */
+public interface LanceDbManagerInterface {
+    
+    /**
+     * Close the handle tracked as `id` (see `LanceDBHandle::close`) and stop
+     * tracking it. Returns false when no handle has that id. A handle that
+     * was already closed directly is just forgotten.
+     */
+    suspend fun `close`(`id`: kotlin.String): kotlin.Boolean
+    
+    /**
+     * Close every tracked handle and stop tracking them. All are forgotten
+     * even when some fail to close; the first failure is returned.
+     */
+    suspend fun `closeAll`()
+    
+    /**
+     * The handle tracked as `id`, if any.
+     */
+    fun `get`(`id`: kotlin.String): LanceDbHandle?
+    
+    /**
+     * Ids of the tracked handles, sorted.
+     */
+    fun `ids`(): List<kotlin.String>
+    
+    /**
+     * Open the database at `db_path` (see `LanceDBHandle::open`) and track
+     * it as `id`. Fails with `ConnectionFailed` when `id` is already open.
+     */
+    suspend fun `open`(`id`: kotlin.String, `dbPath`: kotlin.String, `embeddingDim`: kotlin.Int): LanceDbHandle
+    
+    companion object
+}
+
+/**
+ * Opens `LanceDBHandle`s under app-chosen ids so several independent
+ * databases can be looked up, listed and closed together. The handles run
+ * on the shared runtime like any other.
+ */
+open class LanceDbManager: Disposable, AutoCloseable, LanceDbManagerInterface {
+
+    constructor(pointer: Pointer) {
+        this.pointer = pointer
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
+    }
+
+    /**
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noPointer: NoPointer) {
+        this.pointer = null
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
+    }
+    constructor() :
+        this(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_constructor_lancedbmanager_new(
+        _status)
+}
+    )
+
+    protected val pointer: Pointer?
+    protected val cleanable: UniffiCleaner.Cleanable
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithPointer(block: (ptr: Pointer) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the pointer being freed concurrently.
+        try {
+            return block(this.uniffiClonePointer())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val pointer: Pointer?) : Runnable {
+        override fun run() {
+            pointer?.let { ptr ->
+                uniffiRustCall { status ->
+                    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_free_lancedbmanager(ptr, status)
+                }
+            }
+        }
+    }
+
+    fun uniffiClonePointer(): Pointer {
+        return uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_clone_lancedbmanager(pointer!!, status)
+        }
+    }
+
+    
+    /**
+     * Close the handle tracked as `id` (see `LanceDBHandle::close`) and stop
+     * tracking it. Returns false when no handle has that id. A handle that
+     * was already closed directly is just forgotten.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `close`(`id`: kotlin.String) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbmanager_close(
+                thisPtr,
+                FfiConverterString.lower(`id`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Close every tracked handle and stop tracking them. All are forgotten
+     * even when some fail to close; the first failure is returned.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `closeAll`() {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbmanager_close_all(
+                thisPtr,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * The handle tracked as `id`, if any.
+     */override fun `get`(`id`: kotlin.String): LanceDbHandle? {
+            return FfiConverterOptionalTypeLanceDBHandle.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbmanager_get(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Ids of the tracked handles, sorted.
+     */override fun `ids`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbmanager_ids(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Open the database at `db_path` (see `LanceDBHandle::open`) and track
+     * it as `id`. Fails with `ConnectionFailed` when `id` is already open.
+     */
+    @Throws(LanceException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `open`(`id`: kotlin.String, `dbPath`: kotlin.String, `embeddingDim`: kotlin.Int) : LanceDbHandle {
+        return uniffiRustCallAsync(
+        callWithPointer { thisPtr ->
+            UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_method_lancedbmanager_open(
+                thisPtr,
+                FfiConverterString.lower(`id`),FfiConverterString.lower(`dbPath`),FfiConverterInt.lower(`embeddingDim`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_poll_pointer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_complete_pointer(future, continuation) },
+        { future -> UniffiLib.INSTANCE.ffi_lancedb_ffi_rust_future_free_pointer(future) },
+        // lift function
+        { FfiConverterTypeLanceDBHandle.lift(it) },
+        // Error FFI converter
+        LanceException.ErrorHandler,
+    )
+    }
+
+    
+
+    
+    
+    companion object
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLanceDBManager: FfiConverter<LanceDbManager, Pointer> {
+
+    override fun lower(value: LanceDbManager): Pointer {
+        return value.uniffiClonePointer()
+    }
+
+    override fun lift(value: Pointer): LanceDbManager {
+        return LanceDbManager(value)
+    }
+
+    override fun read(buf: ByteBuffer): LanceDbManager {
+        // The Rust code always writes pointers as 8 bytes, and will
+        // fail to compile if they don't fit.
+        return lift(Pointer(buf.getLong()))
+    }
+
+    override fun allocationSize(value: LanceDbManager) = 8UL
+
+    override fun write(value: LanceDbManager, buf: ByteBuffer) {
+        // The Rust code always expects pointers written as 8 bytes,
+        // and will fail to compile if they don't fit.
+        buf.putLong(Pointer.nativeValue(lower(value)))
+    }
+}
+
+
+
+/**
+ * Number of entries stored by one agent (see `counts_by_agent`).
+ */
+data class AgentCount (
+    var `agentId`: kotlin.String, 
+    var `count`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAgentCount: FfiConverterRustBuffer<AgentCount> {
+    override fun read(buf: ByteBuffer): AgentCount {
+        return AgentCount(
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AgentCount) = (
+            FfiConverterString.allocationSize(value.`agentId`) +
+            FfiConverterULong.allocationSize(value.`count`)
+    )
+
+    override fun write(value: AgentCount, buf: ByteBuffer) {
+            FfiConverterString.write(value.`agentId`, buf)
+            FfiConverterULong.write(value.`count`, buf)
+    }
+}
+
+
+
+/**
+ * What `cleanup_old_versions` removed.
+ */
+data class CleanupStats (
+    var `versionsRemoved`: kotlin.ULong, 
+    /**
+     * Approximate size of the deleted files.
+     */
+    var `bytesRemoved`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCleanupStats: FfiConverterRustBuffer<CleanupStats> {
+    override fun read(buf: ByteBuffer): CleanupStats {
+        return CleanupStats(
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CleanupStats) = (
+            FfiConverterULong.allocationSize(value.`versionsRemoved`) +
+            FfiConverterULong.allocationSize(value.`bytesRemoved`)
+    )
+
+    override fun write(value: CleanupStats, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`versionsRemoved`, buf)
+            FfiConverterULong.write(value.`bytesRemoved`, buf)
+    }
+}
+
+
+
+/**
+ * Per-collection read/write tuning registered via `OpenOptions`.
+ * Every field left `None` keeps the engine default.
+ */
+data class CollectionPreset (
+    /**
+     * Rows per data file written to this collection.
+     */
+    var `maxRowsPerFile`: kotlin.ULong? = null, 
+    /**
+     * Rows per row group within a data file.
+     */
+    var `maxRowsPerGroup`: kotlin.ULong? = null, 
+    /**
+     * IVF partitions probed by vector searches once the collection has an index.
+     */
+    var `nprobes`: kotlin.UInt? = null, 
+    /**
+     * Re-rank `refine_factor * limit` index candidates with exact distances.
+     */
+    var `refineFactor`: kotlin.UInt? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCollectionPreset: FfiConverterRustBuffer<CollectionPreset> {
+    override fun read(buf: ByteBuffer): CollectionPreset {
+        return CollectionPreset(
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CollectionPreset) = (
+            FfiConverterOptionalULong.allocationSize(value.`maxRowsPerFile`) +
+            FfiConverterOptionalULong.allocationSize(value.`maxRowsPerGroup`) +
+            FfiConverterOptionalUInt.allocationSize(value.`nprobes`) +
+            FfiConverterOptionalUInt.allocationSize(value.`refineFactor`)
+    )
+
+    override fun write(value: CollectionPreset, buf: ByteBuffer) {
+            FfiConverterOptionalULong.write(value.`maxRowsPerFile`, buf)
+            FfiConverterOptionalULong.write(value.`maxRowsPerGroup`, buf)
+            FfiConverterOptionalUInt.write(value.`nprobes`, buf)
+            FfiConverterOptionalUInt.write(value.`refineFactor`, buf)
+    }
+}
+
+
+
+/**
+ * Size readout for a collection, as returned by `stats`.
+ */
+data class CollectionStats (
+    /**
+     * Rows in the collection, including staged entries.
+     */
+    var `numRows`: kotlin.ULong, 
+    var `numVersions`: kotlin.ULong, 
+    var `latestVersion`: kotlin.ULong, 
+    /**
+     * Sum of file sizes under the table directory; 0 for in-memory databases.
+     */
+    var `bytes`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCollectionStats: FfiConverterRustBuffer<CollectionStats> {
+    override fun read(buf: ByteBuffer): CollectionStats {
+        return CollectionStats(
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CollectionStats) = (
+            FfiConverterULong.allocationSize(value.`numRows`) +
+            FfiConverterULong.allocationSize(value.`numVersions`) +
+            FfiConverterULong.allocationSize(value.`latestVersion`) +
+            FfiConverterULong.allocationSize(value.`bytes`)
+    )
+
+    override fun write(value: CollectionStats, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`numRows`, buf)
+            FfiConverterULong.write(value.`numVersions`, buf)
+            FfiConverterULong.write(value.`latestVersion`, buf)
+            FfiConverterULong.write(value.`bytes`, buf)
+    }
+}
+
+
+
+/**
+ * One extra column value passed to `store`.
+ */
+data class ColumnEntry (
+    var `name`: kotlin.String, 
+    var `value`: ColumnValue
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeColumnEntry: FfiConverterRustBuffer<ColumnEntry> {
+    override fun read(buf: ByteBuffer): ColumnEntry {
+        return ColumnEntry(
+            FfiConverterString.read(buf),
+            FfiConverterTypeColumnValue.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ColumnEntry) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterTypeColumnValue.allocationSize(value.`value`)
+    )
+
+    override fun write(value: ColumnEntry, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterTypeColumnValue.write(value.`value`, buf)
+    }
+}
+
+
+
+/**
+ * An app-defined column added to the schema (see `open_with_columns`).
+ */
+data class ColumnSpec (
+    /**
+     * Letters, digits and `_`, not starting with a digit, so it can be used
+     * in filters unquoted. Must not clash with a built-in column.
+     */
+    var `name`: kotlin.String, 
+    var `columnType`: ColumnType
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeColumnSpec: FfiConverterRustBuffer<ColumnSpec> {
+    override fun read(buf: ByteBuffer): ColumnSpec {
+        return ColumnSpec(
+            FfiConverterString.read(buf),
+            FfiConverterTypeColumnType.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ColumnSpec) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterTypeColumnType.allocationSize(value.`columnType`)
+    )
+
+    override fun write(value: ColumnSpec, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterTypeColumnType.write(value.`columnType`, buf)
+    }
+}
+
+
+
+/**
+ * One column of a collection, as reported by `describe`.
+ */
+data class FieldInfo (
+    var `name`: kotlin.String, 
+    /**
+     * The Arrow type, e.g. `Utf8` or `FixedSizeList(... 384)`.
+     */
+    var `dataType`: kotlin.String, 
+    var `nullable`: kotlin.Boolean
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFieldInfo: FfiConverterRustBuffer<FieldInfo> {
+    override fun read(buf: ByteBuffer): FieldInfo {
+        return FieldInfo(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: FieldInfo) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`dataType`) +
+            FfiConverterBoolean.allocationSize(value.`nullable`)
+    )
+
+    override fun write(value: FieldInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`dataType`, buf)
+            FfiConverterBoolean.write(value.`nullable`, buf)
+    }
+}
+
+
+
+/**
+ * Per-call options for `get` and `get_many`. Passing `None` uses the
+ * defaults.
+ */
+data class GetOptions (
+    /**
+     * Ignore entries still buffered by write-ahead staging, as
+     * `SearchOptions::committed_only` does.
+     */
+    var `committedOnly`: kotlin.Boolean = false, 
+    /**
+     * Fail with `LanceError::NotFound` instead of returning `None` (for
+     * `get`) or no results (for `get_many`).
+     */
+    var `errorOnEmpty`: kotlin.Boolean = false
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGetOptions: FfiConverterRustBuffer<GetOptions> {
+    override fun read(buf: ByteBuffer): GetOptions {
+        return GetOptions(
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: GetOptions) = (
+            FfiConverterBoolean.allocationSize(value.`committedOnly`) +
+            FfiConverterBoolean.allocationSize(value.`errorOnEmpty`)
+    )
+
+    override fun write(value: GetOptions, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`committedOnly`, buf)
+            FfiConverterBoolean.write(value.`errorOnEmpty`, buf)
+    }
+}
+
+
+
+/**
+ * Result of `health_check`.
+ */
+data class HealthStatus (
+    var `canConnect`: kotlin.Boolean, 
+    /**
+     * Collections in the database; 0 when it can't be reached.
+     */
+    var `tableCount`: kotlin.ULong, 
+    var `dbPath`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHealthStatus: FfiConverterRustBuffer<HealthStatus> {
+    override fun read(buf: ByteBuffer): HealthStatus {
+        return HealthStatus(
+            FfiConverterBoolean.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: HealthStatus) = (
+            FfiConverterBoolean.allocationSize(value.`canConnect`) +
+            FfiConverterULong.allocationSize(value.`tableCount`) +
+            FfiConverterString.allocationSize(value.`dbPath`)
+    )
+
+    override fun write(value: HealthStatus, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`canConnect`, buf)
+            FfiConverterULong.write(value.`tableCount`, buf)
+            FfiConverterString.write(value.`dbPath`, buf)
+    }
+}
+
+
+
+data class HybridSearchResult (
+    var `key`: kotlin.String, 
+    var `text`: kotlin.String, 
+    var `vectorRank`: kotlin.UInt, 
+    var `textRank`: kotlin.UInt, 
+    var `rrfScore`: kotlin.Double, 
+    var `metadata`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHybridSearchResult: FfiConverterRustBuffer<HybridSearchResult> {
+    override fun read(buf: ByteBuffer): HybridSearchResult {
+        return HybridSearchResult(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: HybridSearchResult) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterString.allocationSize(value.`text`) +
+            FfiConverterUInt.allocationSize(value.`vectorRank`) +
+            FfiConverterUInt.allocationSize(value.`textRank`) +
+            FfiConverterDouble.allocationSize(value.`rrfScore`) +
+            FfiConverterOptionalString.allocationSize(value.`metadata`)
+    )
+
+    override fun write(value: HybridSearchResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`text`, buf)
+            FfiConverterUInt.write(value.`vectorRank`, buf)
+            FfiConverterUInt.write(value.`textRank`, buf)
+            FfiConverterDouble.write(value.`rrfScore`, buf)
+            FfiConverterOptionalString.write(value.`metadata`, buf)
+    }
+}
+
+
+
+/**
+ * One index of a collection, as returned by `index_info`.
+ */
+data class IndexInfo (
+    var `name`: kotlin.String, 
+    var `column`: kotlin.String, 
+    /**
+     * Engine index type, e.g. `IVF_PQ`, `BTREE` or `FTS`.
+     */
+    var `indexType`: kotlin.String, 
+    var `numIndexedRows`: kotlin.ULong, 
+    /**
+     * Rows written since the index was built or last optimized, which
+     * searches scan exhaustively.
+     */
+    var `numUnindexedRows`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeIndexInfo: FfiConverterRustBuffer<IndexInfo> {
+    override fun read(buf: ByteBuffer): IndexInfo {
+        return IndexInfo(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: IndexInfo) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`column`) +
+            FfiConverterString.allocationSize(value.`indexType`) +
+            FfiConverterULong.allocationSize(value.`numIndexedRows`) +
+            FfiConverterULong.allocationSize(value.`numUnindexedRows`)
+    )
+
+    override fun write(value: IndexInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`column`, buf)
+            FfiConverterString.write(value.`indexType`, buf)
+            FfiConverterULong.write(value.`numIndexedRows`, buf)
+            FfiConverterULong.write(value.`numUnindexedRows`, buf)
+    }
+}
+
+
+
+/**
+ * A replacement embedding for `update_embeddings`.
+ */
+data class KeyEmbedding (
+    var `key`: kotlin.String, 
+    var `embedding`: List<kotlin.Float>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeKeyEmbedding: FfiConverterRustBuffer<KeyEmbedding> {
+    override fun read(buf: ByteBuffer): KeyEmbedding {
+        return KeyEmbedding(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: KeyEmbedding) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterSequenceFloat.allocationSize(value.`embedding`)
+    )
+
+    override fun write(value: KeyEmbedding, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterSequenceFloat.write(value.`embedding`, buf)
+    }
+}
+
+
+
+/**
+ * Per-call options for `list` and `list_detailed`. Passing `None` uses the
+ * defaults.
+ */
+data class ListOptions (
+    /**
+     * Collection to list; `None` is the handle's default collection.
+     */
+    var `collection`: kotlin.String? = null, 
+    /**
+     * Skip that many entries first, for paging with `limit`.
+     */
+    var `offset`: kotlin.UInt? = null, 
+    /**
+     * List the collection as of that version, without staged entries.
+     */
+    var `version`: kotlin.ULong? = null, 
+    /**
+     * Keep only entries whose `created_at` (ms since epoch) is at or after this.
+     */
+    var `createdAfter`: kotlin.Long? = null, 
+    /**
+     * Keep only entries whose `created_at` (ms since epoch) is at or before this.
+     */
+    var `createdBefore`: kotlin.Long? = null, 
+    /**
+     * Sort before `offset` and `limit` apply; `None` keeps scan order.
+     */
+    var `order`: SortOrder? = null, 
+    /**
+     * Also list entries hidden by `soft_delete`.
+     */
+    var `includeDeleted`: kotlin.Boolean = false, 
+    /**
+     * Fail with `LanceError::NotFound` instead of returning no entries.
+     */
+    var `errorOnEmpty`: kotlin.Boolean = false
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeListOptions: FfiConverterRustBuffer<ListOptions> {
+    override fun read(buf: ByteBuffer): ListOptions {
+        return ListOptions(
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterOptionalTypeSortOrder.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ListOptions) = (
+            FfiConverterOptionalString.allocationSize(value.`collection`) +
+            FfiConverterOptionalUInt.allocationSize(value.`offset`) +
+            FfiConverterOptionalULong.allocationSize(value.`version`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdAfter`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdBefore`) +
+            FfiConverterOptionalTypeSortOrder.allocationSize(value.`order`) +
+            FfiConverterBoolean.allocationSize(value.`includeDeleted`) +
+            FfiConverterBoolean.allocationSize(value.`errorOnEmpty`)
+    )
+
+    override fun write(value: ListOptions, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`collection`, buf)
+            FfiConverterOptionalUInt.write(value.`offset`, buf)
+            FfiConverterOptionalULong.write(value.`version`, buf)
+            FfiConverterOptionalLong.write(value.`createdAfter`, buf)
+            FfiConverterOptionalLong.write(value.`createdBefore`, buf)
+            FfiConverterOptionalTypeSortOrder.write(value.`order`, buf)
+            FfiConverterBoolean.write(value.`includeDeleted`, buf)
+            FfiConverterBoolean.write(value.`errorOnEmpty`, buf)
+    }
+}
+
+
+
+/**
+ * One row for `store_many`; fields match the `store` arguments.
+ */
+data class MemoryEntry (
+    var `key`: kotlin.String, 
+    var `agentId`: kotlin.String, 
+    var `text`: kotlin.String, 
+    var `embedding`: List<kotlin.Float>, 
+    var `metadata`: kotlin.String? = null, 
+    var `tags`: List<kotlin.String>? = null, 
+    /**
+     * Creation time in ms since epoch, e.g. when importing history.
+     * `None` stamps the time of the write. Must not be negative.
+     */
+    var `createdAt`: kotlin.Long? = null, 
+    /**
+     * Second vector, as `StoreOptions::embedding2`.
+     */
+    var `embedding2`: List<kotlin.Float>? = null, 
+    /**
+     * Extra column values, as `StoreOptions::columns`.
+     */
+    var `columns`: List<ColumnEntry>? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMemoryEntry: FfiConverterRustBuffer<MemoryEntry> {
+    override fun read(buf: ByteBuffer): MemoryEntry {
+        return MemoryEntry(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceFloat.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalSequenceString.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterOptionalSequenceFloat.read(buf),
+            FfiConverterOptionalSequenceTypeColumnEntry.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: MemoryEntry) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterString.allocationSize(value.`agentId`) +
+            FfiConverterString.allocationSize(value.`text`) +
+            FfiConverterSequenceFloat.allocationSize(value.`embedding`) +
+            FfiConverterOptionalString.allocationSize(value.`metadata`) +
+            FfiConverterOptionalSequenceString.allocationSize(value.`tags`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdAt`) +
+            FfiConverterOptionalSequenceFloat.allocationSize(value.`embedding2`) +
+            FfiConverterOptionalSequenceTypeColumnEntry.allocationSize(value.`columns`)
+    )
+
+    override fun write(value: MemoryEntry, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`agentId`, buf)
+            FfiConverterString.write(value.`text`, buf)
+            FfiConverterSequenceFloat.write(value.`embedding`, buf)
+            FfiConverterOptionalString.write(value.`metadata`, buf)
+            FfiConverterOptionalSequenceString.write(value.`tags`, buf)
+            FfiConverterOptionalLong.write(value.`createdAt`, buf)
+            FfiConverterOptionalSequenceFloat.write(value.`embedding2`, buf)
+            FfiConverterOptionalSequenceTypeColumnEntry.write(value.`columns`, buf)
+    }
+}
+
+
+
+/**
+ * One metadata field for `store_with_meta`. Non-string JSON values read back
+ * through `SearchResult::meta` are rendered as their JSON text.
+ */
+data class MetaPair (
+    var `key`: kotlin.String, 
+    var `value`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMetaPair: FfiConverterRustBuffer<MetaPair> {
+    override fun read(buf: ByteBuffer): MetaPair {
+        return MetaPair(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: MetaPair) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterString.allocationSize(value.`value`)
+    )
+
+    override fun write(value: MetaPair, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`value`, buf)
+    }
+}
+
+
+
+/**
+ * Handle-level options for `LanceDBHandle::open_with_options`.
+ * The defaults match plain `open`.
+ */
+data class OpenOptions (
+    var `staging`: StagingConfig? = null, 
+    /**
+     * Lance data storage version for newly created tables, e.g. `"2.0"`,
+     * `"2.1"`, `"stable"` or `"legacy"`. Existing tables keep their version.
+     * `None` uses the engine default.
+     */
+    var `storageVersion`: kotlin.String? = null, 
+    /**
+     * Store `tags` in a List(Utf8) column so `store` accepts tags and
+     * `search` can filter on them. An existing table gains the column (null
+     * for old rows) on its next write. Keep the flag on for a table once set.
+     */
+    var `enableTags`: kotlin.Boolean = false, 
+    /**
+     * Tuning applied automatically to the named collections (the default
+     * collection is `"memories"`). Unlisted collections use engine defaults.
+     */
+    var `collectionPresets`: Map<kotlin.String, CollectionPreset>? = null, 
+    /**
+     * Metric used by vector searches, which also decides how `score` is
+     * derived from the engine's distance `d` (see `DistanceMetric`). `None`
+     * keeps the engine default (L2) with the original `score = 1 - d`.
+     */
+    var `distanceMetric`: DistanceMetric? = null, 
+    /**
+     * L2-normalize embeddings before they are stored and query vectors
+     * before they are searched, so cosine and dot scores are comparable.
+     * Zero vectors are rejected. Prefix-search queries are left as given.
+     */
+    var `normalize`: kotlin.Boolean = false, 
+    /**
+     * Append every write instead of upserting on `key`, so a key can hold
+     * several rows (e.g. an event log). `get`, `search` and `list` may then
+     * return more than one row per key, and staged entries no longer hide
+     * committed rows with the same key.
+     */
+    var `appendOnly`: kotlin.Boolean = false, 
+    /**
+     * Commit with `UnsafeCommitHandler`, which avoids the hardlink() that
+     * Android's SELinux policy blocks for apps but gives no protection
+     * against concurrent writers in other processes. Set false off Android
+     * to use the engine's default, safe commit handler.
+     */
+    var `useUnsafeCommit`: kotlin.Boolean = true, 
+    /**
+     * Attempts made by each write when its commit conflicts with a
+     * concurrent writer, with exponential backoff between them (starting at
+     * `WRITE_RETRY_BACKOFF_MS`). 0 is treated as 1.
+     */
+    var `maxWriteAttempts`: kotlin.UInt = 3u, 
+    /**
+     * Collection used when a method's `collection` is `None`, and the one
+     * staging applies to. `None` keeps `"memories"`.
+     */
+    var `defaultTable`: kotlin.String? = null, 
+    /**
+     * Cap on the rows of each collection. After a `store`, `store_many` or
+     * staging flush takes a collection over it, its oldest rows by
+     * `created_at` (then key) are deleted down to the cap. Staged entries
+     * count once flushed. The cap counts every agent's rows, so it cannot
+     * be combined with `agent_scope`. `None` leaves collections unbounded.
+     */
+    var `maxRows`: kotlin.ULong? = null, 
+    /**
+     * Reject every method that writes (`store`, `delete`, `clear`, index
+     * builds, imports, ...) with `TableError`, for components that must
+     * only query. See `LanceDBHandle::open_read_only`.
+     */
+    var `readOnly`: kotlin.Boolean = false, 
+    /**
+     * Type the `embedding` column is stored as in newly created collections.
+     * Must match the existing default collection, or opening fails with
+     * `SchemaError`. `None` keeps f32.
+     */
+    var `embeddingDtype`: EmbeddingDtype? = null, 
+    /**
+     * How long connecting to the database may take before failing with
+     * `ConnectionFailed`, e.g. on a stalled SD card mount. 0 waits forever.
+     */
+    var `connectTimeoutMs`: kotlin.ULong = 30000uL, 
+    /**
+     * How stale a cached collection may be before a read checks for commits
+     * made through other handles or processes; this handle's own writes are
+     * always seen. 0 checks on every read, at the cost of a manifest read
+     * per call.
+     */
+    var `readConsistencyMs`: kotlin.ULong = 1000uL, 
+    /**
+     * Give newly created collections a second, nullable `embedding2`
+     * vector column of this dimension, e.g. a title vector next to a body
+     * vector. Existing collections gain it on their next write. Stored with
+     * `embedding_dtype` and searched with `SearchOptions::which`.
+     */
+    var `embedding2Dim`: kotlin.Int? = null, 
+    /**
+     * Skip an entry when a row with identical `text` already exists for the
+     * same `agent_id` under another key, so re-ingested content doesn't pile
+     * up as duplicates. Texts are compared by a SHA-256 kept in a
+     * `text_hash` column; rows written before it was enabled have none and
+     * never match. Staged entries are checked when they are flushed.
+     */
+    var `dedupByText`: kotlin.Boolean = false, 
+    /**
+     * App-defined nullable columns added to every collection, which filters
+     * can use like the built-in ones (see `open_with_columns`).
+     */
+    var `extraColumns`: List<ColumnSpec>? = null, 
+    /**
+     * Confine the handle to one agent's entries (see `open_scoped`).
+     */
+    var `agentScope`: kotlin.String? = null, 
+    /**
+     * Build the vector index of a collection in the background, as
+     * `create_index` with default parameters would, once a `store`,
+     * `store_many` or `flush` leaves it with at least this many rows and no
+     * vector index. PQ training needs 256 rows, so lower thresholds wait for
+     * that many. A failed build is logged and tried again after the next
+     * write.
+     */
+    var `autoIndexThreshold`: kotlin.ULong? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOpenOptions: FfiConverterRustBuffer<OpenOptions> {
+    override fun read(buf: ByteBuffer): OpenOptions {
+        return OpenOptions(
+            FfiConverterOptionalTypeStagingConfig.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalMapStringTypeCollectionPreset.read(buf),
+            FfiConverterOptionalTypeDistanceMetric.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeEmbeddingDtype.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterOptionalInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalSequenceTypeColumnSpec.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OpenOptions) = (
+            FfiConverterOptionalTypeStagingConfig.allocationSize(value.`staging`) +
+            FfiConverterOptionalString.allocationSize(value.`storageVersion`) +
+            FfiConverterBoolean.allocationSize(value.`enableTags`) +
+            FfiConverterOptionalMapStringTypeCollectionPreset.allocationSize(value.`collectionPresets`) +
+            FfiConverterOptionalTypeDistanceMetric.allocationSize(value.`distanceMetric`) +
+            FfiConverterBoolean.allocationSize(value.`normalize`) +
+            FfiConverterBoolean.allocationSize(value.`appendOnly`) +
+            FfiConverterBoolean.allocationSize(value.`useUnsafeCommit`) +
+            FfiConverterUInt.allocationSize(value.`maxWriteAttempts`) +
+            FfiConverterOptionalString.allocationSize(value.`defaultTable`) +
+            FfiConverterOptionalULong.allocationSize(value.`maxRows`) +
+            FfiConverterBoolean.allocationSize(value.`readOnly`) +
+            FfiConverterOptionalTypeEmbeddingDtype.allocationSize(value.`embeddingDtype`) +
+            FfiConverterULong.allocationSize(value.`connectTimeoutMs`) +
+            FfiConverterULong.allocationSize(value.`readConsistencyMs`) +
+            FfiConverterOptionalInt.allocationSize(value.`embedding2Dim`) +
+            FfiConverterBoolean.allocationSize(value.`dedupByText`) +
+            FfiConverterOptionalSequenceTypeColumnSpec.allocationSize(value.`extraColumns`) +
+            FfiConverterOptionalString.allocationSize(value.`agentScope`) +
+            FfiConverterOptionalULong.allocationSize(value.`autoIndexThreshold`)
+    )
+
+    override fun write(value: OpenOptions, buf: ByteBuffer) {
+            FfiConverterOptionalTypeStagingConfig.write(value.`staging`, buf)
+            FfiConverterOptionalString.write(value.`storageVersion`, buf)
+            FfiConverterBoolean.write(value.`enableTags`, buf)
+            FfiConverterOptionalMapStringTypeCollectionPreset.write(value.`collectionPresets`, buf)
+            FfiConverterOptionalTypeDistanceMetric.write(value.`distanceMetric`, buf)
+            FfiConverterBoolean.write(value.`normalize`, buf)
+            FfiConverterBoolean.write(value.`appendOnly`, buf)
+            FfiConverterBoolean.write(value.`useUnsafeCommit`, buf)
+            FfiConverterUInt.write(value.`maxWriteAttempts`, buf)
+            FfiConverterOptionalString.write(value.`defaultTable`, buf)
+            FfiConverterOptionalULong.write(value.`maxRows`, buf)
+            FfiConverterBoolean.write(value.`readOnly`, buf)
+            FfiConverterOptionalTypeEmbeddingDtype.write(value.`embeddingDtype`, buf)
+            FfiConverterULong.write(value.`connectTimeoutMs`, buf)
+            FfiConverterULong.write(value.`readConsistencyMs`, buf)
+            FfiConverterOptionalInt.write(value.`embedding2Dim`, buf)
+            FfiConverterBoolean.write(value.`dedupByText`, buf)
+            FfiConverterOptionalSequenceTypeColumnSpec.write(value.`extraColumns`, buf)
+            FfiConverterOptionalString.write(value.`agentScope`, buf)
+            FfiConverterOptionalULong.write(value.`autoIndexThreshold`, buf)
+    }
+}
+
+
+
+/**
+ * Columns of a collection, as returned by `describe`.
+ */
+data class SchemaInfo (
+    var `fields`: List<FieldInfo>, 
+    /**
+     * Width of the `embedding` column; `None` if it has none of the
+     * expected fixed-size type.
+     */
+    var `embeddingDim`: kotlin.Int?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSchemaInfo: FfiConverterRustBuffer<SchemaInfo> {
+    override fun read(buf: ByteBuffer): SchemaInfo {
+        return SchemaInfo(
+            FfiConverterSequenceTypeFieldInfo.read(buf),
+            FfiConverterOptionalInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SchemaInfo) = (
+            FfiConverterSequenceTypeFieldInfo.allocationSize(value.`fields`) +
+            FfiConverterOptionalInt.allocationSize(value.`embeddingDim`)
+    )
+
+    override fun write(value: SchemaInfo, buf: ByteBuffer) {
+            FfiConverterSequenceTypeFieldInfo.write(value.`fields`, buf)
+            FfiConverterOptionalInt.write(value.`embeddingDim`, buf)
+    }
+}
+
+
+
+/**
+ * Per-call options for `search`. Passing `None` uses the defaults.
+ */
+data class SearchOptions (
+    var `includeProvenance`: kotlin.Boolean = false, 
+    /**
+     * Allow a `query_vector` shorter than `embedding_dim` and compare it
+     * against the first `query_vector.len()` dimensions of each stored
+     * embedding. The truncated distance is computed in Rust over a full scan:
+     * no index is used and every embedding matching `filter` is read, so cost
+     * grows linearly with the collection.
+     */
+    var `prefixSearch`: kotlin.Boolean = false, 
+    /**
+     * Only match rows whose `tags` contain all of these tags, or any of them
+     * with `match_any_tag`, in addition to `filter`. An empty list adds no
+     * constraint. Requires a handle opened with `OpenOptions::enable_tags`.
+     */
+    var `hasTags`: List<kotlin.String>? = null, 
+    var `matchAnyTag`: kotlin.Boolean = false, 
+    /**
+     * Ignore entries still buffered by write-ahead staging and return only
+     * rows already committed to the table. By default staged entries are
+     * merged in (read-your-writes), but they are lost if the process dies
+     * before a flush; jobs that must not act on such rows should set this.
+     * A staged update then reads as the previously committed row.
+     */
+    var `committedOnly`: kotlin.Boolean = false, 
+    /**
+     * Fail with `LanceError::NotFound` instead of returning no results.
+     */
+    var `errorOnEmpty`: kotlin.Boolean = false, 
+    /**
+     * Index tuning for this call, overriding the collection's preset. Ignored
+     * when the collection has no vector index.
+     */
+    var `nprobes`: kotlin.UInt? = null, 
+    var `refineFactor`: kotlin.UInt? = null, 
+    /**
+     * Skip this many of the nearest results, for paging. With a vector
+     * index each page is cut from an approximate ranking, so pages fetched
+     * separately may overlap or miss rows that an exact search would return.
+     */
+    var `offset`: kotlin.UInt? = null, 
+    /**
+     * Typed predicates ANDed with `filter`; values are escaped, so untrusted
+     * input can be passed without building SQL by hand.
+     */
+    var `filters`: List<MetaFilter>? = null, 
+    /**
+     * Search the collection as of this version (see `list_versions`)
+     * instead of the latest. Staged entries are not included. Not supported
+     * together with `prefix_search`.
+     */
+    var `version`: kotlin.ULong? = null, 
+    /**
+     * Drop results whose `score` is below this, so fewer than `limit` rows
+     * may come back. Scores depend on the handle's `DistanceMetric`.
+     */
+    var `minScore`: kotlin.Double? = null, 
+    /**
+     * Only match rows with `created_at` (ms since epoch) at or after this.
+     */
+    var `createdAfter`: kotlin.Long? = null, 
+    /**
+     * Only match rows with `created_at` (ms since epoch) at or before this.
+     */
+    var `createdBefore`: kotlin.Long? = null, 
+    /**
+     * Return each result's stored embedding in `SearchResult::embedding`.
+     */
+    var `includeEmbedding`: kotlin.Boolean = false, 
+    /**
+     * Also match entries hidden by `soft_delete`.
+     */
+    var `includeDeleted`: kotlin.Boolean = false, 
+    /**
+     * Compare against every row instead of using the vector index, so the
+     * ranking is exact. Latency then grows linearly with the collection
+     * size, so keep this for small collections or when recall is critical.
+     */
+    var `exact`: kotlin.Boolean = false, 
+    /**
+     * Vector column to compare `query_vector` against. `Secondary` requires
+     * a handle opened with `OpenOptions::embedding2_dim` and only matches
+     * rows stored with an `embedding2`. `None` searches `embedding`.
+     */
+    var `which`: EmbeddingColumn? = null, 
+    /**
+     * Collection to search; `None` searches the handle's default collection.
+     */
+    var `collection`: kotlin.String? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSearchOptions: FfiConverterRustBuffer<SearchOptions> {
+    override fun read(buf: ByteBuffer): SearchOptions {
+        return SearchOptions(
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalSequenceString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalSequenceTypeMetaFilter.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalDouble.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeEmbeddingColumn.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SearchOptions) = (
+            FfiConverterBoolean.allocationSize(value.`includeProvenance`) +
+            FfiConverterBoolean.allocationSize(value.`prefixSearch`) +
+            FfiConverterOptionalSequenceString.allocationSize(value.`hasTags`) +
+            FfiConverterBoolean.allocationSize(value.`matchAnyTag`) +
+            FfiConverterBoolean.allocationSize(value.`committedOnly`) +
+            FfiConverterBoolean.allocationSize(value.`errorOnEmpty`) +
+            FfiConverterOptionalUInt.allocationSize(value.`nprobes`) +
+            FfiConverterOptionalUInt.allocationSize(value.`refineFactor`) +
+            FfiConverterOptionalUInt.allocationSize(value.`offset`) +
+            FfiConverterOptionalSequenceTypeMetaFilter.allocationSize(value.`filters`) +
+            FfiConverterOptionalULong.allocationSize(value.`version`) +
+            FfiConverterOptionalDouble.allocationSize(value.`minScore`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdAfter`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdBefore`) +
+            FfiConverterBoolean.allocationSize(value.`includeEmbedding`) +
+            FfiConverterBoolean.allocationSize(value.`includeDeleted`) +
+            FfiConverterBoolean.allocationSize(value.`exact`) +
+            FfiConverterOptionalTypeEmbeddingColumn.allocationSize(value.`which`) +
+            FfiConverterOptionalString.allocationSize(value.`collection`)
+    )
+
+    override fun write(value: SearchOptions, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`includeProvenance`, buf)
+            FfiConverterBoolean.write(value.`prefixSearch`, buf)
+            FfiConverterOptionalSequenceString.write(value.`hasTags`, buf)
+            FfiConverterBoolean.write(value.`matchAnyTag`, buf)
+            FfiConverterBoolean.write(value.`committedOnly`, buf)
+            FfiConverterBoolean.write(value.`errorOnEmpty`, buf)
+            FfiConverterOptionalUInt.write(value.`nprobes`, buf)
+            FfiConverterOptionalUInt.write(value.`refineFactor`, buf)
+            FfiConverterOptionalUInt.write(value.`offset`, buf)
+            FfiConverterOptionalSequenceTypeMetaFilter.write(value.`filters`, buf)
+            FfiConverterOptionalULong.write(value.`version`, buf)
+            FfiConverterOptionalDouble.write(value.`minScore`, buf)
+            FfiConverterOptionalLong.write(value.`createdAfter`, buf)
+            FfiConverterOptionalLong.write(value.`createdBefore`, buf)
+            FfiConverterBoolean.write(value.`includeEmbedding`, buf)
+            FfiConverterBoolean.write(value.`includeDeleted`, buf)
+            FfiConverterBoolean.write(value.`exact`, buf)
+            FfiConverterOptionalTypeEmbeddingColumn.write(value.`which`, buf)
+            FfiConverterOptionalString.write(value.`collection`, buf)
+    }
+}
+
+
+
+/**
+ * One page of results from `search_paged`.
+ */
+data class SearchPage (
+    var `results`: List<SearchResult>, 
+    /**
+     * Rows matching the filter, across all pages (see `search_paged`).
+     */
+    var `totalMatching`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSearchPage: FfiConverterRustBuffer<SearchPage> {
+    override fun read(buf: ByteBuffer): SearchPage {
+        return SearchPage(
+            FfiConverterSequenceTypeSearchResult.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SearchPage) = (
+            FfiConverterSequenceTypeSearchResult.allocationSize(value.`results`) +
+            FfiConverterULong.allocationSize(value.`totalMatching`)
+    )
+
+    override fun write(value: SearchPage, buf: ByteBuffer) {
+            FfiConverterSequenceTypeSearchResult.write(value.`results`, buf)
+            FfiConverterULong.write(value.`totalMatching`, buf)
+    }
+}
+
+
+
+/**
+ * Output of `search_detailed`.
+ */
+data class SearchResponse (
+    var `results`: List<SearchResult>, 
+    /**
+     * The vector index was used, so results may miss true neighbours. `false`
+     * means every row was compared (flat scan), i.e. the ranking is exact.
+     */
+    var `approximate`: kotlin.Boolean
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSearchResponse: FfiConverterRustBuffer<SearchResponse> {
+    override fun read(buf: ByteBuffer): SearchResponse {
+        return SearchResponse(
+            FfiConverterSequenceTypeSearchResult.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SearchResponse) = (
+            FfiConverterSequenceTypeSearchResult.allocationSize(value.`results`) +
+            FfiConverterBoolean.allocationSize(value.`approximate`)
+    )
+
+    override fun write(value: SearchResponse, buf: ByteBuffer) {
+            FfiConverterSequenceTypeSearchResult.write(value.`results`, buf)
+            FfiConverterBoolean.write(value.`approximate`, buf)
+    }
+}
+
+
+
+data class SearchResult (
+    var `key`: kotlin.String, 
+    /**
+     * Collection the entry was read from.
+     */
+    var `collection`: kotlin.String, 
+    var `agentId`: kotlin.String, 
+    var `text`: kotlin.String, 
+    var `score`: kotlin.Double, 
+    /**
+     * Raw `_distance` reported by the engine, which `score` is derived from
+     * (see `DistanceMetric`). `None` when the result didn't come from a
+     * vector query.
+     */
+    var `distance`: kotlin.Double?, 
+    var `metadata`: kotlin.String?, 
+    /**
+     * `metadata` parsed into pairs when it is a JSON object (as written by
+     * `store_with_meta`), sorted by key; otherwise `None`.
+     */
+    var `meta`: List<MetaPair>?, 
+    /**
+     * Milliseconds since the Unix epoch at which the entry was stored.
+     */
+    var `createdAt`: kotlin.Long, 
+    /**
+     * Provenance, populated only when `SearchOptions::include_provenance` is set.
+     * `row_id` is Lance's `_rowid`, which (without stable row ids) is the row
+     * address `fragment_id << 32 | offset`; `dataset_version` is the table
+     * version the query ran against. All three are `None` for staged rows that
+     * have not been flushed yet.
+     */
+    var `rowId`: kotlin.ULong?, 
+    var `fragmentId`: kotlin.ULong?, 
+    var `datasetVersion`: kotlin.ULong?, 
+    /**
+     * The stored embedding, populated only when requested with
+     * `SearchOptions::include_embedding` or `get`'s `include_embedding`.
+     */
+    var `embedding`: List<kotlin.Float>?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSearchResult: FfiConverterRustBuffer<SearchResult> {
+    override fun read(buf: ByteBuffer): SearchResult {
+        return SearchResult(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterOptionalDouble.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalSequenceTypeMetaPair.read(buf),
+            FfiConverterLong.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalSequenceFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SearchResult) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterString.allocationSize(value.`collection`) +
+            FfiConverterString.allocationSize(value.`agentId`) +
+            FfiConverterString.allocationSize(value.`text`) +
+            FfiConverterDouble.allocationSize(value.`score`) +
+            FfiConverterOptionalDouble.allocationSize(value.`distance`) +
+            FfiConverterOptionalString.allocationSize(value.`metadata`) +
+            FfiConverterOptionalSequenceTypeMetaPair.allocationSize(value.`meta`) +
+            FfiConverterLong.allocationSize(value.`createdAt`) +
+            FfiConverterOptionalULong.allocationSize(value.`rowId`) +
+            FfiConverterOptionalULong.allocationSize(value.`fragmentId`) +
+            FfiConverterOptionalULong.allocationSize(value.`datasetVersion`) +
+            FfiConverterOptionalSequenceFloat.allocationSize(value.`embedding`)
+    )
+
+    override fun write(value: SearchResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`collection`, buf)
+            FfiConverterString.write(value.`agentId`, buf)
+            FfiConverterString.write(value.`text`, buf)
+            FfiConverterDouble.write(value.`score`, buf)
+            FfiConverterOptionalDouble.write(value.`distance`, buf)
+            FfiConverterOptionalString.write(value.`metadata`, buf)
+            FfiConverterOptionalSequenceTypeMetaPair.write(value.`meta`, buf)
+            FfiConverterLong.write(value.`createdAt`, buf)
+            FfiConverterOptionalULong.write(value.`rowId`, buf)
+            FfiConverterOptionalULong.write(value.`fragmentId`, buf)
+            FfiConverterOptionalULong.write(value.`datasetVersion`, buf)
+            FfiConverterOptionalSequenceFloat.write(value.`embedding`, buf)
+    }
+}
+
+
+
+/**
+ * Opt-in write-ahead staging for `store()`.
+ * Staged entries are buffered in memory and written as a single batch on an
+ * explicit `flush()`, once `max_entries` are pending, or every
+ * `flush_interval_ms` — whichever comes first. Reads see staged entries
+ * immediately. Entries that are still pending when the handle is dropped are lost.
+ */
+data class StagingConfig (
+    var `maxEntries`: kotlin.UInt? = null, 
+    var `flushIntervalMs`: kotlin.ULong? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeStagingConfig: FfiConverterRustBuffer<StagingConfig> {
+    override fun read(buf: ByteBuffer): StagingConfig {
+        return StagingConfig(
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: StagingConfig) = (
+            FfiConverterOptionalUInt.allocationSize(value.`maxEntries`) +
+            FfiConverterOptionalULong.allocationSize(value.`flushIntervalMs`)
+    )
+
+    override fun write(value: StagingConfig, buf: ByteBuffer) {
+            FfiConverterOptionalUInt.write(value.`maxEntries`, buf)
+            FfiConverterOptionalULong.write(value.`flushIntervalMs`, buf)
+    }
+}
+
+
+
+/**
+ * Per-call options for `store`. Passing `None` uses the defaults.
+ */
+data class StoreOptions (
+    /**
+     * Requires a handle opened with `OpenOptions::enable_tags`.
+     */
+    var `tags`: List<kotlin.String>? = null, 
+    /**
+     * Collection to write to; `None` is the handle's default collection.
+     */
+    var `collection`: kotlin.String? = null, 
+    /**
+     * Written instead of the current time (ms since epoch, not negative),
+     * e.g. to import history.
+     */
+    var `createdAt`: kotlin.Long? = null, 
+    /**
+     * Fills the second vector column; requires a handle opened with
+     * `OpenOptions::embedding2_dim`. `None` leaves it null.
+     */
+    var `embedding2`: List<kotlin.Float>? = null, 
+    /**
+     * Extra column values (see `open_with_columns`); those left out are null.
+     */
+    var `columns`: List<ColumnEntry>? = null
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeStoreOptions: FfiConverterRustBuffer<StoreOptions> {
+    override fun read(buf: ByteBuffer): StoreOptions {
+        return StoreOptions(
+            FfiConverterOptionalSequenceString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalLong.read(buf),
+            FfiConverterOptionalSequenceFloat.read(buf),
+            FfiConverterOptionalSequenceTypeColumnEntry.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: StoreOptions) = (
+            FfiConverterOptionalSequenceString.allocationSize(value.`tags`) +
+            FfiConverterOptionalString.allocationSize(value.`collection`) +
+            FfiConverterOptionalLong.allocationSize(value.`createdAt`) +
+            FfiConverterOptionalSequenceFloat.allocationSize(value.`embedding2`) +
+            FfiConverterOptionalSequenceTypeColumnEntry.allocationSize(value.`columns`)
+    )
+
+    override fun write(value: StoreOptions, buf: ByteBuffer) {
+            FfiConverterOptionalSequenceString.write(value.`tags`, buf)
+            FfiConverterOptionalString.write(value.`collection`, buf)
+            FfiConverterOptionalLong.write(value.`createdAt`, buf)
+            FfiConverterOptionalSequenceFloat.write(value.`embedding2`, buf)
+            FfiConverterOptionalSequenceTypeColumnEntry.write(value.`columns`, buf)
+    }
+}
+
+
+
+/**
+ * Integrity report returned by `verify`.
+ */
+data class VerifyReport (
+    var `totalRows`: kotlin.ULong, 
+    /**
+     * Keys stored in more than one row.
+     */
+    var `duplicateKeys`: kotlin.ULong, 
+    /**
+     * Rows beyond the first for each duplicated key.
+     */
+    var `duplicateRows`: kotlin.ULong, 
+    /**
+     * Up to `VERIFY_SAMPLE_KEYS` duplicated keys, sorted.
+     */
+    var `sampleKeys`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVerifyReport: FfiConverterRustBuffer<VerifyReport> {
+    override fun read(buf: ByteBuffer): VerifyReport {
+        return VerifyReport(
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: VerifyReport) = (
+            FfiConverterULong.allocationSize(value.`totalRows`) +
+            FfiConverterULong.allocationSize(value.`duplicateKeys`) +
+            FfiConverterULong.allocationSize(value.`duplicateRows`) +
+            FfiConverterSequenceString.allocationSize(value.`sampleKeys`)
+    )
+
+    override fun write(value: VerifyReport, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`totalRows`, buf)
+            FfiConverterULong.write(value.`duplicateKeys`, buf)
+            FfiConverterULong.write(value.`duplicateRows`, buf)
+            FfiConverterSequenceString.write(value.`sampleKeys`, buf)
+    }
+}
+
+
+
+/**
+ * A committed version of a collection, as listed by `list_versions`.
+ */
+data class VersionInfo (
+    var `version`: kotlin.ULong, 
+    /**
+     * Commit time in ms since epoch.
+     */
+    var `timestampMs`: kotlin.Long
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVersionInfo: FfiConverterRustBuffer<VersionInfo> {
+    override fun read(buf: ByteBuffer): VersionInfo {
+        return VersionInfo(
+            FfiConverterULong.read(buf),
+            FfiConverterLong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: VersionInfo) = (
+            FfiConverterULong.allocationSize(value.`version`) +
+            FfiConverterLong.allocationSize(value.`timestampMs`)
+    )
+
+    override fun write(value: VersionInfo, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`version`, buf)
+            FfiConverterLong.write(value.`timestampMs`, buf)
+    }
+}
+
+
+
+/**
+ * Rows written by `store`: new keys inserted and existing rows overwritten.
+ */
+data class WriteStats (
+    var `inserted`: kotlin.ULong, 
+    var `replaced`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeWriteStats: FfiConverterRustBuffer<WriteStats> {
+    override fun read(buf: ByteBuffer): WriteStats {
+        return WriteStats(
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: WriteStats) = (
+            FfiConverterULong.allocationSize(value.`inserted`) +
+            FfiConverterULong.allocationSize(value.`replaced`)
+    )
+
+    override fun write(value: WriteStats, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`inserted`, buf)
+            FfiConverterULong.write(value.`replaced`, buf)
+    }
+}
+
+
+
+/**
+ * Type of a `ColumnSpec` column.
+ */
+
+enum class ColumnType {
+    
+    UTF8,
+    INT64,
+    FLOAT64,
+    BOOL;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeColumnType: FfiConverterRustBuffer<ColumnType> {
+    override fun read(buf: ByteBuffer) = try {
+        ColumnType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ColumnType) = 4UL
+
+    override fun write(value: ColumnType, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * A value for an extra column, whose variant must match its `ColumnType`.
+ */
+sealed class ColumnValue {
+    
+    data class Utf8(
+        val `value`: kotlin.String) : ColumnValue() {
+        companion object
+    }
+    
+    data class Int64(
+        val `value`: kotlin.Long) : ColumnValue() {
+        companion object
+    }
+    
+    data class Float64(
+        val `value`: kotlin.Double) : ColumnValue() {
+        companion object
+    }
+    
+    data class Bool(
+        val `value`: kotlin.Boolean) : ColumnValue() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeColumnValue : FfiConverterRustBuffer<ColumnValue>{
+    override fun read(buf: ByteBuffer): ColumnValue {
+        return when(buf.getInt()) {
+            1 -> ColumnValue.Utf8(
+                FfiConverterString.read(buf),
+                )
+            2 -> ColumnValue.Int64(
+                FfiConverterLong.read(buf),
+                )
+            3 -> ColumnValue.Float64(
+                FfiConverterDouble.read(buf),
+                )
+            4 -> ColumnValue.Bool(
+                FfiConverterBoolean.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: ColumnValue) = when(value) {
+        is ColumnValue.Utf8 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`value`)
+            )
+        }
+        is ColumnValue.Int64 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterLong.allocationSize(value.`value`)
+            )
+        }
+        is ColumnValue.Float64 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterDouble.allocationSize(value.`value`)
+            )
+        }
+        is ColumnValue.Bool -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterBoolean.allocationSize(value.`value`)
+            )
+        }
+    }
+
+    override fun write(value: ColumnValue, buf: ByteBuffer) {
+        when(value) {
+            is ColumnValue.Utf8 -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`value`, buf)
+                Unit
+            }
+            is ColumnValue.Int64 -> {
+                buf.putInt(2)
+                FfiConverterLong.write(value.`value`, buf)
+                Unit
+            }
+            is ColumnValue.Float64 -> {
+                buf.putInt(3)
+                FfiConverterDouble.write(value.`value`, buf)
+                Unit
+            }
+            is ColumnValue.Bool -> {
+                buf.putInt(4)
+                FfiConverterBoolean.write(value.`value`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+/**
+ * Vector distance metric for a handle. Search results map the engine's
+ * distance `d` to a higher-is-better `score` as follows:
+ * - `Cosine`: `d = 1 - cos(a, b)`, score `1 - d` (cosine similarity)
+ * - `L2`: `d = |a - b|²`, score `-d`
+ * - `Dot`: `d = 1 - a·b`, score `1 - d` (the dot product)
+ * - `Hamming`: `d` = number of differing bits, score `-d`; only for
+ * `EmbeddingDtype::Binary`
+ */
+
+enum class DistanceMetric {
+    
+    COSINE,
+    L2,
+    DOT,
+    HAMMING;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeDistanceMetric: FfiConverterRustBuffer<DistanceMetric> {
+    override fun read(buf: ByteBuffer) = try {
+        DistanceMetric.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: DistanceMetric) = 4UL
+
+    override fun write(value: DistanceMetric, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * One of the handle's vector columns (see `OpenOptions::embedding2_dim`).
+ */
+
+enum class EmbeddingColumn {
+    
+    /**
+     * `embedding`, which every row has.
+     */
+    PRIMARY,
+    /**
+     * `embedding2`, which rows may leave null.
+     */
+    SECONDARY;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEmbeddingColumn: FfiConverterRustBuffer<EmbeddingColumn> {
+    override fun read(buf: ByteBuffer) = try {
+        EmbeddingColumn.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: EmbeddingColumn) = 4UL
+
+    override fun write(value: EmbeddingColumn, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Storage type of the `embedding` column (see `OpenOptions::embedding_dtype`).
+ * Embeddings and query vectors cross the FFI as f32 either way.
+ */
+sealed class EmbeddingDtype {
+    
+    object F32 : EmbeddingDtype()
+    
+    
+    /**
+     * Half-precision floats: half the size, with about three significant
+     * digits. Values beyond ±65504 are stored as infinity.
+     */
+    object F16 : EmbeddingDtype()
+    
+    
+    /**
+     * Symmetric int8 quantization: a quarter of the size. Each value is
+     * stored as `round(v / scale)` with `scale = max_abs / 127`, so reads
+     * are within `scale / 2` of what was stored and values beyond
+     * `±max_abs` are clamped. `max_abs` is recorded in the `embedding`
+     * field's metadata and reopening with another one fails. The engine
+     * can't search int8 vectors, so searches scan every row, comparing the
+     * query quantized the same way.
+     */
+    data class Int8(
+        val `maxAbs`: kotlin.Float) : EmbeddingDtype() {
+        companion object
+    }
+    
+    /**
+     * Packed binary codes, one byte per value: `embedding_dim` counts
+     * bytes, so a 256-bit code has dimension 32. Every value must be a
+     * whole number in `0..=255`, or use `store_binary`/`search_binary`.
+     * Requires `DistanceMetric::Hamming`.
+     */
+    object Binary : EmbeddingDtype()
+    
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEmbeddingDtype : FfiConverterRustBuffer<EmbeddingDtype>{
+    override fun read(buf: ByteBuffer): EmbeddingDtype {
+        return when(buf.getInt()) {
+            1 -> EmbeddingDtype.F32
+            2 -> EmbeddingDtype.F16
+            3 -> EmbeddingDtype.Int8(
+                FfiConverterFloat.read(buf),
+                )
+            4 -> EmbeddingDtype.Binary
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: EmbeddingDtype) = when(value) {
+        is EmbeddingDtype.F32 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is EmbeddingDtype.F16 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is EmbeddingDtype.Int8 -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`maxAbs`)
+            )
+        }
+        is EmbeddingDtype.Binary -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: EmbeddingDtype, buf: ByteBuffer) {
+        when(value) {
+            is EmbeddingDtype.F32 -> {
+                buf.putInt(1)
+                Unit
+            }
+            is EmbeddingDtype.F16 -> {
+                buf.putInt(2)
+                Unit
+            }
+            is EmbeddingDtype.Int8 -> {
+                buf.putInt(3)
+                FfiConverterFloat.write(value.`maxAbs`, buf)
+                Unit
+            }
+            is EmbeddingDtype.Binary -> {
+                buf.putInt(4)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+/**
+ * How `import_parquet` combines the file's rows with the collection.
+ */
+
+enum class ImportMode {
+    
+    /**
+     * Add the rows to whatever the collection already holds.
+     */
+    APPEND,
+    /**
+     * Replace the collection's contents, including staged entries.
+     */
+    OVERWRITE;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeImportMode: FfiConverterRustBuffer<ImportMode> {
+    override fun read(buf: ByteBuffer) = try {
+        ImportMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ImportMode) = 4UL
+
+    override fun write(value: ImportMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+sealed class LanceException: kotlin.Exception() {
+    
+    class ConnectionFailed(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class TableException(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class QueryException(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class InsertException(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class DeleteException(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class SchemaException(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    class NotFound(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+    /**
+     * A vector's length doesn't match the handle's `embedding_dim`. `key`
+     * names the entry being written, when there is one, and `index` is the
+     * position of the offending entry or query in a batch call.
+     */
+    class DimensionMismatch(
+        
+        val `expected`: kotlin.Int, 
+        
+        val `actual`: kotlin.Int, 
+        
+        val `key`: kotlin.String?, 
+        
+        val `index`: kotlin.UInt?
+        ) : LanceException() {
+        override val message
+            get() = "expected=${ `expected` }, actual=${ `actual` }, key=${ `key` }, index=${ `index` }"
+    }
+    
+    /**
+     * A write lost to a concurrent writer on every attempt (see
+     * `OpenOptions::max_write_attempts`); retrying later may succeed.
+     */
+    class ConcurrencyConflict(
+        
+        val `msg`: kotlin.String
+        ) : LanceException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<LanceException> {
+        override fun lift(error_buf: RustBuffer.ByValue): LanceException = FfiConverterTypeLanceError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLanceError : FfiConverterRustBuffer<LanceException> {
+    override fun read(buf: ByteBuffer): LanceException {
+        
+
+        return when(buf.getInt()) {
+            1 -> LanceException.ConnectionFailed(
+                FfiConverterString.read(buf),
+                )
+            2 -> LanceException.TableException(
+                FfiConverterString.read(buf),
+                )
+            3 -> LanceException.QueryException(
+                FfiConverterString.read(buf),
+                )
+            4 -> LanceException.InsertException(
+                FfiConverterString.read(buf),
+                )
+            5 -> LanceException.DeleteException(
+                FfiConverterString.read(buf),
+                )
+            6 -> LanceException.SchemaException(
+                FfiConverterString.read(buf),
+                )
+            7 -> LanceException.NotFound(
+                FfiConverterString.read(buf),
+                )
+            8 -> LanceException.DimensionMismatch(
+                FfiConverterInt.read(buf),
+                FfiConverterInt.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterOptionalUInt.read(buf),
+                )
+            9 -> LanceException.ConcurrencyConflict(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: LanceException): ULong {
+        return when(value) {
+            is LanceException.ConnectionFailed -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.TableException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.QueryException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.InsertException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.DeleteException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.SchemaException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.NotFound -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is LanceException.DimensionMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterInt.allocationSize(value.`expected`)
+                + FfiConverterInt.allocationSize(value.`actual`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+                + FfiConverterOptionalUInt.allocationSize(value.`index`)
+            )
+            is LanceException.ConcurrencyConflict -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+        }
+    }
+
+    override fun write(value: LanceException, buf: ByteBuffer) {
+        when(value) {
+            is LanceException.ConnectionFailed -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.TableException -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.QueryException -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.InsertException -> {
+                buf.putInt(4)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.DeleteException -> {
+                buf.putInt(5)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.SchemaException -> {
+                buf.putInt(6)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.NotFound -> {
+                buf.putInt(7)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is LanceException.DimensionMismatch -> {
+                buf.putInt(8)
+                FfiConverterInt.write(value.`expected`, buf)
+                FfiConverterInt.write(value.`actual`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                FfiConverterOptionalUInt.write(value.`index`, buf)
+                Unit
+            }
+            is LanceException.ConcurrencyConflict -> {
+                buf.putInt(9)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+/**
+ * A typed filter predicate, compiled to an escaped SQL expression.
+ */
+sealed class MetaFilter {
+    
+    /**
+     * `agent_id` equals the given id.
+     */
+    data class AgentId(
+        val `agentId`: kotlin.String) : MetaFilter() {
+        companion object
+    }
+    
+    /**
+     * `created_at` (ms since epoch) is at or after `ms`.
+     */
+    data class CreatedSince(
+        val `ms`: kotlin.Long) : MetaFilter() {
+        companion object
+    }
+    
+    /**
+     * `created_at` (ms since epoch) is at or before `ms`.
+     */
+    data class CreatedBefore(
+        val `ms`: kotlin.Long) : MetaFilter() {
+        companion object
+    }
+    
+    /**
+     * `key` starts with `prefix`.
+     */
+    data class KeyPrefix(
+        val `prefix`: kotlin.String) : MetaFilter() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMetaFilter : FfiConverterRustBuffer<MetaFilter>{
+    override fun read(buf: ByteBuffer): MetaFilter {
+        return when(buf.getInt()) {
+            1 -> MetaFilter.AgentId(
+                FfiConverterString.read(buf),
+                )
+            2 -> MetaFilter.CreatedSince(
+                FfiConverterLong.read(buf),
+                )
+            3 -> MetaFilter.CreatedBefore(
+                FfiConverterLong.read(buf),
+                )
+            4 -> MetaFilter.KeyPrefix(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: MetaFilter) = when(value) {
+        is MetaFilter.AgentId -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`agentId`)
+            )
+        }
+        is MetaFilter.CreatedSince -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterLong.allocationSize(value.`ms`)
+            )
+        }
+        is MetaFilter.CreatedBefore -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterLong.allocationSize(value.`ms`)
+            )
+        }
+        is MetaFilter.KeyPrefix -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`prefix`)
+            )
+        }
+    }
+
+    override fun write(value: MetaFilter, buf: ByteBuffer) {
+        when(value) {
+            is MetaFilter.AgentId -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`agentId`, buf)
+                Unit
+            }
+            is MetaFilter.CreatedSince -> {
+                buf.putInt(2)
+                FfiConverterLong.write(value.`ms`, buf)
+                Unit
+            }
+            is MetaFilter.CreatedBefore -> {
+                buf.putInt(3)
+                FfiConverterLong.write(value.`ms`, buf)
+                Unit
+            }
+            is MetaFilter.KeyPrefix -> {
+                buf.putInt(4)
+                FfiConverterString.write(value.`prefix`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+/**
+ * Row order for `list` and `list_detailed`. Ties on `created_at` are broken
+ * by key.
+ */
+
+enum class SortOrder {
+    
+    CREATED_AT_ASC,
+    CREATED_AT_DESC,
+    KEY_ASC;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSortOrder: FfiConverterRustBuffer<SortOrder> {
+    override fun read(buf: ByteBuffer) = try {
+        SortOrder.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: SortOrder) = 4UL
+
+    override fun write(value: SortOrder, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+/**
+ * Progress callback for long-running bulk operations.
+ */
+public interface ProgressReporter {
+    
+    fun `onProgress`(`done`: kotlin.ULong, `total`: kotlin.ULong)
+    
+    companion object
+}
+
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+internal const val IDX_CALLBACK_FREE = 0
+// Callback return codes
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+/**
+ * @suppress
+ */
+public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
+    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+
+    internal fun drop(handle: Long) {
+        handleMap.remove(handle)
+    }
+
+    override fun lift(value: Long): CallbackInterface {
+        return handleMap.get(value)
+    }
+
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+
+    override fun lower(value: CallbackInterface) = handleMap.insert(value)
+
+    override fun allocationSize(value: CallbackInterface) = 8UL
+
+    override fun write(value: CallbackInterface, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceProgressReporter {
+    internal object `onProgress`: UniffiCallbackInterfaceProgressReporterMethod0 {
+        override fun callback(`uniffiHandle`: Long,`done`: Long,`total`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeProgressReporter.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onProgress`(
+                    FfiConverterULong.lift(`done`),
+                    FfiConverterULong.lift(`total`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeProgressReporter.handleMap.remove(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceProgressReporter.UniffiByValue(
+        `onProgress`,
+        uniffiFree,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_lancedb_ffi_fn_init_callback_vtable_progressreporter(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeProgressReporter: FfiConverterCallbackInterface<ProgressReporter>()
+
+
+
+
+
+/**
+ * Host-side embedding model used by `migrate_dimension` to re-embed stored text.
+ */
+public interface Reembedder {
+    
+    fun `embed`(`text`: kotlin.String): List<kotlin.Float>
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceReembedder {
+    internal object `embed`: UniffiCallbackInterfaceReembedderMethod0 {
+        override fun callback(`uniffiHandle`: Long,`text`: RustBuffer.ByValue,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeReembedder.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`embed`(
+                    FfiConverterString.lift(`text`),
+                )
+            }
+            val writeReturn = { value: List<kotlin.Float> -> uniffiOutReturn.setValue(FfiConverterSequenceFloat.lower(value)) }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeReembedder.handleMap.remove(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceReembedder.UniffiByValue(
+        `embed`,
+        uniffiFree,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_lancedb_ffi_fn_init_callback_vtable_reembedder(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeReembedder: FfiConverterCallbackInterface<Reembedder>()
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
+    override fun read(buf: ByteBuffer): kotlin.UInt? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterUInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.UInt?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUInt.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalInt: FfiConverterRustBuffer<kotlin.Int?> {
+    override fun read(buf: ByteBuffer): kotlin.Int? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Int?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Int?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterInt.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalULong: FfiConverterRustBuffer<kotlin.ULong?> {
+    override fun read(buf: ByteBuffer): kotlin.ULong? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterULong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ULong?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterULong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ULong?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterULong.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalLong: FfiConverterRustBuffer<kotlin.Long?> {
+    override fun read(buf: ByteBuffer): kotlin.Long? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterLong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Long?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterLong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Long?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterLong.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalDouble: FfiConverterRustBuffer<kotlin.Double?> {
+    override fun read(buf: ByteBuffer): kotlin.Double? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterDouble.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Double?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterDouble.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Double?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterDouble.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
+    override fun read(buf: ByteBuffer): kotlin.String? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterString.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.String?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeLanceDBHandle: FfiConverterRustBuffer<LanceDbHandle?> {
+    override fun read(buf: ByteBuffer): LanceDbHandle? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeLanceDBHandle.read(buf)
+    }
+
+    override fun allocationSize(value: LanceDbHandle?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeLanceDBHandle.allocationSize(value)
+        }
+    }
+
+    override fun write(value: LanceDbHandle?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeLanceDBHandle.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeGetOptions: FfiConverterRustBuffer<GetOptions?> {
+    override fun read(buf: ByteBuffer): GetOptions? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeGetOptions.read(buf)
+    }
+
+    override fun allocationSize(value: GetOptions?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeGetOptions.allocationSize(value)
+        }
+    }
+
+    override fun write(value: GetOptions?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeGetOptions.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeListOptions: FfiConverterRustBuffer<ListOptions?> {
+    override fun read(buf: ByteBuffer): ListOptions? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeListOptions.read(buf)
+    }
+
+    override fun allocationSize(value: ListOptions?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeListOptions.allocationSize(value)
+        }
+    }
+
+    override fun write(value: ListOptions?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeListOptions.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeSearchOptions: FfiConverterRustBuffer<SearchOptions?> {
+    override fun read(buf: ByteBuffer): SearchOptions? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSearchOptions.read(buf)
+    }
+
+    override fun allocationSize(value: SearchOptions?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSearchOptions.allocationSize(value)
+        }
+    }
+
+    override fun write(value: SearchOptions?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSearchOptions.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeSearchResult: FfiConverterRustBuffer<SearchResult?> {
+    override fun read(buf: ByteBuffer): SearchResult? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSearchResult.read(buf)
+    }
+
+    override fun allocationSize(value: SearchResult?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSearchResult.allocationSize(value)
+        }
+    }
+
+    override fun write(value: SearchResult?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSearchResult.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeStagingConfig: FfiConverterRustBuffer<StagingConfig?> {
+    override fun read(buf: ByteBuffer): StagingConfig? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeStagingConfig.read(buf)
+    }
+
+    override fun allocationSize(value: StagingConfig?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeStagingConfig.allocationSize(value)
+        }
+    }
+
+    override fun write(value: StagingConfig?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeStagingConfig.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeStoreOptions: FfiConverterRustBuffer<StoreOptions?> {
+    override fun read(buf: ByteBuffer): StoreOptions? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeStoreOptions.read(buf)
+    }
+
+    override fun allocationSize(value: StoreOptions?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeStoreOptions.allocationSize(value)
+        }
+    }
+
+    override fun write(value: StoreOptions?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeStoreOptions.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeDistanceMetric: FfiConverterRustBuffer<DistanceMetric?> {
+    override fun read(buf: ByteBuffer): DistanceMetric? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeDistanceMetric.read(buf)
+    }
+
+    override fun allocationSize(value: DistanceMetric?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeDistanceMetric.allocationSize(value)
+        }
+    }
+
+    override fun write(value: DistanceMetric?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeDistanceMetric.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeEmbeddingColumn: FfiConverterRustBuffer<EmbeddingColumn?> {
+    override fun read(buf: ByteBuffer): EmbeddingColumn? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeEmbeddingColumn.read(buf)
+    }
+
+    override fun allocationSize(value: EmbeddingColumn?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeEmbeddingColumn.allocationSize(value)
+        }
+    }
+
+    override fun write(value: EmbeddingColumn?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeEmbeddingColumn.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeEmbeddingDtype: FfiConverterRustBuffer<EmbeddingDtype?> {
+    override fun read(buf: ByteBuffer): EmbeddingDtype? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeEmbeddingDtype.read(buf)
+    }
+
+    override fun allocationSize(value: EmbeddingDtype?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeEmbeddingDtype.allocationSize(value)
+        }
+    }
+
+    override fun write(value: EmbeddingDtype?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeEmbeddingDtype.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeSortOrder: FfiConverterRustBuffer<SortOrder?> {
+    override fun read(buf: ByteBuffer): SortOrder? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSortOrder.read(buf)
+    }
+
+    override fun allocationSize(value: SortOrder?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSortOrder.allocationSize(value)
+        }
+    }
+
+    override fun write(value: SortOrder?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSortOrder.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeProgressReporter: FfiConverterRustBuffer<ProgressReporter?> {
+    override fun read(buf: ByteBuffer): ProgressReporter? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeProgressReporter.read(buf)
+    }
+
+    override fun allocationSize(value: ProgressReporter?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeProgressReporter.allocationSize(value)
+        }
+    }
+
+    override fun write(value: ProgressReporter?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeProgressReporter.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceFloat: FfiConverterRustBuffer<List<kotlin.Float>?> {
+    override fun read(buf: ByteBuffer): List<kotlin.Float>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceFloat.read(buf)
+    }
+
+    override fun allocationSize(value: List<kotlin.Float>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceFloat.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<kotlin.Float>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceFloat.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceString: FfiConverterRustBuffer<List<kotlin.String>?> {
+    override fun read(buf: ByteBuffer): List<kotlin.String>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceString.read(buf)
+    }
+
+    override fun allocationSize(value: List<kotlin.String>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<kotlin.String>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceString.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeColumnEntry: FfiConverterRustBuffer<List<ColumnEntry>?> {
+    override fun read(buf: ByteBuffer): List<ColumnEntry>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeColumnEntry.read(buf)
+    }
+
+    override fun allocationSize(value: List<ColumnEntry>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeColumnEntry.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<ColumnEntry>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeColumnEntry.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeColumnSpec: FfiConverterRustBuffer<List<ColumnSpec>?> {
+    override fun read(buf: ByteBuffer): List<ColumnSpec>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeColumnSpec.read(buf)
+    }
+
+    override fun allocationSize(value: List<ColumnSpec>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeColumnSpec.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<ColumnSpec>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeColumnSpec.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeMetaPair: FfiConverterRustBuffer<List<MetaPair>?> {
+    override fun read(buf: ByteBuffer): List<MetaPair>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeMetaPair.read(buf)
+    }
+
+    override fun allocationSize(value: List<MetaPair>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeMetaPair.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<MetaPair>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeMetaPair.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeMetaFilter: FfiConverterRustBuffer<List<MetaFilter>?> {
+    override fun read(buf: ByteBuffer): List<MetaFilter>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeMetaFilter.read(buf)
+    }
+
+    override fun allocationSize(value: List<MetaFilter>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeMetaFilter.allocationSize(value)
+        }
+    }
+
+    override fun write(value: List<MetaFilter>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeMetaFilter.write(value, buf)
+        }
+    }
+}
+
+
+
 
 /**
  * @suppress
  */
-public object FfiConverterTypeLanceDBHandle: FfiConverter<LanceDbHandle, Pointer> {
+public object FfiConverterOptionalMapStringTypeCollectionPreset: FfiConverterRustBuffer<Map<kotlin.String, CollectionPreset>?> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, CollectionPreset>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterMapStringTypeCollectionPreset.read(buf)
+    }
 
-    override fun lower(value: LanceDbHandle): Pointer {
-        return value.uniffiClonePointer()
+    override fun allocationSize(value: Map<kotlin.String, CollectionPreset>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterMapStringTypeCollectionPreset.allocationSize(value)
+        }
     }
 
-    override fun lift(value: Pointer): LanceDbHandle {
-        return LanceDbHandle(value)
+    override fun write(value: Map<kotlin.String, CollectionPreset>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterMapStringTypeCollectionPreset.write(value, buf)
+        }
     }
+}
 
-    override fun read(buf: ByteBuffer): LanceDbHandle {
-        // The Rust code always writes pointers as 8 bytes, and will
-        // fail to compile if they don't fit.
-        return lift(Pointer(buf.getLong()))
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceFloat: FfiConverterRustBuffer<List<kotlin.Float>> {
+    override fun read(buf: ByteBuffer): List<kotlin.Float> {
+        val len = buf.getInt()
+        return List<kotlin.Float>(len) {
+            FfiConverterFloat.read(buf)
+        }
     }
 
-    override fun allocationSize(value: LanceDbHandle) = 8UL
+    override fun allocationSize(value: List<kotlin.Float>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterFloat.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-    override fun write(value: LanceDbHandle, buf: ByteBuffer) {
-        // The Rust code always expects pointers written as 8 bytes,
-        // and will fail to compile if they don't fit.
-        buf.putLong(Pointer.nativeValue(lower(value)))
+    override fun write(value: List<kotlin.Float>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterFloat.write(it, buf)
+        }
     }
 }
 
 
 
-data class HybridSearchResult (
-    var `key`: kotlin.String, 
-    var `text`: kotlin.String, 
-    var `vectorRank`: kotlin.UInt, 
-    var `textRank`: kotlin.UInt, 
-    var `rrfScore`: kotlin.Double, 
-    var `metadata`: kotlin.String?
-) {
-    
-    companion object
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeHybridSearchResult: FfiConverterRustBuffer<HybridSearchResult> {
-    override fun read(buf: ByteBuffer): HybridSearchResult {
-        return HybridSearchResult(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterDouble.read(buf),
-            FfiConverterOptionalString.read(buf),
-        )
+public object FfiConverterSequenceTypeAgentCount: FfiConverterRustBuffer<List<AgentCount>> {
+    override fun read(buf: ByteBuffer): List<AgentCount> {
+        val len = buf.getInt()
+        return List<AgentCount>(len) {
+            FfiConverterTypeAgentCount.read(buf)
+        }
     }
 
-    override fun allocationSize(value: HybridSearchResult) = (
-            FfiConverterString.allocationSize(value.`key`) +
-            FfiConverterString.allocationSize(value.`text`) +
-            FfiConverterUInt.allocationSize(value.`vectorRank`) +
-            FfiConverterUInt.allocationSize(value.`textRank`) +
-            FfiConverterDouble.allocationSize(value.`rrfScore`) +
-            FfiConverterOptionalString.allocationSize(value.`metadata`)
-    )
+    override fun allocationSize(value: List<AgentCount>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeAgentCount.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-    override fun write(value: HybridSearchResult, buf: ByteBuffer) {
-            FfiConverterString.write(value.`key`, buf)
-            FfiConverterString.write(value.`text`, buf)
-            FfiConverterUInt.write(value.`vectorRank`, buf)
-            FfiConverterUInt.write(value.`textRank`, buf)
-            FfiConverterDouble.write(value.`rrfScore`, buf)
-            FfiConverterOptionalString.write(value.`metadata`, buf)
+    override fun write(value: List<AgentCount>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeAgentCount.write(it, buf)
+        }
     }
 }
 
 
 
-data class SearchResult (
-    var `key`: kotlin.String, 
-    var `text`: kotlin.String, 
-    var `score`: kotlin.Double, 
-    var `metadata`: kotlin.String?
-) {
-    
-    companion object
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeColumnEntry: FfiConverterRustBuffer<List<ColumnEntry>> {
+    override fun read(buf: ByteBuffer): List<ColumnEntry> {
+        val len = buf.getInt()
+        return List<ColumnEntry>(len) {
+            FfiConverterTypeColumnEntry.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<ColumnEntry>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeColumnEntry.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<ColumnEntry>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeColumnEntry.write(it, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeSearchResult: FfiConverterRustBuffer<SearchResult> {
-    override fun read(buf: ByteBuffer): SearchResult {
-        return SearchResult(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterDouble.read(buf),
-            FfiConverterOptionalString.read(buf),
-        )
+public object FfiConverterSequenceTypeColumnSpec: FfiConverterRustBuffer<List<ColumnSpec>> {
+    override fun read(buf: ByteBuffer): List<ColumnSpec> {
+        val len = buf.getInt()
+        return List<ColumnSpec>(len) {
+            FfiConverterTypeColumnSpec.read(buf)
+        }
     }
 
-    override fun allocationSize(value: SearchResult) = (
-            FfiConverterString.allocationSize(value.`key`) +
-            FfiConverterString.allocationSize(value.`text`) +
-            FfiConverterDouble.allocationSize(value.`score`) +
-            FfiConverterOptionalString.allocationSize(value.`metadata`)
-    )
+    override fun allocationSize(value: List<ColumnSpec>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeColumnSpec.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-    override fun write(value: SearchResult, buf: ByteBuffer) {
-            FfiConverterString.write(value.`key`, buf)
-            FfiConverterString.write(value.`text`, buf)
-            FfiConverterDouble.write(value.`score`, buf)
-            FfiConverterOptionalString.write(value.`metadata`, buf)
+    override fun write(value: List<ColumnSpec>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeColumnSpec.write(it, buf)
+        }
     }
 }
 
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeFieldInfo: FfiConverterRustBuffer<List<FieldInfo>> {
+    override fun read(buf: ByteBuffer): List<FieldInfo> {
+        val len = buf.getInt()
+        return List<FieldInfo>(len) {
+            FfiConverterTypeFieldInfo.read(buf)
+        }
+    }
 
-sealed class LanceException: kotlin.Exception() {
-    
-    class ConnectionFailed(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+    override fun allocationSize(value: List<FieldInfo>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeFieldInfo.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    class TableException(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+
+    override fun write(value: List<FieldInfo>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeFieldInfo.write(it, buf)
+        }
     }
-    
-    class QueryException(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustBuffer<List<HybridSearchResult>> {
+    override fun read(buf: ByteBuffer): List<HybridSearchResult> {
+        val len = buf.getInt()
+        return List<HybridSearchResult>(len) {
+            FfiConverterTypeHybridSearchResult.read(buf)
+        }
     }
-    
-    class InsertException(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+
+    override fun allocationSize(value: List<HybridSearchResult>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeHybridSearchResult.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    class DeleteException(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+
+    override fun write(value: List<HybridSearchResult>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeHybridSearchResult.write(it, buf)
+        }
     }
-    
-    class SchemaException(
-        
-        val `msg`: kotlin.String
-        ) : LanceException() {
-        override val message
-            get() = "msg=${ `msg` }"
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeIndexInfo: FfiConverterRustBuffer<List<IndexInfo>> {
+    override fun read(buf: ByteBuffer): List<IndexInfo> {
+        val len = buf.getInt()
+        return List<IndexInfo>(len) {
+            FfiConverterTypeIndexInfo.read(buf)
+        }
     }
-    
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<LanceException> {
-        override fun lift(error_buf: RustBuffer.ByValue): LanceException = FfiConverterTypeLanceError.lift(error_buf)
+    override fun allocationSize(value: List<IndexInfo>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeIndexInfo.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    
+    override fun write(value: List<IndexInfo>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeIndexInfo.write(it, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeLanceError : FfiConverterRustBuffer<LanceException> {
-    override fun read(buf: ByteBuffer): LanceException {
-        
+public object FfiConverterSequenceTypeKeyEmbedding: FfiConverterRustBuffer<List<KeyEmbedding>> {
+    override fun read(buf: ByteBuffer): List<KeyEmbedding> {
+        val len = buf.getInt()
+        return List<KeyEmbedding>(len) {
+            FfiConverterTypeKeyEmbedding.read(buf)
+        }
+    }
 
-        return when(buf.getInt()) {
-            1 -> LanceException.ConnectionFailed(
-                FfiConverterString.read(buf),
-                )
-            2 -> LanceException.TableException(
-                FfiConverterString.read(buf),
-                )
-            3 -> LanceException.QueryException(
-                FfiConverterString.read(buf),
-                )
-            4 -> LanceException.InsertException(
-                FfiConverterString.read(buf),
-                )
-            5 -> LanceException.DeleteException(
-                FfiConverterString.read(buf),
-                )
-            6 -> LanceException.SchemaException(
-                FfiConverterString.read(buf),
-                )
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+    override fun allocationSize(value: List<KeyEmbedding>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeKeyEmbedding.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<KeyEmbedding>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeKeyEmbedding.write(it, buf)
         }
     }
+}
 
-    override fun allocationSize(value: LanceException): ULong {
-        return when(value) {
-            is LanceException.ConnectionFailed -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
-            is LanceException.TableException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
-            is LanceException.QueryException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
-            is LanceException.InsertException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
-            is LanceException.DeleteException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
-            is LanceException.SchemaException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`msg`)
-            )
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeMemoryEntry: FfiConverterRustBuffer<List<MemoryEntry>> {
+    override fun read(buf: ByteBuffer): List<MemoryEntry> {
+        val len = buf.getInt()
+        return List<MemoryEntry>(len) {
+            FfiConverterTypeMemoryEntry.read(buf)
         }
     }
 
-    override fun write(value: LanceException, buf: ByteBuffer) {
-        when(value) {
-            is LanceException.ConnectionFailed -> {
-                buf.putInt(1)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-            is LanceException.TableException -> {
-                buf.putInt(2)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-            is LanceException.QueryException -> {
-                buf.putInt(3)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-            is LanceException.InsertException -> {
-                buf.putInt(4)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-            is LanceException.DeleteException -> {
-                buf.putInt(5)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-            is LanceException.SchemaException -> {
-                buf.putInt(6)
-                FfiConverterString.write(value.`msg`, buf)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun allocationSize(value: List<MemoryEntry>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMemoryEntry.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
+    override fun write(value: List<MemoryEntry>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMemoryEntry.write(it, buf)
+        }
+    }
 }
 
 
@@ -1926,28 +8431,24 @@ public object FfiConverterTypeLanceError : FfiConverterRustBuffer<LanceException
 /**
  * @suppress
  */
-public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
-    override fun read(buf: ByteBuffer): kotlin.UInt? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeMetaPair: FfiConverterRustBuffer<List<MetaPair>> {
+    override fun read(buf: ByteBuffer): List<MetaPair> {
+        val len = buf.getInt()
+        return List<MetaPair>(len) {
+            FfiConverterTypeMetaPair.read(buf)
         }
-        return FfiConverterUInt.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.UInt?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterUInt.allocationSize(value)
-        }
+    override fun allocationSize(value: List<MetaPair>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMetaPair.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterUInt.write(value, buf)
+    override fun write(value: List<MetaPair>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMetaPair.write(it, buf)
         }
     }
 }
@@ -1958,28 +8459,24 @@ public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
 /**
  * @suppress
  */
-public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
-    override fun read(buf: ByteBuffer): kotlin.String? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer<List<SearchResult>> {
+    override fun read(buf: ByteBuffer): List<SearchResult> {
+        val len = buf.getInt()
+        return List<SearchResult>(len) {
+            FfiConverterTypeSearchResult.read(buf)
         }
-        return FfiConverterString.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.String?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterString.allocationSize(value)
-        }
+    override fun allocationSize(value: List<SearchResult>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeSearchResult.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: kotlin.String?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterString.write(value, buf)
+    override fun write(value: List<SearchResult>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeSearchResult.write(it, buf)
         }
     }
 }
@@ -1990,24 +8487,24 @@ public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?>
 /**
  * @suppress
  */
-public object FfiConverterSequenceFloat: FfiConverterRustBuffer<List<kotlin.Float>> {
-    override fun read(buf: ByteBuffer): List<kotlin.Float> {
+public object FfiConverterSequenceTypeVersionInfo: FfiConverterRustBuffer<List<VersionInfo>> {
+    override fun read(buf: ByteBuffer): List<VersionInfo> {
         val len = buf.getInt()
-        return List<kotlin.Float>(len) {
-            FfiConverterFloat.read(buf)
+        return List<VersionInfo>(len) {
+            FfiConverterTypeVersionInfo.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<kotlin.Float>): ULong {
+    override fun allocationSize(value: List<VersionInfo>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterFloat.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeVersionInfo.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<kotlin.Float>, buf: ByteBuffer) {
+    override fun write(value: List<VersionInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterFloat.write(it, buf)
+            FfiConverterTypeVersionInfo.write(it, buf)
         }
     }
 }
@@ -2018,24 +8515,24 @@ public object FfiConverterSequenceFloat: FfiConverterRustBuffer<List<kotlin.Floa
 /**
  * @suppress
  */
-public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
-    override fun read(buf: ByteBuffer): List<kotlin.String> {
+public object FfiConverterSequenceTypeMetaFilter: FfiConverterRustBuffer<List<MetaFilter>> {
+    override fun read(buf: ByteBuffer): List<MetaFilter> {
         val len = buf.getInt()
-        return List<kotlin.String>(len) {
-            FfiConverterString.read(buf)
+        return List<MetaFilter>(len) {
+            FfiConverterTypeMetaFilter.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<kotlin.String>): ULong {
+    override fun allocationSize(value: List<MetaFilter>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeMetaFilter.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+    override fun write(value: List<MetaFilter>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterString.write(it, buf)
+            FfiConverterTypeMetaFilter.write(it, buf)
         }
     }
 }
@@ -2046,24 +8543,24 @@ public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.Str
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustBuffer<List<HybridSearchResult>> {
-    override fun read(buf: ByteBuffer): List<HybridSearchResult> {
+public object FfiConverterSequenceSequenceFloat: FfiConverterRustBuffer<List<List<kotlin.Float>>> {
+    override fun read(buf: ByteBuffer): List<List<kotlin.Float>> {
         val len = buf.getInt()
-        return List<HybridSearchResult>(len) {
-            FfiConverterTypeHybridSearchResult.read(buf)
+        return List<List<kotlin.Float>>(len) {
+            FfiConverterSequenceFloat.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<HybridSearchResult>): ULong {
+    override fun allocationSize(value: List<List<kotlin.Float>>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeHybridSearchResult.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterSequenceFloat.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<HybridSearchResult>, buf: ByteBuffer) {
+    override fun write(value: List<List<kotlin.Float>>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeHybridSearchResult.write(it, buf)
+            FfiConverterSequenceFloat.write(it, buf)
         }
     }
 }
@@ -2074,24 +8571,63 @@ public object FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustBuffer
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer<List<SearchResult>> {
-    override fun read(buf: ByteBuffer): List<SearchResult> {
+public object FfiConverterSequenceSequenceTypeSearchResult: FfiConverterRustBuffer<List<List<SearchResult>>> {
+    override fun read(buf: ByteBuffer): List<List<SearchResult>> {
         val len = buf.getInt()
-        return List<SearchResult>(len) {
-            FfiConverterTypeSearchResult.read(buf)
+        return List<List<SearchResult>>(len) {
+            FfiConverterSequenceTypeSearchResult.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<SearchResult>): ULong {
+    override fun allocationSize(value: List<List<SearchResult>>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeSearchResult.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterSequenceTypeSearchResult.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<SearchResult>, buf: ByteBuffer) {
+    override fun write(value: List<List<SearchResult>>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeSearchResult.write(it, buf)
+            FfiConverterSequenceTypeSearchResult.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterMapStringTypeCollectionPreset: FfiConverterRustBuffer<Map<kotlin.String, CollectionPreset>> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, CollectionPreset> {
+        val len = buf.getInt()
+        return buildMap<kotlin.String, CollectionPreset>(len) {
+            repeat(len) {
+                val k = FfiConverterString.read(buf)
+                val v = FfiConverterTypeCollectionPreset.read(buf)
+                this[k] = v
+            }
+        }
+    }
+
+    override fun allocationSize(value: Map<kotlin.String, CollectionPreset>): ULong {
+        val spaceForMapSize = 4UL
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterTypeCollectionPreset.allocationSize(v)
+        }.sum()
+        return spaceForMapSize + spaceForChildren
+    }
+
+    override fun write(value: Map<kotlin.String, CollectionPreset>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        // The parens on `(k, v)` here ensure we're calling the right method,
+        // which is important for compatibility with older android devices.
+        // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
+        value.forEach { (k, v) ->
+            FfiConverterString.write(k, buf)
+            FfiConverterTypeCollectionPreset.write(v, buf)
         }
     }
 }
@@ -2103,4 +8639,31 @@ public object FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer<List<
 
 
 
+        /**
+         * Versions of this library and of the LanceDB and Lance crates compiled
+         * into it, as `name/version` pairs separated by spaces, e.g.
+         * `lancedb-ffi/0.1.0 lancedb/0.26.2 lance/2.0.0`, for support reports.
+         */ fun `lancedbVersion`(): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_func_lancedb_version(
+        _status)
+}
+    )
+    }
+    
+
+        /**
+         * Compile `filters` into one SQL predicate (their conjunction), for methods
+         * that take a raw `filter` string. An empty list matches every row.
+         */ fun `metaFilterSql`(`filters`: List<MetaFilter>): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_lancedb_ffi_fn_func_meta_filter_sql(
+        FfiConverterSequenceTypeMetaFilter.lower(`filters`),_status)
+}
+    )
+    }
+    
+
 
@@ -428,6 +428,38 @@ fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -460,6 +492,30 @@ fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterBool : FfiConverter {
+    typealias FfiType = Int8
+    typealias SwiftType = Bool
+
+    public static func lift(_ value: Int8) throws -> Bool {
+        return value != 0
+    }
+
+    public static func lower(_ value: Bool) -> Int8 {
+        return value ? 1 : 0
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Bool, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -501,20 +557,228 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
+
 
 
 
 public protocol LanceDbHandleProtocol : AnyObject {
     
+    /**
+     * Mean of the embeddings in `collection` matching `filter` (all rows when
+     * `None`), including staged entries and leaving out soft-deleted ones.
+     * Errors when no rows match, since the centroid is then undefined.
+     */
+    func centroid(filter: String?, collection: String?) async throws  -> [Float]
+    
+    /**
+     * Delete versions of `collection` older than `older_than_seconds`, and
+     * the files only they reference, to reclaim disk. The latest version is
+     * always kept, whatever its age, and pruned versions can no longer be
+     * read or restored. A missing collection removes nothing.
+     */
+    func cleanupOldVersions(collection: String?, olderThanSeconds: UInt64) async throws  -> CleanupStats
+    
     /**
      * Drop all data. If `collection` is None, drops the default table.
      */
     func clear(collection: String?) async throws 
     
     /**
-     * Delete a memory entry by key.
+     * Drop every collection, including staged entries. A no-op on an empty
+     * database.
+     */
+    func clearAll() async throws 
+    
+    /**
+     * Flush staged entries, then release the connection and cached tables so
+     * their file handles close. Every later call on this handle, including
+     * another `close`, fails with `ConnectionFailed`. If the flush fails the
+     * handle stays open and the error is returned. Exported as
+     * `closeHandle`, since Kotlin bindings already give every object a
+     * `close()` that frees it.
+     */
+    func closeHandle() async throws 
+    
+    /**
+     * Copy every row of collection `from` into a new collection `to`, with
+     * the same schema. Staged entries are flushed first so they are copied.
+     * `from` must exist and `to` must not (`TableError` otherwise).
+     */
+    func copyCollection(from: String, to: String) async throws 
+    
+    /**
+     * Number of rows in `collection` matching `filter` (all rows when `None`),
+     * including staged entries and leaving out soft-deleted ones. Returns 0
+     * when the collection doesn't exist.
+     */
+    func count(collection: String?, filter: String?) async throws  -> UInt64
+    
+    /**
+     * Entries per `agent_id` in `collection`, including staged entries,
+     * sorted by `agent_id`. Empty when the collection doesn't exist.
+     */
+    func countsByAgent(collection: String?) async throws  -> [AgentCount]
+    
+    /**
+     * Build an IVF_PQ index on `embedding` in `collection`, using the handle's
+     * distance metric. Omitted parameters default to `sqrt(rows)` partitions
+     * and `embedding_dim / 16` sub-vectors (or the next value that divides
+     * the dimension). PQ training needs at least 256 rows. A no-op when the
+     * collection already has a vector index or doesn't exist. IVF_PQ only
+     * indexes float embeddings, so a handle opened with
+     * `EmbeddingDtype::Int8` or `EmbeddingDtype::Binary` gets a
+     * `SchemaError`; its searches scan every row instead. The engine
+     * reports no progress while training, so `progress` only hears (0, 1)
+     * when the build starts and (1, 1) once it is done.
+     */
+    func createIndex(collection: String?, numPartitions: UInt32?, numSubVectors: UInt32?, progress: ProgressReporter?) async throws 
+    
+    /**
+     * Build a BTree index on the scalar `column` of `collection` (e.g.
+     * `key`, `agent_id` or `created_at`), so equality and range filters on
+     * it, such as the `key = ...` lookups behind `get`, `exists`, `delete`
+     * and upserts, no longer scan every row. Optional: every method works
+     * without it. `optimize` keeps it up to date with later writes. A no-op
+     * when the index already exists or the collection doesn't; an unknown
+     * or non-scalar column is a `SchemaError`.
+     */
+    func createScalarIndex(collection: String?, column: String) async throws 
+    
+    /**
+     * Delete a memory entry by key from `collection`.
+     */
+    func delete(key: String, collection: String?) async throws 
+    
+    /**
+     * Delete every entry stored by `agent_id` in `collection` and return how
+     * many were removed. No-op returning 0 when the collection doesn't exist.
+     */
+    func deleteByAgent(agentId: String, collection: String?) async throws  -> UInt64
+    
+    /**
+     * Delete the entries for `keys` from `collection` with a single commit and
+     * return how many rows were removed. Staged entries are flushed first.
+     * Keys that aren't stored are ignored, and a missing collection is a
+     * no-op returning 0.
+     */
+    func deleteMany(keys: [String], collection: String?) async throws  -> UInt64
+    
+    /**
+     * Delete every row in `collection` matching the SQL predicate `filter`
+     * (e.g. `"created_at < 1700000000000 AND agent_id = 'old'"`) and return
+     * how many rows matched. The predicate is checked against the table schema
+     * before anything is deleted, and `dry_run` stops after counting.
+     * An empty filter is refused unless `delete_all` is set, so a missing
+     * predicate can't wipe the table by accident. Staged entries are flushed
+     * first so the predicate sees them.
+     *
+     * Deleted rows stay in any index until it is rebuilt, only masked at query
+     * time. `maintain_index` fixes that incrementally after the delete: data
+     * files holding deleted rows are rewritten without them, existing indexes
+     * are remapped to the rewritten rows (no retraining), and remaining
+     * unindexed rows are added. The cost is proportional to the affected
+     * files, not the table. A full index rebuild is still preferable once a
+     * large share of the table has changed, since remapping keeps the
+     * original partitioning. No-op when the table has no index.
+     */
+    func deleteWhere(filter: String, collection: String?, dryRun: Bool, deleteAll: Bool, maintainIndex: Bool) async throws  -> UInt64
+    
+    /**
+     * The columns of `collection` as stored, which may differ from the
+     * handle's own schema (e.g. a database created with another
+     * `embedding_dim`). A missing collection is a `TableError`.
+     */
+    func describe(collection: String?) async throws  -> SchemaInfo
+    
+    /**
+     * Drop every index on `column` (default `embedding`) in `collection`, e.g.
+     * to rebuild it with other parameters or to go back to flat scans. The
+     * index files stay on disk until the next `optimize`. A no-op when the
+     * column has no index or the collection doesn't exist.
+     */
+    func dropIndex(collection: String?, column: String?) async throws 
+    
+    /**
+     * Whether `collection` holds an entry for `key`, including a staged one,
+     * without reading the row. False when the collection doesn't exist or the
+     * entry is soft-deleted.
+     */
+    func exists(key: String, collection: String?) async throws  -> Bool
+    
+    /**
+     * The query plan `search` would run for these arguments with default
+     * options, for checking whether a slow search used the vector index or
+     * fell back to a flat scan. Nothing is executed, and staged entries
+     * (merged in memory) do not appear in the plan.
+     */
+    func explainSearch(queryVector: [Float], limit: UInt32, filter: String?, collection: String?) async throws  -> String
+    
+    /**
+     * Every live row of `collection`, staged entries included, as a JSON
+     * array of objects with `key`, `agent_id`, `text`, `embedding` (an array
+     * of floats), `metadata` (the stored string or null), `created_at` and,
+     * on tagged tables, `tags`, plus `embedding2` (null when unset) on
+     * collections with a second vector column, and on handles opened with
+     * extra columns a `columns` object of each set value by name. Meant for
+     * debugging and small datasets; use
+     * `export_parquet` for backups. A missing collection gives `[]`.
+     */
+    func exportJson(collection: String?) async throws  -> String
+    
+    /**
+     * Write every row of `collection`, with all of its columns, to a Parquet
+     * file at `out_path`, replacing any file already there. Rows are streamed
+     * a batch at a time. Staged entries are flushed first; a missing
+     * collection writes an empty file with the handle's schema. Returns the
+     * number of rows written.
+     */
+    func exportParquet(collection: String?, outPath: String) async throws  -> UInt64
+    
+    /**
+     * Write all staged entries to the table as a single batch.
+     * No-op when staging is disabled or nothing is pending.
+     */
+    func flush() async throws 
+    
+    /**
+     * Fetch the stored entry for `key` in `collection`, including a staged
+     * one unless `options.committed_only` is set, or `None`. A soft-deleted
+     * entry is `None`.
+     * There is no query vector, so `score` is 0 and `distance` is `None`.
+     * `include_embedding` also returns the stored embedding.
+     */
+    func get(key: String, collection: String?, includeEmbedding: Bool, options: GetOptions?) async throws  -> SearchResult?
+    
+    /**
+     * `get` for several keys with a single scan. Results follow the order of
+     * `keys`; missing and soft-deleted keys are left out and repeated keys
+     * returned once.
+     */
+    func getMany(keys: [String], collection: String?, options: GetOptions?) async throws  -> [SearchResult]
+    
+    /**
+     * Cheap probe that the database can be reached: connects and lists the
+     * collections. A failed (or closed) connection is reported as
+     * `can_connect: false` rather than an error; an empty database is healthy.
      */
-    func delete(key: String) async throws 
+    func healthCheck() async throws  -> HealthStatus
     
     /**
      * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
@@ -528,20 +792,294 @@ public protocol LanceDbHandleProtocol : AnyObject {
     func hybridSearch(queryVector: [Float], queryText: String, limit: UInt32, filter: String?, rrfK: UInt32?, vectorLimit: UInt32?) async throws  -> [HybridSearchResult]
     
     /**
-     * List memory keys, optionally filtered by prefix.
+     * Stream the Arrow IPC file at `path` into `collection` one record batch
+     * at a time, so the file never has to fit in memory. The file schema is
+     * checked once against the table (or, for a new table, the handle's
+     * schema) before anything is written. With `upsert`, rows replace
+     * existing rows with the same `key`; otherwise they are appended.
+     * Each batch is its own commit: if one fails, the error names it and the
+     * batches before it stay imported. `progress` is called with
+     * (batches done, total batches). Returns the number of rows imported.
+     */
+    func importArrowFile(collection: String?, path: String, upsert: Bool, progress: ProgressReporter?) async throws  -> UInt64
+    
+    /**
+     * Load records in the format of `export_json` into `collection` with
+     * `store_many`, so `Append` upserts by key. `columns` values are read
+     * as the types of the handle's extra columns. `Overwrite` replaces the
+     * collection's rows, and any of its staged entries, with the records in
+     * a single commit once every record has passed `store_many`'s checks; a
+     * bad record leaves the collection untouched. `Overwrite` is not
+     * available on an agent-scoped handle. Returns the number of rows
+     * written.
+     */
+    func importJson(collection: String?, json: String, mode: ImportMode) async throws  -> UInt64
+    
+    /**
+     * Load the rows of the Parquet file at `in_path` (as written by
+     * `export_parquet`) into `collection` in a single commit. `Append`
+     * upserts by key, as `import_json` does, except on an append-only handle,
+     * where rows are added as they are. The file schema
+     * must match the table's for `Append` to an existing collection, and the
+     * handle's (including `embedding_dim`) otherwise; a mismatch is a
+     * `SchemaError` and nothing is written. Rows are read
+     * `DEFAULT_CHUNK_ROWS` at a time and `progress` is called with (rows
+     * read, total rows) after each chunk. Returns the number of rows
+     * imported.
+     */
+    func importParquet(collection: String?, inPath: String, mode: ImportMode, progress: ProgressReporter?) async throws  -> UInt64
+    
+    /**
+     * The indexes of `collection`, sorted by column, with how many rows each
+     * covers. Empty when the collection has none or doesn't exist.
+     */
+    func indexInfo(collection: String?) async throws  -> [IndexInfo]
+    
+    /**
+     * List memory keys in `options.collection`, optionally filtered by
+     * prefix, returning at most `limit`. See `ListOptions` for paging, time
+     * windows, versions and ordering. Soft-deleted keys are left out unless
+     * `options.include_deleted` is set.
      */
-    func list(prefix: String?, limit: UInt32?) async throws  -> [String]
+    func list(prefix: String?, limit: UInt32?, options: ListOptions?) async throws  -> [String]
     
     /**
-     * Search for the `limit` nearest neighbours to `query_vector`.
+     * Distinct `agent_id`s with at least one entry in `collection`, including
+     * staged entries, sorted. Empty when the collection doesn't exist.
+     */
+    func listAgents(collection: String?) async throws  -> [String]
+    
+    /**
+     * Like `list`, but returns each entry's text, metadata and timestamps as
+     * `SearchResult`s. There is no query vector, so `score` is 0 and
+     * `distance` is None. `options` are as for `list`.
+     */
+    func listDetailed(prefix: String?, limit: UInt32?, options: ListOptions?) async throws  -> [SearchResult]
+    
+    /**
+     * Committed versions of `collection`, oldest first. Every write creates
+     * one; pass its number as `version` to `search` or `list` to read the
+     * collection as it was then. A missing collection has no versions.
+     */
+    func listVersions(collection: String?) async throws  -> [VersionInfo]
+    
+    /**
+     * Re-embed every row of `collection` with `reembed` and rewrite it at
+     * `new_dim`, e.g. after switching embedding models. Rows are streamed
+     * batch by batch into a single overwrite commit, so the new table is
+     * swapped in atomically and a failure part-way leaves the old one intact.
+     * `progress` is called with (rows done, total rows) after each batch.
+     * Indexes are not carried over. The handle keeps its original
+     * `embedding_dim`; reopen with `new_dim` to use the migrated collection.
+     * Returns the number of rows migrated.
+     */
+    func migrateDimension(collection: String?, newDim: Int32, reembed: Reembedder, progress: ProgressReporter?) async throws  -> UInt64
+    
+    /**
+     * The single nearest neighbour to `query_vector` in `collection`, or
+     * `None` when nothing matches `filter` or the collection doesn't exist.
+     * A cheaper `search` with `limit = 1` that decodes only the winning row.
+     */
+    func nearest(queryVector: [Float], filter: String?, collection: String?) async throws  -> SearchResult?
+    
+    /**
+     * Compact `collection`: merge small data files and rewrite away rows
+     * that upserts and deletes left behind, then bring any vector or text
+     * index up to date with the rewritten rows and any rows written since.
+     * Old versions are kept, so earlier versions remain readable. No-op when
+     * the collection doesn't exist or has nothing to compact.
+     */
+    func optimize(collection: String?) async throws 
+    
+    /**
+     * Rename collection `from` to `to`. The engine has no native rename for
+     * local databases, so this is `copy_collection` followed by dropping
+     * `from`; large collections take a while and briefly need twice the disk.
+     */
+    func renameCollection(from: String, to: String) async throws 
+    
+    /**
+     * Roll `collection` back to `version` by committing a copy of it as the
+     * new latest version; later versions stay listed and can be restored in
+     * turn. Entries still staged are kept and land on top at the next flush.
+     * A missing collection or unknown version is a `TableError`.
+     */
+    func restore(collection: String?, version: UInt64) async throws 
+    
+    /**
+     * Undo `soft_delete` for `key` in `collection`. Returns whether a
+     * soft-deleted entry matched.
+     */
+    func restoreKey(key: String, collection: String?) async throws  -> Bool
+    
+    /**
+     * Search `options.collection` (default `"memories"`) for the `limit`
+     * nearest neighbours to `query_vector`.
      * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
      */
-    func search(queryVector: [Float], limit: UInt32, filter: String?) async throws  -> [SearchResult]
+    func search(queryVector: [Float], limit: UInt32, filter: String?, options: SearchOptions?) async throws  -> [SearchResult]
+    
+    /**
+     * `search` by Hamming distance to the packed binary `code` (see
+     * `store_binary`); the nearest codes come first.
+     */
+    func searchBinary(code: Data, limit: UInt32, filter: String?, options: SearchOptions?) async throws  -> [SearchResult]
+    
+    /**
+     * `search` with `query_vector` passed as little-endian f32 bytes (see
+     * `store_bytes`): `embedding_dim * 4` bytes (`embedding2_dim * 4` when
+     * searching `embedding2`), or any non-empty multiple of 4 up to that
+     * with `prefix_search`.
+     */
+    func searchBytes(queryVector: Data, limit: UInt32, filter: String?, options: SearchOptions?) async throws  -> [SearchResult]
+    
+    /**
+     * Like `search`, but also reports whether the results are approximate.
+     */
+    func searchDetailed(queryVector: [Float], limit: UInt32, filter: String?, options: SearchOptions?) async throws  -> SearchResponse
+    
+    /**
+     * Blend `search` and `search_text` over `collection`. Each side fetches
+     * `limit * 4` candidates and its scores are min-max normalised to
+     * `[0, 1]` (a side whose candidates all score alike gives them 1). The
+     * fused score is a weighted sum, `alpha * vector + (1 - alpha) * text`,
+     * with 0 for a side that didn't return the key, so `alpha = 1` ranks by
+     * vector similarity only and `alpha = 0` by keyword relevance only.
+     * Results are unique by key, best first, with `score` set to the fused
+     * value and `distance` kept from the vector side.
+     */
+    func searchHybrid(queryVector: [Float], queryText: String, limit: UInt32, alpha: Double, collection: String?) async throws  -> [SearchResult]
+    
+    /**
+     * Run `search` for each of `query_vectors` and return one result list
+     * per query, in input order. Every vector is validated before any query
+     * runs, and the queries share one connection and one snapshot of the
+     * staged entries.
+     */
+    func searchMany(queryVectors: [[Float]], limit: UInt32, filter: String?, options: SearchOptions?) async throws  -> [[SearchResult]]
+    
+    /**
+     * `search` over each of `collections`, merged into one list of the
+     * global top `limit` by score. `SearchResult::collection` tells which
+     * collection a result came from. Collections that don't exist, and
+     * repeats in `collections`, are skipped.
+     */
+    func searchMulti(collections: [String], queryVector: [Float], limit: UInt32, filter: String?) async throws  -> [SearchResult]
+    
+    /**
+     * The page of `limit` results after the nearest `offset`, plus the
+     * number of rows matching `filter`, for rendering page controls.
+     * `total_matching` is an exact count of the filtered rows, staged entries
+     * included. Without a vector index, paging through it reaches every
+     * one; with an index, each page is cut from an approximate ranking
+     * (see `SearchOptions::offset`) and can't be relied on to reach all
+     * `total_matching` rows.
+     */
+    func searchPaged(queryVector: [Float], limit: UInt32, offset: UInt32, filter: String?, collection: String?) async throws  -> SearchPage
+    
+    /**
+     * `search` using the stored embedding of `key` as the query, i.e. "more
+     * like this". `exclude_self` leaves `key` itself out of the results.
+     * A missing `key` is a `QueryError`.
+     */
+    func searchSimilar(key: String, limit: UInt32, filter: String?, excludeSelf: Bool, collection: String?) async throws  -> [SearchResult]
+    
+    /**
+     * Keyword search over `text` in `collection` using the engine's
+     * full-text (BM25) index, which is built on the first call. `score` is
+     * the BM25 relevance and `distance` is `None`. Only committed rows that
+     * aren't soft-deleted are searched; rows written after the index was built are still matched by
+     * a slower unindexed pass until `optimize` indexes them. Returns no
+     * results when the collection doesn't exist.
+     */
+    func searchText(query: String, limit: UInt32, filter: String?, collection: String?) async throws  -> [SearchResult]
+    
+    /**
+     * Move `key` in `collection` to the trash: `search` and `list` skip it
+     * until `restore_key` (or storing the key again) brings it back. Staged
+     * entries are flushed first. Returns whether a live entry matched.
+     */
+    func softDelete(key: String, collection: String?) async throws  -> Bool
+    
+    /**
+     * Row count, version history and on-disk size of `collection` in one
+     * call. A missing collection reports all zeros.
+     */
+    func stats(collection: String?) async throws  -> CollectionStats
+    
+    /**
+     * Store a memory entry in `options.collection` (default `"memories"`).
+     * Overwrites if `key` already exists, unless the handle is append-only.
+     * With staging enabled, entries for the default collection are buffered
+     * until the next flush and the returned stats are zero. Passing `None`
+     * for `options` uses the defaults (see `StoreOptions`).
+     */
+    func store(key: String, agentId: String, text: String, embedding: [Float], metadata: String?, options: StoreOptions?) async throws  -> WriteStats
+    
+    /**
+     * `store` for a handle opened with `EmbeddingDtype::Binary`, taking the
+     * packed code as `embedding_dim` bytes.
+     */
+    func storeBinary(key: String, agentId: String, text: String, code: Data, metadata: String?, collection: String?) async throws  -> WriteStats
+    
+    /**
+     * `store` with the embedding passed as `embedding_dim * 4` bytes of
+     * little-endian f32, which crosses the FFI boundary far more cheaply
+     * than a list of floats. `options` are as for `store`.
+     */
+    func storeBytes(key: String, agentId: String, text: String, embedding: Data, metadata: String?, options: StoreOptions?) async throws  -> WriteStats
+    
+    /**
+     * Store many entries with one upsert: every embedding is validated first
+     * and the rows are written in a single commit, so a bad entry fails the
+     * whole call and nothing is written. When a key repeats within `entries`
+     * the last occurrence wins. Staged entries are flushed first and the
+     * batch bypasses staging. The rows are converted and written
+     * `chunk_size` at a time (default `DEFAULT_CHUNK_ROWS`) rather than as
+     * one large record batch. `progress` is called with (rows handed to the
+     * engine, total rows) once per chunk; it starts over if a conflicting
+     * commit is retried. Returns the number of rows written.
+     */
+    func storeMany(entries: [MemoryEntry], collection: String?, chunkSize: UInt32?, progress: ProgressReporter?) async throws  -> UInt64
+    
+    /**
+     * `store` with `meta` written to `metadata` as a JSON object of strings,
+     * so it reads back as `SearchResult::meta`. When a key repeats, the last
+     * pair wins.
+     */
+    func storeWithMeta(key: String, agentId: String, text: String, embedding: [Float], meta: [MetaPair], collection: String?) async throws  -> WriteStats
+    
+    /**
+     * Mark `key` in `collection` as just used by setting its `created_at` to
+     * now, without rewriting its text or embedding, so apps can evict
+     * least-recently-used entries by `created_at`. A staged entry is updated
+     * in place. Returns whether an entry matched.
+     */
+    func touch(key: String, collection: String?) async throws  -> Bool
+    
+    /**
+     * Overwrite the `embedding` of each listed key in `collection`, e.g. after
+     * switching embedding models, leaving text, metadata and timestamps
+     * untouched. Every vector is checked before anything is written, keys
+     * not stored are skipped, and a key listed twice takes its last vector.
+     * Returns how many entries were updated.
+     */
+    func updateEmbeddings(updates: [KeyEmbedding], collection: String?) async throws  -> UInt64
+    
+    /**
+     * Set only the `metadata` of the entry stored under `key` in
+     * `collection`, leaving its text, embedding and timestamp untouched.
+     * A staged entry is updated in place. Returns whether an entry matched.
+     */
+    func updateMetadata(key: String, metadata: String?, collection: String?) async throws  -> Bool
     
     /**
-     * Store a memory entry. Overwrites if `key` already exists.
+     * Audit `collection` for rows sharing a `key`, which concurrent upserts
+     * can leave behind. Read-only: only the `key` column of committed rows is
+     * scanned, and a missing collection reports zero rows. An agent-scoped
+     * handle audits only its agent's rows.
      */
-    func store(key: String, agentId: String, text: String, embedding: [Float], metadata: String?) async throws 
+    func verify(collection: String?) async throws  -> VerifyReport
     
 }
 
@@ -596,6 +1134,7 @@ open class LanceDbHandle:
     /**
      * Open (or create) a LanceDB database at `db_path`.
      * `embedding_dim` is the fixed vector dimension (e.g. 1536 for OpenAI ada-002).
+     * A `memory://` path keeps the database in process memory instead.
      */
 public static func `open`(dbPath: String, embeddingDim: Int32)async throws  -> LanceDbHandle {
     return
@@ -612,125 +1151,479 @@ public static func `open`(dbPath: String, embeddingDim: Int32)async throws  -> L
         )
 }
     
-
-    
     /**
-     * Drop all data. If `collection` is None, drops the default table.
+     * Open a fresh database held only in process memory, for caches and
+     * tests. It behaves like an on-disk one and its data is freed when this
+     * handle is closed or dropped. Other handles opened on its `db_path`
+     * share the store but don't free it.
      */
-open func clear(collection: String?)async throws  {
+public static func openMemory(embeddingDim: Int32)async throws  -> LanceDbHandle {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(
-                    self.uniffiClonePointer(),
-                    FfiConverterOptionString.lower(collection)
+                uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_memory(FfiConverterInt32.lower(embeddingDim)
                 )
             },
-            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
-            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
-            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
-            liftFunc: { $0 },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
             errorHandler: FfiConverterTypeLanceError.lift
         )
 }
     
     /**
-     * Delete a memory entry by key.
+     * Like `open`, but every method that writes fails with `TableError`
+     * (see `OpenOptions::read_only`); reads work as usual. `search_text`
+     * doesn't build the missing text index it would otherwise create.
      */
-open func delete(key: String)async throws  {
+public static func openReadOnly(dbPath: String, embeddingDim: Int32)async throws  -> LanceDbHandle {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(
-                    self.uniffiClonePointer(),
-                    FfiConverterString.lower(key)
+                uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_read_only(FfiConverterString.lower(dbPath),FfiConverterInt32.lower(embeddingDim)
                 )
             },
-            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
-            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
-            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
-            liftFunc: { $0 },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
             errorHandler: FfiConverterTypeLanceError.lift
         )
 }
     
     /**
-     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
-     * `query_vector`: embedding for ANN search.
-     * `query_text`: text query for BM25 scoring.
-     * `limit`: number of results to return.
-     * `filter`: optional SQL-like predicate.
-     * `rrf_k`: RRF constant (default 60).
-     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     * Like `open`, but confined to the entries of `agent_id`, for isolating
+     * tenants: every read, update and delete only matches rows with that
+     * `agent_id` (ANDed with any caller filter), and `store` writes it
+     * whatever `agent_id` is passed. A key already stored by another agent
+     * is left untouched by writes through this handle. Methods acting on
+     * whole collections regardless of agent (`clear_all`, copies, renames,
+     * `restore`, `migrate_dimension` and the Arrow/Parquet import and
+     * export) fail with `TableError`; `clear` deletes only the agent's rows.
      */
-open func hybridSearch(queryVector: [Float], queryText: String, limit: UInt32, filter: String?, rrfK: UInt32?, vectorLimit: UInt32?)async throws  -> [HybridSearchResult] {
+public static func openScoped(dbPath: String, embeddingDim: Int32, agentId: String)async throws  -> LanceDbHandle {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(
-                    self.uniffiClonePointer(),
-                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterString.lower(queryText),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionUInt32.lower(rrfK),FfiConverterOptionUInt32.lower(vectorLimit)
+                uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_scoped(FfiConverterString.lower(dbPath),FfiConverterInt32.lower(embeddingDim),FfiConverterString.lower(agentId)
                 )
             },
-            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
-            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
-            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeHybridSearchResult.lift,
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
             errorHandler: FfiConverterTypeLanceError.lift
         )
 }
     
     /**
-     * List memory keys, optionally filtered by prefix.
+     * Like `open`, with `extra_columns` added to the schema of every
+     * collection (see `OpenOptions::extra_columns`). Values are passed to
+     * `store` through its `columns` argument and can be filtered on by name,
+     * e.g. `"importance >= 3"`. Collections created without a column gain it
+     * on their next write, null for existing rows.
      */
-open func list(prefix: String?, limit: UInt32?)async throws  -> [String] {
+public static func openWithColumns(dbPath: String, embeddingDim: Int32, extraColumns: [ColumnSpec])async throws  -> LanceDbHandle {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_list(
+                uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_columns(FfiConverterString.lower(dbPath),FfiConverterInt32.lower(embeddingDim),FfiConverterSequenceTypeColumnSpec.lower(extraColumns)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Like `open`, but with handle-level `options` (see `OpenOptions`).
+     */
+public static func openWithOptions(dbPath: String, embeddingDim: Int32, options: OpenOptions)async throws  -> LanceDbHandle {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_options(FfiConverterString.lower(dbPath),FfiConverterInt32.lower(embeddingDim),FfiConverterTypeOpenOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+
+    
+    /**
+     * Mean of the embeddings in `collection` matching `filter` (all rows when
+     * `None`), including staged entries and leaving out soft-deleted ones.
+     * Errors when no rows match, since the centroid is then undefined.
+     */
+open func centroid(filter: String?, collection: String?)async throws  -> [Float] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_centroid(
                     self.uniffiClonePointer(),
-                    FfiConverterOptionString.lower(prefix),FfiConverterOptionUInt32.lower(limit)
+                    FfiConverterOptionString.lower(filter),FfiConverterOptionString.lower(collection)
                 )
             },
             pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
             completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
             freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceString.lift,
+            liftFunc: FfiConverterSequenceFloat.lift,
             errorHandler: FfiConverterTypeLanceError.lift
         )
 }
     
     /**
-     * Search for the `limit` nearest neighbours to `query_vector`.
-     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     * Delete versions of `collection` older than `older_than_seconds`, and
+     * the files only they reference, to reclaim disk. The latest version is
+     * always kept, whatever its age, and pruned versions can no longer be
+     * read or restored. A missing collection removes nothing.
      */
-open func search(queryVector: [Float], limit: UInt32, filter: String?)async throws  -> [SearchResult] {
+open func cleanupOldVersions(collection: String?, olderThanSeconds: UInt64)async throws  -> CleanupStats {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_search(
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_cleanup_old_versions(
                     self.uniffiClonePointer(),
-                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter)
+                    FfiConverterOptionString.lower(collection),FfiConverterUInt64.lower(olderThanSeconds)
                 )
             },
             pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
             completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
             freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            liftFunc: FfiConverterTypeCleanupStats.lift,
             errorHandler: FfiConverterTypeLanceError.lift
         )
 }
     
     /**
-     * Store a memory entry. Overwrites if `key` already exists.
+     * Drop all data. If `collection` is None, drops the default table.
      */
-open func store(key: String, agentId: String, text: String, embedding: [Float], metadata: String?)async throws  {
+open func clear(collection: String?)async throws  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
-                uniffi_lancedb_ffi_fn_method_lancedbhandle_store(
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Drop every collection, including staged entries. A no-op on an empty
+     * database.
+     */
+open func clearAll()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_clear_all(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Flush staged entries, then release the connection and cached tables so
+     * their file handles close. Every later call on this handle, including
+     * another `close`, fails with `ConnectionFailed`. If the flush fails the
+     * handle stays open and the error is returned. Exported as
+     * `closeHandle`, since Kotlin bindings already give every object a
+     * `close()` that frees it.
+     */
+open func closeHandle()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_close_handle(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Copy every row of collection `from` into a new collection `to`, with
+     * the same schema. Staged entries are flushed first so they are copied.
+     * `from` must exist and `to` must not (`TableError` otherwise).
+     */
+open func copyCollection(from: String, to: String)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_copy_collection(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(from),FfiConverterString.lower(to)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Number of rows in `collection` matching `filter` (all rows when `None`),
+     * including staged entries and leaving out soft-deleted ones. Returns 0
+     * when the collection doesn't exist.
+     */
+open func count(collection: String?, filter: String?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_count(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterOptionString.lower(filter)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Entries per `agent_id` in `collection`, including staged entries,
+     * sorted by `agent_id`. Empty when the collection doesn't exist.
+     */
+open func countsByAgent(collection: String?)async throws  -> [AgentCount] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_counts_by_agent(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeAgentCount.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Build an IVF_PQ index on `embedding` in `collection`, using the handle's
+     * distance metric. Omitted parameters default to `sqrt(rows)` partitions
+     * and `embedding_dim / 16` sub-vectors (or the next value that divides
+     * the dimension). PQ training needs at least 256 rows. A no-op when the
+     * collection already has a vector index or doesn't exist. IVF_PQ only
+     * indexes float embeddings, so a handle opened with
+     * `EmbeddingDtype::Int8` or `EmbeddingDtype::Binary` gets a
+     * `SchemaError`; its searches scan every row instead. The engine
+     * reports no progress while training, so `progress` only hears (0, 1)
+     * when the build starts and (1, 1) once it is done.
+     */
+open func createIndex(collection: String?, numPartitions: UInt32?, numSubVectors: UInt32?, progress: ProgressReporter?)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_create_index(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterOptionUInt32.lower(numPartitions),FfiConverterOptionUInt32.lower(numSubVectors),FfiConverterOptionCallbackInterfaceProgressReporter.lower(progress)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Build a BTree index on the scalar `column` of `collection` (e.g.
+     * `key`, `agent_id` or `created_at`), so equality and range filters on
+     * it, such as the `key = ...` lookups behind `get`, `exists`, `delete`
+     * and upserts, no longer scan every row. Optional: every method works
+     * without it. `optimize` keeps it up to date with later writes. A no-op
+     * when the index already exists or the collection doesn't; an unknown
+     * or non-scalar column is a `SchemaError`.
+     */
+open func createScalarIndex(collection: String?, column: String)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_create_scalar_index(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterString.lower(column)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Delete a memory entry by key from `collection`.
+     */
+open func delete(key: String, collection: String?)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Delete every entry stored by `agent_id` in `collection` and return how
+     * many were removed. No-op returning 0 when the collection doesn't exist.
+     */
+open func deleteByAgent(agentId: String, collection: String?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_by_agent(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(agentId),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Delete the entries for `keys` from `collection` with a single commit and
+     * return how many rows were removed. Staged entries are flushed first.
+     * Keys that aren't stored are ignored, and a missing collection is a
+     * no-op returning 0.
+     */
+open func deleteMany(keys: [String], collection: String?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_many(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceString.lower(keys),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Delete every row in `collection` matching the SQL predicate `filter`
+     * (e.g. `"created_at < 1700000000000 AND agent_id = 'old'"`) and return
+     * how many rows matched. The predicate is checked against the table schema
+     * before anything is deleted, and `dry_run` stops after counting.
+     * An empty filter is refused unless `delete_all` is set, so a missing
+     * predicate can't wipe the table by accident. Staged entries are flushed
+     * first so the predicate sees them.
+     *
+     * Deleted rows stay in any index until it is rebuilt, only masked at query
+     * time. `maintain_index` fixes that incrementally after the delete: data
+     * files holding deleted rows are rewritten without them, existing indexes
+     * are remapped to the rewritten rows (no retraining), and remaining
+     * unindexed rows are added. The cost is proportional to the affected
+     * files, not the table. A full index rebuild is still preferable once a
+     * large share of the table has changed, since remapping keeps the
+     * original partitioning. No-op when the table has no index.
+     */
+open func deleteWhere(filter: String, collection: String?, dryRun: Bool, deleteAll: Bool, maintainIndex: Bool)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_where(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(filter),FfiConverterOptionString.lower(collection),FfiConverterBool.lower(dryRun),FfiConverterBool.lower(deleteAll),FfiConverterBool.lower(maintainIndex)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The columns of `collection` as stored, which may differ from the
+     * handle's own schema (e.g. a database created with another
+     * `embedding_dim`). A missing collection is a `TableError`.
+     */
+open func describe(collection: String?)async throws  -> SchemaInfo {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_describe(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeSchemaInfo.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Drop every index on `column` (default `embedding`) in `collection`, e.g.
+     * to rebuild it with other parameters or to go back to flat scans. The
+     * index files stay on disk until the next `optimize`. A no-op when the
+     * column has no index or the collection doesn't exist.
+     */
+open func dropIndex(collection: String?, column: String?)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_drop_index(
                     self.uniffiClonePointer(),
-                    FfiConverterString.lower(key),FfiConverterString.lower(agentId),FfiConverterString.lower(text),FfiConverterSequenceFloat.lower(embedding),FfiConverterOptionString.lower(metadata)
+                    FfiConverterOptionString.lower(collection),FfiConverterOptionString.lower(column)
                 )
             },
             pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
@@ -741,413 +1634,6344 @@ open func store(key: String, agentId: String, text: String, embedding: [Float],
         )
 }
     
+    /**
+     * Whether `collection` holds an entry for `key`, including a staged one,
+     * without reading the row. False when the collection doesn't exist or the
+     * entry is soft-deleted.
+     */
+open func exists(key: String, collection: String?)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_exists(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The query plan `search` would run for these arguments with default
+     * options, for checking whether a slow search used the vector index or
+     * fell back to a flat scan. Nothing is executed, and staged entries
+     * (merged in memory) do not appear in the plan.
+     */
+open func explainSearch(queryVector: [Float], limit: UInt32, filter: String?, collection: String?)async throws  -> String {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_explain_search(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterString.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Every live row of `collection`, staged entries included, as a JSON
+     * array of objects with `key`, `agent_id`, `text`, `embedding` (an array
+     * of floats), `metadata` (the stored string or null), `created_at` and,
+     * on tagged tables, `tags`, plus `embedding2` (null when unset) on
+     * collections with a second vector column, and on handles opened with
+     * extra columns a `columns` object of each set value by name. Meant for
+     * debugging and small datasets; use
+     * `export_parquet` for backups. A missing collection gives `[]`.
+     */
+open func exportJson(collection: String?)async throws  -> String {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_export_json(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterString.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Write every row of `collection`, with all of its columns, to a Parquet
+     * file at `out_path`, replacing any file already there. Rows are streamed
+     * a batch at a time. Staged entries are flushed first; a missing
+     * collection writes an empty file with the handle's schema. Returns the
+     * number of rows written.
+     */
+open func exportParquet(collection: String?, outPath: String)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_export_parquet(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterString.lower(outPath)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Write all staged entries to the table as a single batch.
+     * No-op when staging is disabled or nothing is pending.
+     */
+open func flush()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_flush(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Fetch the stored entry for `key` in `collection`, including a staged
+     * one unless `options.committed_only` is set, or `None`. A soft-deleted
+     * entry is `None`.
+     * There is no query vector, so `score` is 0 and `distance` is `None`.
+     * `include_embedding` also returns the stored embedding.
+     */
+open func get(key: String, collection: String?, includeEmbedding: Bool, options: GetOptions? = nil)async throws  -> SearchResult? {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_get(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection),FfiConverterBool.lower(includeEmbedding),FfiConverterOptionTypeGetOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `get` for several keys with a single scan. Results follow the order of
+     * `keys`; missing and soft-deleted keys are left out and repeated keys
+     * returned once.
+     */
+open func getMany(keys: [String], collection: String?, options: GetOptions? = nil)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_get_many(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceString.lower(keys),FfiConverterOptionString.lower(collection),FfiConverterOptionTypeGetOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Cheap probe that the database can be reached: connects and lists the
+     * collections. A failed (or closed) connection is reported as
+     * `can_connect: false` rather than an error; an empty database is healthy.
+     */
+open func healthCheck()async throws  -> HealthStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_health_check(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeHealthStatus.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
+     * `query_vector`: embedding for ANN search.
+     * `query_text`: text query for BM25 scoring.
+     * `limit`: number of results to return.
+     * `filter`: optional SQL-like predicate.
+     * `rrf_k`: RRF constant (default 60).
+     * `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+     */
+open func hybridSearch(queryVector: [Float], queryText: String, limit: UInt32, filter: String?, rrfK: UInt32?, vectorLimit: UInt32?)async throws  -> [HybridSearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterString.lower(queryText),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionUInt32.lower(rrfK),FfiConverterOptionUInt32.lower(vectorLimit)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeHybridSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Stream the Arrow IPC file at `path` into `collection` one record batch
+     * at a time, so the file never has to fit in memory. The file schema is
+     * checked once against the table (or, for a new table, the handle's
+     * schema) before anything is written. With `upsert`, rows replace
+     * existing rows with the same `key`; otherwise they are appended.
+     * Each batch is its own commit: if one fails, the error names it and the
+     * batches before it stay imported. `progress` is called with
+     * (batches done, total batches). Returns the number of rows imported.
+     */
+open func importArrowFile(collection: String?, path: String, upsert: Bool, progress: ProgressReporter?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_import_arrow_file(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterString.lower(path),FfiConverterBool.lower(upsert),FfiConverterOptionCallbackInterfaceProgressReporter.lower(progress)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Load records in the format of `export_json` into `collection` with
+     * `store_many`, so `Append` upserts by key. `columns` values are read
+     * as the types of the handle's extra columns. `Overwrite` replaces the
+     * collection's rows, and any of its staged entries, with the records in
+     * a single commit once every record has passed `store_many`'s checks; a
+     * bad record leaves the collection untouched. `Overwrite` is not
+     * available on an agent-scoped handle. Returns the number of rows
+     * written.
+     */
+open func importJson(collection: String?, json: String, mode: ImportMode)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_import_json(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterString.lower(json),FfiConverterTypeImportMode.lower(mode)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Load the rows of the Parquet file at `in_path` (as written by
+     * `export_parquet`) into `collection` in a single commit. `Append`
+     * upserts by key, as `import_json` does, except on an append-only handle,
+     * where rows are added as they are. The file schema
+     * must match the table's for `Append` to an existing collection, and the
+     * handle's (including `embedding_dim`) otherwise; a mismatch is a
+     * `SchemaError` and nothing is written. Rows are read
+     * `DEFAULT_CHUNK_ROWS` at a time and `progress` is called with (rows
+     * read, total rows) after each chunk. Returns the number of rows
+     * imported.
+     */
+open func importParquet(collection: String?, inPath: String, mode: ImportMode, progress: ProgressReporter?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_import_parquet(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterString.lower(inPath),FfiConverterTypeImportMode.lower(mode),FfiConverterOptionCallbackInterfaceProgressReporter.lower(progress)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The indexes of `collection`, sorted by column, with how many rows each
+     * covers. Empty when the collection has none or doesn't exist.
+     */
+open func indexInfo(collection: String?)async throws  -> [IndexInfo] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_index_info(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeIndexInfo.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * List memory keys in `options.collection`, optionally filtered by
+     * prefix, returning at most `limit`. See `ListOptions` for paging, time
+     * windows, versions and ordering. Soft-deleted keys are left out unless
+     * `options.include_deleted` is set.
+     */
+open func list(prefix: String?, limit: UInt32?, options: ListOptions? = nil)async throws  -> [String] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_list(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(prefix),FfiConverterOptionUInt32.lower(limit),FfiConverterOptionTypeListOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Distinct `agent_id`s with at least one entry in `collection`, including
+     * staged entries, sorted. Empty when the collection doesn't exist.
+     */
+open func listAgents(collection: String?)async throws  -> [String] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_list_agents(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Like `list`, but returns each entry's text, metadata and timestamps as
+     * `SearchResult`s. There is no query vector, so `score` is 0 and
+     * `distance` is None. `options` are as for `list`.
+     */
+open func listDetailed(prefix: String?, limit: UInt32?, options: ListOptions? = nil)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_list_detailed(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(prefix),FfiConverterOptionUInt32.lower(limit),FfiConverterOptionTypeListOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Committed versions of `collection`, oldest first. Every write creates
+     * one; pass its number as `version` to `search` or `list` to read the
+     * collection as it was then. A missing collection has no versions.
+     */
+open func listVersions(collection: String?)async throws  -> [VersionInfo] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_list_versions(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeVersionInfo.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Re-embed every row of `collection` with `reembed` and rewrite it at
+     * `new_dim`, e.g. after switching embedding models. Rows are streamed
+     * batch by batch into a single overwrite commit, so the new table is
+     * swapped in atomically and a failure part-way leaves the old one intact.
+     * `progress` is called with (rows done, total rows) after each batch.
+     * Indexes are not carried over. The handle keeps its original
+     * `embedding_dim`; reopen with `new_dim` to use the migrated collection.
+     * Returns the number of rows migrated.
+     */
+open func migrateDimension(collection: String?, newDim: Int32, reembed: Reembedder, progress: ProgressReporter?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_migrate_dimension(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterInt32.lower(newDim),FfiConverterCallbackInterfaceReembedder.lower(reembed),FfiConverterOptionCallbackInterfaceProgressReporter.lower(progress)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The single nearest neighbour to `query_vector` in `collection`, or
+     * `None` when nothing matches `filter` or the collection doesn't exist.
+     * A cheaper `search` with `limit = 1` that decodes only the winning row.
+     */
+open func nearest(queryVector: [Float], filter: String?, collection: String?)async throws  -> SearchResult? {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_nearest(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterOptionString.lower(filter),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Compact `collection`: merge small data files and rewrite away rows
+     * that upserts and deletes left behind, then bring any vector or text
+     * index up to date with the rewritten rows and any rows written since.
+     * Old versions are kept, so earlier versions remain readable. No-op when
+     * the collection doesn't exist or has nothing to compact.
+     */
+open func optimize(collection: String?)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_optimize(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Rename collection `from` to `to`. The engine has no native rename for
+     * local databases, so this is `copy_collection` followed by dropping
+     * `from`; large collections take a while and briefly need twice the disk.
+     */
+open func renameCollection(from: String, to: String)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_rename_collection(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(from),FfiConverterString.lower(to)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Roll `collection` back to `version` by committing a copy of it as the
+     * new latest version; later versions stay listed and can be restored in
+     * turn. Entries still staged are kept and land on top at the next flush.
+     * A missing collection or unknown version is a `TableError`.
+     */
+open func restore(collection: String?, version: UInt64)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_restore(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection),FfiConverterUInt64.lower(version)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Undo `soft_delete` for `key` in `collection`. Returns whether a
+     * soft-deleted entry matched.
+     */
+open func restoreKey(key: String, collection: String?)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_restore_key(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Search `options.collection` (default `"memories"`) for the `limit`
+     * nearest neighbours to `query_vector`.
+     * Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+     */
+open func search(queryVector: [Float], limit: UInt32, filter: String?, options: SearchOptions? = nil)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionTypeSearchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `search` by Hamming distance to the packed binary `code` (see
+     * `store_binary`); the nearest codes come first.
+     */
+open func searchBinary(code: Data, limit: UInt32, filter: String?, options: SearchOptions?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_binary(
+                    self.uniffiClonePointer(),
+                    FfiConverterData.lower(code),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionTypeSearchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `search` with `query_vector` passed as little-endian f32 bytes (see
+     * `store_bytes`): `embedding_dim * 4` bytes (`embedding2_dim * 4` when
+     * searching `embedding2`), or any non-empty multiple of 4 up to that
+     * with `prefix_search`.
+     */
+open func searchBytes(queryVector: Data, limit: UInt32, filter: String?, options: SearchOptions?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_bytes(
+                    self.uniffiClonePointer(),
+                    FfiConverterData.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionTypeSearchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Like `search`, but also reports whether the results are approximate.
+     */
+open func searchDetailed(queryVector: [Float], limit: UInt32, filter: String?, options: SearchOptions?)async throws  -> SearchResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_detailed(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionTypeSearchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeSearchResponse.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Blend `search` and `search_text` over `collection`. Each side fetches
+     * `limit * 4` candidates and its scores are min-max normalised to
+     * `[0, 1]` (a side whose candidates all score alike gives them 1). The
+     * fused score is a weighted sum, `alpha * vector + (1 - alpha) * text`,
+     * with 0 for a side that didn't return the key, so `alpha = 1` ranks by
+     * vector similarity only and `alpha = 0` by keyword relevance only.
+     * Results are unique by key, best first, with `score` set to the fused
+     * value and `distance` kept from the vector side.
+     */
+open func searchHybrid(queryVector: [Float], queryText: String, limit: UInt32, alpha: Double, collection: String?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_hybrid(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterString.lower(queryText),FfiConverterUInt32.lower(limit),FfiConverterDouble.lower(alpha),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Run `search` for each of `query_vectors` and return one result list
+     * per query, in input order. Every vector is validated before any query
+     * runs, and the queries share one connection and one snapshot of the
+     * staged entries.
+     */
+open func searchMany(queryVectors: [[Float]], limit: UInt32, filter: String?, options: SearchOptions?)async throws  -> [[SearchResult]] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_many(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceSequenceFloat.lower(queryVectors),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionTypeSearchOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `search` over each of `collections`, merged into one list of the
+     * global top `limit` by score. `SearchResult::collection` tells which
+     * collection a result came from. Collections that don't exist, and
+     * repeats in `collections`, are skipped.
+     */
+open func searchMulti(collections: [String], queryVector: [Float], limit: UInt32, filter: String?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_multi(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceString.lower(collections),FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The page of `limit` results after the nearest `offset`, plus the
+     * number of rows matching `filter`, for rendering page controls.
+     * `total_matching` is an exact count of the filtered rows, staged entries
+     * included. Without a vector index, paging through it reaches every
+     * one; with an index, each page is cut from an approximate ranking
+     * (see `SearchOptions::offset`) and can't be relied on to reach all
+     * `total_matching` rows.
+     */
+open func searchPaged(queryVector: [Float], limit: UInt32, offset: UInt32, filter: String?, collection: String?)async throws  -> SearchPage {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_paged(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceFloat.lower(queryVector),FfiConverterUInt32.lower(limit),FfiConverterUInt32.lower(offset),FfiConverterOptionString.lower(filter),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeSearchPage.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `search` using the stored embedding of `key` as the query, i.e. "more
+     * like this". `exclude_self` leaves `key` itself out of the results.
+     * A missing `key` is a `QueryError`.
+     */
+open func searchSimilar(key: String, limit: UInt32, filter: String?, excludeSelf: Bool, collection: String?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_similar(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterBool.lower(excludeSelf),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Keyword search over `text` in `collection` using the engine's
+     * full-text (BM25) index, which is built on the first call. `score` is
+     * the BM25 relevance and `distance` is `None`. Only committed rows that
+     * aren't soft-deleted are searched; rows written after the index was built are still matched by
+     * a slower unindexed pass until `optimize` indexes them. Returns no
+     * results when the collection doesn't exist.
+     */
+open func searchText(query: String, limit: UInt32, filter: String?, collection: String?)async throws  -> [SearchResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_search_text(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(query),FfiConverterUInt32.lower(limit),FfiConverterOptionString.lower(filter),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSearchResult.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Move `key` in `collection` to the trash: `search` and `list` skip it
+     * until `restore_key` (or storing the key again) brings it back. Staged
+     * entries are flushed first. Returns whether a live entry matched.
+     */
+open func softDelete(key: String, collection: String?)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_soft_delete(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Row count, version history and on-disk size of `collection` in one
+     * call. A missing collection reports all zeros.
+     */
+open func stats(collection: String?)async throws  -> CollectionStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_stats(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeCollectionStats.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Store a memory entry in `options.collection` (default `"memories"`).
+     * Overwrites if `key` already exists, unless the handle is append-only.
+     * With staging enabled, entries for the default collection are buffered
+     * until the next flush and the returned stats are zero. Passing `None`
+     * for `options` uses the defaults (see `StoreOptions`).
+     */
+open func store(key: String, agentId: String, text: String, embedding: [Float], metadata: String?, options: StoreOptions? = nil)async throws  -> WriteStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_store(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterString.lower(agentId),FfiConverterString.lower(text),FfiConverterSequenceFloat.lower(embedding),FfiConverterOptionString.lower(metadata),FfiConverterOptionTypeStoreOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeWriteStats.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `store` for a handle opened with `EmbeddingDtype::Binary`, taking the
+     * packed code as `embedding_dim` bytes.
+     */
+open func storeBinary(key: String, agentId: String, text: String, code: Data, metadata: String?, collection: String?)async throws  -> WriteStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_store_binary(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterString.lower(agentId),FfiConverterString.lower(text),FfiConverterData.lower(code),FfiConverterOptionString.lower(metadata),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeWriteStats.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `store` with the embedding passed as `embedding_dim * 4` bytes of
+     * little-endian f32, which crosses the FFI boundary far more cheaply
+     * than a list of floats. `options` are as for `store`.
+     */
+open func storeBytes(key: String, agentId: String, text: String, embedding: Data, metadata: String?, options: StoreOptions? = nil)async throws  -> WriteStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_store_bytes(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterString.lower(agentId),FfiConverterString.lower(text),FfiConverterData.lower(embedding),FfiConverterOptionString.lower(metadata),FfiConverterOptionTypeStoreOptions.lower(options)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeWriteStats.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Store many entries with one upsert: every embedding is validated first
+     * and the rows are written in a single commit, so a bad entry fails the
+     * whole call and nothing is written. When a key repeats within `entries`
+     * the last occurrence wins. Staged entries are flushed first and the
+     * batch bypasses staging. The rows are converted and written
+     * `chunk_size` at a time (default `DEFAULT_CHUNK_ROWS`) rather than as
+     * one large record batch. `progress` is called with (rows handed to the
+     * engine, total rows) once per chunk; it starts over if a conflicting
+     * commit is retried. Returns the number of rows written.
+     */
+open func storeMany(entries: [MemoryEntry], collection: String?, chunkSize: UInt32?, progress: ProgressReporter?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_store_many(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeMemoryEntry.lower(entries),FfiConverterOptionString.lower(collection),FfiConverterOptionUInt32.lower(chunkSize),FfiConverterOptionCallbackInterfaceProgressReporter.lower(progress)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * `store` with `meta` written to `metadata` as a JSON object of strings,
+     * so it reads back as `SearchResult::meta`. When a key repeats, the last
+     * pair wins.
+     */
+open func storeWithMeta(key: String, agentId: String, text: String, embedding: [Float], meta: [MetaPair], collection: String?)async throws  -> WriteStats {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_store_with_meta(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterString.lower(agentId),FfiConverterString.lower(text),FfiConverterSequenceFloat.lower(embedding),FfiConverterSequenceTypeMetaPair.lower(meta),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeWriteStats.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Mark `key` in `collection` as just used by setting its `created_at` to
+     * now, without rewriting its text or embedding, so apps can evict
+     * least-recently-used entries by `created_at`. A staged entry is updated
+     * in place. Returns whether an entry matched.
+     */
+open func touch(key: String, collection: String?)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_touch(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Overwrite the `embedding` of each listed key in `collection`, e.g. after
+     * switching embedding models, leaving text, metadata and timestamps
+     * untouched. Every vector is checked before anything is written, keys
+     * not stored are skipped, and a key listed twice takes its last vector.
+     * Returns how many entries were updated.
+     */
+open func updateEmbeddings(updates: [KeyEmbedding], collection: String?)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_update_embeddings(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeKeyEmbedding.lower(updates),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_u64,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_u64,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Set only the `metadata` of the entry stored under `key` in
+     * `collection`, leaving its text, embedding and timestamp untouched.
+     * A staged entry is updated in place. Returns whether an entry matched.
+     */
+open func updateMetadata(key: String, metadata: String?, collection: String?)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_update_metadata(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(key),FfiConverterOptionString.lower(metadata),FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Audit `collection` for rows sharing a `key`, which concurrent upserts
+     * can leave behind. Read-only: only the `key` column of committed rows is
+     * scanned, and a missing collection reports zero rows. An agent-scoped
+     * handle audits only its agent's rows.
+     */
+open func verify(collection: String?)async throws  -> VerifyReport {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbhandle_verify(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(collection)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeVerifyReport.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLanceDBHandle: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = LanceDbHandle
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbHandle {
+        return LanceDbHandle(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: LanceDbHandle) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LanceDbHandle {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: LanceDbHandle, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLanceDBHandle_lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbHandle {
+    return try FfiConverterTypeLanceDBHandle.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLanceDBHandle_lower(_ value: LanceDbHandle) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeLanceDBHandle.lower(value)
+}
+
+
+
+
+/**
+ * Opens `LanceDBHandle`s under app-chosen ids so several independent
+ * databases can be looked up, listed and closed together. The handles run
+ * on the shared runtime like any other.
+ */
+public protocol LanceDbManagerProtocol : AnyObject {
+    
+    /**
+     * Close the handle tracked as `id` (see `LanceDBHandle::close`) and stop
+     * tracking it. Returns false when no handle has that id. A handle that
+     * was already closed directly is just forgotten.
+     */
+    func close(id: String) async throws  -> Bool
+    
+    /**
+     * Close every tracked handle and stop tracking them. All are forgotten
+     * even when some fail to close; the first failure is returned.
+     */
+    func closeAll() async throws 
+    
+    /**
+     * The handle tracked as `id`, if any.
+     */
+    func get(id: String)  -> LanceDbHandle?
+    
+    /**
+     * Ids of the tracked handles, sorted.
+     */
+    func ids()  -> [String]
+    
+    /**
+     * Open the database at `db_path` (see `LanceDBHandle::open`) and track
+     * it as `id`. Fails with `ConnectionFailed` when `id` is already open.
+     */
+    func `open`(id: String, dbPath: String, embeddingDim: Int32) async throws  -> LanceDbHandle
+    
+}
+
+/**
+ * Opens `LanceDBHandle`s under app-chosen ids so several independent
+ * databases can be looked up, listed and closed together. The handles run
+ * on the shared runtime like any other.
+ */
+open class LanceDbManager:
+    LanceDbManagerProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_lancedb_ffi_fn_clone_lancedbmanager(self.pointer, $0) }
+    }
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_lancedb_ffi_fn_constructor_lancedbmanager_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_lancedb_ffi_fn_free_lancedbmanager(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Close the handle tracked as `id` (see `LanceDBHandle::close`) and stop
+     * tracking it. Returns false when no handle has that id. A handle that
+     * was already closed directly is just forgotten.
+     */
+open func close(id: String)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbmanager_close(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(id)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_i8,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_i8,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * Close every tracked handle and stop tracking them. All are forgotten
+     * even when some fail to close; the first failure is returned.
+     */
+open func closeAll()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbmanager_close_all(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_void,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_void,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+    /**
+     * The handle tracked as `id`, if any.
+     */
+open func get(id: String) -> LanceDbHandle? {
+    return try!  FfiConverterOptionTypeLanceDBHandle.lift(try! rustCall() {
+    uniffi_lancedb_ffi_fn_method_lancedbmanager_get(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+    /**
+     * Ids of the tracked handles, sorted.
+     */
+open func ids() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_lancedb_ffi_fn_method_lancedbmanager_ids(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Open the database at `db_path` (see `LanceDBHandle::open`) and track
+     * it as `id`. Fails with `ConnectionFailed` when `id` is already open.
+     */
+open func `open`(id: String, dbPath: String, embeddingDim: Int32)async throws  -> LanceDbHandle {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_lancedb_ffi_fn_method_lancedbmanager_open(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(id),FfiConverterString.lower(dbPath),FfiConverterInt32.lower(embeddingDim)
+                )
+            },
+            pollFunc: ffi_lancedb_ffi_rust_future_poll_pointer,
+            completeFunc: ffi_lancedb_ffi_rust_future_complete_pointer,
+            freeFunc: ffi_lancedb_ffi_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeLanceDBHandle.lift,
+            errorHandler: FfiConverterTypeLanceError.lift
+        )
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLanceDBManager: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = LanceDbManager
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbManager {
+        return LanceDbManager(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: LanceDbManager) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LanceDbManager {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: LanceDbManager, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLanceDBManager_lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbManager {
+    return try FfiConverterTypeLanceDBManager.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLanceDBManager_lower(_ value: LanceDbManager) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeLanceDBManager.lower(value)
+}
+
+
+/**
+ * Number of entries stored by one agent (see `counts_by_agent`).
+ */
+public struct AgentCount {
+    public var agentId: String
+    public var count: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(agentId: String, count: UInt64) {
+        self.agentId = agentId
+        self.count = count
+    }
+}
+
+
+
+extension AgentCount: Equatable, Hashable {
+    public static func ==(lhs: AgentCount, rhs: AgentCount) -> Bool {
+        if lhs.agentId != rhs.agentId {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(agentId)
+        hasher.combine(count)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAgentCount: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentCount {
+        return
+            try AgentCount(
+                agentId: FfiConverterString.read(from: &buf), 
+                count: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AgentCount, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.agentId, into: &buf)
+        FfiConverterUInt64.write(value.count, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentCount_lift(_ buf: RustBuffer) throws -> AgentCount {
+    return try FfiConverterTypeAgentCount.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentCount_lower(_ value: AgentCount) -> RustBuffer {
+    return FfiConverterTypeAgentCount.lower(value)
+}
+
+
+/**
+ * What `cleanup_old_versions` removed.
+ */
+public struct CleanupStats {
+    public var versionsRemoved: UInt64
+    /**
+     * Approximate size of the deleted files.
+     */
+    public var bytesRemoved: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(versionsRemoved: UInt64, 
+        /**
+         * Approximate size of the deleted files.
+         */bytesRemoved: UInt64) {
+        self.versionsRemoved = versionsRemoved
+        self.bytesRemoved = bytesRemoved
+    }
+}
+
+
+
+extension CleanupStats: Equatable, Hashable {
+    public static func ==(lhs: CleanupStats, rhs: CleanupStats) -> Bool {
+        if lhs.versionsRemoved != rhs.versionsRemoved {
+            return false
+        }
+        if lhs.bytesRemoved != rhs.bytesRemoved {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(versionsRemoved)
+        hasher.combine(bytesRemoved)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCleanupStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CleanupStats {
+        return
+            try CleanupStats(
+                versionsRemoved: FfiConverterUInt64.read(from: &buf), 
+                bytesRemoved: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CleanupStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.versionsRemoved, into: &buf)
+        FfiConverterUInt64.write(value.bytesRemoved, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCleanupStats_lift(_ buf: RustBuffer) throws -> CleanupStats {
+    return try FfiConverterTypeCleanupStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCleanupStats_lower(_ value: CleanupStats) -> RustBuffer {
+    return FfiConverterTypeCleanupStats.lower(value)
+}
+
+
+/**
+ * Per-collection read/write tuning registered via `OpenOptions`.
+ * Every field left `None` keeps the engine default.
+ */
+public struct CollectionPreset {
+    /**
+     * Rows per data file written to this collection.
+     */
+    public var maxRowsPerFile: UInt64?
+    /**
+     * Rows per row group within a data file.
+     */
+    public var maxRowsPerGroup: UInt64?
+    /**
+     * IVF partitions probed by vector searches once the collection has an index.
+     */
+    public var nprobes: UInt32?
+    /**
+     * Re-rank `refine_factor * limit` index candidates with exact distances.
+     */
+    public var refineFactor: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Rows per data file written to this collection.
+         */maxRowsPerFile: UInt64? = nil, 
+        /**
+         * Rows per row group within a data file.
+         */maxRowsPerGroup: UInt64? = nil, 
+        /**
+         * IVF partitions probed by vector searches once the collection has an index.
+         */nprobes: UInt32? = nil, 
+        /**
+         * Re-rank `refine_factor * limit` index candidates with exact distances.
+         */refineFactor: UInt32? = nil) {
+        self.maxRowsPerFile = maxRowsPerFile
+        self.maxRowsPerGroup = maxRowsPerGroup
+        self.nprobes = nprobes
+        self.refineFactor = refineFactor
+    }
+}
+
+
+
+extension CollectionPreset: Equatable, Hashable {
+    public static func ==(lhs: CollectionPreset, rhs: CollectionPreset) -> Bool {
+        if lhs.maxRowsPerFile != rhs.maxRowsPerFile {
+            return false
+        }
+        if lhs.maxRowsPerGroup != rhs.maxRowsPerGroup {
+            return false
+        }
+        if lhs.nprobes != rhs.nprobes {
+            return false
+        }
+        if lhs.refineFactor != rhs.refineFactor {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxRowsPerFile)
+        hasher.combine(maxRowsPerGroup)
+        hasher.combine(nprobes)
+        hasher.combine(refineFactor)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCollectionPreset: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CollectionPreset {
+        return
+            try CollectionPreset(
+                maxRowsPerFile: FfiConverterOptionUInt64.read(from: &buf), 
+                maxRowsPerGroup: FfiConverterOptionUInt64.read(from: &buf), 
+                nprobes: FfiConverterOptionUInt32.read(from: &buf), 
+                refineFactor: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CollectionPreset, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.maxRowsPerFile, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxRowsPerGroup, into: &buf)
+        FfiConverterOptionUInt32.write(value.nprobes, into: &buf)
+        FfiConverterOptionUInt32.write(value.refineFactor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCollectionPreset_lift(_ buf: RustBuffer) throws -> CollectionPreset {
+    return try FfiConverterTypeCollectionPreset.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCollectionPreset_lower(_ value: CollectionPreset) -> RustBuffer {
+    return FfiConverterTypeCollectionPreset.lower(value)
+}
+
+
+/**
+ * Size readout for a collection, as returned by `stats`.
+ */
+public struct CollectionStats {
+    /**
+     * Rows in the collection, including staged entries.
+     */
+    public var numRows: UInt64
+    public var numVersions: UInt64
+    public var latestVersion: UInt64
+    /**
+     * Sum of file sizes under the table directory; 0 for in-memory databases.
+     */
+    public var bytes: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Rows in the collection, including staged entries.
+         */numRows: UInt64, numVersions: UInt64, latestVersion: UInt64, 
+        /**
+         * Sum of file sizes under the table directory; 0 for in-memory databases.
+         */bytes: UInt64) {
+        self.numRows = numRows
+        self.numVersions = numVersions
+        self.latestVersion = latestVersion
+        self.bytes = bytes
+    }
+}
+
+
+
+extension CollectionStats: Equatable, Hashable {
+    public static func ==(lhs: CollectionStats, rhs: CollectionStats) -> Bool {
+        if lhs.numRows != rhs.numRows {
+            return false
+        }
+        if lhs.numVersions != rhs.numVersions {
+            return false
+        }
+        if lhs.latestVersion != rhs.latestVersion {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(numRows)
+        hasher.combine(numVersions)
+        hasher.combine(latestVersion)
+        hasher.combine(bytes)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCollectionStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CollectionStats {
+        return
+            try CollectionStats(
+                numRows: FfiConverterUInt64.read(from: &buf), 
+                numVersions: FfiConverterUInt64.read(from: &buf), 
+                latestVersion: FfiConverterUInt64.read(from: &buf), 
+                bytes: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CollectionStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.numRows, into: &buf)
+        FfiConverterUInt64.write(value.numVersions, into: &buf)
+        FfiConverterUInt64.write(value.latestVersion, into: &buf)
+        FfiConverterUInt64.write(value.bytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCollectionStats_lift(_ buf: RustBuffer) throws -> CollectionStats {
+    return try FfiConverterTypeCollectionStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCollectionStats_lower(_ value: CollectionStats) -> RustBuffer {
+    return FfiConverterTypeCollectionStats.lower(value)
+}
+
+
+/**
+ * One extra column value passed to `store`.
+ */
+public struct ColumnEntry {
+    public var name: String
+    public var value: ColumnValue
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, value: ColumnValue) {
+        self.name = name
+        self.value = value
+    }
+}
+
+
+
+extension ColumnEntry: Equatable, Hashable {
+    public static func ==(lhs: ColumnEntry, rhs: ColumnEntry) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeColumnEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ColumnEntry {
+        return
+            try ColumnEntry(
+                name: FfiConverterString.read(from: &buf), 
+                value: FfiConverterTypeColumnValue.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ColumnEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeColumnValue.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnEntry_lift(_ buf: RustBuffer) throws -> ColumnEntry {
+    return try FfiConverterTypeColumnEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnEntry_lower(_ value: ColumnEntry) -> RustBuffer {
+    return FfiConverterTypeColumnEntry.lower(value)
+}
+
+
+/**
+ * An app-defined column added to the schema (see `open_with_columns`).
+ */
+public struct ColumnSpec {
+    /**
+     * Letters, digits and `_`, not starting with a digit, so it can be used
+     * in filters unquoted. Must not clash with a built-in column.
+     */
+    public var name: String
+    public var columnType: ColumnType
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Letters, digits and `_`, not starting with a digit, so it can be used
+         * in filters unquoted. Must not clash with a built-in column.
+         */name: String, columnType: ColumnType) {
+        self.name = name
+        self.columnType = columnType
+    }
+}
+
+
+
+extension ColumnSpec: Equatable, Hashable {
+    public static func ==(lhs: ColumnSpec, rhs: ColumnSpec) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.columnType != rhs.columnType {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(columnType)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeColumnSpec: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ColumnSpec {
+        return
+            try ColumnSpec(
+                name: FfiConverterString.read(from: &buf), 
+                columnType: FfiConverterTypeColumnType.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ColumnSpec, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeColumnType.write(value.columnType, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnSpec_lift(_ buf: RustBuffer) throws -> ColumnSpec {
+    return try FfiConverterTypeColumnSpec.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnSpec_lower(_ value: ColumnSpec) -> RustBuffer {
+    return FfiConverterTypeColumnSpec.lower(value)
+}
+
+
+/**
+ * One column of a collection, as reported by `describe`.
+ */
+public struct FieldInfo {
+    public var name: String
+    /**
+     * The Arrow type, e.g. `Utf8` or `FixedSizeList(... 384)`.
+     */
+    public var dataType: String
+    public var nullable: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, 
+        /**
+         * The Arrow type, e.g. `Utf8` or `FixedSizeList(... 384)`.
+         */dataType: String, nullable: Bool) {
+        self.name = name
+        self.dataType = dataType
+        self.nullable = nullable
+    }
+}
+
+
+
+extension FieldInfo: Equatable, Hashable {
+    public static func ==(lhs: FieldInfo, rhs: FieldInfo) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.dataType != rhs.dataType {
+            return false
+        }
+        if lhs.nullable != rhs.nullable {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(dataType)
+        hasher.combine(nullable)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFieldInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldInfo {
+        return
+            try FieldInfo(
+                name: FfiConverterString.read(from: &buf), 
+                dataType: FfiConverterString.read(from: &buf), 
+                nullable: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FieldInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.dataType, into: &buf)
+        FfiConverterBool.write(value.nullable, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldInfo_lift(_ buf: RustBuffer) throws -> FieldInfo {
+    return try FfiConverterTypeFieldInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldInfo_lower(_ value: FieldInfo) -> RustBuffer {
+    return FfiConverterTypeFieldInfo.lower(value)
+}
+
+
+/**
+ * Per-call options for `get` and `get_many`. Passing `None` uses the
+ * defaults.
+ */
+public struct GetOptions {
+    /**
+     * Ignore entries still buffered by write-ahead staging, as
+     * `SearchOptions::committed_only` does.
+     */
+    public var committedOnly: Bool
+    /**
+     * Fail with `LanceError::NotFound` instead of returning `None` (for
+     * `get`) or no results (for `get_many`).
+     */
+    public var errorOnEmpty: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Ignore entries still buffered by write-ahead staging, as
+         * `SearchOptions::committed_only` does.
+         */committedOnly: Bool = false, 
+        /**
+         * Fail with `LanceError::NotFound` instead of returning `None` (for
+         * `get`) or no results (for `get_many`).
+         */errorOnEmpty: Bool = false) {
+        self.committedOnly = committedOnly
+        self.errorOnEmpty = errorOnEmpty
+    }
+}
+
+
+
+extension GetOptions: Equatable, Hashable {
+    public static func ==(lhs: GetOptions, rhs: GetOptions) -> Bool {
+        if lhs.committedOnly != rhs.committedOnly {
+            return false
+        }
+        if lhs.errorOnEmpty != rhs.errorOnEmpty {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(committedOnly)
+        hasher.combine(errorOnEmpty)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGetOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GetOptions {
+        return
+            try GetOptions(
+                committedOnly: FfiConverterBool.read(from: &buf), 
+                errorOnEmpty: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GetOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.committedOnly, into: &buf)
+        FfiConverterBool.write(value.errorOnEmpty, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGetOptions_lift(_ buf: RustBuffer) throws -> GetOptions {
+    return try FfiConverterTypeGetOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGetOptions_lower(_ value: GetOptions) -> RustBuffer {
+    return FfiConverterTypeGetOptions.lower(value)
+}
+
+
+/**
+ * Result of `health_check`.
+ */
+public struct HealthStatus {
+    public var canConnect: Bool
+    /**
+     * Collections in the database; 0 when it can't be reached.
+     */
+    public var tableCount: UInt64
+    public var dbPath: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(canConnect: Bool, 
+        /**
+         * Collections in the database; 0 when it can't be reached.
+         */tableCount: UInt64, dbPath: String) {
+        self.canConnect = canConnect
+        self.tableCount = tableCount
+        self.dbPath = dbPath
+    }
+}
+
+
+
+extension HealthStatus: Equatable, Hashable {
+    public static func ==(lhs: HealthStatus, rhs: HealthStatus) -> Bool {
+        if lhs.canConnect != rhs.canConnect {
+            return false
+        }
+        if lhs.tableCount != rhs.tableCount {
+            return false
+        }
+        if lhs.dbPath != rhs.dbPath {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(canConnect)
+        hasher.combine(tableCount)
+        hasher.combine(dbPath)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHealthStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HealthStatus {
+        return
+            try HealthStatus(
+                canConnect: FfiConverterBool.read(from: &buf), 
+                tableCount: FfiConverterUInt64.read(from: &buf), 
+                dbPath: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HealthStatus, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.canConnect, into: &buf)
+        FfiConverterUInt64.write(value.tableCount, into: &buf)
+        FfiConverterString.write(value.dbPath, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHealthStatus_lift(_ buf: RustBuffer) throws -> HealthStatus {
+    return try FfiConverterTypeHealthStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHealthStatus_lower(_ value: HealthStatus) -> RustBuffer {
+    return FfiConverterTypeHealthStatus.lower(value)
+}
+
+
+public struct HybridSearchResult {
+    public var key: String
+    public var text: String
+    public var vectorRank: UInt32
+    public var textRank: UInt32
+    public var rrfScore: Double
+    public var metadata: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, text: String, vectorRank: UInt32, textRank: UInt32, rrfScore: Double, metadata: String?) {
+        self.key = key
+        self.text = text
+        self.vectorRank = vectorRank
+        self.textRank = textRank
+        self.rrfScore = rrfScore
+        self.metadata = metadata
+    }
+}
+
+
+
+extension HybridSearchResult: Equatable, Hashable {
+    public static func ==(lhs: HybridSearchResult, rhs: HybridSearchResult) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.vectorRank != rhs.vectorRank {
+            return false
+        }
+        if lhs.textRank != rhs.textRank {
+            return false
+        }
+        if lhs.rrfScore != rhs.rrfScore {
+            return false
+        }
+        if lhs.metadata != rhs.metadata {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(text)
+        hasher.combine(vectorRank)
+        hasher.combine(textRank)
+        hasher.combine(rrfScore)
+        hasher.combine(metadata)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHybridSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HybridSearchResult {
+        return
+            try HybridSearchResult(
+                key: FfiConverterString.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                vectorRank: FfiConverterUInt32.read(from: &buf), 
+                textRank: FfiConverterUInt32.read(from: &buf), 
+                rrfScore: FfiConverterDouble.read(from: &buf), 
+                metadata: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HybridSearchResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterUInt32.write(value.vectorRank, into: &buf)
+        FfiConverterUInt32.write(value.textRank, into: &buf)
+        FfiConverterDouble.write(value.rrfScore, into: &buf)
+        FfiConverterOptionString.write(value.metadata, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHybridSearchResult_lift(_ buf: RustBuffer) throws -> HybridSearchResult {
+    return try FfiConverterTypeHybridSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHybridSearchResult_lower(_ value: HybridSearchResult) -> RustBuffer {
+    return FfiConverterTypeHybridSearchResult.lower(value)
+}
+
+
+/**
+ * One index of a collection, as returned by `index_info`.
+ */
+public struct IndexInfo {
+    public var name: String
+    public var column: String
+    /**
+     * Engine index type, e.g. `IVF_PQ`, `BTREE` or `FTS`.
+     */
+    public var indexType: String
+    public var numIndexedRows: UInt64
+    /**
+     * Rows written since the index was built or last optimized, which
+     * searches scan exhaustively.
+     */
+    public var numUnindexedRows: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, column: String, 
+        /**
+         * Engine index type, e.g. `IVF_PQ`, `BTREE` or `FTS`.
+         */indexType: String, numIndexedRows: UInt64, 
+        /**
+         * Rows written since the index was built or last optimized, which
+         * searches scan exhaustively.
+         */numUnindexedRows: UInt64) {
+        self.name = name
+        self.column = column
+        self.indexType = indexType
+        self.numIndexedRows = numIndexedRows
+        self.numUnindexedRows = numUnindexedRows
+    }
+}
+
+
+
+extension IndexInfo: Equatable, Hashable {
+    public static func ==(lhs: IndexInfo, rhs: IndexInfo) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.column != rhs.column {
+            return false
+        }
+        if lhs.indexType != rhs.indexType {
+            return false
+        }
+        if lhs.numIndexedRows != rhs.numIndexedRows {
+            return false
+        }
+        if lhs.numUnindexedRows != rhs.numUnindexedRows {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(column)
+        hasher.combine(indexType)
+        hasher.combine(numIndexedRows)
+        hasher.combine(numUnindexedRows)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIndexInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IndexInfo {
+        return
+            try IndexInfo(
+                name: FfiConverterString.read(from: &buf), 
+                column: FfiConverterString.read(from: &buf), 
+                indexType: FfiConverterString.read(from: &buf), 
+                numIndexedRows: FfiConverterUInt64.read(from: &buf), 
+                numUnindexedRows: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: IndexInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.column, into: &buf)
+        FfiConverterString.write(value.indexType, into: &buf)
+        FfiConverterUInt64.write(value.numIndexedRows, into: &buf)
+        FfiConverterUInt64.write(value.numUnindexedRows, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIndexInfo_lift(_ buf: RustBuffer) throws -> IndexInfo {
+    return try FfiConverterTypeIndexInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIndexInfo_lower(_ value: IndexInfo) -> RustBuffer {
+    return FfiConverterTypeIndexInfo.lower(value)
+}
+
+
+/**
+ * A replacement embedding for `update_embeddings`.
+ */
+public struct KeyEmbedding {
+    public var key: String
+    public var embedding: [Float]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, embedding: [Float]) {
+        self.key = key
+        self.embedding = embedding
+    }
+}
+
+
+
+extension KeyEmbedding: Equatable, Hashable {
+    public static func ==(lhs: KeyEmbedding, rhs: KeyEmbedding) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.embedding != rhs.embedding {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(embedding)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKeyEmbedding: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyEmbedding {
+        return
+            try KeyEmbedding(
+                key: FfiConverterString.read(from: &buf), 
+                embedding: FfiConverterSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: KeyEmbedding, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterSequenceFloat.write(value.embedding, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyEmbedding_lift(_ buf: RustBuffer) throws -> KeyEmbedding {
+    return try FfiConverterTypeKeyEmbedding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyEmbedding_lower(_ value: KeyEmbedding) -> RustBuffer {
+    return FfiConverterTypeKeyEmbedding.lower(value)
+}
+
+
+/**
+ * Per-call options for `list` and `list_detailed`. Passing `None` uses the
+ * defaults.
+ */
+public struct ListOptions {
+    /**
+     * Collection to list; `None` is the handle's default collection.
+     */
+    public var collection: String?
+    /**
+     * Skip that many entries first, for paging with `limit`.
+     */
+    public var offset: UInt32?
+    /**
+     * List the collection as of that version, without staged entries.
+     */
+    public var version: UInt64?
+    /**
+     * Keep only entries whose `created_at` (ms since epoch) is at or after this.
+     */
+    public var createdAfter: Int64?
+    /**
+     * Keep only entries whose `created_at` (ms since epoch) is at or before this.
+     */
+    public var createdBefore: Int64?
+    /**
+     * Sort before `offset` and `limit` apply; `None` keeps scan order.
+     */
+    public var order: SortOrder?
+    /**
+     * Also list entries hidden by `soft_delete`.
+     */
+    public var includeDeleted: Bool
+    /**
+     * Fail with `LanceError::NotFound` instead of returning no entries.
+     */
+    public var errorOnEmpty: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Collection to list; `None` is the handle's default collection.
+         */collection: String? = nil, 
+        /**
+         * Skip that many entries first, for paging with `limit`.
+         */offset: UInt32? = nil, 
+        /**
+         * List the collection as of that version, without staged entries.
+         */version: UInt64? = nil, 
+        /**
+         * Keep only entries whose `created_at` (ms since epoch) is at or after this.
+         */createdAfter: Int64? = nil, 
+        /**
+         * Keep only entries whose `created_at` (ms since epoch) is at or before this.
+         */createdBefore: Int64? = nil, 
+        /**
+         * Sort before `offset` and `limit` apply; `None` keeps scan order.
+         */order: SortOrder? = nil, 
+        /**
+         * Also list entries hidden by `soft_delete`.
+         */includeDeleted: Bool = false, 
+        /**
+         * Fail with `LanceError::NotFound` instead of returning no entries.
+         */errorOnEmpty: Bool = false) {
+        self.collection = collection
+        self.offset = offset
+        self.version = version
+        self.createdAfter = createdAfter
+        self.createdBefore = createdBefore
+        self.order = order
+        self.includeDeleted = includeDeleted
+        self.errorOnEmpty = errorOnEmpty
+    }
+}
+
+
+
+extension ListOptions: Equatable, Hashable {
+    public static func ==(lhs: ListOptions, rhs: ListOptions) -> Bool {
+        if lhs.collection != rhs.collection {
+            return false
+        }
+        if lhs.offset != rhs.offset {
+            return false
+        }
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.createdAfter != rhs.createdAfter {
+            return false
+        }
+        if lhs.createdBefore != rhs.createdBefore {
+            return false
+        }
+        if lhs.order != rhs.order {
+            return false
+        }
+        if lhs.includeDeleted != rhs.includeDeleted {
+            return false
+        }
+        if lhs.errorOnEmpty != rhs.errorOnEmpty {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(collection)
+        hasher.combine(offset)
+        hasher.combine(version)
+        hasher.combine(createdAfter)
+        hasher.combine(createdBefore)
+        hasher.combine(order)
+        hasher.combine(includeDeleted)
+        hasher.combine(errorOnEmpty)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeListOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ListOptions {
+        return
+            try ListOptions(
+                collection: FfiConverterOptionString.read(from: &buf), 
+                offset: FfiConverterOptionUInt32.read(from: &buf), 
+                version: FfiConverterOptionUInt64.read(from: &buf), 
+                createdAfter: FfiConverterOptionInt64.read(from: &buf), 
+                createdBefore: FfiConverterOptionInt64.read(from: &buf), 
+                order: FfiConverterOptionTypeSortOrder.read(from: &buf), 
+                includeDeleted: FfiConverterBool.read(from: &buf), 
+                errorOnEmpty: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ListOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.collection, into: &buf)
+        FfiConverterOptionUInt32.write(value.offset, into: &buf)
+        FfiConverterOptionUInt64.write(value.version, into: &buf)
+        FfiConverterOptionInt64.write(value.createdAfter, into: &buf)
+        FfiConverterOptionInt64.write(value.createdBefore, into: &buf)
+        FfiConverterOptionTypeSortOrder.write(value.order, into: &buf)
+        FfiConverterBool.write(value.includeDeleted, into: &buf)
+        FfiConverterBool.write(value.errorOnEmpty, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeListOptions_lift(_ buf: RustBuffer) throws -> ListOptions {
+    return try FfiConverterTypeListOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeListOptions_lower(_ value: ListOptions) -> RustBuffer {
+    return FfiConverterTypeListOptions.lower(value)
+}
+
+
+/**
+ * One row for `store_many`; fields match the `store` arguments.
+ */
+public struct MemoryEntry {
+    public var key: String
+    public var agentId: String
+    public var text: String
+    public var embedding: [Float]
+    public var metadata: String?
+    public var tags: [String]?
+    /**
+     * Creation time in ms since epoch, e.g. when importing history.
+     * `None` stamps the time of the write. Must not be negative.
+     */
+    public var createdAt: Int64?
+    /**
+     * Second vector, as `StoreOptions::embedding2`.
+     */
+    public var embedding2: [Float]?
+    /**
+     * Extra column values, as `StoreOptions::columns`.
+     */
+    public var columns: [ColumnEntry]?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, agentId: String, text: String, embedding: [Float], metadata: String? = nil, tags: [String]? = nil, 
+        /**
+         * Creation time in ms since epoch, e.g. when importing history.
+         * `None` stamps the time of the write. Must not be negative.
+         */createdAt: Int64? = nil, 
+        /**
+         * Second vector, as `StoreOptions::embedding2`.
+         */embedding2: [Float]? = nil, 
+        /**
+         * Extra column values, as `StoreOptions::columns`.
+         */columns: [ColumnEntry]? = nil) {
+        self.key = key
+        self.agentId = agentId
+        self.text = text
+        self.embedding = embedding
+        self.metadata = metadata
+        self.tags = tags
+        self.createdAt = createdAt
+        self.embedding2 = embedding2
+        self.columns = columns
+    }
+}
+
+
+
+extension MemoryEntry: Equatable, Hashable {
+    public static func ==(lhs: MemoryEntry, rhs: MemoryEntry) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.agentId != rhs.agentId {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.embedding != rhs.embedding {
+            return false
+        }
+        if lhs.metadata != rhs.metadata {
+            return false
+        }
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        if lhs.createdAt != rhs.createdAt {
+            return false
+        }
+        if lhs.embedding2 != rhs.embedding2 {
+            return false
+        }
+        if lhs.columns != rhs.columns {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(agentId)
+        hasher.combine(text)
+        hasher.combine(embedding)
+        hasher.combine(metadata)
+        hasher.combine(tags)
+        hasher.combine(createdAt)
+        hasher.combine(embedding2)
+        hasher.combine(columns)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMemoryEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MemoryEntry {
+        return
+            try MemoryEntry(
+                key: FfiConverterString.read(from: &buf), 
+                agentId: FfiConverterString.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                embedding: FfiConverterSequenceFloat.read(from: &buf), 
+                metadata: FfiConverterOptionString.read(from: &buf), 
+                tags: FfiConverterOptionSequenceString.read(from: &buf), 
+                createdAt: FfiConverterOptionInt64.read(from: &buf), 
+                embedding2: FfiConverterOptionSequenceFloat.read(from: &buf), 
+                columns: FfiConverterOptionSequenceTypeColumnEntry.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MemoryEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.agentId, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterSequenceFloat.write(value.embedding, into: &buf)
+        FfiConverterOptionString.write(value.metadata, into: &buf)
+        FfiConverterOptionSequenceString.write(value.tags, into: &buf)
+        FfiConverterOptionInt64.write(value.createdAt, into: &buf)
+        FfiConverterOptionSequenceFloat.write(value.embedding2, into: &buf)
+        FfiConverterOptionSequenceTypeColumnEntry.write(value.columns, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMemoryEntry_lift(_ buf: RustBuffer) throws -> MemoryEntry {
+    return try FfiConverterTypeMemoryEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMemoryEntry_lower(_ value: MemoryEntry) -> RustBuffer {
+    return FfiConverterTypeMemoryEntry.lower(value)
+}
+
+
+/**
+ * One metadata field for `store_with_meta`. Non-string JSON values read back
+ * through `SearchResult::meta` are rendered as their JSON text.
+ */
+public struct MetaPair {
+    public var key: String
+    public var value: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, value: String) {
+        self.key = key
+        self.value = value
+    }
+}
+
+
+
+extension MetaPair: Equatable, Hashable {
+    public static func ==(lhs: MetaPair, rhs: MetaPair) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMetaPair: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MetaPair {
+        return
+            try MetaPair(
+                key: FfiConverterString.read(from: &buf), 
+                value: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MetaPair, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMetaPair_lift(_ buf: RustBuffer) throws -> MetaPair {
+    return try FfiConverterTypeMetaPair.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMetaPair_lower(_ value: MetaPair) -> RustBuffer {
+    return FfiConverterTypeMetaPair.lower(value)
+}
+
+
+/**
+ * Handle-level options for `LanceDBHandle::open_with_options`.
+ * The defaults match plain `open`.
+ */
+public struct OpenOptions {
+    public var staging: StagingConfig?
+    /**
+     * Lance data storage version for newly created tables, e.g. `"2.0"`,
+     * `"2.1"`, `"stable"` or `"legacy"`. Existing tables keep their version.
+     * `None` uses the engine default.
+     */
+    public var storageVersion: String?
+    /**
+     * Store `tags` in a List(Utf8) column so `store` accepts tags and
+     * `search` can filter on them. An existing table gains the column (null
+     * for old rows) on its next write. Keep the flag on for a table once set.
+     */
+    public var enableTags: Bool
+    /**
+     * Tuning applied automatically to the named collections (the default
+     * collection is `"memories"`). Unlisted collections use engine defaults.
+     */
+    public var collectionPresets: [String: CollectionPreset]?
+    /**
+     * Metric used by vector searches, which also decides how `score` is
+     * derived from the engine's distance `d` (see `DistanceMetric`). `None`
+     * keeps the engine default (L2) with the original `score = 1 - d`.
+     */
+    public var distanceMetric: DistanceMetric?
+    /**
+     * L2-normalize embeddings before they are stored and query vectors
+     * before they are searched, so cosine and dot scores are comparable.
+     * Zero vectors are rejected. Prefix-search queries are left as given.
+     */
+    public var normalize: Bool
+    /**
+     * Append every write instead of upserting on `key`, so a key can hold
+     * several rows (e.g. an event log). `get`, `search` and `list` may then
+     * return more than one row per key, and staged entries no longer hide
+     * committed rows with the same key.
+     */
+    public var appendOnly: Bool
+    /**
+     * Commit with `UnsafeCommitHandler`, which avoids the hardlink() that
+     * Android's SELinux policy blocks for apps but gives no protection
+     * against concurrent writers in other processes. Set false off Android
+     * to use the engine's default, safe commit handler.
+     */
+    public var useUnsafeCommit: Bool
+    /**
+     * Attempts made by each write when its commit conflicts with a
+     * concurrent writer, with exponential backoff between them (starting at
+     * `WRITE_RETRY_BACKOFF_MS`). 0 is treated as 1.
+     */
+    public var maxWriteAttempts: UInt32
+    /**
+     * Collection used when a method's `collection` is `None`, and the one
+     * staging applies to. `None` keeps `"memories"`.
+     */
+    public var defaultTable: String?
+    /**
+     * Cap on the rows of each collection. After a `store`, `store_many` or
+     * staging flush takes a collection over it, its oldest rows by
+     * `created_at` (then key) are deleted down to the cap. Staged entries
+     * count once flushed. The cap counts every agent's rows, so it cannot
+     * be combined with `agent_scope`. `None` leaves collections unbounded.
+     */
+    public var maxRows: UInt64?
+    /**
+     * Reject every method that writes (`store`, `delete`, `clear`, index
+     * builds, imports, ...) with `TableError`, for components that must
+     * only query. See `LanceDBHandle::open_read_only`.
+     */
+    public var readOnly: Bool
+    /**
+     * Type the `embedding` column is stored as in newly created collections.
+     * Must match the existing default collection, or opening fails with
+     * `SchemaError`. `None` keeps f32.
+     */
+    public var embeddingDtype: EmbeddingDtype?
+    /**
+     * How long connecting to the database may take before failing with
+     * `ConnectionFailed`, e.g. on a stalled SD card mount. 0 waits forever.
+     */
+    public var connectTimeoutMs: UInt64
+    /**
+     * How stale a cached collection may be before a read checks for commits
+     * made through other handles or processes; this handle's own writes are
+     * always seen. 0 checks on every read, at the cost of a manifest read
+     * per call.
+     */
+    public var readConsistencyMs: UInt64
+    /**
+     * Give newly created collections a second, nullable `embedding2`
+     * vector column of this dimension, e.g. a title vector next to a body
+     * vector. Existing collections gain it on their next write. Stored with
+     * `embedding_dtype` and searched with `SearchOptions::which`.
+     */
+    public var embedding2Dim: Int32?
+    /**
+     * Skip an entry when a row with identical `text` already exists for the
+     * same `agent_id` under another key, so re-ingested content doesn't pile
+     * up as duplicates. Texts are compared by a SHA-256 kept in a
+     * `text_hash` column; rows written before it was enabled have none and
+     * never match. Staged entries are checked when they are flushed.
+     */
+    public var dedupByText: Bool
+    /**
+     * App-defined nullable columns added to every collection, which filters
+     * can use like the built-in ones (see `open_with_columns`).
+     */
+    public var extraColumns: [ColumnSpec]?
+    /**
+     * Confine the handle to one agent's entries (see `open_scoped`).
+     */
+    public var agentScope: String?
+    /**
+     * Build the vector index of a collection in the background, as
+     * `create_index` with default parameters would, once a `store`,
+     * `store_many` or `flush` leaves it with at least this many rows and no
+     * vector index. PQ training needs 256 rows, so lower thresholds wait for
+     * that many. A failed build is logged and tried again after the next
+     * write.
+     */
+    public var autoIndexThreshold: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(staging: StagingConfig? = nil, 
+        /**
+         * Lance data storage version for newly created tables, e.g. `"2.0"`,
+         * `"2.1"`, `"stable"` or `"legacy"`. Existing tables keep their version.
+         * `None` uses the engine default.
+         */storageVersion: String? = nil, 
+        /**
+         * Store `tags` in a List(Utf8) column so `store` accepts tags and
+         * `search` can filter on them. An existing table gains the column (null
+         * for old rows) on its next write. Keep the flag on for a table once set.
+         */enableTags: Bool = false, 
+        /**
+         * Tuning applied automatically to the named collections (the default
+         * collection is `"memories"`). Unlisted collections use engine defaults.
+         */collectionPresets: [String: CollectionPreset]? = nil, 
+        /**
+         * Metric used by vector searches, which also decides how `score` is
+         * derived from the engine's distance `d` (see `DistanceMetric`). `None`
+         * keeps the engine default (L2) with the original `score = 1 - d`.
+         */distanceMetric: DistanceMetric? = nil, 
+        /**
+         * L2-normalize embeddings before they are stored and query vectors
+         * before they are searched, so cosine and dot scores are comparable.
+         * Zero vectors are rejected. Prefix-search queries are left as given.
+         */normalize: Bool = false, 
+        /**
+         * Append every write instead of upserting on `key`, so a key can hold
+         * several rows (e.g. an event log). `get`, `search` and `list` may then
+         * return more than one row per key, and staged entries no longer hide
+         * committed rows with the same key.
+         */appendOnly: Bool = false, 
+        /**
+         * Commit with `UnsafeCommitHandler`, which avoids the hardlink() that
+         * Android's SELinux policy blocks for apps but gives no protection
+         * against concurrent writers in other processes. Set false off Android
+         * to use the engine's default, safe commit handler.
+         */useUnsafeCommit: Bool = true, 
+        /**
+         * Attempts made by each write when its commit conflicts with a
+         * concurrent writer, with exponential backoff between them (starting at
+         * `WRITE_RETRY_BACKOFF_MS`). 0 is treated as 1.
+         */maxWriteAttempts: UInt32 = UInt32(3), 
+        /**
+         * Collection used when a method's `collection` is `None`, and the one
+         * staging applies to. `None` keeps `"memories"`.
+         */defaultTable: String? = nil, 
+        /**
+         * Cap on the rows of each collection. After a `store`, `store_many` or
+         * staging flush takes a collection over it, its oldest rows by
+         * `created_at` (then key) are deleted down to the cap. Staged entries
+         * count once flushed. The cap counts every agent's rows, so it cannot
+         * be combined with `agent_scope`. `None` leaves collections unbounded.
+         */maxRows: UInt64? = nil, 
+        /**
+         * Reject every method that writes (`store`, `delete`, `clear`, index
+         * builds, imports, ...) with `TableError`, for components that must
+         * only query. See `LanceDBHandle::open_read_only`.
+         */readOnly: Bool = false, 
+        /**
+         * Type the `embedding` column is stored as in newly created collections.
+         * Must match the existing default collection, or opening fails with
+         * `SchemaError`. `None` keeps f32.
+         */embeddingDtype: EmbeddingDtype? = nil, 
+        /**
+         * How long connecting to the database may take before failing with
+         * `ConnectionFailed`, e.g. on a stalled SD card mount. 0 waits forever.
+         */connectTimeoutMs: UInt64 = UInt64(30000), 
+        /**
+         * How stale a cached collection may be before a read checks for commits
+         * made through other handles or processes; this handle's own writes are
+         * always seen. 0 checks on every read, at the cost of a manifest read
+         * per call.
+         */readConsistencyMs: UInt64 = UInt64(1000), 
+        /**
+         * Give newly created collections a second, nullable `embedding2`
+         * vector column of this dimension, e.g. a title vector next to a body
+         * vector. Existing collections gain it on their next write. Stored with
+         * `embedding_dtype` and searched with `SearchOptions::which`.
+         */embedding2Dim: Int32? = nil, 
+        /**
+         * Skip an entry when a row with identical `text` already exists for the
+         * same `agent_id` under another key, so re-ingested content doesn't pile
+         * up as duplicates. Texts are compared by a SHA-256 kept in a
+         * `text_hash` column; rows written before it was enabled have none and
+         * never match. Staged entries are checked when they are flushed.
+         */dedupByText: Bool = false, 
+        /**
+         * App-defined nullable columns added to every collection, which filters
+         * can use like the built-in ones (see `open_with_columns`).
+         */extraColumns: [ColumnSpec]? = nil, 
+        /**
+         * Confine the handle to one agent's entries (see `open_scoped`).
+         */agentScope: String? = nil, 
+        /**
+         * Build the vector index of a collection in the background, as
+         * `create_index` with default parameters would, once a `store`,
+         * `store_many` or `flush` leaves it with at least this many rows and no
+         * vector index. PQ training needs 256 rows, so lower thresholds wait for
+         * that many. A failed build is logged and tried again after the next
+         * write.
+         */autoIndexThreshold: UInt64? = nil) {
+        self.staging = staging
+        self.storageVersion = storageVersion
+        self.enableTags = enableTags
+        self.collectionPresets = collectionPresets
+        self.distanceMetric = distanceMetric
+        self.normalize = normalize
+        self.appendOnly = appendOnly
+        self.useUnsafeCommit = useUnsafeCommit
+        self.maxWriteAttempts = maxWriteAttempts
+        self.defaultTable = defaultTable
+        self.maxRows = maxRows
+        self.readOnly = readOnly
+        self.embeddingDtype = embeddingDtype
+        self.connectTimeoutMs = connectTimeoutMs
+        self.readConsistencyMs = readConsistencyMs
+        self.embedding2Dim = embedding2Dim
+        self.dedupByText = dedupByText
+        self.extraColumns = extraColumns
+        self.agentScope = agentScope
+        self.autoIndexThreshold = autoIndexThreshold
+    }
+}
+
+
+
+extension OpenOptions: Equatable, Hashable {
+    public static func ==(lhs: OpenOptions, rhs: OpenOptions) -> Bool {
+        if lhs.staging != rhs.staging {
+            return false
+        }
+        if lhs.storageVersion != rhs.storageVersion {
+            return false
+        }
+        if lhs.enableTags != rhs.enableTags {
+            return false
+        }
+        if lhs.collectionPresets != rhs.collectionPresets {
+            return false
+        }
+        if lhs.distanceMetric != rhs.distanceMetric {
+            return false
+        }
+        if lhs.normalize != rhs.normalize {
+            return false
+        }
+        if lhs.appendOnly != rhs.appendOnly {
+            return false
+        }
+        if lhs.useUnsafeCommit != rhs.useUnsafeCommit {
+            return false
+        }
+        if lhs.maxWriteAttempts != rhs.maxWriteAttempts {
+            return false
+        }
+        if lhs.defaultTable != rhs.defaultTable {
+            return false
+        }
+        if lhs.maxRows != rhs.maxRows {
+            return false
+        }
+        if lhs.readOnly != rhs.readOnly {
+            return false
+        }
+        if lhs.embeddingDtype != rhs.embeddingDtype {
+            return false
+        }
+        if lhs.connectTimeoutMs != rhs.connectTimeoutMs {
+            return false
+        }
+        if lhs.readConsistencyMs != rhs.readConsistencyMs {
+            return false
+        }
+        if lhs.embedding2Dim != rhs.embedding2Dim {
+            return false
+        }
+        if lhs.dedupByText != rhs.dedupByText {
+            return false
+        }
+        if lhs.extraColumns != rhs.extraColumns {
+            return false
+        }
+        if lhs.agentScope != rhs.agentScope {
+            return false
+        }
+        if lhs.autoIndexThreshold != rhs.autoIndexThreshold {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(staging)
+        hasher.combine(storageVersion)
+        hasher.combine(enableTags)
+        hasher.combine(collectionPresets)
+        hasher.combine(distanceMetric)
+        hasher.combine(normalize)
+        hasher.combine(appendOnly)
+        hasher.combine(useUnsafeCommit)
+        hasher.combine(maxWriteAttempts)
+        hasher.combine(defaultTable)
+        hasher.combine(maxRows)
+        hasher.combine(readOnly)
+        hasher.combine(embeddingDtype)
+        hasher.combine(connectTimeoutMs)
+        hasher.combine(readConsistencyMs)
+        hasher.combine(embedding2Dim)
+        hasher.combine(dedupByText)
+        hasher.combine(extraColumns)
+        hasher.combine(agentScope)
+        hasher.combine(autoIndexThreshold)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOpenOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OpenOptions {
+        return
+            try OpenOptions(
+                staging: FfiConverterOptionTypeStagingConfig.read(from: &buf), 
+                storageVersion: FfiConverterOptionString.read(from: &buf), 
+                enableTags: FfiConverterBool.read(from: &buf), 
+                collectionPresets: FfiConverterOptionDictionaryStringTypeCollectionPreset.read(from: &buf), 
+                distanceMetric: FfiConverterOptionTypeDistanceMetric.read(from: &buf), 
+                normalize: FfiConverterBool.read(from: &buf), 
+                appendOnly: FfiConverterBool.read(from: &buf), 
+                useUnsafeCommit: FfiConverterBool.read(from: &buf), 
+                maxWriteAttempts: FfiConverterUInt32.read(from: &buf), 
+                defaultTable: FfiConverterOptionString.read(from: &buf), 
+                maxRows: FfiConverterOptionUInt64.read(from: &buf), 
+                readOnly: FfiConverterBool.read(from: &buf), 
+                embeddingDtype: FfiConverterOptionTypeEmbeddingDtype.read(from: &buf), 
+                connectTimeoutMs: FfiConverterUInt64.read(from: &buf), 
+                readConsistencyMs: FfiConverterUInt64.read(from: &buf), 
+                embedding2Dim: FfiConverterOptionInt32.read(from: &buf), 
+                dedupByText: FfiConverterBool.read(from: &buf), 
+                extraColumns: FfiConverterOptionSequenceTypeColumnSpec.read(from: &buf), 
+                agentScope: FfiConverterOptionString.read(from: &buf), 
+                autoIndexThreshold: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OpenOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeStagingConfig.write(value.staging, into: &buf)
+        FfiConverterOptionString.write(value.storageVersion, into: &buf)
+        FfiConverterBool.write(value.enableTags, into: &buf)
+        FfiConverterOptionDictionaryStringTypeCollectionPreset.write(value.collectionPresets, into: &buf)
+        FfiConverterOptionTypeDistanceMetric.write(value.distanceMetric, into: &buf)
+        FfiConverterBool.write(value.normalize, into: &buf)
+        FfiConverterBool.write(value.appendOnly, into: &buf)
+        FfiConverterBool.write(value.useUnsafeCommit, into: &buf)
+        FfiConverterUInt32.write(value.maxWriteAttempts, into: &buf)
+        FfiConverterOptionString.write(value.defaultTable, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxRows, into: &buf)
+        FfiConverterBool.write(value.readOnly, into: &buf)
+        FfiConverterOptionTypeEmbeddingDtype.write(value.embeddingDtype, into: &buf)
+        FfiConverterUInt64.write(value.connectTimeoutMs, into: &buf)
+        FfiConverterUInt64.write(value.readConsistencyMs, into: &buf)
+        FfiConverterOptionInt32.write(value.embedding2Dim, into: &buf)
+        FfiConverterBool.write(value.dedupByText, into: &buf)
+        FfiConverterOptionSequenceTypeColumnSpec.write(value.extraColumns, into: &buf)
+        FfiConverterOptionString.write(value.agentScope, into: &buf)
+        FfiConverterOptionUInt64.write(value.autoIndexThreshold, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenOptions_lift(_ buf: RustBuffer) throws -> OpenOptions {
+    return try FfiConverterTypeOpenOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenOptions_lower(_ value: OpenOptions) -> RustBuffer {
+    return FfiConverterTypeOpenOptions.lower(value)
+}
+
+
+/**
+ * Columns of a collection, as returned by `describe`.
+ */
+public struct SchemaInfo {
+    public var fields: [FieldInfo]
+    /**
+     * Width of the `embedding` column; `None` if it has none of the
+     * expected fixed-size type.
+     */
+    public var embeddingDim: Int32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fields: [FieldInfo], 
+        /**
+         * Width of the `embedding` column; `None` if it has none of the
+         * expected fixed-size type.
+         */embeddingDim: Int32?) {
+        self.fields = fields
+        self.embeddingDim = embeddingDim
+    }
+}
+
+
+
+extension SchemaInfo: Equatable, Hashable {
+    public static func ==(lhs: SchemaInfo, rhs: SchemaInfo) -> Bool {
+        if lhs.fields != rhs.fields {
+            return false
+        }
+        if lhs.embeddingDim != rhs.embeddingDim {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(fields)
+        hasher.combine(embeddingDim)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSchemaInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SchemaInfo {
+        return
+            try SchemaInfo(
+                fields: FfiConverterSequenceTypeFieldInfo.read(from: &buf), 
+                embeddingDim: FfiConverterOptionInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SchemaInfo, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeFieldInfo.write(value.fields, into: &buf)
+        FfiConverterOptionInt32.write(value.embeddingDim, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSchemaInfo_lift(_ buf: RustBuffer) throws -> SchemaInfo {
+    return try FfiConverterTypeSchemaInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSchemaInfo_lower(_ value: SchemaInfo) -> RustBuffer {
+    return FfiConverterTypeSchemaInfo.lower(value)
+}
+
+
+/**
+ * Per-call options for `search`. Passing `None` uses the defaults.
+ */
+public struct SearchOptions {
+    public var includeProvenance: Bool
+    /**
+     * Allow a `query_vector` shorter than `embedding_dim` and compare it
+     * against the first `query_vector.len()` dimensions of each stored
+     * embedding. The truncated distance is computed in Rust over a full scan:
+     * no index is used and every embedding matching `filter` is read, so cost
+     * grows linearly with the collection.
+     */
+    public var prefixSearch: Bool
+    /**
+     * Only match rows whose `tags` contain all of these tags, or any of them
+     * with `match_any_tag`, in addition to `filter`. An empty list adds no
+     * constraint. Requires a handle opened with `OpenOptions::enable_tags`.
+     */
+    public var hasTags: [String]?
+    public var matchAnyTag: Bool
+    /**
+     * Ignore entries still buffered by write-ahead staging and return only
+     * rows already committed to the table. By default staged entries are
+     * merged in (read-your-writes), but they are lost if the process dies
+     * before a flush; jobs that must not act on such rows should set this.
+     * A staged update then reads as the previously committed row.
+     */
+    public var committedOnly: Bool
+    /**
+     * Fail with `LanceError::NotFound` instead of returning no results.
+     */
+    public var errorOnEmpty: Bool
+    /**
+     * Index tuning for this call, overriding the collection's preset. Ignored
+     * when the collection has no vector index.
+     */
+    public var nprobes: UInt32?
+    public var refineFactor: UInt32?
+    /**
+     * Skip this many of the nearest results, for paging. With a vector
+     * index each page is cut from an approximate ranking, so pages fetched
+     * separately may overlap or miss rows that an exact search would return.
+     */
+    public var offset: UInt32?
+    /**
+     * Typed predicates ANDed with `filter`; values are escaped, so untrusted
+     * input can be passed without building SQL by hand.
+     */
+    public var filters: [MetaFilter]?
+    /**
+     * Search the collection as of this version (see `list_versions`)
+     * instead of the latest. Staged entries are not included. Not supported
+     * together with `prefix_search`.
+     */
+    public var version: UInt64?
+    /**
+     * Drop results whose `score` is below this, so fewer than `limit` rows
+     * may come back. Scores depend on the handle's `DistanceMetric`.
+     */
+    public var minScore: Double?
+    /**
+     * Only match rows with `created_at` (ms since epoch) at or after this.
+     */
+    public var createdAfter: Int64?
+    /**
+     * Only match rows with `created_at` (ms since epoch) at or before this.
+     */
+    public var createdBefore: Int64?
+    /**
+     * Return each result's stored embedding in `SearchResult::embedding`.
+     */
+    public var includeEmbedding: Bool
+    /**
+     * Also match entries hidden by `soft_delete`.
+     */
+    public var includeDeleted: Bool
+    /**
+     * Compare against every row instead of using the vector index, so the
+     * ranking is exact. Latency then grows linearly with the collection
+     * size, so keep this for small collections or when recall is critical.
+     */
+    public var exact: Bool
+    /**
+     * Vector column to compare `query_vector` against. `Secondary` requires
+     * a handle opened with `OpenOptions::embedding2_dim` and only matches
+     * rows stored with an `embedding2`. `None` searches `embedding`.
+     */
+    public var which: EmbeddingColumn?
+    /**
+     * Collection to search; `None` searches the handle's default collection.
+     */
+    public var collection: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(includeProvenance: Bool = false, 
+        /**
+         * Allow a `query_vector` shorter than `embedding_dim` and compare it
+         * against the first `query_vector.len()` dimensions of each stored
+         * embedding. The truncated distance is computed in Rust over a full scan:
+         * no index is used and every embedding matching `filter` is read, so cost
+         * grows linearly with the collection.
+         */prefixSearch: Bool = false, 
+        /**
+         * Only match rows whose `tags` contain all of these tags, or any of them
+         * with `match_any_tag`, in addition to `filter`. An empty list adds no
+         * constraint. Requires a handle opened with `OpenOptions::enable_tags`.
+         */hasTags: [String]? = nil, matchAnyTag: Bool = false, 
+        /**
+         * Ignore entries still buffered by write-ahead staging and return only
+         * rows already committed to the table. By default staged entries are
+         * merged in (read-your-writes), but they are lost if the process dies
+         * before a flush; jobs that must not act on such rows should set this.
+         * A staged update then reads as the previously committed row.
+         */committedOnly: Bool = false, 
+        /**
+         * Fail with `LanceError::NotFound` instead of returning no results.
+         */errorOnEmpty: Bool = false, 
+        /**
+         * Index tuning for this call, overriding the collection's preset. Ignored
+         * when the collection has no vector index.
+         */nprobes: UInt32? = nil, refineFactor: UInt32? = nil, 
+        /**
+         * Skip this many of the nearest results, for paging. With a vector
+         * index each page is cut from an approximate ranking, so pages fetched
+         * separately may overlap or miss rows that an exact search would return.
+         */offset: UInt32? = nil, 
+        /**
+         * Typed predicates ANDed with `filter`; values are escaped, so untrusted
+         * input can be passed without building SQL by hand.
+         */filters: [MetaFilter]? = nil, 
+        /**
+         * Search the collection as of this version (see `list_versions`)
+         * instead of the latest. Staged entries are not included. Not supported
+         * together with `prefix_search`.
+         */version: UInt64? = nil, 
+        /**
+         * Drop results whose `score` is below this, so fewer than `limit` rows
+         * may come back. Scores depend on the handle's `DistanceMetric`.
+         */minScore: Double? = nil, 
+        /**
+         * Only match rows with `created_at` (ms since epoch) at or after this.
+         */createdAfter: Int64? = nil, 
+        /**
+         * Only match rows with `created_at` (ms since epoch) at or before this.
+         */createdBefore: Int64? = nil, 
+        /**
+         * Return each result's stored embedding in `SearchResult::embedding`.
+         */includeEmbedding: Bool = false, 
+        /**
+         * Also match entries hidden by `soft_delete`.
+         */includeDeleted: Bool = false, 
+        /**
+         * Compare against every row instead of using the vector index, so the
+         * ranking is exact. Latency then grows linearly with the collection
+         * size, so keep this for small collections or when recall is critical.
+         */exact: Bool = false, 
+        /**
+         * Vector column to compare `query_vector` against. `Secondary` requires
+         * a handle opened with `OpenOptions::embedding2_dim` and only matches
+         * rows stored with an `embedding2`. `None` searches `embedding`.
+         */which: EmbeddingColumn? = nil, 
+        /**
+         * Collection to search; `None` searches the handle's default collection.
+         */collection: String? = nil) {
+        self.includeProvenance = includeProvenance
+        self.prefixSearch = prefixSearch
+        self.hasTags = hasTags
+        self.matchAnyTag = matchAnyTag
+        self.committedOnly = committedOnly
+        self.errorOnEmpty = errorOnEmpty
+        self.nprobes = nprobes
+        self.refineFactor = refineFactor
+        self.offset = offset
+        self.filters = filters
+        self.version = version
+        self.minScore = minScore
+        self.createdAfter = createdAfter
+        self.createdBefore = createdBefore
+        self.includeEmbedding = includeEmbedding
+        self.includeDeleted = includeDeleted
+        self.exact = exact
+        self.which = which
+        self.collection = collection
+    }
+}
+
+
+
+extension SearchOptions: Equatable, Hashable {
+    public static func ==(lhs: SearchOptions, rhs: SearchOptions) -> Bool {
+        if lhs.includeProvenance != rhs.includeProvenance {
+            return false
+        }
+        if lhs.prefixSearch != rhs.prefixSearch {
+            return false
+        }
+        if lhs.hasTags != rhs.hasTags {
+            return false
+        }
+        if lhs.matchAnyTag != rhs.matchAnyTag {
+            return false
+        }
+        if lhs.committedOnly != rhs.committedOnly {
+            return false
+        }
+        if lhs.errorOnEmpty != rhs.errorOnEmpty {
+            return false
+        }
+        if lhs.nprobes != rhs.nprobes {
+            return false
+        }
+        if lhs.refineFactor != rhs.refineFactor {
+            return false
+        }
+        if lhs.offset != rhs.offset {
+            return false
+        }
+        if lhs.filters != rhs.filters {
+            return false
+        }
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.minScore != rhs.minScore {
+            return false
+        }
+        if lhs.createdAfter != rhs.createdAfter {
+            return false
+        }
+        if lhs.createdBefore != rhs.createdBefore {
+            return false
+        }
+        if lhs.includeEmbedding != rhs.includeEmbedding {
+            return false
+        }
+        if lhs.includeDeleted != rhs.includeDeleted {
+            return false
+        }
+        if lhs.exact != rhs.exact {
+            return false
+        }
+        if lhs.which != rhs.which {
+            return false
+        }
+        if lhs.collection != rhs.collection {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(includeProvenance)
+        hasher.combine(prefixSearch)
+        hasher.combine(hasTags)
+        hasher.combine(matchAnyTag)
+        hasher.combine(committedOnly)
+        hasher.combine(errorOnEmpty)
+        hasher.combine(nprobes)
+        hasher.combine(refineFactor)
+        hasher.combine(offset)
+        hasher.combine(filters)
+        hasher.combine(version)
+        hasher.combine(minScore)
+        hasher.combine(createdAfter)
+        hasher.combine(createdBefore)
+        hasher.combine(includeEmbedding)
+        hasher.combine(includeDeleted)
+        hasher.combine(exact)
+        hasher.combine(which)
+        hasher.combine(collection)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchOptions {
+        return
+            try SearchOptions(
+                includeProvenance: FfiConverterBool.read(from: &buf), 
+                prefixSearch: FfiConverterBool.read(from: &buf), 
+                hasTags: FfiConverterOptionSequenceString.read(from: &buf), 
+                matchAnyTag: FfiConverterBool.read(from: &buf), 
+                committedOnly: FfiConverterBool.read(from: &buf), 
+                errorOnEmpty: FfiConverterBool.read(from: &buf), 
+                nprobes: FfiConverterOptionUInt32.read(from: &buf), 
+                refineFactor: FfiConverterOptionUInt32.read(from: &buf), 
+                offset: FfiConverterOptionUInt32.read(from: &buf), 
+                filters: FfiConverterOptionSequenceTypeMetaFilter.read(from: &buf), 
+                version: FfiConverterOptionUInt64.read(from: &buf), 
+                minScore: FfiConverterOptionDouble.read(from: &buf), 
+                createdAfter: FfiConverterOptionInt64.read(from: &buf), 
+                createdBefore: FfiConverterOptionInt64.read(from: &buf), 
+                includeEmbedding: FfiConverterBool.read(from: &buf), 
+                includeDeleted: FfiConverterBool.read(from: &buf), 
+                exact: FfiConverterBool.read(from: &buf), 
+                which: FfiConverterOptionTypeEmbeddingColumn.read(from: &buf), 
+                collection: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.includeProvenance, into: &buf)
+        FfiConverterBool.write(value.prefixSearch, into: &buf)
+        FfiConverterOptionSequenceString.write(value.hasTags, into: &buf)
+        FfiConverterBool.write(value.matchAnyTag, into: &buf)
+        FfiConverterBool.write(value.committedOnly, into: &buf)
+        FfiConverterBool.write(value.errorOnEmpty, into: &buf)
+        FfiConverterOptionUInt32.write(value.nprobes, into: &buf)
+        FfiConverterOptionUInt32.write(value.refineFactor, into: &buf)
+        FfiConverterOptionUInt32.write(value.offset, into: &buf)
+        FfiConverterOptionSequenceTypeMetaFilter.write(value.filters, into: &buf)
+        FfiConverterOptionUInt64.write(value.version, into: &buf)
+        FfiConverterOptionDouble.write(value.minScore, into: &buf)
+        FfiConverterOptionInt64.write(value.createdAfter, into: &buf)
+        FfiConverterOptionInt64.write(value.createdBefore, into: &buf)
+        FfiConverterBool.write(value.includeEmbedding, into: &buf)
+        FfiConverterBool.write(value.includeDeleted, into: &buf)
+        FfiConverterBool.write(value.exact, into: &buf)
+        FfiConverterOptionTypeEmbeddingColumn.write(value.which, into: &buf)
+        FfiConverterOptionString.write(value.collection, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchOptions_lift(_ buf: RustBuffer) throws -> SearchOptions {
+    return try FfiConverterTypeSearchOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchOptions_lower(_ value: SearchOptions) -> RustBuffer {
+    return FfiConverterTypeSearchOptions.lower(value)
+}
+
+
+/**
+ * One page of results from `search_paged`.
+ */
+public struct SearchPage {
+    public var results: [SearchResult]
+    /**
+     * Rows matching the filter, across all pages (see `search_paged`).
+     */
+    public var totalMatching: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(results: [SearchResult], 
+        /**
+         * Rows matching the filter, across all pages (see `search_paged`).
+         */totalMatching: UInt64) {
+        self.results = results
+        self.totalMatching = totalMatching
+    }
+}
+
+
+
+extension SearchPage: Equatable, Hashable {
+    public static func ==(lhs: SearchPage, rhs: SearchPage) -> Bool {
+        if lhs.results != rhs.results {
+            return false
+        }
+        if lhs.totalMatching != rhs.totalMatching {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(results)
+        hasher.combine(totalMatching)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchPage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchPage {
+        return
+            try SearchPage(
+                results: FfiConverterSequenceTypeSearchResult.read(from: &buf), 
+                totalMatching: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchPage, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeSearchResult.write(value.results, into: &buf)
+        FfiConverterUInt64.write(value.totalMatching, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchPage_lift(_ buf: RustBuffer) throws -> SearchPage {
+    return try FfiConverterTypeSearchPage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchPage_lower(_ value: SearchPage) -> RustBuffer {
+    return FfiConverterTypeSearchPage.lower(value)
+}
+
+
+/**
+ * Output of `search_detailed`.
+ */
+public struct SearchResponse {
+    public var results: [SearchResult]
+    /**
+     * The vector index was used, so results may miss true neighbours. `false`
+     * means every row was compared (flat scan), i.e. the ranking is exact.
+     */
+    public var approximate: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(results: [SearchResult], 
+        /**
+         * The vector index was used, so results may miss true neighbours. `false`
+         * means every row was compared (flat scan), i.e. the ranking is exact.
+         */approximate: Bool) {
+        self.results = results
+        self.approximate = approximate
+    }
+}
+
+
+
+extension SearchResponse: Equatable, Hashable {
+    public static func ==(lhs: SearchResponse, rhs: SearchResponse) -> Bool {
+        if lhs.results != rhs.results {
+            return false
+        }
+        if lhs.approximate != rhs.approximate {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(results)
+        hasher.combine(approximate)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResponse: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResponse {
+        return
+            try SearchResponse(
+                results: FfiConverterSequenceTypeSearchResult.read(from: &buf), 
+                approximate: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchResponse, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeSearchResult.write(value.results, into: &buf)
+        FfiConverterBool.write(value.approximate, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResponse_lift(_ buf: RustBuffer) throws -> SearchResponse {
+    return try FfiConverterTypeSearchResponse.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResponse_lower(_ value: SearchResponse) -> RustBuffer {
+    return FfiConverterTypeSearchResponse.lower(value)
+}
+
+
+public struct SearchResult {
+    public var key: String
+    /**
+     * Collection the entry was read from.
+     */
+    public var collection: String
+    public var agentId: String
+    public var text: String
+    public var score: Double
+    /**
+     * Raw `_distance` reported by the engine, which `score` is derived from
+     * (see `DistanceMetric`). `None` when the result didn't come from a
+     * vector query.
+     */
+    public var distance: Double?
+    public var metadata: String?
+    /**
+     * `metadata` parsed into pairs when it is a JSON object (as written by
+     * `store_with_meta`), sorted by key; otherwise `None`.
+     */
+    public var meta: [MetaPair]?
+    /**
+     * Milliseconds since the Unix epoch at which the entry was stored.
+     */
+    public var createdAt: Int64
+    /**
+     * Provenance, populated only when `SearchOptions::include_provenance` is set.
+     * `row_id` is Lance's `_rowid`, which (without stable row ids) is the row
+     * address `fragment_id << 32 | offset`; `dataset_version` is the table
+     * version the query ran against. All three are `None` for staged rows that
+     * have not been flushed yet.
+     */
+    public var rowId: UInt64?
+    public var fragmentId: UInt64?
+    public var datasetVersion: UInt64?
+    /**
+     * The stored embedding, populated only when requested with
+     * `SearchOptions::include_embedding` or `get`'s `include_embedding`.
+     */
+    public var embedding: [Float]?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, 
+        /**
+         * Collection the entry was read from.
+         */collection: String, agentId: String, text: String, score: Double, 
+        /**
+         * Raw `_distance` reported by the engine, which `score` is derived from
+         * (see `DistanceMetric`). `None` when the result didn't come from a
+         * vector query.
+         */distance: Double?, metadata: String?, 
+        /**
+         * `metadata` parsed into pairs when it is a JSON object (as written by
+         * `store_with_meta`), sorted by key; otherwise `None`.
+         */meta: [MetaPair]?, 
+        /**
+         * Milliseconds since the Unix epoch at which the entry was stored.
+         */createdAt: Int64, 
+        /**
+         * Provenance, populated only when `SearchOptions::include_provenance` is set.
+         * `row_id` is Lance's `_rowid`, which (without stable row ids) is the row
+         * address `fragment_id << 32 | offset`; `dataset_version` is the table
+         * version the query ran against. All three are `None` for staged rows that
+         * have not been flushed yet.
+         */rowId: UInt64?, fragmentId: UInt64?, datasetVersion: UInt64?, 
+        /**
+         * The stored embedding, populated only when requested with
+         * `SearchOptions::include_embedding` or `get`'s `include_embedding`.
+         */embedding: [Float]?) {
+        self.key = key
+        self.collection = collection
+        self.agentId = agentId
+        self.text = text
+        self.score = score
+        self.distance = distance
+        self.metadata = metadata
+        self.meta = meta
+        self.createdAt = createdAt
+        self.rowId = rowId
+        self.fragmentId = fragmentId
+        self.datasetVersion = datasetVersion
+        self.embedding = embedding
+    }
+}
+
+
+
+extension SearchResult: Equatable, Hashable {
+    public static func ==(lhs: SearchResult, rhs: SearchResult) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.collection != rhs.collection {
+            return false
+        }
+        if lhs.agentId != rhs.agentId {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.score != rhs.score {
+            return false
+        }
+        if lhs.distance != rhs.distance {
+            return false
+        }
+        if lhs.metadata != rhs.metadata {
+            return false
+        }
+        if lhs.meta != rhs.meta {
+            return false
+        }
+        if lhs.createdAt != rhs.createdAt {
+            return false
+        }
+        if lhs.rowId != rhs.rowId {
+            return false
+        }
+        if lhs.fragmentId != rhs.fragmentId {
+            return false
+        }
+        if lhs.datasetVersion != rhs.datasetVersion {
+            return false
+        }
+        if lhs.embedding != rhs.embedding {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(collection)
+        hasher.combine(agentId)
+        hasher.combine(text)
+        hasher.combine(score)
+        hasher.combine(distance)
+        hasher.combine(metadata)
+        hasher.combine(meta)
+        hasher.combine(createdAt)
+        hasher.combine(rowId)
+        hasher.combine(fragmentId)
+        hasher.combine(datasetVersion)
+        hasher.combine(embedding)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResult {
+        return
+            try SearchResult(
+                key: FfiConverterString.read(from: &buf), 
+                collection: FfiConverterString.read(from: &buf), 
+                agentId: FfiConverterString.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                score: FfiConverterDouble.read(from: &buf), 
+                distance: FfiConverterOptionDouble.read(from: &buf), 
+                metadata: FfiConverterOptionString.read(from: &buf), 
+                meta: FfiConverterOptionSequenceTypeMetaPair.read(from: &buf), 
+                createdAt: FfiConverterInt64.read(from: &buf), 
+                rowId: FfiConverterOptionUInt64.read(from: &buf), 
+                fragmentId: FfiConverterOptionUInt64.read(from: &buf), 
+                datasetVersion: FfiConverterOptionUInt64.read(from: &buf), 
+                embedding: FfiConverterOptionSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.collection, into: &buf)
+        FfiConverterString.write(value.agentId, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterDouble.write(value.score, into: &buf)
+        FfiConverterOptionDouble.write(value.distance, into: &buf)
+        FfiConverterOptionString.write(value.metadata, into: &buf)
+        FfiConverterOptionSequenceTypeMetaPair.write(value.meta, into: &buf)
+        FfiConverterInt64.write(value.createdAt, into: &buf)
+        FfiConverterOptionUInt64.write(value.rowId, into: &buf)
+        FfiConverterOptionUInt64.write(value.fragmentId, into: &buf)
+        FfiConverterOptionUInt64.write(value.datasetVersion, into: &buf)
+        FfiConverterOptionSequenceFloat.write(value.embedding, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResult_lift(_ buf: RustBuffer) throws -> SearchResult {
+    return try FfiConverterTypeSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResult_lower(_ value: SearchResult) -> RustBuffer {
+    return FfiConverterTypeSearchResult.lower(value)
+}
+
+
+/**
+ * Opt-in write-ahead staging for `store()`.
+ * Staged entries are buffered in memory and written as a single batch on an
+ * explicit `flush()`, once `max_entries` are pending, or every
+ * `flush_interval_ms` — whichever comes first. Reads see staged entries
+ * immediately. Entries that are still pending when the handle is dropped are lost.
+ */
+public struct StagingConfig {
+    public var maxEntries: UInt32?
+    public var flushIntervalMs: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxEntries: UInt32? = nil, flushIntervalMs: UInt64? = nil) {
+        self.maxEntries = maxEntries
+        self.flushIntervalMs = flushIntervalMs
+    }
+}
+
+
+
+extension StagingConfig: Equatable, Hashable {
+    public static func ==(lhs: StagingConfig, rhs: StagingConfig) -> Bool {
+        if lhs.maxEntries != rhs.maxEntries {
+            return false
+        }
+        if lhs.flushIntervalMs != rhs.flushIntervalMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxEntries)
+        hasher.combine(flushIntervalMs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStagingConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StagingConfig {
+        return
+            try StagingConfig(
+                maxEntries: FfiConverterOptionUInt32.read(from: &buf), 
+                flushIntervalMs: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StagingConfig, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt32.write(value.maxEntries, into: &buf)
+        FfiConverterOptionUInt64.write(value.flushIntervalMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStagingConfig_lift(_ buf: RustBuffer) throws -> StagingConfig {
+    return try FfiConverterTypeStagingConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStagingConfig_lower(_ value: StagingConfig) -> RustBuffer {
+    return FfiConverterTypeStagingConfig.lower(value)
+}
+
+
+/**
+ * Per-call options for `store`. Passing `None` uses the defaults.
+ */
+public struct StoreOptions {
+    /**
+     * Requires a handle opened with `OpenOptions::enable_tags`.
+     */
+    public var tags: [String]?
+    /**
+     * Collection to write to; `None` is the handle's default collection.
+     */
+    public var collection: String?
+    /**
+     * Written instead of the current time (ms since epoch, not negative),
+     * e.g. to import history.
+     */
+    public var createdAt: Int64?
+    /**
+     * Fills the second vector column; requires a handle opened with
+     * `OpenOptions::embedding2_dim`. `None` leaves it null.
+     */
+    public var embedding2: [Float]?
+    /**
+     * Extra column values (see `open_with_columns`); those left out are null.
+     */
+    public var columns: [ColumnEntry]?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Requires a handle opened with `OpenOptions::enable_tags`.
+         */tags: [String]? = nil, 
+        /**
+         * Collection to write to; `None` is the handle's default collection.
+         */collection: String? = nil, 
+        /**
+         * Written instead of the current time (ms since epoch, not negative),
+         * e.g. to import history.
+         */createdAt: Int64? = nil, 
+        /**
+         * Fills the second vector column; requires a handle opened with
+         * `OpenOptions::embedding2_dim`. `None` leaves it null.
+         */embedding2: [Float]? = nil, 
+        /**
+         * Extra column values (see `open_with_columns`); those left out are null.
+         */columns: [ColumnEntry]? = nil) {
+        self.tags = tags
+        self.collection = collection
+        self.createdAt = createdAt
+        self.embedding2 = embedding2
+        self.columns = columns
+    }
+}
+
+
+
+extension StoreOptions: Equatable, Hashable {
+    public static func ==(lhs: StoreOptions, rhs: StoreOptions) -> Bool {
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        if lhs.collection != rhs.collection {
+            return false
+        }
+        if lhs.createdAt != rhs.createdAt {
+            return false
+        }
+        if lhs.embedding2 != rhs.embedding2 {
+            return false
+        }
+        if lhs.columns != rhs.columns {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(tags)
+        hasher.combine(collection)
+        hasher.combine(createdAt)
+        hasher.combine(embedding2)
+        hasher.combine(columns)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStoreOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StoreOptions {
+        return
+            try StoreOptions(
+                tags: FfiConverterOptionSequenceString.read(from: &buf), 
+                collection: FfiConverterOptionString.read(from: &buf), 
+                createdAt: FfiConverterOptionInt64.read(from: &buf), 
+                embedding2: FfiConverterOptionSequenceFloat.read(from: &buf), 
+                columns: FfiConverterOptionSequenceTypeColumnEntry.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StoreOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionSequenceString.write(value.tags, into: &buf)
+        FfiConverterOptionString.write(value.collection, into: &buf)
+        FfiConverterOptionInt64.write(value.createdAt, into: &buf)
+        FfiConverterOptionSequenceFloat.write(value.embedding2, into: &buf)
+        FfiConverterOptionSequenceTypeColumnEntry.write(value.columns, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStoreOptions_lift(_ buf: RustBuffer) throws -> StoreOptions {
+    return try FfiConverterTypeStoreOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStoreOptions_lower(_ value: StoreOptions) -> RustBuffer {
+    return FfiConverterTypeStoreOptions.lower(value)
+}
+
+
+/**
+ * Integrity report returned by `verify`.
+ */
+public struct VerifyReport {
+    public var totalRows: UInt64
+    /**
+     * Keys stored in more than one row.
+     */
+    public var duplicateKeys: UInt64
+    /**
+     * Rows beyond the first for each duplicated key.
+     */
+    public var duplicateRows: UInt64
+    /**
+     * Up to `VERIFY_SAMPLE_KEYS` duplicated keys, sorted.
+     */
+    public var sampleKeys: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(totalRows: UInt64, 
+        /**
+         * Keys stored in more than one row.
+         */duplicateKeys: UInt64, 
+        /**
+         * Rows beyond the first for each duplicated key.
+         */duplicateRows: UInt64, 
+        /**
+         * Up to `VERIFY_SAMPLE_KEYS` duplicated keys, sorted.
+         */sampleKeys: [String]) {
+        self.totalRows = totalRows
+        self.duplicateKeys = duplicateKeys
+        self.duplicateRows = duplicateRows
+        self.sampleKeys = sampleKeys
+    }
+}
+
+
+
+extension VerifyReport: Equatable, Hashable {
+    public static func ==(lhs: VerifyReport, rhs: VerifyReport) -> Bool {
+        if lhs.totalRows != rhs.totalRows {
+            return false
+        }
+        if lhs.duplicateKeys != rhs.duplicateKeys {
+            return false
+        }
+        if lhs.duplicateRows != rhs.duplicateRows {
+            return false
+        }
+        if lhs.sampleKeys != rhs.sampleKeys {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(totalRows)
+        hasher.combine(duplicateKeys)
+        hasher.combine(duplicateRows)
+        hasher.combine(sampleKeys)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVerifyReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VerifyReport {
+        return
+            try VerifyReport(
+                totalRows: FfiConverterUInt64.read(from: &buf), 
+                duplicateKeys: FfiConverterUInt64.read(from: &buf), 
+                duplicateRows: FfiConverterUInt64.read(from: &buf), 
+                sampleKeys: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VerifyReport, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.totalRows, into: &buf)
+        FfiConverterUInt64.write(value.duplicateKeys, into: &buf)
+        FfiConverterUInt64.write(value.duplicateRows, into: &buf)
+        FfiConverterSequenceString.write(value.sampleKeys, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerifyReport_lift(_ buf: RustBuffer) throws -> VerifyReport {
+    return try FfiConverterTypeVerifyReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerifyReport_lower(_ value: VerifyReport) -> RustBuffer {
+    return FfiConverterTypeVerifyReport.lower(value)
+}
+
+
+/**
+ * A committed version of a collection, as listed by `list_versions`.
+ */
+public struct VersionInfo {
+    public var version: UInt64
+    /**
+     * Commit time in ms since epoch.
+     */
+    public var timestampMs: Int64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(version: UInt64, 
+        /**
+         * Commit time in ms since epoch.
+         */timestampMs: Int64) {
+        self.version = version
+        self.timestampMs = timestampMs
+    }
+}
+
+
+
+extension VersionInfo: Equatable, Hashable {
+    public static func ==(lhs: VersionInfo, rhs: VersionInfo) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.timestampMs != rhs.timestampMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(timestampMs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVersionInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VersionInfo {
+        return
+            try VersionInfo(
+                version: FfiConverterUInt64.read(from: &buf), 
+                timestampMs: FfiConverterInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VersionInfo, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.version, into: &buf)
+        FfiConverterInt64.write(value.timestampMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVersionInfo_lift(_ buf: RustBuffer) throws -> VersionInfo {
+    return try FfiConverterTypeVersionInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVersionInfo_lower(_ value: VersionInfo) -> RustBuffer {
+    return FfiConverterTypeVersionInfo.lower(value)
+}
+
+
+/**
+ * Rows written by `store`: new keys inserted and existing rows overwritten.
+ */
+public struct WriteStats {
+    public var inserted: UInt64
+    public var replaced: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(inserted: UInt64, replaced: UInt64) {
+        self.inserted = inserted
+        self.replaced = replaced
+    }
+}
+
+
+
+extension WriteStats: Equatable, Hashable {
+    public static func ==(lhs: WriteStats, rhs: WriteStats) -> Bool {
+        if lhs.inserted != rhs.inserted {
+            return false
+        }
+        if lhs.replaced != rhs.replaced {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(inserted)
+        hasher.combine(replaced)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWriteStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WriteStats {
+        return
+            try WriteStats(
+                inserted: FfiConverterUInt64.read(from: &buf), 
+                replaced: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: WriteStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.inserted, into: &buf)
+        FfiConverterUInt64.write(value.replaced, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWriteStats_lift(_ buf: RustBuffer) throws -> WriteStats {
+    return try FfiConverterTypeWriteStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWriteStats_lower(_ value: WriteStats) -> RustBuffer {
+    return FfiConverterTypeWriteStats.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Type of a `ColumnSpec` column.
+ */
+
+public enum ColumnType {
+    
+    case utf8
+    case int64
+    case float64
+    case bool
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeColumnType: FfiConverterRustBuffer {
+    typealias SwiftType = ColumnType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ColumnType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .utf8
+        
+        case 2: return .int64
+        
+        case 3: return .float64
+        
+        case 4: return .bool
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ColumnType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .utf8:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .int64:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .float64:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .bool:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnType_lift(_ buf: RustBuffer) throws -> ColumnType {
+    return try FfiConverterTypeColumnType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnType_lower(_ value: ColumnType) -> RustBuffer {
+    return FfiConverterTypeColumnType.lower(value)
+}
+
+
+
+extension ColumnType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A value for an extra column, whose variant must match its `ColumnType`.
+ */
+
+public enum ColumnValue {
+    
+    case utf8(value: String
+    )
+    case int64(value: Int64
+    )
+    case float64(value: Double
+    )
+    case bool(value: Bool
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeColumnValue: FfiConverterRustBuffer {
+    typealias SwiftType = ColumnValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ColumnValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .utf8(value: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .int64(value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 3: return .float64(value: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 4: return .bool(value: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ColumnValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .utf8(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(value, into: &buf)
+            
+        
+        case let .int64(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .float64(value):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(value, into: &buf)
+            
+        
+        case let .bool(value):
+            writeInt(&buf, Int32(4))
+            FfiConverterBool.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnValue_lift(_ buf: RustBuffer) throws -> ColumnValue {
+    return try FfiConverterTypeColumnValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeColumnValue_lower(_ value: ColumnValue) -> RustBuffer {
+    return FfiConverterTypeColumnValue.lower(value)
+}
+
+
+
+extension ColumnValue: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Vector distance metric for a handle. Search results map the engine's
+ * distance `d` to a higher-is-better `score` as follows:
+ * - `Cosine`: `d = 1 - cos(a, b)`, score `1 - d` (cosine similarity)
+ * - `L2`: `d = |a - b|²`, score `-d`
+ * - `Dot`: `d = 1 - a·b`, score `1 - d` (the dot product)
+ * - `Hamming`: `d` = number of differing bits, score `-d`; only for
+ * `EmbeddingDtype::Binary`
+ */
+
+public enum DistanceMetric {
+    
+    case cosine
+    case l2
+    case dot
+    case hamming
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDistanceMetric: FfiConverterRustBuffer {
+    typealias SwiftType = DistanceMetric
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DistanceMetric {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .cosine
+        
+        case 2: return .l2
+        
+        case 3: return .dot
+        
+        case 4: return .hamming
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DistanceMetric, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .cosine:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .l2:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .dot:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .hamming:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceMetric_lift(_ buf: RustBuffer) throws -> DistanceMetric {
+    return try FfiConverterTypeDistanceMetric.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceMetric_lower(_ value: DistanceMetric) -> RustBuffer {
+    return FfiConverterTypeDistanceMetric.lower(value)
+}
+
+
+
+extension DistanceMetric: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * One of the handle's vector columns (see `OpenOptions::embedding2_dim`).
+ */
+
+public enum EmbeddingColumn {
+    
+    /**
+     * `embedding`, which every row has.
+     */
+    case primary
+    /**
+     * `embedding2`, which rows may leave null.
+     */
+    case secondary
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEmbeddingColumn: FfiConverterRustBuffer {
+    typealias SwiftType = EmbeddingColumn
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EmbeddingColumn {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .primary
+        
+        case 2: return .secondary
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: EmbeddingColumn, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .primary:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .secondary:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEmbeddingColumn_lift(_ buf: RustBuffer) throws -> EmbeddingColumn {
+    return try FfiConverterTypeEmbeddingColumn.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEmbeddingColumn_lower(_ value: EmbeddingColumn) -> RustBuffer {
+    return FfiConverterTypeEmbeddingColumn.lower(value)
+}
+
+
+
+extension EmbeddingColumn: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Storage type of the `embedding` column (see `OpenOptions::embedding_dtype`).
+ * Embeddings and query vectors cross the FFI as f32 either way.
+ */
+
+public enum EmbeddingDtype {
+    
+    case f32
+    /**
+     * Half-precision floats: half the size, with about three significant
+     * digits. Values beyond ±65504 are stored as infinity.
+     */
+    case f16
+    /**
+     * Symmetric int8 quantization: a quarter of the size. Each value is
+     * stored as `round(v / scale)` with `scale = max_abs / 127`, so reads
+     * are within `scale / 2` of what was stored and values beyond
+     * `±max_abs` are clamped. `max_abs` is recorded in the `embedding`
+     * field's metadata and reopening with another one fails. The engine
+     * can't search int8 vectors, so searches scan every row, comparing the
+     * query quantized the same way.
+     */
+    case int8(maxAbs: Float
+    )
+    /**
+     * Packed binary codes, one byte per value: `embedding_dim` counts
+     * bytes, so a 256-bit code has dimension 32. Every value must be a
+     * whole number in `0..=255`, or use `store_binary`/`search_binary`.
+     * Requires `DistanceMetric::Hamming`.
+     */
+    case binary
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEmbeddingDtype: FfiConverterRustBuffer {
+    typealias SwiftType = EmbeddingDtype
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EmbeddingDtype {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .f32
+        
+        case 2: return .f16
+        
+        case 3: return .int8(maxAbs: try FfiConverterFloat.read(from: &buf)
+        )
+        
+        case 4: return .binary
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: EmbeddingDtype, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .f32:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .f16:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .int8(maxAbs):
+            writeInt(&buf, Int32(3))
+            FfiConverterFloat.write(maxAbs, into: &buf)
+            
+        
+        case .binary:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEmbeddingDtype_lift(_ buf: RustBuffer) throws -> EmbeddingDtype {
+    return try FfiConverterTypeEmbeddingDtype.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEmbeddingDtype_lower(_ value: EmbeddingDtype) -> RustBuffer {
+    return FfiConverterTypeEmbeddingDtype.lower(value)
+}
+
+
+
+extension EmbeddingDtype: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How `import_parquet` combines the file's rows with the collection.
+ */
+
+public enum ImportMode {
+    
+    /**
+     * Add the rows to whatever the collection already holds.
+     */
+    case append
+    /**
+     * Replace the collection's contents, including staged entries.
+     */
+    case overwrite
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImportMode: FfiConverterRustBuffer {
+    typealias SwiftType = ImportMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImportMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .append
+        
+        case 2: return .overwrite
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ImportMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .append:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .overwrite:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImportMode_lift(_ buf: RustBuffer) throws -> ImportMode {
+    return try FfiConverterTypeImportMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImportMode_lower(_ value: ImportMode) -> RustBuffer {
+    return FfiConverterTypeImportMode.lower(value)
+}
+
+
+
+extension ImportMode: Equatable, Hashable {}
+
+
+
+
+public enum LanceError {
+
+    
+    
+    case ConnectionFailed(msg: String
+    )
+    case TableError(msg: String
+    )
+    case QueryError(msg: String
+    )
+    case InsertError(msg: String
+    )
+    case DeleteError(msg: String
+    )
+    case SchemaError(msg: String
+    )
+    case NotFound(msg: String
+    )
+    /**
+     * A vector's length doesn't match the handle's `embedding_dim`. `key`
+     * names the entry being written, when there is one, and `index` is the
+     * position of the offending entry or query in a batch call.
+     */
+    case DimensionMismatch(expected: Int32, actual: Int32, key: String?, index: UInt32?
+    )
+    /**
+     * A write lost to a concurrent writer on every attempt (see
+     * `OpenOptions::max_write_attempts`); retrying later may succeed.
+     */
+    case ConcurrencyConflict(msg: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLanceError: FfiConverterRustBuffer {
+    typealias SwiftType = LanceError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LanceError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .ConnectionFailed(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .TableError(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .QueryError(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .InsertError(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .DeleteError(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .SchemaError(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .NotFound(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .DimensionMismatch(
+            expected: try FfiConverterInt32.read(from: &buf), 
+            actual: try FfiConverterInt32.read(from: &buf), 
+            key: try FfiConverterOptionString.read(from: &buf), 
+            index: try FfiConverterOptionUInt32.read(from: &buf)
+            )
+        case 9: return .ConcurrencyConflict(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LanceError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .ConnectionFailed(msg):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .TableError(msg):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .QueryError(msg):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .InsertError(msg):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .DeleteError(msg):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .SchemaError(msg):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .NotFound(msg):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(msg, into: &buf)
+            
+        
+        case let .DimensionMismatch(expected,actual,key,index):
+            writeInt(&buf, Int32(8))
+            FfiConverterInt32.write(expected, into: &buf)
+            FfiConverterInt32.write(actual, into: &buf)
+            FfiConverterOptionString.write(key, into: &buf)
+            FfiConverterOptionUInt32.write(index, into: &buf)
+            
+        
+        case let .ConcurrencyConflict(msg):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(msg, into: &buf)
+            
+        }
+    }
+}
+
+
+extension LanceError: Equatable, Hashable {}
+
+extension LanceError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A typed filter predicate, compiled to an escaped SQL expression.
+ */
+
+public enum MetaFilter {
+    
+    /**
+     * `agent_id` equals the given id.
+     */
+    case agentId(agentId: String
+    )
+    /**
+     * `created_at` (ms since epoch) is at or after `ms`.
+     */
+    case createdSince(ms: Int64
+    )
+    /**
+     * `created_at` (ms since epoch) is at or before `ms`.
+     */
+    case createdBefore(ms: Int64
+    )
+    /**
+     * `key` starts with `prefix`.
+     */
+    case keyPrefix(prefix: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMetaFilter: FfiConverterRustBuffer {
+    typealias SwiftType = MetaFilter
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MetaFilter {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .agentId(agentId: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .createdSince(ms: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 3: return .createdBefore(ms: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 4: return .keyPrefix(prefix: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: MetaFilter, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .agentId(agentId):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(agentId, into: &buf)
+            
+        
+        case let .createdSince(ms):
+            writeInt(&buf, Int32(2))
+            FfiConverterInt64.write(ms, into: &buf)
+            
+        
+        case let .createdBefore(ms):
+            writeInt(&buf, Int32(3))
+            FfiConverterInt64.write(ms, into: &buf)
+            
+        
+        case let .keyPrefix(prefix):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(prefix, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMetaFilter_lift(_ buf: RustBuffer) throws -> MetaFilter {
+    return try FfiConverterTypeMetaFilter.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMetaFilter_lower(_ value: MetaFilter) -> RustBuffer {
+    return FfiConverterTypeMetaFilter.lower(value)
+}
+
+
+
+extension MetaFilter: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Row order for `list` and `list_detailed`. Ties on `created_at` are broken
+ * by key.
+ */
+
+public enum SortOrder {
+    
+    case createdAtAsc
+    case createdAtDesc
+    case keyAsc
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSortOrder: FfiConverterRustBuffer {
+    typealias SwiftType = SortOrder
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SortOrder {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .createdAtAsc
+        
+        case 2: return .createdAtDesc
+        
+        case 3: return .keyAsc
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SortOrder, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .createdAtAsc:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .createdAtDesc:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .keyAsc:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortOrder_lift(_ buf: RustBuffer) throws -> SortOrder {
+    return try FfiConverterTypeSortOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortOrder_lower(_ value: SortOrder) -> RustBuffer {
+    return FfiConverterTypeSortOrder.lower(value)
+}
+
+
+
+extension SortOrder: Equatable, Hashable {}
+
+
+
+
+
+
+/**
+ * Progress callback for long-running bulk operations.
+ */
+public protocol ProgressReporter : AnyObject {
+    
+    func onProgress(done: UInt64, total: UInt64) 
+    
+}
+
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceProgressReporter {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceProgressReporter = UniffiVTableCallbackInterfaceProgressReporter(
+        onProgress: { (
+            uniffiHandle: UInt64,
+            done: UInt64,
+            total: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceProgressReporter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     done: try FfiConverterUInt64.lift(done),
+                     total: try FfiConverterUInt64.lift(total)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceProgressReporter.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface ProgressReporter: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitProgressReporter() {
+    uniffi_lancedb_ffi_fn_init_callback_vtable_progressreporter(&UniffiCallbackInterfaceProgressReporter.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceProgressReporter {
+    fileprivate static var handleMap = UniffiHandleMap<ProgressReporter>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceProgressReporter : FfiConverter {
+    typealias SwiftType = ProgressReporter
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+/**
+ * Host-side embedding model used by `migrate_dimension` to re-embed stored text.
+ */
+public protocol Reembedder : AnyObject {
+    
+    func embed(text: String)  -> [Float]
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceReembedder {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceReembedder = UniffiVTableCallbackInterfaceReembedder(
+        embed: { (
+            uniffiHandle: UInt64,
+            text: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [Float] in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceReembedder.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.embed(
+                     text: try FfiConverterString.lift(text)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceFloat.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceReembedder.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface Reembedder: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitReembedder() {
+    uniffi_lancedb_ffi_fn_init_callback_vtable_reembedder(&UniffiCallbackInterfaceReembedder.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceReembedder {
+    fileprivate static var handleMap = UniffiHandleMap<Reembedder>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceReembedder : FfiConverter {
+    typealias SwiftType = Reembedder
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionInt32: FfiConverterRustBuffer {
+    typealias SwiftType = Int32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionInt64: FfiConverterRustBuffer {
+    typealias SwiftType = Int64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeLanceDBHandle: FfiConverterRustBuffer {
+    typealias SwiftType = LanceDbHandle?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLanceDBHandle.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLanceDBHandle.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeGetOptions: FfiConverterRustBuffer {
+    typealias SwiftType = GetOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeGetOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeGetOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeListOptions: FfiConverterRustBuffer {
+    typealias SwiftType = ListOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeListOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeListOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSearchOptions: FfiConverterRustBuffer {
+    typealias SwiftType = SearchOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSearchOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSearchOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = SearchResult?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSearchResult.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSearchResult.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeStagingConfig: FfiConverterRustBuffer {
+    typealias SwiftType = StagingConfig?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeStagingConfig.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeStagingConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeStoreOptions: FfiConverterRustBuffer {
+    typealias SwiftType = StoreOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeStoreOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeStoreOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDistanceMetric: FfiConverterRustBuffer {
+    typealias SwiftType = DistanceMetric?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDistanceMetric.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDistanceMetric.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLanceDBHandle: FfiConverter {
-
-    typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = LanceDbHandle
+fileprivate struct FfiConverterOptionTypeEmbeddingColumn: FfiConverterRustBuffer {
+    typealias SwiftType = EmbeddingColumn?
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbHandle {
-        return LanceDbHandle(unsafeFromRawPointer: pointer)
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeEmbeddingColumn.write(value, into: &buf)
     }
 
-    public static func lower(_ value: LanceDbHandle) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeEmbeddingColumn.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LanceDbHandle {
-        let v: UInt64 = try readInt(&buf)
-        // The Rust code won't compile if a pointer won't fit in a UInt64.
-        // We have to go via `UInt` because that's the thing that's the size of a pointer.
-        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
-        if (ptr == nil) {
-            throw UniffiInternalError.unexpectedNullPointer
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeEmbeddingDtype: FfiConverterRustBuffer {
+    typealias SwiftType = EmbeddingDtype?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return try lift(ptr!)
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeEmbeddingDtype.write(value, into: &buf)
     }
 
-    public static func write(_ value: LanceDbHandle, into buf: inout [UInt8]) {
-        // This fiddling is because `Int` is the thing that's the same size as a pointer.
-        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
-        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeEmbeddingDtype.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSortOrder: FfiConverterRustBuffer {
+    typealias SwiftType = SortOrder?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSortOrder.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSortOrder.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLanceDBHandle_lift(_ pointer: UnsafeMutableRawPointer) throws -> LanceDbHandle {
-    return try FfiConverterTypeLanceDBHandle.lift(pointer)
+fileprivate struct FfiConverterOptionCallbackInterfaceProgressReporter: FfiConverterRustBuffer {
+    typealias SwiftType = ProgressReporter?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceProgressReporter.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceProgressReporter.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLanceDBHandle_lower(_ value: LanceDbHandle) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeLanceDBHandle.lower(value)
+fileprivate struct FfiConverterOptionSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [Float]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceFloat.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceFloat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]?
 
-public struct HybridSearchResult {
-    public var key: String
-    public var text: String
-    public var vectorRank: UInt32
-    public var textRank: UInt32
-    public var rrfScore: Double
-    public var metadata: String?
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceString.write(value, into: &buf)
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(key: String, text: String, vectorRank: UInt32, textRank: UInt32, rrfScore: Double, metadata: String?) {
-        self.key = key
-        self.text = text
-        self.vectorRank = vectorRank
-        self.textRank = textRank
-        self.rrfScore = rrfScore
-        self.metadata = metadata
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeColumnEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [ColumnEntry]?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeColumnEntry.write(value, into: &buf)
+    }
 
-extension HybridSearchResult: Equatable, Hashable {
-    public static func ==(lhs: HybridSearchResult, rhs: HybridSearchResult) -> Bool {
-        if lhs.key != rhs.key {
-            return false
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeColumnEntry.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
-        if lhs.text != rhs.text {
-            return false
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeColumnSpec: FfiConverterRustBuffer {
+    typealias SwiftType = [ColumnSpec]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        if lhs.vectorRank != rhs.vectorRank {
-            return false
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeColumnSpec.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeColumnSpec.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
-        if lhs.textRank != rhs.textRank {
-            return false
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeMetaPair: FfiConverterRustBuffer {
+    typealias SwiftType = [MetaPair]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        if lhs.rrfScore != rhs.rrfScore {
-            return false
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeMetaPair.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeMetaPair.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
-        if lhs.metadata != rhs.metadata {
-            return false
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeMetaFilter: FfiConverterRustBuffer {
+    typealias SwiftType = [MetaFilter]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeMetaFilter.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(key)
-        hasher.combine(text)
-        hasher.combine(vectorRank)
-        hasher.combine(textRank)
-        hasher.combine(rrfScore)
-        hasher.combine(metadata)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeMetaFilter.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHybridSearchResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HybridSearchResult {
-        return
-            try HybridSearchResult(
-                key: FfiConverterString.read(from: &buf), 
-                text: FfiConverterString.read(from: &buf), 
-                vectorRank: FfiConverterUInt32.read(from: &buf), 
-                textRank: FfiConverterUInt32.read(from: &buf), 
-                rrfScore: FfiConverterDouble.read(from: &buf), 
-                metadata: FfiConverterOptionString.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionDictionaryStringTypeCollectionPreset: FfiConverterRustBuffer {
+    typealias SwiftType = [String: CollectionPreset]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDictionaryStringTypeCollectionPreset.write(value, into: &buf)
     }
 
-    public static func write(_ value: HybridSearchResult, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.key, into: &buf)
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterUInt32.write(value.vectorRank, into: &buf)
-        FfiConverterUInt32.write(value.textRank, into: &buf)
-        FfiConverterDouble.write(value.rrfScore, into: &buf)
-        FfiConverterOptionString.write(value.metadata, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDictionaryStringTypeCollectionPreset.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHybridSearchResult_lift(_ buf: RustBuffer) throws -> HybridSearchResult {
-    return try FfiConverterTypeHybridSearchResult.lift(buf)
+fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [Float]
+
+    public static func write(_ value: [Float], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterFloat.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Float] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Float]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterFloat.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHybridSearchResult_lower(_ value: HybridSearchResult) -> RustBuffer {
-    return FfiConverterTypeHybridSearchResult.lower(value)
-}
-
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-public struct SearchResult {
-    public var key: String
-    public var text: String
-    public var score: Double
-    public var metadata: String?
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(key: String, text: String, score: Double, metadata: String?) {
-        self.key = key
-        self.text = text
-        self.score = score
-        self.metadata = metadata
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAgentCount: FfiConverterRustBuffer {
+    typealias SwiftType = [AgentCount]
 
-
-extension SearchResult: Equatable, Hashable {
-    public static func ==(lhs: SearchResult, rhs: SearchResult) -> Bool {
-        if lhs.key != rhs.key {
-            return false
-        }
-        if lhs.text != rhs.text {
-            return false
-        }
-        if lhs.score != rhs.score {
-            return false
-        }
-        if lhs.metadata != rhs.metadata {
-            return false
+    public static func write(_ value: [AgentCount], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAgentCount.write(item, into: &buf)
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(key)
-        hasher.combine(text)
-        hasher.combine(score)
-        hasher.combine(metadata)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AgentCount] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AgentCount]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAgentCount.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSearchResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResult {
-        return
-            try SearchResult(
-                key: FfiConverterString.read(from: &buf), 
-                text: FfiConverterString.read(from: &buf), 
-                score: FfiConverterDouble.read(from: &buf), 
-                metadata: FfiConverterOptionString.read(from: &buf)
-        )
+fileprivate struct FfiConverterSequenceTypeColumnEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [ColumnEntry]
+
+    public static func write(_ value: [ColumnEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeColumnEntry.write(item, into: &buf)
+        }
     }
 
-    public static func write(_ value: SearchResult, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.key, into: &buf)
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterDouble.write(value.score, into: &buf)
-        FfiConverterOptionString.write(value.metadata, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ColumnEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ColumnEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeColumnEntry.read(from: &buf))
+        }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResult_lift(_ buf: RustBuffer) throws -> SearchResult {
-    return try FfiConverterTypeSearchResult.lift(buf)
+fileprivate struct FfiConverterSequenceTypeColumnSpec: FfiConverterRustBuffer {
+    typealias SwiftType = [ColumnSpec]
+
+    public static func write(_ value: [ColumnSpec], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeColumnSpec.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ColumnSpec] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ColumnSpec]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeColumnSpec.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResult_lower(_ value: SearchResult) -> RustBuffer {
-    return FfiConverterTypeSearchResult.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeFieldInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [FieldInfo]
 
+    public static func write(_ value: [FieldInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFieldInfo.write(item, into: &buf)
+        }
+    }
 
-public enum LanceError {
-
-    
-    
-    case ConnectionFailed(msg: String
-    )
-    case TableError(msg: String
-    )
-    case QueryError(msg: String
-    )
-    case InsertError(msg: String
-    )
-    case DeleteError(msg: String
-    )
-    case SchemaError(msg: String
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FieldInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FieldInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFieldInfo.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLanceError: FfiConverterRustBuffer {
-    typealias SwiftType = LanceError
+fileprivate struct FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [HybridSearchResult]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LanceError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+    public static func write(_ value: [HybridSearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHybridSearchResult.write(item, into: &buf)
+        }
+    }
 
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HybridSearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HybridSearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHybridSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        
-        case 1: return .ConnectionFailed(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .TableError(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .QueryError(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InsertError(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .DeleteError(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .SchemaError(
-            msg: try FfiConverterString.read(from: &buf)
-            )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeIndexInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [IndexInfo]
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [IndexInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIndexInfo.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: LanceError, into buf: inout [UInt8]) {
-        switch value {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [IndexInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [IndexInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIndexInfo.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeKeyEmbedding: FfiConverterRustBuffer {
+    typealias SwiftType = [KeyEmbedding]
 
-        
-        
-        case let .ConnectionFailed(msg):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .TableError(msg):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .QueryError(msg):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .InsertError(msg):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .DeleteError(msg):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .SchemaError(msg):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(msg, into: &buf)
-            
+    public static func write(_ value: [KeyEmbedding], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeKeyEmbedding.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [KeyEmbedding] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [KeyEmbedding]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeKeyEmbedding.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeMemoryEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [MemoryEntry]
 
-extension LanceError: Equatable, Hashable {}
+    public static func write(_ value: [MemoryEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMemoryEntry.write(item, into: &buf)
+        }
+    }
 
-extension LanceError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MemoryEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [MemoryEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMemoryEntry.read(from: &buf))
+        }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
-    typealias SwiftType = UInt32?
+fileprivate struct FfiConverterSequenceTypeMetaPair: FfiConverterRustBuffer {
+    typealias SwiftType = [MetaPair]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [MetaPair], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMetaPair.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt32.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt32.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MetaPair] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [MetaPair]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMetaPair.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [SearchResult]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [SearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSearchResult.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSearchResult.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
-    typealias SwiftType = [Float]
+fileprivate struct FfiConverterSequenceTypeVersionInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [VersionInfo]
 
-    public static func write(_ value: [Float], into buf: inout [UInt8]) {
+    public static func write(_ value: [VersionInfo], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterFloat.write(item, into: &buf)
+            FfiConverterTypeVersionInfo.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Float] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [VersionInfo] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Float]()
+        var seq = [VersionInfo]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterFloat.read(from: &buf))
+            seq.append(try FfiConverterTypeVersionInfo.read(from: &buf))
         }
         return seq
     }
@@ -1156,23 +7980,23 @@ fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeMetaFilter: FfiConverterRustBuffer {
+    typealias SwiftType = [MetaFilter]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [MetaFilter], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeMetaFilter.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MetaFilter] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [MetaFilter]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeMetaFilter.read(from: &buf))
         }
         return seq
     }
@@ -1181,23 +8005,23 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustBuffer {
-    typealias SwiftType = [HybridSearchResult]
+fileprivate struct FfiConverterSequenceSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [[Float]]
 
-    public static func write(_ value: [HybridSearchResult], into buf: inout [UInt8]) {
+    public static func write(_ value: [[Float]], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeHybridSearchResult.write(item, into: &buf)
+            FfiConverterSequenceFloat.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HybridSearchResult] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[Float]] {
         let len: Int32 = try readInt(&buf)
-        var seq = [HybridSearchResult]()
+        var seq = [[Float]]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeHybridSearchResult.read(from: &buf))
+            seq.append(try FfiConverterSequenceFloat.read(from: &buf))
         }
         return seq
     }
@@ -1206,27 +8030,53 @@ fileprivate struct FfiConverterSequenceTypeHybridSearchResult: FfiConverterRustB
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer {
-    typealias SwiftType = [SearchResult]
+fileprivate struct FfiConverterSequenceSequenceTypeSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [[SearchResult]]
 
-    public static func write(_ value: [SearchResult], into buf: inout [UInt8]) {
+    public static func write(_ value: [[SearchResult]], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeSearchResult.write(item, into: &buf)
+            FfiConverterSequenceTypeSearchResult.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResult] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[SearchResult]] {
         let len: Int32 = try readInt(&buf)
-        var seq = [SearchResult]()
+        var seq = [[SearchResult]]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeSearchResult.read(from: &buf))
+            seq.append(try FfiConverterSequenceTypeSearchResult.read(from: &buf))
         }
         return seq
     }
 }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringTypeCollectionPreset: FfiConverterRustBuffer {
+    public static func write(_ value: [String: CollectionPreset], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterTypeCollectionPreset.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: CollectionPreset] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: CollectionPreset]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterTypeCollectionPreset.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
 private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
 private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
 
@@ -1273,6 +8123,28 @@ fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: In
         print("uniffiFutureContinuationCallback invalid handle")
     }
 }
+/**
+ * Versions of this library and of the LanceDB and Lance crates compiled
+ * into it, as `name/version` pairs separated by spaces, e.g.
+ * `lancedb-ffi/0.1.0 lancedb/0.26.2 lance/2.0.0`, for support reports.
+ */
+public func lancedbVersion() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_lancedb_ffi_fn_func_lancedb_version($0
+    )
+})
+}
+/**
+ * Compile `filters` into one SQL predicate (their conjunction), for methods
+ * that take a raw `filter` string. An empty list matches every row.
+ */
+public func metaFilterSql(filters: [MetaFilter]) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_lancedb_ffi_fn_func_meta_filter_sql(
+        FfiConverterSequenceTypeMetaFilter.lower(filters),$0
+    )
+})
+}
 
 private enum InitializationResult {
     case ok
@@ -1289,28 +8161,237 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_lancedb_ffi_checksum_func_lancedb_version() != 8508) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_func_meta_filter_sql() != 25967) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_centroid() != 13849) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_cleanup_old_versions() != 62346) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear() != 62802) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete() != 18738) {
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear_all() != 40720) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_close_handle() != 43058) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_copy_collection() != 20394) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_count() != 41206) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_counts_by_agent() != 13099) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_index() != 47246) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_scalar_index() != 8198) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete() != 51313) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_by_agent() != 30348) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_many() != 24190) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_where() != 7246) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_describe() != 51022) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_drop_index() != 22298) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_exists() != 39757) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_explain_search() != 40581) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_json() != 60901) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_parquet() != 35510) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_flush() != 13475) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_get() != 36780) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_get_many() != 60650) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_health_check() != 35972) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_hybrid_search() != 51031) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_list() != 30004) {
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_arrow_file() != 1893) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_json() != 26566) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_parquet() != 38027) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_index_info() != 46571) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_list() != 48228) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_agents() != 5983) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_detailed() != 25291) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_versions() != 57995) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_migrate_dimension() != 23958) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_nearest() != 18440) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_optimize() != 17977) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_rename_collection() != 39069) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore() != 28753) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore_key() != 22086) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search() != 30283) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_binary() != 40342) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_bytes() != 22944) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_detailed() != 533) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_hybrid() != 43508) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_many() != 62245) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_multi() != 59773) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_paged() != 27549) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_similar() != 18702) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_text() != 19530) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_soft_delete() != 53334) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_stats() != 6880) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store() != 32837) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_binary() != 51332) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_bytes() != 61032) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_many() != 31302) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_with_meta() != 50419) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_touch() != 43871) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_embeddings() != 56752) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_metadata() != 63496) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_verify() != 11124) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbmanager_close() != 2415) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbmanager_close_all() != 2264) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbmanager_get() != 49138) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbmanager_ids() != 13633) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_method_lancedbmanager_open() != 53275) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open() != 39229) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_memory() != 5971) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_read_only() != 3901) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_scoped() != 25237) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_columns() != 34491) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_options() != 59604) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_search() != 42957) {
+    if (uniffi_lancedb_ffi_checksum_constructor_lancedbmanager_new() != 12261) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_lancedb_ffi_checksum_method_lancedbhandle_store() != 43577) {
+    if (uniffi_lancedb_ffi_checksum_method_progressreporter_on_progress() != 693) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open() != 20925) {
+    if (uniffi_lancedb_ffi_checksum_method_reembedder_embed() != 11632) {
         return InitializationResult.apiChecksumMismatch
     }
 
+    uniffiCallbackInitProgressReporter()
+    uniffiCallbackInitReembedder()
     return InitializationResult.ok
 }()
 
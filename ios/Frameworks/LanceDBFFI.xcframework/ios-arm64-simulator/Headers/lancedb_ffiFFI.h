@@ -250,6 +250,36 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_PROGRESS_REPORTER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_PROGRESS_REPORTER_METHOD0
+typedef void (*UniffiCallbackInterfaceProgressReporterMethod0)(uint64_t, uint64_t, uint64_t, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_REEMBEDDER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_REEMBEDDER_METHOD0
+typedef void (*UniffiCallbackInterfaceReembedderMethod0)(uint64_t, RustBuffer, RustBuffer* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PROGRESS_REPORTER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PROGRESS_REPORTER
+typedef struct UniffiVTableCallbackInterfaceProgressReporter {
+    UniffiCallbackInterfaceProgressReporterMethod0 _Nonnull onProgress;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceProgressReporter;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_REEMBEDDER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_REEMBEDDER
+typedef struct UniffiVTableCallbackInterfaceReembedder {
+    UniffiCallbackInterfaceReembedderMethod0 _Nonnull embed;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceReembedder;
+
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CLONE_LANCEDBHANDLE
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CLONE_LANCEDBHANDLE
@@ -266,14 +296,149 @@ void uniffi_lancedb_ffi_fn_free_lancedbhandle(void*_Nonnull ptr, RustCallStatus
 uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open(RustBuffer db_path, int32_t embedding_dim
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_MEMORY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_MEMORY
+uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_memory(int32_t embedding_dim
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_READ_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_READ_ONLY
+uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_read_only(RustBuffer db_path, int32_t embedding_dim
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_SCOPED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_SCOPED
+uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_scoped(RustBuffer db_path, int32_t embedding_dim, RustBuffer agent_id
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_COLUMNS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_COLUMNS
+uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_columns(RustBuffer db_path, int32_t embedding_dim, RustBuffer extra_columns
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_OPTIONS
+uint64_t uniffi_lancedb_ffi_fn_constructor_lancedbhandle_open_with_options(RustBuffer db_path, int32_t embedding_dim, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CENTROID
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CENTROID
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_centroid(void*_Nonnull ptr, RustBuffer filter, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEANUP_OLD_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEANUP_OLD_VERSIONS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_cleanup_old_versions(void*_Nonnull ptr, RustBuffer collection, uint64_t older_than_seconds
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEAR
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEAR
 uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_clear(void*_Nonnull ptr, RustBuffer collection
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEAR_ALL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLEAR_ALL
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_clear_all(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLOSE_HANDLE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CLOSE_HANDLE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_close_handle(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COPY_COLLECTION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COPY_COLLECTION
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_copy_collection(void*_Nonnull ptr, RustBuffer from, RustBuffer to
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COUNT
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_count(void*_Nonnull ptr, RustBuffer collection, RustBuffer filter
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COUNTS_BY_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_COUNTS_BY_AGENT
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_counts_by_agent(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CREATE_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CREATE_INDEX
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_create_index(void*_Nonnull ptr, RustBuffer collection, RustBuffer num_partitions, RustBuffer num_sub_vectors, RustBuffer progress
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CREATE_SCALAR_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_CREATE_SCALAR_INDEX
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_create_scalar_index(void*_Nonnull ptr, RustBuffer collection, RustBuffer column
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE
-uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(void*_Nonnull ptr, RustBuffer key
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(void*_Nonnull ptr, RustBuffer key, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_BY_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_BY_AGENT
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_by_agent(void*_Nonnull ptr, RustBuffer agent_id, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_MANY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_many(void*_Nonnull ptr, RustBuffer keys, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_WHERE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DELETE_WHERE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete_where(void*_Nonnull ptr, RustBuffer filter, RustBuffer collection, int8_t dry_run, int8_t delete_all, int8_t maintain_index
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DESCRIBE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DESCRIBE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_describe(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DROP_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_DROP_INDEX
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_drop_index(void*_Nonnull ptr, RustBuffer collection, RustBuffer column
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXISTS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_exists(void*_Nonnull ptr, RustBuffer key, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPLAIN_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPLAIN_SEARCH
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_explain_search(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, RustBuffer filter, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPORT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPORT_JSON
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_export_json(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPORT_PARQUET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_EXPORT_PARQUET
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_export_parquet(void*_Nonnull ptr, RustBuffer collection, RustBuffer out_path
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_FLUSH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_FLUSH
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_flush(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_GET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_GET
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_get(void*_Nonnull ptr, RustBuffer key, RustBuffer collection, int8_t include_embedding, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_GET_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_GET_MANY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_get_many(void*_Nonnull ptr, RustBuffer keys, RustBuffer collection, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_HEALTH_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_HEALTH_CHECK
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_health_check(void*_Nonnull ptr
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_HYBRID_SEARCH
@@ -281,19 +446,241 @@ uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_delete(void*_Nonnull ptr, Ru
 uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_hybrid_search(void*_Nonnull ptr, RustBuffer query_vector, RustBuffer query_text, uint32_t limit, RustBuffer filter, RustBuffer rrf_k, RustBuffer vector_limit
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_ARROW_FILE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_ARROW_FILE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_import_arrow_file(void*_Nonnull ptr, RustBuffer collection, RustBuffer path, int8_t upsert, RustBuffer progress
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_JSON
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_import_json(void*_Nonnull ptr, RustBuffer collection, RustBuffer json, RustBuffer mode
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_PARQUET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_IMPORT_PARQUET
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_import_parquet(void*_Nonnull ptr, RustBuffer collection, RustBuffer in_path, RustBuffer mode, RustBuffer progress
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_INDEX_INFO
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_INDEX_INFO
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_index_info(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST
-uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_list(void*_Nonnull ptr, RustBuffer prefix, RustBuffer limit
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_list(void*_Nonnull ptr, RustBuffer prefix, RustBuffer limit, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_AGENTS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_AGENTS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_list_agents(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_DETAILED
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_list_detailed(void*_Nonnull ptr, RustBuffer prefix, RustBuffer limit, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_LIST_VERSIONS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_list_versions(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_MIGRATE_DIMENSION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_MIGRATE_DIMENSION
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_migrate_dimension(void*_Nonnull ptr, RustBuffer collection, int32_t new_dim, uint64_t reembed, RustBuffer progress
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_NEAREST
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_NEAREST
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_nearest(void*_Nonnull ptr, RustBuffer query_vector, RustBuffer filter, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_OPTIMIZE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_OPTIMIZE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_optimize(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RENAME_COLLECTION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RENAME_COLLECTION
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_rename_collection(void*_Nonnull ptr, RustBuffer from, RustBuffer to
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RESTORE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RESTORE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_restore(void*_Nonnull ptr, RustBuffer collection, uint64_t version
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RESTORE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_RESTORE_KEY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_restore_key(void*_Nonnull ptr, RustBuffer key, RustBuffer collection
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH
-uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, RustBuffer filter
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, RustBuffer filter, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_BINARY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_BINARY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_binary(void*_Nonnull ptr, RustBuffer code, uint32_t limit, RustBuffer filter, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_BYTES
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_bytes(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, RustBuffer filter, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_DETAILED
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_detailed(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, RustBuffer filter, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_HYBRID
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_HYBRID
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_hybrid(void*_Nonnull ptr, RustBuffer query_vector, RustBuffer query_text, uint32_t limit, double alpha, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_MANY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_many(void*_Nonnull ptr, RustBuffer query_vectors, uint32_t limit, RustBuffer filter, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_MULTI
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_MULTI
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_multi(void*_Nonnull ptr, RustBuffer collections, RustBuffer query_vector, uint32_t limit, RustBuffer filter
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_PAGED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_PAGED
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_paged(void*_Nonnull ptr, RustBuffer query_vector, uint32_t limit, uint32_t offset, RustBuffer filter, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_SIMILAR
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_SIMILAR
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_similar(void*_Nonnull ptr, RustBuffer key, uint32_t limit, RustBuffer filter, int8_t exclude_self, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SEARCH_TEXT
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_search_text(void*_Nonnull ptr, RustBuffer query, uint32_t limit, RustBuffer filter, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SOFT_DELETE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_SOFT_DELETE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_soft_delete(void*_Nonnull ptr, RustBuffer key, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STATS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_stats(void*_Nonnull ptr, RustBuffer collection
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE
-uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store(void*_Nonnull ptr, RustBuffer key, RustBuffer agent_id, RustBuffer text, RustBuffer embedding, RustBuffer metadata
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store(void*_Nonnull ptr, RustBuffer key, RustBuffer agent_id, RustBuffer text, RustBuffer embedding, RustBuffer metadata, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_BINARY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_BINARY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store_binary(void*_Nonnull ptr, RustBuffer key, RustBuffer agent_id, RustBuffer text, RustBuffer code, RustBuffer metadata, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_BYTES
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store_bytes(void*_Nonnull ptr, RustBuffer key, RustBuffer agent_id, RustBuffer text, RustBuffer embedding, RustBuffer metadata, RustBuffer options
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_MANY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store_many(void*_Nonnull ptr, RustBuffer entries, RustBuffer collection, RustBuffer chunk_size, RustBuffer progress
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_WITH_META
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_STORE_WITH_META
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_store_with_meta(void*_Nonnull ptr, RustBuffer key, RustBuffer agent_id, RustBuffer text, RustBuffer embedding, RustBuffer meta, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_TOUCH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_TOUCH
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_touch(void*_Nonnull ptr, RustBuffer key, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_UPDATE_EMBEDDINGS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_UPDATE_EMBEDDINGS
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_update_embeddings(void*_Nonnull ptr, RustBuffer updates, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_UPDATE_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_UPDATE_METADATA
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_update_metadata(void*_Nonnull ptr, RustBuffer key, RustBuffer metadata, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBHANDLE_VERIFY
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbhandle_verify(void*_Nonnull ptr, RustBuffer collection
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CLONE_LANCEDBMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CLONE_LANCEDBMANAGER
+void*_Nonnull uniffi_lancedb_ffi_fn_clone_lancedbmanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FREE_LANCEDBMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FREE_LANCEDBMANAGER
+void uniffi_lancedb_ffi_fn_free_lancedbmanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_CONSTRUCTOR_LANCEDBMANAGER_NEW
+void*_Nonnull uniffi_lancedb_ffi_fn_constructor_lancedbmanager_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_CLOSE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_CLOSE
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbmanager_close(void*_Nonnull ptr, RustBuffer id
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_CLOSE_ALL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_CLOSE_ALL
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbmanager_close_all(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_GET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_GET
+RustBuffer uniffi_lancedb_ffi_fn_method_lancedbmanager_get(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_IDS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_IDS
+RustBuffer uniffi_lancedb_ffi_fn_method_lancedbmanager_ids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_OPEN
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_METHOD_LANCEDBMANAGER_OPEN
+uint64_t uniffi_lancedb_ffi_fn_method_lancedbmanager_open(void*_Nonnull ptr, RustBuffer id, RustBuffer db_path, int32_t embedding_dim
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_INIT_CALLBACK_VTABLE_PROGRESSREPORTER
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_INIT_CALLBACK_VTABLE_PROGRESSREPORTER
+void uniffi_lancedb_ffi_fn_init_callback_vtable_progressreporter(UniffiVTableCallbackInterfaceProgressReporter* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_INIT_CALLBACK_VTABLE_REEMBEDDER
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_INIT_CALLBACK_VTABLE_REEMBEDDER
+void uniffi_lancedb_ffi_fn_init_callback_vtable_reembedder(UniffiVTableCallbackInterfaceReembedder* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FUNC_LANCEDB_VERSION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FUNC_LANCEDB_VERSION
+RustBuffer uniffi_lancedb_ffi_fn_func_lancedb_version(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FUNC_META_FILTER_SQL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_FN_FUNC_META_FILTER_SQL
+RustBuffer uniffi_lancedb_ffi_fn_func_meta_filter_sql(RustBuffer filters, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_LANCEDB_FFI_RUSTBUFFER_ALLOC
@@ -574,48 +961,462 @@ void ffi_lancedb_ffi_rust_future_free_void(uint64_t handle
 #ifndef UNIFFI_FFIDEF_FFI_LANCEDB_FFI_RUST_FUTURE_COMPLETE_VOID
 #define UNIFFI_FFIDEF_FFI_LANCEDB_FFI_RUST_FUTURE_COMPLETE_VOID
 void ffi_lancedb_ffi_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_FUNC_LANCEDB_VERSION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_FUNC_LANCEDB_VERSION
+uint16_t uniffi_lancedb_ffi_checksum_func_lancedb_version(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_FUNC_META_FILTER_SQL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_FUNC_META_FILTER_SQL
+uint16_t uniffi_lancedb_ffi_checksum_func_meta_filter_sql(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CENTROID
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CENTROID
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_centroid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEANUP_OLD_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEANUP_OLD_VERSIONS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_cleanup_old_versions(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEAR
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEAR
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEAR_ALL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLEAR_ALL
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_clear_all(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLOSE_HANDLE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CLOSE_HANDLE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_close_handle(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COPY_COLLECTION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COPY_COLLECTION
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_copy_collection(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COUNT
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COUNTS_BY_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_COUNTS_BY_AGENT
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_counts_by_agent(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CREATE_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CREATE_INDEX
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_index(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CREATE_SCALAR_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_CREATE_SCALAR_INDEX
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_create_scalar_index(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_BY_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_BY_AGENT
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_by_agent(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_MANY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_many(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_WHERE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DELETE_WHERE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_delete_where(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DESCRIBE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DESCRIBE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_describe(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DROP_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_DROP_INDEX
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_drop_index(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXISTS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_exists(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPLAIN_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPLAIN_SEARCH
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_explain_search(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPORT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPORT_JSON
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPORT_PARQUET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_EXPORT_PARQUET
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_export_parquet(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_FLUSH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_FLUSH
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_flush(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_GET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_GET
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_get(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_GET_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_GET_MANY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_get_many(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_HEALTH_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_HEALTH_CHECK
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_health_check(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_HYBRID_SEARCH
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_HYBRID_SEARCH
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_hybrid_search(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_ARROW_FILE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_ARROW_FILE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_arrow_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_JSON
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_PARQUET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_IMPORT_PARQUET
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_import_parquet(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_INDEX_INFO
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_INDEX_INFO
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_index_info(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_list(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_AGENTS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_AGENTS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_agents(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_DETAILED
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_detailed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_LIST_VERSIONS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_list_versions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_MIGRATE_DIMENSION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_MIGRATE_DIMENSION
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_migrate_dimension(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_NEAREST
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_NEAREST
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_nearest(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_OPTIMIZE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_OPTIMIZE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_optimize(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RENAME_COLLECTION
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RENAME_COLLECTION
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_rename_collection(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RESTORE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RESTORE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RESTORE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_RESTORE_KEY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_restore_key(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_BINARY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_BINARY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_binary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_BYTES
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_DETAILED
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_detailed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_HYBRID
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_HYBRID
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_hybrid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_MANY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_many(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_MULTI
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_MULTI
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_multi(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_PAGED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_PAGED
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_paged(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_SIMILAR
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_SIMILAR
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_similar(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SEARCH_TEXT
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_search_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SOFT_DELETE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_SOFT_DELETE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_soft_delete(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STATS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_stats(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE
 uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_store(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_BINARY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_BINARY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_binary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_BYTES
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_MANY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_MANY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_many(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_WITH_META
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_STORE_WITH_META
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_store_with_meta(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_TOUCH
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_TOUCH
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_touch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_UPDATE_EMBEDDINGS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_UPDATE_EMBEDDINGS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_embeddings(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_UPDATE_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_UPDATE_METADATA
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_update_metadata(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBHANDLE_VERIFY
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbhandle_verify(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_CLOSE
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_CLOSE
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbmanager_close(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_CLOSE_ALL
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_CLOSE_ALL
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbmanager_close_all(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_GET
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_GET
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbmanager_get(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_IDS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_IDS
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbmanager_ids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_OPEN
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_LANCEDBMANAGER_OPEN
+uint16_t uniffi_lancedb_ffi_checksum_method_lancedbmanager_open(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN
 #define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN
 uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_MEMORY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_MEMORY
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_memory(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_READ_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_READ_ONLY
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_read_only(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_SCOPED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_SCOPED
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_scoped(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_COLUMNS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_COLUMNS
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_columns(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBHANDLE_OPEN_WITH_OPTIONS
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbhandle_open_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_CONSTRUCTOR_LANCEDBMANAGER_NEW
+uint16_t uniffi_lancedb_ffi_checksum_constructor_lancedbmanager_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_PROGRESSREPORTER_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_PROGRESSREPORTER_ON_PROGRESS
+uint16_t uniffi_lancedb_ffi_checksum_method_progressreporter_on_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_REEMBEDDER_EMBED
+#define UNIFFI_FFIDEF_UNIFFI_LANCEDB_FFI_CHECKSUM_METHOD_REEMBEDDER_EMBED
+uint16_t uniffi_lancedb_ffi_checksum_method_reembedder_embed(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_LANCEDB_FFI_UNIFFI_CONTRACT_VERSION
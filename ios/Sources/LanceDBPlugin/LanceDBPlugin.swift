@@ -143,7 +143,7 @@ public class LanceDBPlugin: CAPPlugin, CAPBridgedPlugin {
 
         Task {
             do {
-                try await handle.store(key: key, agentId: agentId, text: text, embedding: embedding, metadata: metadata)
+                _ = try await handle.store(key: key, agentId: agentId, text: text, embedding: embedding, metadata: metadata)
                 call.resolve()
             } catch {
                 call.reject("store failed: \(error.localizedDescription)")
@@ -189,7 +189,7 @@ public class LanceDBPlugin: CAPPlugin, CAPBridgedPlugin {
 
         Task {
             do {
-                try await handle.delete(key: key)
+                try await handle.delete(key: key, collection: nil)
                 call.resolve()
             } catch {
                 call.reject("delete failed: \(error.localizedDescription)")
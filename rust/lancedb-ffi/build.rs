@@ -0,0 +1,19 @@
+//! Records the resolved `lancedb` and `lance` versions from Cargo.lock so
+//! `lancedb_version` can report them.
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    let lock = std::fs::read_to_string("Cargo.lock").unwrap_or_default();
+    for (name, var) in [("lancedb", "LANCEDB_VERSION"), ("lance", "LANCE_VERSION")] {
+        let version = locked_version(&lock, name).unwrap_or("unknown");
+        println!("cargo:rustc-env={var}={version}");
+    }
+}
+
+/// Version of the first `[[package]]` named `name` in the lockfile.
+fn locked_version<'a>(lock: &'a str, name: &str) -> Option<&'a str> {
+    let header = format!("name = \"{name}\"");
+    let mut lines = lock.lines();
+    lines.find(|l| *l == header)?;
+    lines.next()?.strip_prefix("version = \"")?.strip_suffix('"')
+}
@@ -1,13 +1,40 @@
-use std::sync::Arc;
-
-use arrow_array::{Array, FixedSizeListArray, Float32Array, Int64Array, RecordBatch, RecordBatchIterator, StringArray};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use arrow::compute::{
+    concat_batches, filter_record_batch, lexsort_to_indices, take_record_batch, SortColumn,
+    SortOptions,
+};
+use arrow::buffer::NullBuffer;
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{
+    Array, ArrayRef, BooleanArray, FixedSizeListArray, Float32Array, Float64Array, Int64Array,
+    Int8Array, ListArray, RecordBatch, RecordBatchIterator, RecordBatchReader, StringArray,
+    UInt64Array, UInt8Array,
+};
+use arrow_ipc::reader::FileReader;
 use arrow_schema::{DataType, Field, Schema};
-use futures::TryStreamExt;
-use lance_table::io::commit::UnsafeCommitHandler;
-use lancedb::query::{ExecutableQuery, QueryBase};
-use lancedb::table::{ReadParams, WriteOptions};
+use futures::{StreamExt, TryStreamExt};
+use lance_file::version::LanceFileVersion;
+use lance_table::io::commit::{CommitHandler, UnsafeCommitHandler};
+use lancedb::arrow::{SendableRecordBatchStream, SimpleRecordBatchStream};
+use lancedb::database::CreateTableMode;
+use lancedb::index::scalar::{BTreeIndexBuilder, FtsIndexBuilder, FullTextSearchQuery};
+use lancedb::index::vector::IvfPqIndexBuilder;
+use lancedb::index::{Index, IndexType};
+use lancedb::query::{ExecutableQuery, QueryBase, VectorQuery};
+use lancedb::DistanceType;
+use lancedb::table::{
+    CompactionOptions, NewColumnTransform, OptimizeAction, OptimizeOptions, ReadParams,
+    WriteOptions,
+};
 use lance::dataset::{WriteMode, WriteParams};
 use once_cell::sync::Lazy;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
 use tokio::runtime::Runtime;
 
 uniffi::setup_scaffolding!();
@@ -28,9 +55,385 @@ static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
 #[derive(uniffi::Record, Clone, Debug)]
 pub struct SearchResult {
     pub key: String,
+    /// Collection the entry was read from.
+    pub collection: String,
+    pub agent_id: String,
     pub text: String,
     pub score: f64,
+    /// Raw `_distance` reported by the engine, which `score` is derived from
+    /// (see `DistanceMetric`). `None` when the result didn't come from a
+    /// vector query.
+    pub distance: Option<f64>,
+    pub metadata: Option<String>,
+    /// `metadata` parsed into pairs when it is a JSON object (as written by
+    /// `store_with_meta`), sorted by key; otherwise `None`.
+    pub meta: Option<Vec<MetaPair>>,
+    /// Milliseconds since the Unix epoch at which the entry was stored.
+    pub created_at: i64,
+    /// Provenance, populated only when `SearchOptions::include_provenance` is set.
+    /// `row_id` is Lance's `_rowid`, which (without stable row ids) is the row
+    /// address `fragment_id << 32 | offset`; `dataset_version` is the table
+    /// version the query ran against. All three are `None` for staged rows that
+    /// have not been flushed yet.
+    pub row_id: Option<u64>,
+    pub fragment_id: Option<u64>,
+    pub dataset_version: Option<u64>,
+    /// The stored embedding, populated only when requested with
+    /// `SearchOptions::include_embedding` or `get`'s `include_embedding`.
+    pub embedding: Option<Vec<f32>>,
+}
+
+/// One metadata field for `store_with_meta`. Non-string JSON values read back
+/// through `SearchResult::meta` are rendered as their JSON text.
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq)]
+pub struct MetaPair {
+    pub key: String,
+    pub value: String,
+}
+
+/// An app-defined column added to the schema (see `open_with_columns`).
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnSpec {
+    /// Letters, digits and `_`, not starting with a digit, so it can be used
+    /// in filters unquoted. Must not clash with a built-in column.
+    pub name: String,
+    pub column_type: ColumnType,
+}
+
+/// Type of a `ColumnSpec` column.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    Utf8,
+    Int64,
+    Float64,
+    Bool,
+}
+
+impl ColumnSpec {
+    fn field(&self) -> Field {
+        Field::new(&self.name, self.column_type.data_type(), true)
+    }
+}
+
+impl ColumnType {
+    fn data_type(self) -> DataType {
+        match self {
+            ColumnType::Utf8 => DataType::Utf8,
+            ColumnType::Int64 => DataType::Int64,
+            ColumnType::Float64 => DataType::Float64,
+            ColumnType::Bool => DataType::Boolean,
+        }
+    }
+}
+
+/// A value for an extra column, whose variant must match its `ColumnType`.
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
+pub enum ColumnValue {
+    Utf8 { value: String },
+    Int64 { value: i64 },
+    Float64 { value: f64 },
+    Bool { value: bool },
+}
+
+impl ColumnValue {
+    fn column_type(&self) -> ColumnType {
+        match self {
+            ColumnValue::Utf8 { .. } => ColumnType::Utf8,
+            ColumnValue::Int64 { .. } => ColumnType::Int64,
+            ColumnValue::Float64 { .. } => ColumnType::Float64,
+            ColumnValue::Bool { .. } => ColumnType::Bool,
+        }
+    }
+}
+
+/// One extra column value passed to `store`.
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct ColumnEntry {
+    pub name: String,
+    pub value: ColumnValue,
+}
+
+/// Output of `search_detailed`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct SearchResponse {
+    pub results: Vec<SearchResult>,
+    /// The vector index was used, so results may miss true neighbours. `false`
+    /// means every row was compared (flat scan), i.e. the ranking is exact.
+    pub approximate: bool,
+}
+
+/// One page of results from `search_paged`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct SearchPage {
+    pub results: Vec<SearchResult>,
+    /// Rows matching the filter, across all pages (see `search_paged`).
+    pub total_matching: u64,
+}
+
+/// Number of entries stored by one agent (see `counts_by_agent`).
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq)]
+pub struct AgentCount {
+    pub agent_id: String,
+    pub count: u64,
+}
+
+/// One row for `store_many`; fields match the `store` arguments.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct MemoryEntry {
+    pub key: String,
+    pub agent_id: String,
+    pub text: String,
+    pub embedding: Vec<f32>,
+    #[uniffi(default = None)]
     pub metadata: Option<String>,
+    #[uniffi(default = None)]
+    pub tags: Option<Vec<String>>,
+    /// Creation time in ms since epoch, e.g. when importing history.
+    /// `None` stamps the time of the write. Must not be negative.
+    #[uniffi(default = None)]
+    pub created_at: Option<i64>,
+    /// Second vector, as `StoreOptions::embedding2`.
+    #[uniffi(default = None)]
+    pub embedding2: Option<Vec<f32>>,
+    /// Extra column values, as `StoreOptions::columns`.
+    #[uniffi(default = None)]
+    pub columns: Option<Vec<ColumnEntry>>,
+}
+
+/// A replacement embedding for `update_embeddings`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct KeyEmbedding {
+    pub key: String,
+    pub embedding: Vec<f32>,
+}
+
+/// Per-call options for `store`. Passing `None` uses the defaults.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct StoreOptions {
+    /// Requires a handle opened with `OpenOptions::enable_tags`.
+    #[uniffi(default = None)]
+    pub tags: Option<Vec<String>>,
+    /// Collection to write to; `None` is the handle's default collection.
+    #[uniffi(default = None)]
+    pub collection: Option<String>,
+    /// Written instead of the current time (ms since epoch, not negative),
+    /// e.g. to import history.
+    #[uniffi(default = None)]
+    pub created_at: Option<i64>,
+    /// Fills the second vector column; requires a handle opened with
+    /// `OpenOptions::embedding2_dim`. `None` leaves it null.
+    #[uniffi(default = None)]
+    pub embedding2: Option<Vec<f32>>,
+    /// Extra column values (see `open_with_columns`); those left out are null.
+    #[uniffi(default = None)]
+    pub columns: Option<Vec<ColumnEntry>>,
+}
+
+/// Per-call options for `search`. Passing `None` uses the defaults.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct SearchOptions {
+    #[uniffi(default = false)]
+    pub include_provenance: bool,
+    /// Allow a `query_vector` shorter than `embedding_dim` and compare it
+    /// against the first `query_vector.len()` dimensions of each stored
+    /// embedding. The truncated distance is computed in Rust over a full scan:
+    /// no index is used and every embedding matching `filter` is read, so cost
+    /// grows linearly with the collection.
+    #[uniffi(default = false)]
+    pub prefix_search: bool,
+    /// Only match rows whose `tags` contain all of these tags, or any of them
+    /// with `match_any_tag`, in addition to `filter`. An empty list adds no
+    /// constraint. Requires a handle opened with `OpenOptions::enable_tags`.
+    #[uniffi(default = None)]
+    pub has_tags: Option<Vec<String>>,
+    #[uniffi(default = false)]
+    pub match_any_tag: bool,
+    /// Ignore entries still buffered by write-ahead staging and return only
+    /// rows already committed to the table. By default staged entries are
+    /// merged in (read-your-writes), but they are lost if the process dies
+    /// before a flush; jobs that must not act on such rows should set this.
+    /// A staged update then reads as the previously committed row.
+    #[uniffi(default = false)]
+    pub committed_only: bool,
+    /// Fail with `LanceError::NotFound` instead of returning no results.
+    #[uniffi(default = false)]
+    pub error_on_empty: bool,
+    /// Index tuning for this call, overriding the collection's preset. Ignored
+    /// when the collection has no vector index.
+    #[uniffi(default = None)]
+    pub nprobes: Option<u32>,
+    #[uniffi(default = None)]
+    pub refine_factor: Option<u32>,
+    /// Skip this many of the nearest results, for paging. With a vector
+    /// index each page is cut from an approximate ranking, so pages fetched
+    /// separately may overlap or miss rows that an exact search would return.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Typed predicates ANDed with `filter`; values are escaped, so untrusted
+    /// input can be passed without building SQL by hand.
+    #[uniffi(default = None)]
+    pub filters: Option<Vec<MetaFilter>>,
+    /// Search the collection as of this version (see `list_versions`)
+    /// instead of the latest. Staged entries are not included. Not supported
+    /// together with `prefix_search`.
+    #[uniffi(default = None)]
+    pub version: Option<u64>,
+    /// Drop results whose `score` is below this, so fewer than `limit` rows
+    /// may come back. Scores depend on the handle's `DistanceMetric`.
+    #[uniffi(default = None)]
+    pub min_score: Option<f64>,
+    /// Only match rows with `created_at` (ms since epoch) at or after this.
+    #[uniffi(default = None)]
+    pub created_after: Option<i64>,
+    /// Only match rows with `created_at` (ms since epoch) at or before this.
+    #[uniffi(default = None)]
+    pub created_before: Option<i64>,
+    /// Return each result's stored embedding in `SearchResult::embedding`.
+    #[uniffi(default = false)]
+    pub include_embedding: bool,
+    /// Also match entries hidden by `soft_delete`.
+    #[uniffi(default = false)]
+    pub include_deleted: bool,
+    /// Compare against every row instead of using the vector index, so the
+    /// ranking is exact. Latency then grows linearly with the collection
+    /// size, so keep this for small collections or when recall is critical.
+    #[uniffi(default = false)]
+    pub exact: bool,
+    /// Vector column to compare `query_vector` against. `Secondary` requires
+    /// a handle opened with `OpenOptions::embedding2_dim` and only matches
+    /// rows stored with an `embedding2`. `None` searches `embedding`.
+    #[uniffi(default = None)]
+    pub which: Option<EmbeddingColumn>,
+    /// Collection to search; `None` searches the handle's default collection.
+    #[uniffi(default = None)]
+    pub collection: Option<String>,
+}
+
+/// One of the handle's vector columns (see `OpenOptions::embedding2_dim`).
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmbeddingColumn {
+    /// `embedding`, which every row has.
+    #[default]
+    Primary,
+    /// `embedding2`, which rows may leave null.
+    Secondary,
+}
+
+impl EmbeddingColumn {
+    fn name(self) -> &'static str {
+        match self {
+            EmbeddingColumn::Primary => "embedding",
+            EmbeddingColumn::Secondary => "embedding2",
+        }
+    }
+}
+
+/// A typed filter predicate, compiled to an escaped SQL expression.
+#[derive(uniffi::Enum, Clone, Debug, PartialEq, Eq)]
+pub enum MetaFilter {
+    /// `agent_id` equals the given id.
+    AgentId { agent_id: String },
+    /// `created_at` (ms since epoch) is at or after `ms`.
+    CreatedSince { ms: i64 },
+    /// `created_at` (ms since epoch) is at or before `ms`.
+    CreatedBefore { ms: i64 },
+    /// `key` starts with `prefix`.
+    KeyPrefix { prefix: String },
+}
+
+/// Per-call options for `get` and `get_many`. Passing `None` uses the
+/// defaults.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct GetOptions {
+    /// Ignore entries still buffered by write-ahead staging, as
+    /// `SearchOptions::committed_only` does.
+    #[uniffi(default = false)]
+    pub committed_only: bool,
+    /// Fail with `LanceError::NotFound` instead of returning `None` (for
+    /// `get`) or no results (for `get_many`).
+    #[uniffi(default = false)]
+    pub error_on_empty: bool,
+}
+
+/// Per-call options for `list` and `list_detailed`. Passing `None` uses the
+/// defaults.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct ListOptions {
+    /// Collection to list; `None` is the handle's default collection.
+    #[uniffi(default = None)]
+    pub collection: Option<String>,
+    /// Skip that many entries first, for paging with `limit`.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// List the collection as of that version, without staged entries.
+    #[uniffi(default = None)]
+    pub version: Option<u64>,
+    /// Keep only entries whose `created_at` (ms since epoch) is at or after this.
+    #[uniffi(default = None)]
+    pub created_after: Option<i64>,
+    /// Keep only entries whose `created_at` (ms since epoch) is at or before this.
+    #[uniffi(default = None)]
+    pub created_before: Option<i64>,
+    /// Sort before `offset` and `limit` apply; `None` keeps scan order.
+    #[uniffi(default = None)]
+    pub order: Option<SortOrder>,
+    /// Also list entries hidden by `soft_delete`.
+    #[uniffi(default = false)]
+    pub include_deleted: bool,
+    /// Fail with `LanceError::NotFound` instead of returning no entries.
+    #[uniffi(default = false)]
+    pub error_on_empty: bool,
+}
+
+/// Row order for `list` and `list_detailed`. Ties on `created_at` are broken
+/// by key.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    CreatedAtAsc,
+    CreatedAtDesc,
+    KeyAsc,
+}
+
+/// How `import_parquet` combines the file's rows with the collection.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Add the rows to whatever the collection already holds.
+    Append,
+    /// Replace the collection's contents, including staged entries.
+    Overwrite,
+}
+
+impl MetaFilter {
+    fn to_sql(&self) -> String {
+        match self {
+            MetaFilter::AgentId { agent_id } => {
+                format!("agent_id = {}", sql_quote_literal(agent_id))
+            }
+            MetaFilter::CreatedSince { ms } => format!("created_at >= {ms}"),
+            MetaFilter::CreatedBefore { ms } => format!("created_at <= {ms}"),
+            MetaFilter::KeyPrefix { prefix } => key_prefix_sql(prefix),
+        }
+    }
+}
+
+/// Compile `filters` into one SQL predicate (their conjunction), for methods
+/// that take a raw `filter` string. An empty list matches every row.
+#[uniffi::export]
+pub fn meta_filter_sql(filters: Vec<MetaFilter>) -> String {
+    with_meta_filters(None, &filters).unwrap_or_else(|| "true".into())
+}
+
+/// Versions of this library and of the LanceDB and Lance crates compiled
+/// into it, as `name/version` pairs separated by spaces, e.g.
+/// `lancedb-ffi/0.1.0 lancedb/0.26.2 lance/2.0.0`, for support reports.
+#[uniffi::export]
+pub fn lancedb_version() -> String {
+    format!(
+        "lancedb-ffi/{} lancedb/{} lance/{}",
+        env!("CARGO_PKG_VERSION"),
+        env!("LANCEDB_VERSION"),
+        env!("LANCE_VERSION")
+    )
 }
 
 #[derive(uniffi::Record, Clone, Debug)]
@@ -43,6 +446,394 @@ pub struct HybridSearchResult {
     pub metadata: Option<String>,
 }
 
+/// Opt-in write-ahead staging for `store()`.
+/// Staged entries are buffered in memory and written as a single batch on an
+/// explicit `flush()`, once `max_entries` are pending, or every
+/// `flush_interval_ms` — whichever comes first. Reads see staged entries
+/// immediately. Entries that are still pending when the handle is dropped are lost.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct StagingConfig {
+    #[uniffi(default = None)]
+    pub max_entries: Option<u32>,
+    #[uniffi(default = None)]
+    pub flush_interval_ms: Option<u64>,
+}
+
+/// Rows written by `store`: new keys inserted and existing rows overwritten.
+#[derive(uniffi::Record, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteStats {
+    pub inserted: u64,
+    pub replaced: u64,
+}
+
+/// Integrity report returned by `verify`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct VerifyReport {
+    pub total_rows: u64,
+    /// Keys stored in more than one row.
+    pub duplicate_keys: u64,
+    /// Rows beyond the first for each duplicated key.
+    pub duplicate_rows: u64,
+    /// Up to `VERIFY_SAMPLE_KEYS` duplicated keys, sorted.
+    pub sample_keys: Vec<String>,
+}
+
+/// A committed version of a collection, as listed by `list_versions`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct VersionInfo {
+    pub version: u64,
+    /// Commit time in ms since epoch.
+    pub timestamp_ms: i64,
+}
+
+/// One index of a collection, as returned by `index_info`.
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq)]
+pub struct IndexInfo {
+    pub name: String,
+    pub column: String,
+    /// Engine index type, e.g. `IVF_PQ`, `BTREE` or `FTS`.
+    pub index_type: String,
+    pub num_indexed_rows: u64,
+    /// Rows written since the index was built or last optimized, which
+    /// searches scan exhaustively.
+    pub num_unindexed_rows: u64,
+}
+
+/// Size readout for a collection, as returned by `stats`.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct CollectionStats {
+    /// Rows in the collection, including staged entries.
+    pub num_rows: u64,
+    pub num_versions: u64,
+    pub latest_version: u64,
+    /// Sum of file sizes under the table directory; 0 for in-memory databases.
+    pub bytes: u64,
+}
+
+/// Result of `health_check`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct HealthStatus {
+    pub can_connect: bool,
+    /// Collections in the database; 0 when it can't be reached.
+    pub table_count: u64,
+    pub db_path: String,
+}
+
+/// What `cleanup_old_versions` removed.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct CleanupStats {
+    pub versions_removed: u64,
+    /// Approximate size of the deleted files.
+    pub bytes_removed: u64,
+}
+
+/// One column of a collection, as reported by `describe`.
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct FieldInfo {
+    pub name: String,
+    /// The Arrow type, e.g. `Utf8` or `FixedSizeList(... 384)`.
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+/// Columns of a collection, as returned by `describe`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct SchemaInfo {
+    pub fields: Vec<FieldInfo>,
+    /// Width of the `embedding` column; `None` if it has none of the
+    /// expected fixed-size type.
+    pub embedding_dim: Option<i32>,
+}
+
+/// Handle-level options for `LanceDBHandle::open_with_options`.
+/// The defaults match plain `open`.
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct OpenOptions {
+    #[uniffi(default = None)]
+    pub staging: Option<StagingConfig>,
+    /// Lance data storage version for newly created tables, e.g. `"2.0"`,
+    /// `"2.1"`, `"stable"` or `"legacy"`. Existing tables keep their version.
+    /// `None` uses the engine default.
+    #[uniffi(default = None)]
+    pub storage_version: Option<String>,
+    /// Store `tags` in a List(Utf8) column so `store` accepts tags and
+    /// `search` can filter on them. An existing table gains the column (null
+    /// for old rows) on its next write. Keep the flag on for a table once set.
+    #[uniffi(default = false)]
+    pub enable_tags: bool,
+    /// Tuning applied automatically to the named collections (the default
+    /// collection is `"memories"`). Unlisted collections use engine defaults.
+    #[uniffi(default = None)]
+    pub collection_presets: Option<HashMap<String, CollectionPreset>>,
+    /// Metric used by vector searches, which also decides how `score` is
+    /// derived from the engine's distance `d` (see `DistanceMetric`). `None`
+    /// keeps the engine default (L2) with the original `score = 1 - d`.
+    #[uniffi(default = None)]
+    pub distance_metric: Option<DistanceMetric>,
+    /// L2-normalize embeddings before they are stored and query vectors
+    /// before they are searched, so cosine and dot scores are comparable.
+    /// Zero vectors are rejected. Prefix-search queries are left as given.
+    #[uniffi(default = false)]
+    pub normalize: bool,
+    /// Append every write instead of upserting on `key`, so a key can hold
+    /// several rows (e.g. an event log). `get`, `search` and `list` may then
+    /// return more than one row per key, and staged entries no longer hide
+    /// committed rows with the same key.
+    #[uniffi(default = false)]
+    pub append_only: bool,
+    /// Commit with `UnsafeCommitHandler`, which avoids the hardlink() that
+    /// Android's SELinux policy blocks for apps but gives no protection
+    /// against concurrent writers in other processes. Set false off Android
+    /// to use the engine's default, safe commit handler.
+    #[uniffi(default = true)]
+    pub use_unsafe_commit: bool,
+    /// Attempts made by each write when its commit conflicts with a
+    /// concurrent writer, with exponential backoff between them (starting at
+    /// `WRITE_RETRY_BACKOFF_MS`). 0 is treated as 1.
+    #[uniffi(default = 3)]
+    pub max_write_attempts: u32,
+    /// Collection used when a method's `collection` is `None`, and the one
+    /// staging applies to. `None` keeps `"memories"`.
+    #[uniffi(default = None)]
+    pub default_table: Option<String>,
+    /// Cap on the rows of each collection. After a `store`, `store_many` or
+    /// staging flush takes a collection over it, its oldest rows by
+    /// `created_at` (then key) are deleted down to the cap. Staged entries
+    /// count once flushed. The cap counts every agent's rows, so it cannot
+    /// be combined with `agent_scope`. `None` leaves collections unbounded.
+    #[uniffi(default = None)]
+    pub max_rows: Option<u64>,
+    /// Reject every method that writes (`store`, `delete`, `clear`, index
+    /// builds, imports, ...) with `TableError`, for components that must
+    /// only query. See `LanceDBHandle::open_read_only`.
+    #[uniffi(default = false)]
+    pub read_only: bool,
+    /// Type the `embedding` column is stored as in newly created collections.
+    /// Must match the existing default collection, or opening fails with
+    /// `SchemaError`. `None` keeps f32.
+    #[uniffi(default = None)]
+    pub embedding_dtype: Option<EmbeddingDtype>,
+    /// How long connecting to the database may take before failing with
+    /// `ConnectionFailed`, e.g. on a stalled SD card mount. 0 waits forever.
+    #[uniffi(default = 30000)]
+    pub connect_timeout_ms: u64,
+    /// How stale a cached collection may be before a read checks for commits
+    /// made through other handles or processes; this handle's own writes are
+    /// always seen. 0 checks on every read, at the cost of a manifest read
+    /// per call.
+    #[uniffi(default = 1000)]
+    pub read_consistency_ms: u64,
+    /// Give newly created collections a second, nullable `embedding2`
+    /// vector column of this dimension, e.g. a title vector next to a body
+    /// vector. Existing collections gain it on their next write. Stored with
+    /// `embedding_dtype` and searched with `SearchOptions::which`.
+    #[uniffi(default = None)]
+    pub embedding2_dim: Option<i32>,
+    /// Skip an entry when a row with identical `text` already exists for the
+    /// same `agent_id` under another key, so re-ingested content doesn't pile
+    /// up as duplicates. Texts are compared by a SHA-256 kept in a
+    /// `text_hash` column; rows written before it was enabled have none and
+    /// never match. Staged entries are checked when they are flushed.
+    #[uniffi(default = false)]
+    pub dedup_by_text: bool,
+    /// App-defined nullable columns added to every collection, which filters
+    /// can use like the built-in ones (see `open_with_columns`).
+    #[uniffi(default = None)]
+    pub extra_columns: Option<Vec<ColumnSpec>>,
+    /// Confine the handle to one agent's entries (see `open_scoped`).
+    #[uniffi(default = None)]
+    pub agent_scope: Option<String>,
+    /// Build the vector index of a collection in the background, as
+    /// `create_index` with default parameters would, once a `store`,
+    /// `store_many` or `flush` leaves it with at least this many rows and no
+    /// vector index. PQ training needs 256 rows, so lower thresholds wait for
+    /// that many. A failed build is logged and tried again after the next
+    /// write.
+    #[uniffi(default = None)]
+    pub auto_index_threshold: Option<u64>,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self {
+            staging: None,
+            storage_version: None,
+            enable_tags: false,
+            collection_presets: None,
+            distance_metric: None,
+            normalize: false,
+            append_only: false,
+            use_unsafe_commit: true,
+            max_write_attempts: 3,
+            default_table: None,
+            max_rows: None,
+            read_only: false,
+            embedding_dtype: None,
+            connect_timeout_ms: 30_000,
+            read_consistency_ms: 1_000,
+            embedding2_dim: None,
+            dedup_by_text: false,
+            extra_columns: None,
+            agent_scope: None,
+            auto_index_threshold: None,
+        }
+    }
+}
+
+/// Storage type of the `embedding` column (see `OpenOptions::embedding_dtype`).
+/// Embeddings and query vectors cross the FFI as f32 either way.
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum EmbeddingDtype {
+    #[default]
+    F32,
+    /// Half-precision floats: half the size, with about three significant
+    /// digits. Values beyond ±65504 are stored as infinity.
+    F16,
+    /// Symmetric int8 quantization: a quarter of the size. Each value is
+    /// stored as `round(v / scale)` with `scale = max_abs / 127`, so reads
+    /// are within `scale / 2` of what was stored and values beyond
+    /// `±max_abs` are clamped. `max_abs` is recorded in the `embedding`
+    /// field's metadata and reopening with another one fails. The engine
+    /// can't search int8 vectors, so searches scan every row, comparing the
+    /// query quantized the same way.
+    Int8 { max_abs: f32 },
+    /// Packed binary codes, one byte per value: `embedding_dim` counts
+    /// bytes, so a 256-bit code has dimension 32. Every value must be a
+    /// whole number in `0..=255`, or use `store_binary`/`search_binary`.
+    /// Requires `DistanceMetric::Hamming`.
+    Binary,
+}
+
+impl EmbeddingDtype {
+    /// Field metadata recording how the column is quantized, if it is.
+    fn field_metadata(self) -> HashMap<String, String> {
+        match self {
+            EmbeddingDtype::Int8 { max_abs } => {
+                HashMap::from([(MAX_ABS_METADATA.to_string(), max_abs.to_string())])
+            }
+            _ => HashMap::new(),
+        }
+    }
+
+    fn item_type(self) -> DataType {
+        match self {
+            EmbeddingDtype::F32 => DataType::Float32,
+            EmbeddingDtype::F16 => DataType::Float16,
+            EmbeddingDtype::Int8 { .. } => DataType::Int8,
+            EmbeddingDtype::Binary => DataType::UInt8,
+        }
+    }
+
+    /// `values` converted to this storage type.
+    fn encode(self, values: Float32Array) -> Result<ArrayRef, LanceError> {
+        if let EmbeddingDtype::Int8 { max_abs } = self {
+            let scale = max_abs / 127.0;
+            return Ok(Arc::new(Int8Array::from_iter_values(
+                values.values().iter().map(|v| (v / scale).round().clamp(-127.0, 127.0) as i8),
+            )));
+        }
+        if self == EmbeddingDtype::Binary {
+            if let Some(v) = values.values().iter().find(|v| !(0.0..=255.0).contains(*v) || v.fract() != 0.0) {
+                return Err(LanceError::InsertError {
+                    msg: format!("binary embedding value {v} is not a byte"),
+                });
+            }
+            return Ok(Arc::new(UInt8Array::from_iter_values(
+                values.values().iter().map(|&v| v as u8),
+            )));
+        }
+        let values: ArrayRef = Arc::new(values);
+        let item = self.item_type();
+        if item == DataType::Float32 {
+            return Ok(values);
+        }
+        arrow::compute::cast(&values, &item).map_err(|e| LanceError::InsertError {
+            msg: format!("Cannot store embeddings as {item}: {e}"),
+        })
+    }
+
+    /// Stored embedding values as f32, whatever type they are stored as.
+    fn decode(self, values: &ArrayRef) -> Option<Float32Array> {
+        if let Some(v) = values.as_any().downcast_ref::<Float32Array>() {
+            return Some(v.clone());
+        }
+        if let (EmbeddingDtype::Int8 { max_abs }, Some(v)) =
+            (self, values.as_any().downcast_ref::<Int8Array>())
+        {
+            let scale = max_abs / 127.0;
+            return Some(Float32Array::from_iter_values(
+                v.values().iter().map(|&q| q as f32 * scale),
+            ));
+        }
+        let cast = arrow::compute::cast(values, &DataType::Float32).ok()?;
+        cast.as_any().downcast_ref::<Float32Array>().cloned()
+    }
+
+    /// `query_vector` as it would read back after being stored.
+    fn round_trip(self, query_vector: &[f32]) -> Result<Vec<f32>, LanceError> {
+        let stored = self.encode(Float32Array::from(query_vector.to_vec()))?;
+        Ok(self
+            .decode(&stored)
+            .map_or_else(|| query_vector.to_vec(), |v| v.values().to_vec()))
+    }
+}
+
+/// Vector distance metric for a handle. Search results map the engine's
+/// distance `d` to a higher-is-better `score` as follows:
+/// - `Cosine`: `d = 1 - cos(a, b)`, score `1 - d` (cosine similarity)
+/// - `L2`: `d = |a - b|²`, score `-d`
+/// - `Dot`: `d = 1 - a·b`, score `1 - d` (the dot product)
+/// - `Hamming`: `d` = number of differing bits, score `-d`; only for
+///   `EmbeddingDtype::Binary`
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistanceMetric {
+    Cosine,
+    L2,
+    Dot,
+    Hamming,
+}
+
+impl DistanceMetric {
+    fn distance_type(self) -> DistanceType {
+        match self {
+            DistanceMetric::Cosine => DistanceType::Cosine,
+            DistanceMetric::L2 => DistanceType::L2,
+            DistanceMetric::Dot => DistanceType::Dot,
+            DistanceMetric::Hamming => DistanceType::Hamming,
+        }
+    }
+}
+
+/// Per-collection read/write tuning registered via `OpenOptions`.
+/// Every field left `None` keeps the engine default.
+#[derive(uniffi::Record, Clone, Debug, Default)]
+pub struct CollectionPreset {
+    /// Rows per data file written to this collection.
+    #[uniffi(default = None)]
+    pub max_rows_per_file: Option<u64>,
+    /// Rows per row group within a data file.
+    #[uniffi(default = None)]
+    pub max_rows_per_group: Option<u64>,
+    /// IVF partitions probed by vector searches once the collection has an index.
+    #[uniffi(default = None)]
+    pub nprobes: Option<u32>,
+    /// Re-rank `refine_factor * limit` index candidates with exact distances.
+    #[uniffi(default = None)]
+    pub refine_factor: Option<u32>,
+}
+
+/// Host-side embedding model used by `migrate_dimension` to re-embed stored text.
+#[uniffi::export(callback_interface)]
+pub trait Reembedder: Send + Sync {
+    fn embed(&self, text: String) -> Vec<f32>;
+}
+
+/// Progress callback for long-running bulk operations.
+#[uniffi::export(callback_interface)]
+pub trait ProgressReporter: Send + Sync {
+    fn on_progress(&self, done: u64, total: u64);
+}
+
 #[derive(uniffi::Error, Debug)]
 pub enum LanceError {
     ConnectionFailed { msg: String },
@@ -51,6 +842,19 @@ pub enum LanceError {
     InsertError { msg: String },
     DeleteError { msg: String },
     SchemaError { msg: String },
+    NotFound { msg: String },
+    /// A vector's length doesn't match the handle's `embedding_dim`. `key`
+    /// names the entry being written, when there is one, and `index` is the
+    /// position of the offending entry or query in a batch call.
+    DimensionMismatch {
+        expected: i32,
+        actual: i32,
+        key: Option<String>,
+        index: Option<u32>,
+    },
+    /// A write lost to a concurrent writer on every attempt (see
+    /// `OpenOptions::max_write_attempts`); retrying later may succeed.
+    ConcurrencyConflict { msg: String },
 }
 
 impl std::fmt::Display for LanceError {
@@ -62,6 +866,18 @@ impl std::fmt::Display for LanceError {
             LanceError::InsertError { msg } => write!(f, "InsertError: {msg}"),
             LanceError::DeleteError { msg } => write!(f, "DeleteError: {msg}"),
             LanceError::SchemaError { msg } => write!(f, "SchemaError: {msg}"),
+            LanceError::NotFound { msg } => write!(f, "NotFound: {msg}"),
+            LanceError::DimensionMismatch { expected, actual, key, index } => {
+                write!(f, "DimensionMismatch: ")?;
+                if let Some(i) = index {
+                    write!(f, "item {i}: ")?;
+                }
+                if let Some(key) = key {
+                    write!(f, "key {key:?}: ")?;
+                }
+                write!(f, "expected {expected} dimensions, got {actual}")
+            }
+            LanceError::ConcurrencyConflict { msg } => write!(f, "ConcurrencyConflict: {msg}"),
         }
     }
 }
@@ -71,62 +887,572 @@ impl std::fmt::Display for LanceError {
 // ---------------------------------------------------------------------------
 
 const DEFAULT_TABLE: &str = "memories";
+/// `db_path` prefix of an in-memory database (see `LanceDBHandle::open_memory`).
+const MEMORY_SCHEME: &str = "memory://";
+/// `embedding` field metadata key holding the `EmbeddingDtype::Int8` scale.
+const MAX_ABS_METADATA: &str = "max_abs";
+/// Live sessions of in-memory databases, keyed by `db_path`, so every handle
+/// opened on the same `memory://` path sees the same store. The store is
+/// freed once the last connection holding its session goes away.
+static MEMORY_SESSIONS: Lazy<Mutex<HashMap<String, Weak<lance::session::Session>>>> =
+    Lazy::new(Default::default);
+const VERIFY_SAMPLE_KEYS: usize = 10;
+/// Delay before the first retry of a conflicting write; doubled per attempt.
+const WRITE_RETRY_BACKOFF_MS: u64 = 20;
+/// Fewest rows IVF_PQ can be trained on (see `create_index`).
+const PQ_MIN_ROWS: u64 = 256;
+/// Rows per record batch when a write is split up (see `store_many`).
+const DEFAULT_CHUNK_ROWS: usize = 1024;
+/// Columns read by `list_detailed`; the embedding is left out.
+const LIST_COLUMNS: [&str; 5] = ["key", "agent_id", "text", "metadata", "created_at"];
 
 #[derive(uniffi::Object)]
 pub struct LanceDBHandle {
     db_path: String,
     embedding_dim: i32,
+    staging: Option<Staging>,
+    storage_version: Option<LanceFileVersion>,
+    enable_tags: bool,
+    presets: HashMap<String, CollectionPreset>,
+    distance_metric: Option<DistanceMetric>,
+    normalize: bool,
+    append_only: bool,
+    use_unsafe_commit: bool,
+    max_write_attempts: u32,
+    default_table: String,
+    max_rows: Option<u64>,
+    read_only: bool,
+    embedding_dtype: EmbeddingDtype,
+    connect_timeout: Option<Duration>,
+    read_consistency: Duration,
+    embedding2_dim: Option<i32>,
+    dedup_by_text: bool,
+    extra_columns: Vec<ColumnSpec>,
+    agent_scope: Option<String>,
+    auto_index_threshold: Option<u64>,
+    /// Collections with an auto-index build in flight, or whose build failed
+    /// (see `spawn_auto_index`).
+    auto_indexing: Mutex<HashSet<String>>,
+    /// Back-reference for background work started by `&self` methods.
+    this: Weak<LanceDBHandle>,
+    /// Opened tables shared by concurrent reads, keyed by collection. Writes
+    /// evict their collection (see `TableWriteGuard`) so reads reopen at the
+    /// new version. Writes made through another handle or process are picked
+    /// up because `connect` asks for strong read consistency.
+    table_cache: Mutex<HashMap<String, lancedb::Table>>,
+    /// Bumped on every eviction so a read that opened a table before a write
+    /// landed doesn't cache the stale handle.
+    table_generation: AtomicU64,
+    /// Connection shared by every call, opened lazily by `connect`. A failed
+    /// connect leaves it empty so the next call tries again.
+    connection: tokio::sync::Mutex<Option<lancedb::Connection>>,
+    /// Underlying `lancedb::connect` calls made so far.
+    connects: AtomicU64,
+    /// Set by `close`; every later call fails with `ConnectionFailed`.
+    closed: AtomicBool,
+    /// Set by `open_memory`: this handle created its `memory://` store and
+    /// frees it on close or drop. Other handles opened on the same path share
+    /// the store and leave it alone.
+    owns_memory_store: AtomicBool,
+    /// Serialises `write_entries` so concurrent stores through this handle
+    /// commit one after another instead of racing (the unsafe commit handler
+    /// can't detect that race). Conflicts with other writers are retried.
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+/// A single row waiting to be written to the default table.
+#[derive(Clone)]
+struct PendingEntry {
+    key: String,
+    agent_id: String,
+    text: String,
+    embedding: Vec<f32>,
+    metadata: Option<String>,
+    created_at: i64,
+    tags: Option<Vec<String>>,
+    embedding2: Option<Vec<f32>>,
+    columns: Vec<ColumnEntry>,
+}
+
+/// Write-ahead buffer for staged `store()` calls.
+struct Staging {
+    config: StagingConfig,
+    pending: Mutex<Vec<PendingEntry>>,
+    // Serialises flushes (and deletes, which edit the buffer) so the entries
+    // being written stay at the front of `pending` until the write commits.
+    flush_lock: tokio::sync::Mutex<()>,
+}
+
+impl Staging {
+    fn new(config: StagingConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::new(Vec::new()),
+            flush_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Buffer an entry and return the number of entries now pending.
+    fn push(&self, entry: PendingEntry) -> usize {
+        let mut pending = self.pending.lock().unwrap();
+        pending.push(entry);
+        pending.len()
+    }
+
+    fn snapshot(&self) -> Vec<PendingEntry> {
+        self.pending.lock().unwrap().clone()
+    }
 }
 
-fn make_schema(dim: i32) -> Schema {
-    Schema::new(vec![
+fn make_schema(
+    dim: i32,
+    with_tags: bool,
+    dtype: EmbeddingDtype,
+    dim2: Option<i32>,
+    with_text_hash: bool,
+    extra: &[ColumnSpec],
+) -> Schema {
+    let mut fields = vec![
         Field::new("key", DataType::Utf8, false),
         Field::new("agent_id", DataType::Utf8, false),
         Field::new("text", DataType::Utf8, false),
         Field::new(
             "embedding",
             DataType::FixedSizeList(
-                Arc::new(Field::new("item", DataType::Float32, true)),
+                Arc::new(Field::new("item", dtype.item_type(), true)),
                 dim,
             ),
             false,
-        ),
+        )
+        .with_metadata(dtype.field_metadata()),
         Field::new("metadata", DataType::Utf8, true),
         Field::new("created_at", DataType::Int64, false),
-    ])
+    ];
+    if with_tags {
+        fields.push(tags_field());
+    }
+    if let Some(dim2) = dim2 {
+        fields.push(embedding2_field(dim2, dtype));
+    }
+    if with_text_hash {
+        fields.push(text_hash_field());
+    }
+    fields.extend(extra.iter().map(ColumnSpec::field));
+    Schema::new(fields)
+}
+
+/// Add `field` as an all-null column to a table created without it, e.g.
+/// `tags` before tags were enabled.
+async fn ensure_column(table: &lancedb::Table, field: Field) -> Result<(), LanceError> {
+    let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+        msg: e.to_string(),
+    })?;
+    if schema.column_with_name(field.name()).is_some() {
+        return Ok(());
+    }
+    table
+        .add_columns(
+            NewColumnTransform::AllNulls(Arc::new(Schema::new(vec![field]))),
+            None,
+        )
+        .await
+        .map_err(|e| LanceError::SchemaError {
+            msg: e.to_string(),
+        })?;
+    Ok(())
+}
+
+fn embedding2_field(dim: i32, dtype: EmbeddingDtype) -> Field {
+    Field::new(
+        "embedding2",
+        DataType::FixedSizeList(Arc::new(Field::new("item", dtype.item_type(), true)), dim),
+        true,
+    )
+}
+
+fn text_hash_field() -> Field {
+    Field::new("text_hash", DataType::Utf8, true)
+}
+
+/// The `spec` column for a batch, null where an entry has no value for
+/// it. Values must already match the column type (see `check_columns`).
+fn extra_column_array<'a>(
+    spec: &ColumnSpec,
+    values: impl Iterator<Item = Option<&'a ColumnValue>>,
+) -> ArrayRef {
+    match spec.column_type {
+        ColumnType::Utf8 => Arc::new(
+            values
+                .map(|v| match v {
+                    Some(ColumnValue::Utf8 { value }) => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect::<StringArray>(),
+        ),
+        ColumnType::Int64 => Arc::new(
+            values
+                .map(|v| match v {
+                    Some(ColumnValue::Int64 { value }) => Some(*value),
+                    _ => None,
+                })
+                .collect::<Int64Array>(),
+        ),
+        ColumnType::Float64 => Arc::new(
+            values
+                .map(|v| match v {
+                    Some(ColumnValue::Float64 { value }) => Some(*value),
+                    _ => None,
+                })
+                .collect::<Float64Array>(),
+        ),
+        ColumnType::Bool => Arc::new(
+            values
+                .map(|v| match v {
+                    Some(ColumnValue::Bool { value }) => Some(*value),
+                    _ => None,
+                })
+                .collect::<BooleanArray>(),
+        ),
+    }
+}
+
+/// Columns an extra column may not be named after.
+const RESERVED_COLUMNS: [&str; 10] = [
+    "key",
+    "agent_id",
+    "text",
+    "embedding",
+    "metadata",
+    "created_at",
+    "tags",
+    "embedding2",
+    "text_hash",
+    "deleted_at",
+];
+
+/// `SchemaError` unless every `ColumnSpec` name is a fresh identifier.
+fn check_extra_columns(extra: &[ColumnSpec]) -> Result<(), LanceError> {
+    let mut seen = HashSet::new();
+    for spec in extra {
+        let name = spec.name.as_str();
+        let identifier = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !identifier || name.starts_with('_') {
+            return Err(LanceError::SchemaError {
+                msg: format!("extra column name {name:?} must be letters, digits and _"),
+            });
+        }
+        if RESERVED_COLUMNS.contains(&name) || !seen.insert(name) {
+            return Err(LanceError::SchemaError {
+                msg: format!("extra column {name:?} is already defined"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Hex SHA-256 of `text`, stored in `text_hash` (see `OpenOptions::dedup_by_text`).
+fn text_hash(text: &str) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(text.as_bytes()).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn tags_field() -> Field {
+    Field::new(
+        "tags",
+        DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+        true,
+    )
+}
+
+/// Tombstone set by `soft_delete`. Tables gain the column on their first
+/// soft delete; until then nothing in them is deleted.
+fn deleted_at_field() -> Field {
+    Field::new("deleted_at", DataType::Int64, true)
+}
+
+async fn has_deleted_at(table: &lancedb::Table) -> Result<bool, LanceError> {
+    let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+        msg: e.to_string(),
+    })?;
+    Ok(schema.column_with_name("deleted_at").is_some())
+}
+
+/// The shared session of the in-memory database at `db_path` (see
+/// `MEMORY_SESSIONS`), created on first use.
+fn memory_session(db_path: &str) -> Arc<lance::session::Session> {
+    let mut sessions = MEMORY_SESSIONS.lock().unwrap();
+    sessions.retain(|_, s| s.strong_count() > 0);
+    if let Some(session) = sessions.get(db_path).and_then(Weak::upgrade) {
+        return session;
+    }
+    let session = Arc::new(lance::session::Session::default());
+    sessions.insert(db_path.to_string(), Arc::downgrade(&session));
+    session
+}
+
+/// `filter` narrowed to rows of `table` that aren't soft-deleted.
+async fn live_filter(
+    table: &lancedb::Table,
+    filter: Option<&str>,
+) -> Result<Option<String>, LanceError> {
+    if !has_deleted_at(table).await? {
+        return Ok(filter.map(String::from));
+    }
+    Ok(Some(match filter {
+        Some(f) => format!("({f}) AND deleted_at IS NULL"),
+        None => "deleted_at IS NULL".into(),
+    }))
+}
+
+/// `batch` with an all-null `deleted_at` column, so an upsert into a table
+/// that has one clears the tombstone of every key it rewrites.
+fn with_null_deleted_at(batch: RecordBatch) -> Result<RecordBatch, LanceError> {
+    let mut fields = batch.schema().fields().to_vec();
+    fields.push(Arc::new(deleted_at_field()));
+    let mut columns = batch.columns().to_vec();
+    columns.push(Arc::new(Int64Array::new_null(batch.num_rows())));
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(|e| {
+        LanceError::InsertError {
+            msg: e.to_string(),
+        }
+    })
 }
 
 #[uniffi::export(async_runtime = "tokio")]
 impl LanceDBHandle {
     /// Open (or create) a LanceDB database at `db_path`.
     /// `embedding_dim` is the fixed vector dimension (e.g. 1536 for OpenAI ada-002).
+    /// A `memory://` path keeps the database in process memory instead.
     #[uniffi::constructor]
     pub async fn open(db_path: String, embedding_dim: i32) -> Result<Arc<Self>, LanceError> {
+        Self::open_with_options(db_path, embedding_dim, OpenOptions::default()).await
+    }
+
+    /// Like `open`, but every method that writes fails with `TableError`
+    /// (see `OpenOptions::read_only`); reads work as usual. `search_text`
+    /// doesn't build the missing text index it would otherwise create.
+    #[uniffi::constructor]
+    pub async fn open_read_only(db_path: String, embedding_dim: i32) -> Result<Arc<Self>, LanceError> {
+        let options = OpenOptions {
+            read_only: true,
+            ..Default::default()
+        };
+        Self::open_with_options(db_path, embedding_dim, options).await
+    }
+
+    /// Like `open`, with `extra_columns` added to the schema of every
+    /// collection (see `OpenOptions::extra_columns`). Values are passed to
+    /// `store` through its `columns` argument and can be filtered on by name,
+    /// e.g. `"importance >= 3"`. Collections created without a column gain it
+    /// on their next write, null for existing rows.
+    #[uniffi::constructor]
+    pub async fn open_with_columns(
+        db_path: String,
+        embedding_dim: i32,
+        extra_columns: Vec<ColumnSpec>,
+    ) -> Result<Arc<Self>, LanceError> {
+        let options = OpenOptions {
+            extra_columns: Some(extra_columns),
+            ..Default::default()
+        };
+        Self::open_with_options(db_path, embedding_dim, options).await
+    }
+
+    /// Like `open`, but confined to the entries of `agent_id`, for isolating
+    /// tenants: every read, update and delete only matches rows with that
+    /// `agent_id` (ANDed with any caller filter), and `store` writes it
+    /// whatever `agent_id` is passed. A key already stored by another agent
+    /// is left untouched by writes through this handle. Methods acting on
+    /// whole collections regardless of agent (`clear_all`, copies, renames,
+    /// `restore`, `migrate_dimension` and the Arrow/Parquet import and
+    /// export) fail with `TableError`; `clear` deletes only the agent's rows.
+    #[uniffi::constructor]
+    pub async fn open_scoped(
+        db_path: String,
+        embedding_dim: i32,
+        agent_id: String,
+    ) -> Result<Arc<Self>, LanceError> {
+        let options = OpenOptions {
+            agent_scope: Some(agent_id),
+            ..Default::default()
+        };
+        Self::open_with_options(db_path, embedding_dim, options).await
+    }
+
+    /// Like `open`, but with handle-level `options` (see `OpenOptions`).
+    #[uniffi::constructor]
+    pub async fn open_with_options(
+        db_path: String,
+        embedding_dim: i32,
+        options: OpenOptions,
+    ) -> Result<Arc<Self>, LanceError> {
         if embedding_dim <= 0 {
             return Err(LanceError::SchemaError {
                 msg: format!("embedding_dim must be > 0, got {embedding_dim}"),
             });
         }
+        check_extra_columns(options.extra_columns.as_deref().unwrap_or_default())?;
+        if let Some(dim2) = options.embedding2_dim.filter(|d| *d <= 0) {
+            return Err(LanceError::SchemaError {
+                msg: format!("embedding2_dim must be > 0, got {dim2}"),
+            });
+        }
+        if let Some(EmbeddingDtype::Int8 { max_abs }) = options.embedding_dtype {
+            if !(max_abs.is_finite() && max_abs > 0.0) {
+                return Err(LanceError::SchemaError {
+                    msg: format!("Int8 max_abs must be finite and > 0, got {max_abs}"),
+                });
+            }
+        }
+        let binary = options.embedding_dtype == Some(EmbeddingDtype::Binary);
+        let hamming = options.distance_metric == Some(DistanceMetric::Hamming);
+        if binary != hamming {
+            return Err(LanceError::SchemaError {
+                msg: "EmbeddingDtype::Binary and DistanceMetric::Hamming must be used together"
+                    .into(),
+            });
+        }
+        if binary && options.normalize {
+            return Err(LanceError::SchemaError {
+                msg: "binary embeddings cannot be normalized".into(),
+            });
+        }
+        if options.max_rows.is_some() && options.agent_scope.is_some() {
+            return Err(LanceError::SchemaError {
+                msg: "max_rows caps a whole collection and cannot be used with agent_scope".into(),
+            });
+        }
 
-        std::fs::create_dir_all(&db_path).map_err(|e| LanceError::ConnectionFailed {
-            msg: format!("Cannot create db directory: {e}"),
-        })?;
-
-        // Verify we can connect
-        let _db = lancedb::connect(&db_path)
-            .execute()
-            .await
-            .map_err(|e| LanceError::ConnectionFailed {
+        let storage_version = options
+            .storage_version
+            .as_deref()
+            .map(str::parse::<LanceFileVersion>)
+            .transpose()
+            .map_err(|e| LanceError::SchemaError {
                 msg: e.to_string(),
             })?;
 
-        Ok(Arc::new(Self {
+        if !db_path.starts_with(MEMORY_SCHEME) {
+            std::fs::create_dir_all(&db_path).map_err(|e| LanceError::ConnectionFailed {
+                msg: format!("Cannot create db directory: {e}"),
+            })?;
+        }
+
+        let flush_interval = options
+            .staging
+            .as_ref()
+            .and_then(|s| s.flush_interval_ms)
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis);
+
+        let handle = Arc::new_cyclic(|this| Self {
             db_path,
             embedding_dim,
-        }))
-    }
-
-    /// Store a memory entry. Overwrites if `key` already exists.
+            staging: options.staging.map(Staging::new),
+            storage_version,
+            enable_tags: options.enable_tags,
+            presets: options.collection_presets.unwrap_or_default(),
+            distance_metric: options.distance_metric,
+            normalize: options.normalize,
+            append_only: options.append_only,
+            use_unsafe_commit: options.use_unsafe_commit,
+            max_write_attempts: options.max_write_attempts.max(1),
+            default_table: options.default_table.unwrap_or_else(|| DEFAULT_TABLE.into()),
+            max_rows: options.max_rows,
+            read_only: options.read_only,
+            embedding_dtype: options.embedding_dtype.unwrap_or_default(),
+            connect_timeout: Some(options.connect_timeout_ms)
+                .filter(|ms| *ms > 0)
+                .map(Duration::from_millis),
+            read_consistency: Duration::from_millis(options.read_consistency_ms),
+            embedding2_dim: options.embedding2_dim,
+            dedup_by_text: options.dedup_by_text,
+            extra_columns: options.extra_columns.unwrap_or_default(),
+            agent_scope: options.agent_scope,
+            auto_index_threshold: options.auto_index_threshold,
+            auto_indexing: Mutex::new(HashSet::new()),
+            this: this.clone(),
+            table_cache: Mutex::new(HashMap::new()),
+            table_generation: AtomicU64::new(0),
+            connection: tokio::sync::Mutex::new(None),
+            connects: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+            owns_memory_store: AtomicBool::new(false),
+            write_lock: tokio::sync::Mutex::new(()),
+        });
+
+        // Verify we can connect, and that an existing default collection
+        // matches `embedding_dim` so a mismatch fails here, not on first use.
+        let db = handle.connect().await?;
+        if let Some(table) = handle.open_table_cached(&db, &handle.default_table).await? {
+            let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+                msg: e.to_string(),
+            })?;
+            let stored = match schema.field_with_name("embedding").map(|f| f.data_type()) {
+                Ok(DataType::FixedSizeList(item, n)) => Some((*n, item.data_type().clone())),
+                _ => None,
+            };
+            if let Some((n, _)) = stored.as_ref().filter(|(n, _)| *n != embedding_dim) {
+                return Err(LanceError::SchemaError {
+                    msg: format!(
+                        "collection {:?} stores {n}-dim embeddings, \
+                         opened with embedding_dim {embedding_dim}",
+                        handle.default_table
+                    ),
+                });
+            }
+            let expected = handle.embedding_dtype.item_type();
+            if let Some((_, item)) = stored.filter(|(_, item)| *item != expected) {
+                return Err(LanceError::SchemaError {
+                    msg: format!(
+                        "collection {:?} stores {item} embeddings, opened with {expected}",
+                        handle.default_table
+                    ),
+                });
+            }
+            if let EmbeddingDtype::Int8 { max_abs } = handle.embedding_dtype {
+                let stored_max_abs = schema
+                    .field_with_name("embedding")
+                    .ok()
+                    .and_then(|f| f.metadata().get(MAX_ABS_METADATA).cloned());
+                if let Some(stored) = stored_max_abs.filter(|m| m.parse::<f32>().ok() != Some(max_abs)) {
+                    return Err(LanceError::SchemaError {
+                        msg: format!(
+                            "collection {:?} stores int8 embeddings with max_abs {stored}, \
+                             opened with max_abs {max_abs}",
+                            handle.default_table
+                        ),
+                    });
+                }
+            }
+            let stored2 = match schema.field_with_name("embedding2").map(|f| f.data_type()) {
+                Ok(DataType::FixedSizeList(_, n)) => Some(*n),
+                _ => None,
+            };
+            if let (Some(n), Some(dim2)) = (stored2, handle.embedding2_dim.filter(|d| Some(*d) != stored2)) {
+                return Err(LanceError::SchemaError {
+                    msg: format!(
+                        "collection {:?} stores {n}-dim embedding2, opened with embedding2_dim {dim2}",
+                        handle.default_table
+                    ),
+                });
+            }
+        }
+
+        if let Some(interval) = flush_interval {
+            spawn_staging_flusher(Arc::downgrade(&handle), interval);
+        }
+
+        Ok(handle)
+    }
+
+    /// Store a memory entry in `options.collection` (default `"memories"`).
+    /// Overwrites if `key` already exists, unless the handle is append-only.
+    /// With staging enabled, entries for the default collection are buffered
+    /// until the next flush and the returned stats are zero. Passing `None`
+    /// for `options` uses the defaults (see `StoreOptions`).
+    #[uniffi::method(default(options = None))]
     pub async fn store(
         &self,
         key: String,
@@ -134,191 +1460,266 @@ impl LanceDBHandle {
         text: String,
         embedding: Vec<f32>,
         metadata: Option<String>,
-    ) -> Result<(), LanceError> {
+        options: Option<StoreOptions>,
+    ) -> Result<WriteStats, LanceError> {
+        self.ensure_writable()?;
+        let StoreOptions {
+            tags,
+            collection,
+            created_at,
+            embedding2,
+            columns,
+        } = options.unwrap_or_default();
         if embedding.len() != self.embedding_dim as usize {
+            return Err(LanceError::DimensionMismatch {
+                expected: self.embedding_dim,
+                actual: embedding.len() as i32,
+                key: Some(key),
+                index: None,
+            });
+        }
+        if let Some(i) = first_non_finite(&embedding) {
             return Err(LanceError::InsertError {
-                msg: format!(
-                    "embedding length {} != expected {}",
-                    embedding.len(),
-                    self.embedding_dim
-                ),
+                msg: format!("embedding[{i}] is not finite"),
             });
         }
-
-        let db = self.connect().await?;
-        let schema = Arc::new(make_schema(self.embedding_dim));
-
-        // Delete existing entry with this key (upsert semantics)
-        if let Ok(table) = self.open_table_unsafe(&db, DEFAULT_TABLE).await {
-            let _ = table
-                .delete(&format!("key = '{}'", key.replace('\'', "''")))
-                .await;
+        let mut embedding = embedding;
+        if self.normalize && !l2_normalize(&mut embedding) {
+            return Err(LanceError::InsertError {
+                msg: "cannot normalize a zero embedding".into(),
+            });
         }
+        if tags.is_some() && !self.enable_tags {
+            return Err(LanceError::InsertError {
+                msg: "tags require a handle opened with enable_tags".into(),
+            });
+        }
+        if let Some(ms) = created_at.filter(|ms| *ms < 0) {
+            return Err(LanceError::InsertError {
+                msg: format!("created_at {ms} is negative"),
+            });
+        }
+        let embedding2 = self.check_embedding2(embedding2, &key, None)?;
+        let columns = columns.unwrap_or_default();
+        self.check_columns(&columns, "")?;
+
+        let entry = PendingEntry {
+            key,
+            agent_id: self.agent_scope.clone().unwrap_or(agent_id),
+            text,
+            embedding,
+            metadata,
+            created_at: created_at.unwrap_or_else(chrono_now_ms),
+            tags,
+            embedding2,
+            columns,
+        };
 
-        let now = chrono_now_ms();
-        let batch = self.make_batch(
-            &schema,
-            vec![key],
-            vec![agent_id],
-            vec![text],
-            vec![embedding],
-            vec![metadata],
-            vec![now],
-        )?;
-
-        let batches = RecordBatchIterator::new(vec![Ok(batch)], schema.clone());
-
-        // Create table if not exists, otherwise add to existing
-        let tables = db
-            .table_names()
-            .execute()
-            .await
-            .map_err(|e| LanceError::TableError {
-                msg: e.to_string(),
-            })?;
-
-        if tables.contains(&DEFAULT_TABLE.to_string()) {
-            match self.open_table_unsafe(&db, DEFAULT_TABLE).await {
-                Ok(table) => {
-                    table
-                        .add(batches)
-                        .write_options(Self::unsafe_write_options(WriteMode::Append))
-                        .execute()
-                        .await
-                        .map_err(|e| LanceError::InsertError {
-                            msg: e.to_string(),
-                        })?;
-                }
-                Err(_) => {
-                    // Table is corrupted (e.g. partial write) — drop and recreate
-                    let _ = db.drop_table(DEFAULT_TABLE, &[]).await;
-                    db.create_table(DEFAULT_TABLE, batches)
-                        .write_options(Self::unsafe_write_options(WriteMode::Create))
-                        .execute()
-                        .await
-                        .map_err(|e| LanceError::TableError {
-                            msg: e.to_string(),
-                        })?;
-                }
+        self.ensure_open()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        if let (Some(staging), true) = (&self.staging, table_name == self.default_table) {
+            let pending = staging.push(entry);
+            if staging
+                .config
+                .max_entries
+                .is_some_and(|max| pending >= max as usize)
+            {
+                self.flush().await?;
             }
-        } else {
-            db.create_table(DEFAULT_TABLE, batches)
-                .write_options(Self::unsafe_write_options(WriteMode::Create))
-                .execute()
-                .await
-                .map_err(|e| LanceError::TableError {
-                    msg: e.to_string(),
-                })?;
+            return Ok(WriteStats::default());
         }
 
-        Ok(())
+        let stats = self.write_entries(table_name, vec![entry]).await?;
+        self.evict_over_cap(table_name).await?;
+        self.spawn_auto_index(table_name);
+        Ok(stats)
     }
 
-    /// Search for the `limit` nearest neighbours to `query_vector`.
-    /// Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
-    pub async fn search(
+    /// `store` with the embedding passed as `embedding_dim * 4` bytes of
+    /// little-endian f32, which crosses the FFI boundary far more cheaply
+    /// than a list of floats. `options` are as for `store`.
+    #[uniffi::method(default(options = None))]
+    pub async fn store_bytes(
         &self,
-        query_vector: Vec<f32>,
-        limit: u32,
-        filter: Option<String>,
-    ) -> Result<Vec<SearchResult>, LanceError> {
-        if query_vector.len() != self.embedding_dim as usize {
-            return Err(LanceError::QueryError {
-                msg: format!(
-                    "query_vector length {} != expected {}",
-                    query_vector.len(),
-                    self.embedding_dim
-                ),
+        key: String,
+        agent_id: String,
+        text: String,
+        embedding: Vec<u8>,
+        metadata: Option<String>,
+        options: Option<StoreOptions>,
+    ) -> Result<WriteStats, LanceError> {
+        if !embedding.len().is_multiple_of(4) {
+            return Err(LanceError::InsertError {
+                msg: format!("embedding is {} bytes, not a whole number of f32s", embedding.len()),
             });
         }
+        if embedding.len() != self.embedding_dim as usize * 4 {
+            return Err(LanceError::DimensionMismatch {
+                expected: self.embedding_dim,
+                actual: (embedding.len() / 4) as i32,
+                key: Some(key),
+                index: None,
+            });
+        }
+        let embedding = f32s_from_le_bytes(&embedding);
+        self.store(key, agent_id, text, embedding, metadata, options).await
+    }
 
-        let db = self.connect().await?;
+    /// `store` for a handle opened with `EmbeddingDtype::Binary`, taking the
+    /// packed code as `embedding_dim` bytes.
+    pub async fn store_binary(
+        &self,
+        key: String,
+        agent_id: String,
+        text: String,
+        code: Vec<u8>,
+        metadata: Option<String>,
+        collection: Option<String>,
+    ) -> Result<WriteStats, LanceError> {
+        self.ensure_binary()?;
+        let embedding = code.into_iter().map(f32::from).collect();
+        let options = StoreOptions {
+            collection,
+            ..Default::default()
+        };
+        self.store(key, agent_id, text, embedding, metadata, Some(options)).await
+    }
 
-        let tables = db
-            .table_names()
-            .execute()
-            .await
-            .map_err(|e| LanceError::TableError {
-                msg: e.to_string(),
-            })?;
+    /// `store` with `meta` written to `metadata` as a JSON object of strings,
+    /// so it reads back as `SearchResult::meta`. When a key repeats, the last
+    /// pair wins.
+    pub async fn store_with_meta(
+        &self,
+        key: String,
+        agent_id: String,
+        text: String,
+        embedding: Vec<f32>,
+        meta: Vec<MetaPair>,
+        collection: Option<String>,
+    ) -> Result<WriteStats, LanceError> {
+        let object: serde_json::Map<String, serde_json::Value> = meta
+            .into_iter()
+            .map(|p| (p.key, serde_json::Value::String(p.value)))
+            .collect();
+        let metadata = serde_json::Value::Object(object).to_string();
+        let options = StoreOptions {
+            collection,
+            ..Default::default()
+        };
+        self.store(key, agent_id, text, embedding, Some(metadata), Some(options)).await
+    }
 
-        if !tables.contains(&DEFAULT_TABLE.to_string()) {
-            return Ok(vec![]);
+    /// Store many entries with one upsert: every embedding is validated first
+    /// and the rows are written in a single commit, so a bad entry fails the
+    /// whole call and nothing is written. When a key repeats within `entries`
+    /// the last occurrence wins. Staged entries are flushed first and the
+    /// batch bypasses staging. The rows are converted and written
+    /// `chunk_size` at a time (default `DEFAULT_CHUNK_ROWS`) rather than as
+    /// one large record batch. `progress` is called with (rows handed to the
+    /// engine, total rows) once per chunk; it starts over if a conflicting
+    /// commit is retried. Returns the number of rows written.
+    pub async fn store_many(
+        &self,
+        entries: Vec<MemoryEntry>,
+        collection: Option<String>,
+        chunk_size: Option<u32>,
+        progress: Option<Box<dyn ProgressReporter>>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        if chunk_size == Some(0) {
+            return Err(LanceError::InsertError {
+                msg: "chunk_size must be positive".into(),
+            });
+        }
+        let entries = self.prepare_entries(entries)?;
+        if entries.is_empty() {
+            return Ok(0);
         }
 
-        let table = self.open_table_unsafe(&db, DEFAULT_TABLE).await?;
+        self.flush().await?;
+
+        let entries = self.dedup(entries);
+        let chunk_size = chunk_size.map_or(DEFAULT_CHUNK_ROWS, |n| n as usize);
+        let table_name = self.table_or_default(collection.as_deref());
+        let stats = self
+            .write_chunked(table_name, entries, chunk_size, progress.map(Arc::from))
+            .await?;
+        self.evict_over_cap(table_name).await?;
+        self.spawn_auto_index(table_name);
+        Ok(stats.inserted + stats.replaced)
+    }
 
-        let mut query = table
-            .query()
-            .nearest_to(query_vector)
-            .map_err(|e| LanceError::QueryError {
-                msg: e.to_string(),
-            })?
-            .limit(limit as usize);
+    /// Write all staged entries to the table as a single batch.
+    /// No-op when staging is disabled or nothing is pending.
+    pub async fn flush(&self) -> Result<(), LanceError> {
+        self.ensure_open()?;
+        let Some(staging) = &self.staging else {
+            return Ok(());
+        };
+        let _guard = staging.flush_lock.lock().await;
 
-        if let Some(ref f) = filter {
-            query = query.only_if(f.clone());
+        let entries = staging.snapshot();
+        if entries.is_empty() {
+            return Ok(());
         }
+        let flushed = entries.len();
 
-        let stream = query
-            .execute()
-            .await
-            .map_err(|e| LanceError::QueryError {
-                msg: e.to_string(),
-            })?;
-
-        let batches: Vec<RecordBatch> = stream
-            .try_collect()
-            .await
-            .map_err(|e| LanceError::QueryError {
-                msg: e.to_string(),
-            })?;
+        self.write_entries(&self.default_table, entries).await?;
 
-        let mut results = Vec::new();
-        for batch in &batches {
-            let keys = batch
-                .column_by_name("key")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let texts = batch
-                .column_by_name("text")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let metas = batch
-                .column_by_name("metadata")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let distances = batch
-                .column_by_name("_distance")
-                .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
+        // Entries staged while we were writing sit after the flushed prefix.
+        staging.pending.lock().unwrap().drain(..flushed);
+        drop(_guard);
+        self.evict_over_cap(&self.default_table).await?;
+        self.spawn_auto_index(&self.default_table);
+        Ok(())
+    }
 
-            let (keys, texts) = match (keys, texts) {
-                (Some(k), Some(t)) => (k, t),
-                _ => continue,
-            };
+    /// Open a fresh database held only in process memory, for caches and
+    /// tests. It behaves like an on-disk one and its data is freed when this
+    /// handle is closed or dropped. Other handles opened on its `db_path`
+    /// share the store but don't free it.
+    #[uniffi::constructor]
+    pub async fn open_memory(embedding_dim: i32) -> Result<Arc<Self>, LanceError> {
+        static NEXT_DB: AtomicU64 = AtomicU64::new(0);
+        let db_path = format!("{MEMORY_SCHEME}handle_{}", NEXT_DB.fetch_add(1, Ordering::Relaxed));
+        let handle = Self::open(db_path, embedding_dim).await?;
+        handle.owns_memory_store.store(true, Ordering::SeqCst);
+        Ok(handle)
+    }
 
-            for i in 0..batch.num_rows() {
-                let score = distances.map(|d| 1.0 - d.value(i) as f64).unwrap_or(0.0);
-                let metadata = metas.and_then(|m| {
-                    if m.is_null(i) {
-                        None
-                    } else {
-                        Some(m.value(i).to_string())
-                    }
-                });
-                results.push(SearchResult {
-                    key: keys.value(i).to_string(),
-                    text: texts.value(i).to_string(),
-                    score,
-                    metadata,
-                });
+    /// Flush staged entries, then release the connection and cached tables so
+    /// their file handles close. Every later call on this handle, including
+    /// another `close`, fails with `ConnectionFailed`. If the flush fails the
+    /// handle stays open and the error is returned. Exported as
+    /// `closeHandle`, since Kotlin bindings already give every object a
+    /// `close()` that frees it.
+    #[uniffi::method(name = "close_handle")]
+    pub async fn close(&self) -> Result<(), LanceError> {
+        self.flush().await?;
+        let mut connection = self.connection.lock().await;
+        self.ensure_open()?;
+        self.closed.store(true, Ordering::SeqCst);
+        if let Some(db) = connection.take() {
+            if self.owns_memory_store.load(Ordering::SeqCst) {
+                let _ = db.drop_all_tables(&[]).await;
             }
         }
-
-        Ok(results)
+        self.table_cache.lock().unwrap().clear();
+        Ok(())
     }
 
-    /// Delete a memory entry by key.
-    pub async fn delete(&self, key: String) -> Result<(), LanceError> {
-        let db = self.connect().await?;
-
+    /// Cheap probe that the database can be reached: connects and lists the
+    /// collections. A failed (or closed) connection is reported as
+    /// `can_connect: false` rather than an error; an empty database is healthy.
+    pub async fn health_check(&self) -> Result<HealthStatus, LanceError> {
+        let mut status = HealthStatus {
+            can_connect: false,
+            table_count: 0,
+            db_path: self.db_path.clone(),
+        };
+        let Ok(db) = self.connect().await else {
+            return Ok(status);
+        };
         let tables = db
             .table_names()
             .execute()
@@ -326,90 +1727,474 @@ impl LanceDBHandle {
             .map_err(|e| LanceError::TableError {
                 msg: e.to_string(),
             })?;
+        status.can_connect = true;
+        status.table_count = tables.len() as u64;
+        Ok(status)
+    }
 
-        if !tables.contains(&DEFAULT_TABLE.to_string()) {
-            return Ok(());
-        }
-
-        let table = self.open_table_unsafe(&db, DEFAULT_TABLE).await?;
-
-        table
-            .delete(&format!("key = '{}'", key.replace('\'', "''")))
+    /// Search `options.collection` (default `"memories"`) for the `limit`
+    /// nearest neighbours to `query_vector`.
+    /// Optional `filter` is a SQL-like predicate (e.g. `"agent_id = 'main'"`).
+    #[uniffi::method(default(options = None))]
+    pub async fn search(
+        &self,
+        query_vector: Vec<f32>,
+        limit: u32,
+        filter: Option<String>,
+        options: Option<SearchOptions>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        self.search_detailed(query_vector, limit, filter, options)
             .await
-            .map_err(|e| LanceError::DeleteError {
-                msg: e.to_string(),
-            })?;
-
-        Ok(())
+            .map(|r| r.results)
     }
 
-    /// List memory keys, optionally filtered by prefix.
-    pub async fn list(
+    /// The page of `limit` results after the nearest `offset`, plus the
+    /// number of rows matching `filter`, for rendering page controls.
+    /// `total_matching` is an exact count of the filtered rows, staged entries
+    /// included. Without a vector index, paging through it reaches every
+    /// one; with an index, each page is cut from an approximate ranking
+    /// (see `SearchOptions::offset`) and can't be relied on to reach all
+    /// `total_matching` rows.
+    pub async fn search_paged(
         &self,
-        prefix: Option<String>,
-        limit: Option<u32>,
-    ) -> Result<Vec<String>, LanceError> {
-        let db = self.connect().await?;
-
-        let tables = db
-            .table_names()
-            .execute()
-            .await
-            .map_err(|e| LanceError::TableError {
-                msg: e.to_string(),
-            })?;
+        query_vector: Vec<f32>,
+        limit: u32,
+        offset: u32,
+        filter: Option<String>,
+        collection: Option<String>,
+    ) -> Result<SearchPage, LanceError> {
+        let options = SearchOptions {
+            offset: Some(offset),
+            collection: collection.clone(),
+            ..Default::default()
+        };
+        let results = self
+            .search(query_vector, limit, filter.clone(), Some(options))
+            .await?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let total_matching = self.count_where(table_name, filter, true).await?;
+        Ok(SearchPage {
+            results,
+            total_matching,
+        })
+    }
 
-        if !tables.contains(&DEFAULT_TABLE.to_string()) {
-            return Ok(vec![]);
+    /// `search` over each of `collections`, merged into one list of the
+    /// global top `limit` by score. `SearchResult::collection` tells which
+    /// collection a result came from. Collections that don't exist, and
+    /// repeats in `collections`, are skipped.
+    pub async fn search_multi(
+        &self,
+        collections: Vec<String>,
+        query_vector: Vec<f32>,
+        limit: u32,
+        filter: Option<String>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let mut seen = HashSet::new();
+        let mut merged = Vec::new();
+        for collection in collections {
+            if !seen.insert(collection.clone()) {
+                continue;
+            }
+            let results = self
+                .search(query_vector.clone(), limit, filter.clone(), Some(SearchOptions { collection: Some(collection), ..Default::default() }))
+                .await?;
+            merged.extend(results);
         }
+        merged.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        merged.truncate(limit as usize);
+        Ok(merged)
+    }
 
-        let table = self.open_table_unsafe(&db, DEFAULT_TABLE).await?;
-
-        let mut query = table.query()
-            .select(lancedb::query::Select::Columns(vec!["key".to_string()]));
+    /// `search` using the stored embedding of `key` as the query, i.e. "more
+    /// like this". `exclude_self` leaves `key` itself out of the results.
+    /// A missing `key` is a `QueryError`.
+    pub async fn search_similar(
+        &self,
+        key: String,
+        limit: u32,
+        filter: Option<String>,
+        exclude_self: bool,
+        collection: Option<String>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let Some(embedding) = self
+            .get(key.clone(), collection.clone(), true, None)
+            .await?
+            .and_then(|r| r.embedding)
+        else {
+            return Err(LanceError::QueryError {
+                msg: format!("key {key:?} not found"),
+            });
+        };
+        let filter = if exclude_self {
+            let not_self = format!("key <> {}", sql_quote_literal(&key));
+            Some(match filter {
+                Some(f) => format!("({f}) AND {not_self}"),
+                None => not_self,
+            })
+        } else {
+            filter
+        };
+        self.search(embedding, limit, filter, Some(SearchOptions { collection, ..Default::default() })).await
+    }
 
-        if let Some(ref p) = prefix {
-            query = query.only_if(format!("starts_with(key, '{}')", p.replace('\'', "''")));
+    /// Run `search` for each of `query_vectors` and return one result list
+    /// per query, in input order. Every vector is validated before any query
+    /// runs, and the queries share one connection and one snapshot of the
+    /// staged entries.
+    pub async fn search_many(
+        &self,
+        query_vectors: Vec<Vec<f32>>,
+        limit: u32,
+        filter: Option<String>,
+        options: Option<SearchOptions>,
+    ) -> Result<Vec<Vec<SearchResult>>, LanceError> {
+        let options = options.unwrap_or_default();
+        for (i, v) in query_vectors.iter().enumerate() {
+            self.check_query(v, &options, Some(i as u32))?;
         }
 
-        if let Some(lim) = limit {
-            query = query.limit(lim as usize);
+        let table_name = self.table_or_default(options.collection.as_deref());
+        let db = self.connect().await?;
+        let staged = self.search_view(table_name, &options).await?;
+        let results = async {
+            let mut results = Vec::with_capacity(query_vectors.len());
+            for query_vector in query_vectors {
+                let response = self
+                    .search_with_view(&db, table_name, query_vector, limit, filter.clone(), &options, staged.as_ref())
+                    .await?;
+                results.push(response.results);
+            }
+            Ok(results)
         }
+        .await;
+        if let Some(view) = staged {
+            view.discard().await;
+        }
+        results
+    }
 
-        let stream = query
-            .execute()
+    /// `search` with `query_vector` passed as little-endian f32 bytes (see
+    /// `store_bytes`): `embedding_dim * 4` bytes (`embedding2_dim * 4` when
+    /// searching `embedding2`), or any non-empty multiple of 4 up to that
+    /// with `prefix_search`.
+    pub async fn search_bytes(
+        &self,
+        query_vector: Vec<u8>,
+        limit: u32,
+        filter: Option<String>,
+        options: Option<SearchOptions>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let which = options.as_ref().and_then(|o| o.which).unwrap_or_default();
+        let expected = self.column_dim(which)?;
+        if !query_vector.len().is_multiple_of(4) {
+            return Err(LanceError::QueryError {
+                msg: format!("query_vector is {} bytes, not a whole number of f32s", query_vector.len()),
+            });
+        }
+        let dims = query_vector.len() / 4;
+        let prefix = options.as_ref().is_some_and(|o| o.prefix_search);
+        let len_ok = if prefix {
+            (1..=expected as usize).contains(&dims)
+        } else {
+            dims == expected as usize
+        };
+        if !len_ok {
+            return Err(LanceError::DimensionMismatch {
+                expected,
+                actual: dims as i32,
+                key: None,
+                index: None,
+            });
+        }
+        let query_vector = f32s_from_le_bytes(&query_vector);
+        self.search(query_vector, limit, filter, options)
             .await
-            .map_err(|e| LanceError::QueryError {
-                msg: e.to_string(),
-            })?;
+    }
 
-        let batches: Vec<RecordBatch> = stream
-            .try_collect()
+    /// `search` by Hamming distance to the packed binary `code` (see
+    /// `store_binary`); the nearest codes come first.
+    pub async fn search_binary(
+        &self,
+        code: Vec<u8>,
+        limit: u32,
+        filter: Option<String>,
+        options: Option<SearchOptions>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        self.ensure_binary()?;
+        let query_vector = code.into_iter().map(f32::from).collect();
+        self.search(query_vector, limit, filter, options)
+            .await
+    }
+
+    /// The query plan `search` would run for these arguments with default
+    /// options, for checking whether a slow search used the vector index or
+    /// fell back to a flat scan. Nothing is executed, and staged entries
+    /// (merged in memory) do not appear in the plan.
+    pub async fn explain_search(
+        &self,
+        query_vector: Vec<f32>,
+        limit: u32,
+        filter: Option<String>,
+        collection: Option<String>,
+    ) -> Result<String, LanceError> {
+        self.ensure_open()?;
+        if query_vector.len() != self.embedding_dim as usize {
+            return Err(LanceError::DimensionMismatch {
+                expected: self.embedding_dim,
+                actual: query_vector.len() as i32,
+                key: None,
+                index: None,
+            });
+        }
+        if let EmbeddingDtype::Int8 { .. } = self.embedding_dtype {
+            return Err(LanceError::QueryError {
+                msg: "int8 embeddings are searched by a scan in Rust, which has no plan".into(),
+            });
+        }
+        let mut query_vector = query_vector;
+        if self.normalize && !l2_normalize(&mut query_vector) {
+            return Err(LanceError::QueryError {
+                msg: "cannot normalize a zero query_vector".into(),
+            });
+        }
+
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Err(LanceError::NotFound {
+                msg: format!("collection {table_name:?} does not exist"),
+            });
+        };
+        let column = EmbeddingColumn::Primary.name();
+        let approximate = has_vector_index(&table, column).await?;
+        let filter = self.scoped(filter.as_deref());
+        let live = live_filter(&table, filter.as_deref()).await?;
+        let query = nearest_query(
+            &table,
+            column,
+            self.embedding_dtype.encode(Float32Array::from(query_vector))?,
+            limit as usize,
+            0,
+            live.as_deref(),
+            false,
+            approximate.then(|| self.presets.get(table_name)).flatten(),
+            self.distance_metric,
+            false,
+        )?;
+        query
+            .explain_plan(true)
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })
+    }
+
+    /// Like `search`, but also reports whether the results are approximate.
+    pub async fn search_detailed(
+        &self,
+        query_vector: Vec<f32>,
+        limit: u32,
+        filter: Option<String>,
+        options: Option<SearchOptions>,
+    ) -> Result<SearchResponse, LanceError> {
+        let options = options.unwrap_or_default();
+        self.check_query(&query_vector, &options, None)?;
+        let table_name = self.table_or_default(options.collection.as_deref());
+        let db = self.connect().await?;
+        let staged = self.search_view(table_name, &options).await?;
+        let response = self
+            .search_with_view(&db, table_name, query_vector, limit, filter, &options, staged.as_ref())
+            .await;
+        if let Some(view) = staged {
+            view.discard().await;
+        }
+        response
+    }
+
+    /// Whether `collection` holds an entry for `key`, including a staged one,
+    /// without reading the row. False when the collection doesn't exist or the
+    /// entry is soft-deleted.
+    pub async fn exists(&self, key: String, collection: Option<String>) -> Result<bool, LanceError> {
+        self.ensure_open()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        if let (Some(staging), true) = (&self.staging, table_name == self.default_table) {
+            if staging.pending.lock().unwrap().iter().any(|e| e.key == key && self.in_scope(&e.agent_id)) {
+                return Ok(true);
+            }
+        }
+
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(false);
+        };
+        let live = live_filter(&table, Some(&self.key_predicate(&key))).await?;
+        let matched = table
+            .count_rows(live)
             .await
             .map_err(|e| LanceError::QueryError {
                 msg: e.to_string(),
             })?;
+        Ok(matched > 0)
+    }
 
-        let mut keys = Vec::new();
+    /// Fetch the stored entry for `key` in `collection`, including a staged
+    /// one unless `options.committed_only` is set, or `None`. A soft-deleted
+    /// entry is `None`.
+    /// There is no query vector, so `score` is 0 and `distance` is `None`.
+    /// `include_embedding` also returns the stored embedding.
+    #[uniffi::method(default(options = None))]
+    pub async fn get(
+        &self,
+        key: String,
+        collection: Option<String>,
+        include_embedding: bool,
+        options: Option<GetOptions>,
+    ) -> Result<Option<SearchResult>, LanceError> {
+        let options = options.unwrap_or_default();
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let filter = format!("key = {}", sql_quote_literal(&key));
+        let QueryRows { batches, .. } = self
+            .scan_with_staged(&db, table_name, Some(&filter), false, !options.committed_only, false)
+            .await?;
+
+        // Staged rows come last, so the last match is the newest.
+        let Some((batch, i)) = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(move |i| (b, i)))
+            .last()
+        else {
+            if options.error_on_empty {
+                return Err(LanceError::NotFound {
+                    msg: format!("no entry for key {key:?}"),
+                });
+            }
+            return Ok(None);
+        };
+        Ok(Some(SearchResult {
+            embedding: include_embedding.then(|| embedding_at(batch, i, self.embedding_dtype)).flatten(),
+            ..stored_result(batch, i, table_name)?
+        }))
+    }
+
+    /// `get` for several keys with a single scan. Results follow the order of
+    /// `keys`; missing and soft-deleted keys are left out and repeated keys
+    /// returned once.
+    #[uniffi::method(default(options = None))]
+    pub async fn get_many(
+        &self,
+        keys: Vec<String>,
+        collection: Option<String>,
+        options: Option<GetOptions>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let options = options.unwrap_or_default();
+        let not_found = || LanceError::NotFound {
+            msg: "none of the keys have an entry".into(),
+        };
+        if keys.is_empty() {
+            return if options.error_on_empty { Err(not_found()) } else { Ok(Vec::new()) };
+        }
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let filter = format!("key IN ({})", sql_string_list(keys.iter().map(String::as_str)));
+        let QueryRows { batches, .. } = self
+            .scan_with_staged(&db, table_name, Some(&filter), false, !options.committed_only, false)
+            .await?;
+
+        // Staged rows come last, so later matches for a key are newer.
+        let mut found = HashMap::new();
         for batch in &batches {
-            if let Some(arr) = batch
-                .column_by_name("key")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>())
-            {
-                for i in 0..arr.len() {
-                    keys.push(arr.value(i).to_string());
+            for i in 0..batch.num_rows() {
+                let result = stored_result(batch, i, table_name)?;
+                found.insert(result.key.clone(), result);
+            }
+        }
+        let results: Vec<_> = keys.iter().filter_map(|k| found.remove(k)).collect();
+        if options.error_on_empty && results.is_empty() {
+            return Err(not_found());
+        }
+        Ok(results)
+    }
+
+    /// Set only the `metadata` of the entry stored under `key` in
+    /// `collection`, leaving its text, embedding and timestamp untouched.
+    /// A staged entry is updated in place. Returns whether an entry matched.
+    pub async fn update_metadata(
+        &self,
+        key: String,
+        metadata: Option<String>,
+        collection: Option<String>,
+    ) -> Result<bool, LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let mut matched = false;
+        // Hold the flush lock so an in-flight flush can't write the old value back.
+        let _guard = match &self.staging {
+            Some(staging) if table_name == self.default_table => {
+                let guard = staging.flush_lock.lock().await;
+                for e in staging.pending.lock().unwrap().iter_mut().filter(|e| e.key == key && self.in_scope(&e.agent_id)) {
+                    e.metadata = metadata.clone();
+                    matched = true;
                 }
+                Some(guard)
             }
+            _ => None,
+        };
+
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(matched);
         }
 
-        Ok(keys)
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let _written = TableWriteGuard::new(self, table_name);
+        let value = match &metadata {
+            Some(m) => sql_quote_literal(m),
+            None => "NULL".to_string(),
+        };
+        let updated = table
+            .update()
+            .only_if(self.key_predicate(&key))
+            .column("metadata", value)
+            .execute()
+            .await
+            .map_err(|e| LanceError::InsertError {
+                msg: e.to_string(),
+            })?;
+        Ok(matched || updated.rows_updated > 0)
     }
 
-    /// Drop all data. If `collection` is None, drops the default table.
-    pub async fn clear(&self, collection: Option<String>) -> Result<(), LanceError> {
-        let db = self.connect().await?;
-        let table_name = collection.as_deref().unwrap_or(DEFAULT_TABLE);
+    /// Mark `key` in `collection` as just used by setting its `created_at` to
+    /// now, without rewriting its text or embedding, so apps can evict
+    /// least-recently-used entries by `created_at`. A staged entry is updated
+    /// in place. Returns whether an entry matched.
+    pub async fn touch(&self, key: String, collection: Option<String>) -> Result<bool, LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let now = chrono_now_ms();
+        let mut matched = false;
+        // Hold the flush lock so an in-flight flush can't write the old value back.
+        let _guard = match &self.staging {
+            Some(staging) if table_name == self.default_table => {
+                let guard = staging.flush_lock.lock().await;
+                for e in staging.pending.lock().unwrap().iter_mut().filter(|e| e.key == key && self.in_scope(&e.agent_id)) {
+                    e.created_at = now;
+                    matched = true;
+                }
+                Some(guard)
+            }
+            _ => None,
+        };
 
+        let db = self.connect().await?;
         let tables = db
             .table_names()
             .execute()
@@ -417,43 +2202,59 @@ impl LanceDBHandle {
             .map_err(|e| LanceError::TableError {
                 msg: e.to_string(),
             })?;
-
-        if tables.contains(&table_name.to_string()) {
-            db.drop_table(table_name, &[])
-                .await
-                .map_err(|e| LanceError::TableError {
-                    msg: e.to_string(),
-                })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(matched);
         }
 
-        Ok(())
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let _written = TableWriteGuard::new(self, table_name);
+        let updated = table
+            .update()
+            .only_if(self.key_predicate(&key))
+            .column("created_at", now.to_string())
+            .execute()
+            .await
+            .map_err(|e| LanceError::InsertError {
+                msg: e.to_string(),
+            })?;
+        Ok(matched || updated.rows_updated > 0)
     }
 
-    /// Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
-    /// `query_vector`: embedding for ANN search.
-    /// `query_text`: text query for BM25 scoring.
-    /// `limit`: number of results to return.
-    /// `filter`: optional SQL-like predicate.
-    /// `rrf_k`: RRF constant (default 60).
-    /// `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
-    pub async fn hybrid_search(
+    /// Move `key` in `collection` to the trash: `search` and `list` skip it
+    /// until `restore_key` (or storing the key again) brings it back. Staged
+    /// entries are flushed first. Returns whether a live entry matched.
+    pub async fn soft_delete(
         &self,
-        query_vector: Vec<f32>,
-        query_text: String,
-        limit: u32,
-        filter: Option<String>,
-        rrf_k: Option<u32>,
-        vector_limit: Option<u32>,
-    ) -> Result<Vec<HybridSearchResult>, LanceError> {
-        if query_vector.len() != self.embedding_dim as usize {
-            return Err(LanceError::QueryError {
-                msg: format!(
-                    "query_vector length {} != expected {}",
-                    query_vector.len(),
-                    self.embedding_dim
-                ),
-            });
-        }
+        key: String,
+        collection: Option<String>,
+    ) -> Result<bool, LanceError> {
+        self.set_deleted_at(&key, collection.as_deref(), Some(chrono_now_ms()))
+            .await
+    }
+
+    /// Undo `soft_delete` for `key` in `collection`. Returns whether a
+    /// soft-deleted entry matched.
+    pub async fn restore_key(
+        &self,
+        key: String,
+        collection: Option<String>,
+    ) -> Result<bool, LanceError> {
+        self.set_deleted_at(&key, collection.as_deref(), None).await
+    }
+
+    /// Delete a memory entry by key from `collection`.
+    pub async fn delete(&self, key: String, collection: Option<String>) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        // Hold the flush lock so an in-flight flush can't write the key back.
+        let _guard = match &self.staging {
+            Some(staging) if table_name == self.default_table => {
+                let guard = staging.flush_lock.lock().await;
+                staging.pending.lock().unwrap().retain(|e| e.key != key || !self.in_scope(&e.agent_id));
+                Some(guard)
+            }
+            _ => None,
+        };
 
         let db = self.connect().await?;
 
@@ -465,439 +2266,8943 @@ impl LanceDBHandle {
                 msg: e.to_string(),
             })?;
 
-        if !tables.contains(&DEFAULT_TABLE.to_string()) {
-            return Ok(vec![]);
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(());
         }
 
-        let table = self.open_table_unsafe(&db, DEFAULT_TABLE).await?;
-
-        let vlimit = vector_limit.unwrap_or(limit * 4) as usize;
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let _written = TableWriteGuard::new(self, table_name);
 
-        let mut query = table
-            .query()
-            .nearest_to(query_vector)
-            .map_err(|e| LanceError::QueryError {
+        table
+            .delete(&self.key_predicate(&key))
+            .await
+            .map_err(|e| LanceError::DeleteError {
                 msg: e.to_string(),
-            })?
-            .limit(vlimit);
+            })?;
+
+        Ok(())
+    }
 
-        if let Some(ref f) = filter {
-            query = query.only_if(f.clone());
+    /// Delete the entries for `keys` from `collection` with a single commit and
+    /// return how many rows were removed. Staged entries are flushed first.
+    /// Keys that aren't stored are ignored, and a missing collection is a
+    /// no-op returning 0.
+    pub async fn delete_many(
+        &self,
+        keys: Vec<String>,
+        collection: Option<String>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        if keys.is_empty() {
+            return Ok(0);
+        }
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
         }
 
-        let stream = query
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
             .execute()
             .await
-            .map_err(|e| LanceError::QueryError {
+            .map_err(|e| LanceError::TableError {
                 msg: e.to_string(),
             })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(0);
+        }
 
-        let batches: Vec<RecordBatch> = stream
-            .try_collect()
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let predicate = format!("key IN ({})", sql_string_list(keys.iter().map(String::as_str)));
+        let predicate = self.scoped(Some(&predicate)).unwrap_or(predicate);
+        let matched = table
+            .count_rows(Some(predicate.clone()))
             .await
             .map_err(|e| LanceError::QueryError {
                 msg: e.to_string(),
-            })?;
-
-        // Extract candidates from Arrow batches
-        struct Candidate {
-            key: String,
-            text: String,
-            distance: f64,
-            metadata: Option<String>,
+            })? as u64;
+        if matched == 0 {
+            return Ok(0);
         }
 
-        let mut candidates: Vec<Candidate> = Vec::new();
-        for batch in &batches {
-            let keys = batch
-                .column_by_name("key")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let texts = batch
-                .column_by_name("text")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let metas = batch
-                .column_by_name("metadata")
-                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
-            let distances = batch
-                .column_by_name("_distance")
-                .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
-
-            let (keys, texts) = match (keys, texts) {
-                (Some(k), Some(t)) => (k, t),
-                _ => continue,
-            };
+        let _written = TableWriteGuard::new(self, table_name);
+        table
+            .delete(&predicate)
+            .await
+            .map_err(|e| LanceError::DeleteError {
+                msg: e.to_string(),
+            })?;
+        Ok(matched)
+    }
 
-            for i in 0..batch.num_rows() {
-                let distance = distances.map(|d| d.value(i) as f64).unwrap_or(f64::MAX);
-                let metadata = metas.and_then(|m| {
-                    if m.is_null(i) {
-                        None
-                    } else {
-                        Some(m.value(i).to_string())
-                    }
+    /// Overwrite the `embedding` of each listed key in `collection`, e.g. after
+    /// switching embedding models, leaving text, metadata and timestamps
+    /// untouched. Every vector is checked before anything is written, keys
+    /// not stored are skipped, and a key listed twice takes its last vector.
+    /// Returns how many entries were updated.
+    pub async fn update_embeddings(
+        &self,
+        updates: Vec<KeyEmbedding>,
+        collection: Option<String>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        let mut latest: HashMap<String, Vec<f32>> = HashMap::new();
+        for mut u in updates {
+            if u.embedding.len() != self.embedding_dim as usize {
+                return Err(LanceError::DimensionMismatch {
+                    expected: self.embedding_dim,
+                    actual: u.embedding.len() as i32,
+                    key: Some(u.key),
+                    index: None,
                 });
-                candidates.push(Candidate {
-                    key: keys.value(i).to_string(),
-                    text: texts.value(i).to_string(),
-                    distance,
-                    metadata,
+            }
+            if let Some(i) = first_non_finite(&u.embedding) {
+                return Err(LanceError::InsertError {
+                    msg: format!("key {:?}: embedding[{i}] is not finite", u.key),
                 });
             }
+            if self.normalize && !l2_normalize(&mut u.embedding) {
+                return Err(LanceError::InsertError {
+                    msg: format!("key {:?}: cannot normalize a zero embedding", u.key),
+                });
+            }
+            latest.insert(u.key, u.embedding);
         }
-
-        if candidates.is_empty() {
-            return Ok(vec![]);
+        if latest.is_empty() {
+            return Ok(0);
+        }
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
         }
 
-        // Rank by vector distance (ascending = better)
-        candidates.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(std::cmp::Ordering::Equal));
-        let vector_ranks: Vec<u32> = (0..candidates.len()).map(|i| (i + 1) as u32).collect();
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(0);
+        }
 
-        // Compute BM25 scores
-        let doc_texts: Vec<String> = candidates.iter().map(|c| c.text.clone()).collect();
-        let bm25 = bm25_scores(&doc_texts, &query_text);
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let table_schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+            msg: e.to_string(),
+        })?;
+        let (key_field, embedding_field) = match (
+            table_schema.field_with_name("key"),
+            table_schema.field_with_name("embedding"),
+        ) {
+            (Ok(k), Ok(e)) => (k.clone(), e.clone()),
+            _ => {
+                return Err(LanceError::SchemaError {
+                    msg: "table has no key or embedding column".to_string(),
+                })
+            }
+        };
+        let item = match embedding_field.data_type() {
+            DataType::FixedSizeList(item, _) => item.clone(),
+            _ => Arc::new(Field::new("item", self.embedding_dtype.item_type(), true)),
+        };
 
-        // Rank by BM25 score (descending = better)
-        let mut bm25_indices: Vec<usize> = (0..candidates.len()).collect();
-        bm25_indices.sort_by(|&a, &b| bm25[b].partial_cmp(&bm25[a]).unwrap_or(std::cmp::Ordering::Equal));
-        let mut text_ranks = vec![0u32; candidates.len()];
-        for (rank, &idx) in bm25_indices.iter().enumerate() {
-            text_ranks[idx] = (rank + 1) as u32;
-        }
+        let (keys, vectors): (Vec<String>, Vec<Vec<f32>>) = latest.into_iter().unzip();
+        let values = self
+            .embedding_dtype
+            .encode(Float32Array::from(vectors.concat()))?;
+        let schema = Arc::new(Schema::new(vec![key_field, embedding_field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![
+            Arc::new(StringArray::from(keys)),
+            Arc::new(FixedSizeListArray::new(item, self.embedding_dim, values, None)),
+        ])
+        .map_err(|e| LanceError::InsertError {
+            msg: e.to_string(),
+        })?;
 
-        // RRF fusion
+        // Only matched rows are updated, so the source needs no other columns.
+        let mut merge = table.merge_insert(&["key"]);
+        merge.when_matched_update_all(self.scoped(None).map(|scope| format!("target.{scope}")));
+        let _written = TableWriteGuard::new(self, table_name);
+        let result = merge
+            .execute(Box::new(RecordBatchIterator::new(vec![Ok(batch)], schema)))
+            .await
+            .map_err(|e| LanceError::InsertError {
+                msg: e.to_string(),
+            })?;
+        Ok(result.num_updated_rows)
+    }
+
+    /// Delete every row in `collection` matching the SQL predicate `filter`
+    /// (e.g. `"created_at < 1700000000000 AND agent_id = 'old'"`) and return
+    /// how many rows matched. The predicate is checked against the table schema
+    /// before anything is deleted, and `dry_run` stops after counting.
+    /// An empty filter is refused unless `delete_all` is set, so a missing
+    /// predicate can't wipe the table by accident. Staged entries are flushed
+    /// first so the predicate sees them.
+    ///
+    /// Deleted rows stay in any index until it is rebuilt, only masked at query
+    /// time. `maintain_index` fixes that incrementally after the delete: data
+    /// files holding deleted rows are rewritten without them, existing indexes
+    /// are remapped to the rewritten rows (no retraining), and remaining
+    /// unindexed rows are added. The cost is proportional to the affected
+    /// files, not the table. A full index rebuild is still preferable once a
+    /// large share of the table has changed, since remapping keeps the
+    /// original partitioning. No-op when the table has no index.
+    pub async fn delete_where(
+        &self,
+        filter: String,
+        collection: Option<String>,
+        dry_run: bool,
+        delete_all: bool,
+        maintain_index: bool,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        let predicate = if !filter.trim().is_empty() {
+            filter
+        } else if delete_all {
+            "true".to_string()
+        } else {
+            return Err(LanceError::DeleteError {
+                msg: "refusing to delete with an empty filter; set delete_all to remove every row"
+                    .to_string(),
+            });
+        };
+        let predicate = self.scoped(Some(&predicate)).unwrap_or(predicate);
+
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(0);
+        }
+
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        validate_filter(&table, &predicate).await?;
+
+        let matched = table
+            .count_rows(Some(predicate.clone()))
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })? as u64;
+
+        if dry_run || matched == 0 {
+            return Ok(matched);
+        }
+
+        let _written = TableWriteGuard::new(self, table_name);
+        table
+            .delete(&predicate)
+            .await
+            .map_err(|e| LanceError::DeleteError {
+                msg: e.to_string(),
+            })?;
+
+        if maintain_index {
+            maintain_indices(&table).await?;
+        }
+
+        Ok(matched)
+    }
+
+    /// Delete every entry stored by `agent_id` in `collection` and return how
+    /// many were removed. No-op returning 0 when the collection doesn't exist.
+    pub async fn delete_by_agent(
+        &self,
+        agent_id: String,
+        collection: Option<String>,
+    ) -> Result<u64, LanceError> {
+        let filter = MetaFilter::AgentId { agent_id }.to_sql();
+        self.delete_where(filter, collection, false, false, false).await
+    }
+
+    /// List memory keys in `options.collection`, optionally filtered by
+    /// prefix, returning at most `limit`. See `ListOptions` for paging, time
+    /// windows, versions and ordering. Soft-deleted keys are left out unless
+    /// `options.include_deleted` is set.
+    #[uniffi::method(default(options = None))]
+    pub async fn list(
+        &self,
+        prefix: Option<String>,
+        limit: Option<u32>,
+        options: Option<ListOptions>,
+    ) -> Result<Vec<String>, LanceError> {
+        let options = options.unwrap_or_default();
+        let batches = self
+            .list_batches(prefix.as_deref(), limit, &options, &["key"])
+            .await?;
+
+        let mut keys = Vec::new();
+        for batch in &batches {
+            if let Some(arr) = batch
+                .column_by_name("key")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+            {
+                for i in 0..arr.len() {
+                    keys.push(arr.value(i).to_string());
+                }
+            }
+        }
+
+        if let Some(lim) = limit {
+            keys.truncate(lim as usize);
+        }
+        non_empty_listing(keys.is_empty(), &options)?;
+        Ok(keys)
+    }
+
+    /// Like `list`, but returns each entry's text, metadata and timestamps as
+    /// `SearchResult`s. There is no query vector, so `score` is 0 and
+    /// `distance` is None. `options` are as for `list`.
+    #[uniffi::method(default(options = None))]
+    pub async fn list_detailed(
+        &self,
+        prefix: Option<String>,
+        limit: Option<u32>,
+        options: Option<ListOptions>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let options = options.unwrap_or_default();
+        let table_name = self.table_or_default(options.collection.as_deref());
+        let batches = self
+            .list_batches(prefix.as_deref(), limit, &options, &LIST_COLUMNS)
+            .await?;
+
+        let mut results = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(move |i| stored_result(b, i, table_name)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(lim) = limit {
+            results.truncate(lim as usize);
+        }
+        non_empty_listing(results.is_empty(), &options)?;
+        Ok(results)
+    }
+
+    /// Distinct `agent_id`s with at least one entry in `collection`, including
+    /// staged entries, sorted. Empty when the collection doesn't exist.
+    pub async fn list_agents(&self, collection: Option<String>) -> Result<Vec<String>, LanceError> {
+        let batches = self
+            .list_batches(None, None, &ListOptions { collection, ..Default::default() }, &["key", "agent_id"])
+            .await?;
+
+        let mut agents = BTreeSet::new();
+        for batch in &batches {
+            let ids = required_column::<StringArray>(batch, "agent_id")?;
+            agents.extend(ids.iter().flatten().map(String::from));
+        }
+        Ok(agents.into_iter().collect())
+    }
+
+    /// Entries per `agent_id` in `collection`, including staged entries,
+    /// sorted by `agent_id`. Empty when the collection doesn't exist.
+    pub async fn counts_by_agent(
+        &self,
+        collection: Option<String>,
+    ) -> Result<Vec<AgentCount>, LanceError> {
+        let batches = self
+            .list_batches(None, None, &ListOptions { collection, ..Default::default() }, &["key", "agent_id"])
+            .await?;
+
+        let mut counts = BTreeMap::<String, u64>::new();
+        for batch in &batches {
+            let ids = required_column::<StringArray>(batch, "agent_id")?;
+            for id in ids.iter().flatten() {
+                *counts.entry(id.to_string()).or_default() += 1;
+            }
+        }
+        Ok(counts
+            .into_iter()
+            .map(|(agent_id, count)| AgentCount { agent_id, count })
+            .collect())
+    }
+
+    /// Drop all data. If `collection` is None, drops the default table.
+    pub async fn clear(&self, collection: Option<String>) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        if self.agent_scope.is_some() {
+            self.delete_where(String::new(), collection, false, true, false).await?;
+            return Ok(());
+        }
+        let db = self.connect().await?;
+        let table_name = self.table_or_default(collection.as_deref());
+
+        if table_name == self.default_table {
+            if let Some(staging) = &self.staging {
+                let _guard = staging.flush_lock.lock().await;
+                staging.pending.lock().unwrap().clear();
+            }
+        }
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        if tables.contains(&table_name.to_string()) {
+            let _written = TableWriteGuard::new(self, table_name);
+            db.drop_table(table_name, &[])
+                .await
+                .map_err(|e| LanceError::TableError {
+                    msg: e.to_string(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Drop every collection, including staged entries. A no-op on an empty
+    /// database.
+    pub async fn clear_all(&self) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("clear_all")?;
+        let db = self.connect().await?;
+        if let Some(staging) = &self.staging {
+            let _guard = staging.flush_lock.lock().await;
+            staging.pending.lock().unwrap().clear();
+        }
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        for table_name in &tables {
+            let _written = TableWriteGuard::new(self, table_name);
+            db.drop_table(table_name, &[])
+                .await
+                .map_err(|e| LanceError::TableError {
+                    msg: e.to_string(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Copy every row of collection `from` into a new collection `to`, with
+    /// the same schema. Staged entries are flushed first so they are copied.
+    /// `from` must exist and `to` must not (`TableError` otherwise).
+    pub async fn copy_collection(&self, from: String, to: String) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("copy_collection")?;
+        let db = self.connect().await?;
+        self.copy_table(&db, &from, &to).await
+    }
+
+    /// Rename collection `from` to `to`. The engine has no native rename for
+    /// local databases, so this is `copy_collection` followed by dropping
+    /// `from`; large collections take a while and briefly need twice the disk.
+    pub async fn rename_collection(&self, from: String, to: String) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("rename_collection")?;
+        let db = self.connect().await?;
+        self.copy_table(&db, &from, &to).await?;
+        let _written = TableWriteGuard::new(self, &from);
+        db.drop_table(&from, &[])
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })
+    }
+
+    /// Keyword search over `text` in `collection` using the engine's
+    /// full-text (BM25) index, which is built on the first call. `score` is
+    /// the BM25 relevance and `distance` is `None`. Only committed rows that
+    /// aren't soft-deleted are searched; rows written after the index was built are still matched by
+    /// a slower unindexed pass until `optimize` indexes them. Returns no
+    /// results when the collection doesn't exist.
+    pub async fn search_text(
+        &self,
+        query: String,
+        limit: u32,
+        filter: Option<String>,
+        collection: Option<String>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(Vec::new());
+        };
+
+        if !has_text_index(&table).await? && !self.read_only {
+            let _written = TableWriteGuard::new(self, table_name);
+            table
+                .create_index(&["text"], Index::FTS(FtsIndexBuilder::default()))
+                .execute()
+                .await
+                .map_err(|e| LanceError::TableError {
+                    msg: format!("Failed to create text index: {e}"),
+                })?;
+        }
+
+        let mut q = table
+            .query()
+            .full_text_search(FullTextSearchQuery::new(query))
+            .limit(limit as usize);
+        if let Some(f) = live_filter(&table, self.scoped(filter.as_deref()).as_deref()).await? {
+            q = q.only_if(f);
+        }
+        let batches: Vec<RecordBatch> = q
+            .execute()
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?
+            .try_collect()
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+
+        let mut results = Vec::new();
+        for batch in &batches {
+            let scores = required_column::<Float32Array>(batch, "_score")?;
+            for i in 0..batch.num_rows() {
+                results.push(SearchResult {
+                    score: scores.value(i) as f64,
+                    ..stored_result(batch, i, table_name)?
+                });
+            }
+        }
+        Ok(results)
+    }
+
+    /// Blend `search` and `search_text` over `collection`. Each side fetches
+    /// `limit * 4` candidates and its scores are min-max normalised to
+    /// `[0, 1]` (a side whose candidates all score alike gives them 1). The
+    /// fused score is a weighted sum, `alpha * vector + (1 - alpha) * text`,
+    /// with 0 for a side that didn't return the key, so `alpha = 1` ranks by
+    /// vector similarity only and `alpha = 0` by keyword relevance only.
+    /// Results are unique by key, best first, with `score` set to the fused
+    /// value and `distance` kept from the vector side.
+    pub async fn search_hybrid(
+        &self,
+        query_vector: Vec<f32>,
+        query_text: String,
+        limit: u32,
+        alpha: f64,
+        collection: Option<String>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(LanceError::QueryError {
+                msg: format!("alpha must be within [0, 1], got {alpha}"),
+            });
+        }
+
+        let candidates = limit.saturating_mul(4);
+        let vector = self
+            .search(query_vector, candidates, None, Some(SearchOptions { collection: collection.clone(), ..Default::default() }))
+            .await?;
+        let text = self
+            .search_text(query_text, candidates, None, collection)
+            .await?;
+
+        let mut fused: Vec<SearchResult> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (weight, results) in [(alpha, vector), (1.0 - alpha, text)] {
+            let normalized = min_max(&results.iter().map(|r| r.score).collect::<Vec<_>>());
+            for (result, norm) in results.into_iter().zip(normalized) {
+                let contribution = weight * norm;
+                match index.get(&result.key) {
+                    Some(&i) => fused[i].score += contribution,
+                    None => {
+                        index.insert(result.key.clone(), fused.len());
+                        fused.push(SearchResult {
+                            score: contribution,
+                            ..result
+                        });
+                    }
+                }
+            }
+        }
+
+        fused.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        fused.truncate(limit as usize);
+        Ok(fused)
+    }
+
+    /// Hybrid search combining vector ANN and BM25 text scoring via RRF fusion.
+    /// `query_vector`: embedding for ANN search.
+    /// `query_text`: text query for BM25 scoring.
+    /// `limit`: number of results to return.
+    /// `filter`: optional SQL-like predicate.
+    /// `rrf_k`: RRF constant (default 60).
+    /// `vector_limit`: number of ANN candidates to over-fetch (default limit * 4).
+    pub async fn hybrid_search(
+        &self,
+        query_vector: Vec<f32>,
+        query_text: String,
+        limit: u32,
+        filter: Option<String>,
+        rrf_k: Option<u32>,
+        vector_limit: Option<u32>,
+    ) -> Result<Vec<HybridSearchResult>, LanceError> {
+        if query_vector.len() != self.embedding_dim as usize {
+            return Err(LanceError::DimensionMismatch {
+                expected: self.embedding_dim,
+                actual: query_vector.len() as i32,
+                key: None,
+                index: None,
+            });
+        }
+        if let Some(i) = first_non_finite(&query_vector) {
+            return Err(LanceError::QueryError {
+                msg: format!("query_vector[{i}] is not finite"),
+            });
+        }
+        let mut query_vector = query_vector;
+        if self.normalize && !l2_normalize(&mut query_vector) {
+            return Err(LanceError::QueryError {
+                msg: "cannot normalize a zero query_vector".into(),
+            });
+        }
+
+        let db = self.connect().await?;
+
+        let vlimit = vector_limit.unwrap_or(limit * 4) as usize;
+        let QueryRows { batches, merged, .. } = self
+            .nearest_with_staged(
+                &db,
+                &self.default_table,
+                query_vector,
+                vlimit,
+                filter.as_deref(),
+                &SearchOptions::default(),
+            )
+            .await?;
+
+        // Extract candidates from Arrow batches
+        struct Candidate {
+            key: String,
+            text: String,
+            distance: f64,
+            metadata: Option<String>,
+        }
+
+        let mut candidates: Vec<Candidate> = Vec::new();
+        for batch in &batches {
+            let keys = batch
+                .column_by_name("key")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let texts = batch
+                .column_by_name("text")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let metas = batch
+                .column_by_name("metadata")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let distances = batch
+                .column_by_name("_distance")
+                .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
+
+            let (keys, texts) = match (keys, texts) {
+                (Some(k), Some(t)) => (k, t),
+                _ => continue,
+            };
+
+            for i in 0..batch.num_rows() {
+                let distance = distances.map(|d| d.value(i) as f64).unwrap_or(f64::MAX);
+                let metadata = metas.and_then(|m| {
+                    if m.is_null(i) {
+                        None
+                    } else {
+                        Some(m.value(i).to_string())
+                    }
+                });
+                candidates.push(Candidate {
+                    key: keys.value(i).to_string(),
+                    text: texts.value(i).to_string(),
+                    distance,
+                    metadata,
+                });
+            }
+        }
+
+        if candidates.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Rank by vector distance (ascending = better)
+        candidates.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(std::cmp::Ordering::Equal));
+        if merged {
+            candidates.truncate(vlimit);
+        }
+        let vector_ranks: Vec<u32> = (0..candidates.len()).map(|i| (i + 1) as u32).collect();
+
+        // Compute BM25 scores
+        let doc_texts: Vec<String> = candidates.iter().map(|c| c.text.clone()).collect();
+        let bm25 = bm25_scores(&doc_texts, &query_text);
+
+        // Rank by BM25 score (descending = better)
+        let mut bm25_indices: Vec<usize> = (0..candidates.len()).collect();
+        bm25_indices.sort_by(|&a, &b| bm25[b].partial_cmp(&bm25[a]).unwrap_or(std::cmp::Ordering::Equal));
+        let mut text_ranks = vec![0u32; candidates.len()];
+        for (rank, &idx) in bm25_indices.iter().enumerate() {
+            text_ranks[idx] = (rank + 1) as u32;
+        }
+
+        // RRF fusion
         let k = rrf_k.unwrap_or(60) as f64;
         let mut scored: Vec<(usize, f64)> = (0..candidates.len())
             .map(|i| {
-                let rrf = 1.0 / (k + vector_ranks[i] as f64) + 1.0 / (k + text_ranks[i] as f64);
-                (i, rrf)
+                let rrf = 1.0 / (k + vector_ranks[i] as f64) + 1.0 / (k + text_ranks[i] as f64);
+                (i, rrf)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(limit as usize);
+
+        let results = scored
+            .into_iter()
+            .map(|(i, rrf_score)| HybridSearchResult {
+                key: candidates[i].key.clone(),
+                text: candidates[i].text.clone(),
+                vector_rank: vector_ranks[i],
+                text_rank: text_ranks[i],
+                rrf_score,
+                metadata: candidates[i].metadata.clone(),
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Re-embed every row of `collection` with `reembed` and rewrite it at
+    /// `new_dim`, e.g. after switching embedding models. Rows are streamed
+    /// batch by batch into a single overwrite commit, so the new table is
+    /// swapped in atomically and a failure part-way leaves the old one intact.
+    /// `progress` is called with (rows done, total rows) after each batch.
+    /// Indexes are not carried over. The handle keeps its original
+    /// `embedding_dim`; reopen with `new_dim` to use the migrated collection.
+    /// Returns the number of rows migrated.
+    pub async fn migrate_dimension(
+        &self,
+        collection: Option<String>,
+        new_dim: i32,
+        reembed: Box<dyn Reembedder>,
+        progress: Option<Box<dyn ProgressReporter>>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("migrate_dimension")?;
+        if new_dim <= 0 {
+            return Err(LanceError::SchemaError {
+                msg: format!("new_dim must be > 0, got {new_dim}"),
+            });
+        }
+
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(0);
+        }
+
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let total = table
+            .count_rows(None)
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })? as u64;
+
+        let source = table
+            .query()
+            .execute()
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+        let schema = with_embedding_dim(&source.schema(), new_dim);
+
+        let migrated = Arc::new(AtomicU64::new(0));
+        let counter = migrated.clone();
+        let out_schema = schema.clone();
+        let dtype = self.embedding_dtype;
+        let rewritten = source.map(move |batch| {
+            let batch = batch?;
+            let offset = counter.load(Ordering::Relaxed);
+            let batch = reembed_batch(&batch, &out_schema, new_dim, dtype, offset, reembed.as_ref())
+                .map_err(|e| lancedb::Error::Runtime {
+                    message: e.to_string(),
+                })?;
+            let done = offset + batch.num_rows() as u64;
+            counter.store(done, Ordering::Relaxed);
+            if let Some(p) = &progress {
+                p.on_progress(done, total);
+            }
+            Ok(batch)
+        });
+        let stream: SendableRecordBatchStream =
+            Box::pin(SimpleRecordBatchStream::new(rewritten, schema));
+
+        // The old version stays readable until this commit lands.
+        let _written = TableWriteGuard::new(self, table_name);
+        db.create_table_streaming(table_name, stream)
+            .mode(CreateTableMode::Overwrite)
+            .write_options(self.unsafe_write_options(table_name, WriteMode::Overwrite))
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        Ok(migrated.load(Ordering::Relaxed))
+    }
+
+    /// Mean of the embeddings in `collection` matching `filter` (all rows when
+    /// `None`), including staged entries and leaving out soft-deleted ones.
+    /// Errors when no rows match, since the centroid is then undefined.
+    pub async fn centroid(
+        &self,
+        filter: Option<String>,
+        collection: Option<String>,
+    ) -> Result<Vec<f32>, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let QueryRows { batches, .. } = self
+            .scan_with_staged(&db, table_name, filter.as_deref(), false, true, false)
+            .await?;
+
+        // Running mean in f64 so large collections don't lose precision.
+        let mut mean: Vec<f64> = Vec::new();
+        let mut count = 0u64;
+        for batch in &batches {
+            let Some(embeddings) = batch
+                .column_by_name("embedding")
+                .and_then(|c| c.as_any().downcast_ref::<FixedSizeListArray>())
+            else {
+                continue;
+            };
+            let Some(values) = self.embedding_dtype.decode(embeddings.values()) else {
+                continue;
+            };
+            let dim = embeddings.value_length() as usize;
+            if mean.is_empty() {
+                mean = vec![0.0; dim];
+            }
+
+            for i in 0..embeddings.len() {
+                count += 1;
+                let start = embeddings.value_offset(i) as usize;
+                for (m, v) in mean.iter_mut().zip(&values.values()[start..start + dim]) {
+                    *m += (*v as f64 - *m) / count as f64;
+                }
+            }
+        }
+
+        if count == 0 {
+            return Err(LanceError::QueryError {
+                msg: "no rows match, centroid is undefined".into(),
+            });
+        }
+        Ok(mean.into_iter().map(|m| m as f32).collect())
+    }
+
+    /// The single nearest neighbour to `query_vector` in `collection`, or
+    /// `None` when nothing matches `filter` or the collection doesn't exist.
+    /// A cheaper `search` with `limit = 1` that decodes only the winning row.
+    pub async fn nearest(
+        &self,
+        query_vector: Vec<f32>,
+        filter: Option<String>,
+        collection: Option<String>,
+    ) -> Result<Option<SearchResult>, LanceError> {
+        if query_vector.len() != self.embedding_dim as usize {
+            return Err(LanceError::DimensionMismatch {
+                expected: self.embedding_dim,
+                actual: query_vector.len() as i32,
+                key: None,
+                index: None,
+            });
+        }
+        if let Some(i) = first_non_finite(&query_vector) {
+            return Err(LanceError::QueryError {
+                msg: format!("query_vector[{i}] is not finite"),
+            });
+        }
+        let mut query_vector = query_vector;
+        if self.normalize && !l2_normalize(&mut query_vector) {
+            return Err(LanceError::QueryError {
+                msg: "cannot normalize a zero query_vector".into(),
+            });
+        }
+
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let QueryRows { batches, .. } = self
+            .nearest_with_staged(
+                &db,
+                table_name,
+                query_vector,
+                1,
+                filter.as_deref(),
+                &SearchOptions::default(),
+            )
+            .await?;
+
+        // With staged rows merged there can be one candidate per source.
+        let mut best: Option<(&RecordBatch, usize, f32)> = None;
+        for batch in &batches {
+            let Some(distances) = batch
+                .column_by_name("_distance")
+                .and_then(|c| c.as_any().downcast_ref::<Float32Array>())
+            else {
+                continue;
+            };
+            for i in 0..batch.num_rows() {
+                let d = distances.value(i);
+                if best.is_none_or(|(_, _, bd)| d < bd) {
+                    best = Some((batch, i, d));
+                }
+            }
+        }
+
+        let Some((batch, i, distance)) = best else {
+            return Ok(None);
+        };
+        let column = |name| {
+            batch
+                .column_by_name(name)
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        };
+        let (Some(key), Some(text)) = (nullable_string(column("key"), i), nullable_string(column("text"), i))
+        else {
+            return Ok(None);
+        };
+
+        let metadata = nullable_string(column("metadata"), i);
+        Ok(Some(SearchResult {
+            key,
+            collection: table_name.to_string(),
+            agent_id: required_column::<StringArray>(batch, "agent_id")?
+                .value(i)
+                .to_string(),
+            text,
+            score: self.score(distance),
+            distance: Some(distance as f64),
+            meta: meta_pairs(metadata.as_deref()),
+            metadata,
+            created_at: required_column::<Int64Array>(batch, "created_at")?.value(i),
+            row_id: None,
+            fragment_id: None,
+            dataset_version: None,
+            embedding: None,
+        }))
+    }
+
+    /// Number of rows in `collection` matching `filter` (all rows when `None`),
+    /// including staged entries and leaving out soft-deleted ones. Returns 0
+    /// when the collection doesn't exist.
+    pub async fn count(
+        &self,
+        collection: Option<String>,
+        filter: Option<String>,
+    ) -> Result<u64, LanceError> {
+        self.count_where(self.table_or_default(collection.as_deref()), filter, true)
+            .await
+    }
+
+    /// Build an IVF_PQ index on `embedding` in `collection`, using the handle's
+    /// distance metric. Omitted parameters default to `sqrt(rows)` partitions
+    /// and `embedding_dim / 16` sub-vectors (or the next value that divides
+    /// the dimension). PQ training needs at least 256 rows. A no-op when the
+    /// collection already has a vector index or doesn't exist. IVF_PQ only
+    /// indexes float embeddings, so a handle opened with
+    /// `EmbeddingDtype::Int8` or `EmbeddingDtype::Binary` gets a
+    /// `SchemaError`; its searches scan every row instead. The engine
+    /// reports no progress while training, so `progress` only hears (0, 1)
+    /// when the build starts and (1, 1) once it is done.
+    pub async fn create_index(
+        &self,
+        collection: Option<String>,
+        num_partitions: Option<u32>,
+        num_sub_vectors: Option<u32>,
+        progress: Option<Box<dyn ProgressReporter>>,
+    ) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        if matches!(self.embedding_dtype, EmbeddingDtype::Int8 { .. } | EmbeddingDtype::Binary) {
+            return Err(LanceError::SchemaError {
+                msg: format!(
+                    "IVF_PQ cannot index {} embeddings",
+                    self.embedding_dtype.item_type()
+                ),
+            });
+        }
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(());
+        }
+
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        if has_vector_index(&table, "embedding").await? {
+            return Ok(());
+        }
+
+        let rows = table
+            .count_rows(None)
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+        let num_partitions = num_partitions.unwrap_or(((rows as f64).sqrt() as u32).max(1));
+        let num_sub_vectors =
+            num_sub_vectors.unwrap_or_else(|| default_sub_vectors(self.embedding_dim as u32));
+
+        let mut builder = IvfPqIndexBuilder::default()
+            .num_partitions(num_partitions)
+            .num_sub_vectors(num_sub_vectors);
+        if let Some(metric) = self.distance_metric {
+            builder = builder.distance_type(metric.distance_type());
+        }
+
+        let _written = TableWriteGuard::new(self, table_name);
+        if let Some(p) = &progress {
+            p.on_progress(0, 1);
+        }
+        table
+            .create_index(&["embedding"], Index::IvfPq(builder))
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: format!("Failed to create index: {e}"),
+            })?;
+        if let Some(p) = &progress {
+            p.on_progress(1, 1);
+        }
+        Ok(())
+    }
+
+    /// Build a BTree index on the scalar `column` of `collection` (e.g.
+    /// `key`, `agent_id` or `created_at`), so equality and range filters on
+    /// it, such as the `key = ...` lookups behind `get`, `exists`, `delete`
+    /// and upserts, no longer scan every row. Optional: every method works
+    /// without it. `optimize` keeps it up to date with later writes. A no-op
+    /// when the index already exists or the collection doesn't; an unknown
+    /// or non-scalar column is a `SchemaError`.
+    pub async fn create_scalar_index(
+        &self,
+        collection: Option<String>,
+        column: String,
+    ) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(());
+        };
+        let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+            msg: e.to_string(),
+        })?;
+        match schema.field_with_name(&column).map(|f| f.data_type()) {
+            Ok(DataType::Utf8 | DataType::Int64) => {}
+            Ok(other) => {
+                return Err(LanceError::SchemaError {
+                    msg: format!("column {column} has type {other}, which has no scalar index"),
+                })
+            }
+            Err(_) => {
+                return Err(LanceError::SchemaError {
+                    msg: format!("no column {column}"),
+                })
+            }
+        }
+
+        let indices = table
+            .list_indices()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if indices
+            .iter()
+            .any(|i| i.index_type == IndexType::BTree && i.columns == [column.as_str()])
+        {
+            return Ok(());
+        }
+
+        let _written = TableWriteGuard::new(self, table_name);
+        table
+            .create_index(&[column.as_str()], Index::BTree(BTreeIndexBuilder::default()))
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: format!("Failed to create index: {e}"),
+            })
+    }
+
+    /// Drop every index on `column` (default `embedding`) in `collection`, e.g.
+    /// to rebuild it with other parameters or to go back to flat scans. The
+    /// index files stay on disk until the next `optimize`. A no-op when the
+    /// column has no index or the collection doesn't exist.
+    pub async fn drop_index(
+        &self,
+        collection: Option<String>,
+        column: Option<String>,
+    ) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let column = column.unwrap_or_else(|| "embedding".to_string());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(());
+        };
+        let indices = table
+            .list_indices()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        let _written = TableWriteGuard::new(self, table_name);
+        for index in indices.iter().filter(|i| i.columns == [column.as_str()]) {
+            table
+                .drop_index(&index.name)
+                .await
+                .map_err(|e| LanceError::TableError {
+                    msg: format!("Failed to drop index {}: {e}", index.name),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// The indexes of `collection`, sorted by column, with how many rows each
+    /// covers. Empty when the collection has none or doesn't exist.
+    pub async fn index_info(&self, collection: Option<String>) -> Result<Vec<IndexInfo>, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(Vec::new());
+        };
+        let indices = table
+            .list_indices()
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+
+        let mut infos = Vec::with_capacity(indices.len());
+        for index in indices {
+            let stats = table
+                .index_stats(&index.name)
+                .await
+                .map_err(|e| LanceError::QueryError {
+                    msg: e.to_string(),
+                })?;
+            infos.push(IndexInfo {
+                column: index.columns.join(","),
+                index_type: index.index_type.to_string(),
+                num_indexed_rows: stats.as_ref().map_or(0, |s| s.num_indexed_rows as u64),
+                num_unindexed_rows: stats.as_ref().map_or(0, |s| s.num_unindexed_rows as u64),
+                name: index.name,
+            });
+        }
+        infos.sort_by(|a, b| a.column.cmp(&b.column).then_with(|| a.name.cmp(&b.name)));
+        Ok(infos)
+    }
+
+    /// Compact `collection`: merge small data files and rewrite away rows
+    /// that upserts and deletes left behind, then bring any vector or text
+    /// index up to date with the rewritten rows and any rows written since.
+    /// Old versions are kept, so earlier versions remain readable. No-op when
+    /// the collection doesn't exist or has nothing to compact.
+    pub async fn optimize(&self, collection: Option<String>) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(());
+        }
+
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let _written = TableWriteGuard::new(self, table_name);
+        let mut actions = vec![compaction()];
+        let indices = table
+            .list_indices()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !indices.is_empty() {
+            actions.push(OptimizeAction::Index(OptimizeOptions::default()));
+        }
+        for action in actions {
+            table
+                .optimize(action)
+                .await
+                .map_err(|e| LanceError::TableError {
+                    msg: format!("optimize failed: {e}"),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Committed versions of `collection`, oldest first. Every write creates
+    /// one; pass its number as `version` to `search` or `list` to read the
+    /// collection as it was then. A missing collection has no versions.
+    pub async fn list_versions(
+        &self,
+        collection: Option<String>,
+    ) -> Result<Vec<VersionInfo>, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(Vec::new());
+        };
+        let versions = table
+            .list_versions()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        Ok(versions
+            .into_iter()
+            .map(|v| VersionInfo {
+                version: v.version,
+                timestamp_ms: v.timestamp.timestamp_millis(),
+            })
+            .collect())
+    }
+
+    /// Row count, version history and on-disk size of `collection` in one
+    /// call. A missing collection reports all zeros.
+    pub async fn stats(&self, collection: Option<String>) -> Result<CollectionStats, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(CollectionStats::default());
+        };
+        let latest_version = table.version().await.map_err(|e| LanceError::TableError {
+            msg: e.to_string(),
+        })?;
+        let num_versions = self.list_versions(collection.clone()).await?.len() as u64;
+        let num_rows = self.count_where(table_name, None, false).await?;
+        let bytes = if self.db_path.starts_with(MEMORY_SCHEME) {
+            0
+        } else {
+            dir_size(&Path::new(&self.db_path).join(format!("{table_name}.lance")))
+        };
+        Ok(CollectionStats {
+            num_rows,
+            num_versions,
+            latest_version,
+            bytes,
+        })
+    }
+
+    /// The columns of `collection` as stored, which may differ from the
+    /// handle's own schema (e.g. a database created with another
+    /// `embedding_dim`). A missing collection is a `TableError`.
+    pub async fn describe(&self, collection: Option<String>) -> Result<SchemaInfo, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Err(LanceError::TableError {
+                msg: format!("collection {table_name} does not exist"),
+            });
+        };
+        let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+            msg: e.to_string(),
+        })?;
+        Ok(schema_info(&schema))
+    }
+
+    /// Roll `collection` back to `version` by committing a copy of it as the
+    /// new latest version; later versions stay listed and can be restored in
+    /// turn. Entries still staged are kept and land on top at the next flush.
+    /// A missing collection or unknown version is a `TableError`.
+    pub async fn restore(
+        &self,
+        collection: Option<String>,
+        version: u64,
+    ) -> Result<(), LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("restore")?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_at(&db, table_name, Some(version)).await? else {
+            return Err(LanceError::TableError {
+                msg: format!("collection {table_name:?} does not exist"),
+            });
+        };
+
+        let _written = TableWriteGuard::new(self, table_name);
+        table.restore().await.map_err(|e| LanceError::TableError {
+            msg: format!("cannot restore version {version}: {e}"),
+        })
+    }
+
+    /// Delete versions of `collection` older than `older_than_seconds`, and
+    /// the files only they reference, to reclaim disk. The latest version is
+    /// always kept, whatever its age, and pruned versions can no longer be
+    /// read or restored. A missing collection removes nothing.
+    pub async fn cleanup_old_versions(
+        &self,
+        collection: Option<String>,
+        older_than_seconds: u64,
+    ) -> Result<CleanupStats, LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(CleanupStats::default());
+        };
+
+        let older_than = i64::try_from(older_than_seconds)
+            .ok()
+            .and_then(lancedb::table::Duration::try_seconds)
+            .ok_or_else(|| LanceError::TableError {
+                msg: format!("older_than_seconds {older_than_seconds} is too large"),
+            })?;
+        let _written = TableWriteGuard::new(self, table_name);
+        let stats = table
+            .optimize(OptimizeAction::Prune {
+                older_than: Some(older_than),
+                delete_unverified: None,
+                error_if_tagged_old_versions: None,
+            })
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: format!("cleanup failed: {e}"),
+            })?;
+        Ok(stats
+            .prune
+            .map(|p| CleanupStats {
+                versions_removed: p.old_versions,
+                bytes_removed: p.bytes_removed,
+            })
+            .unwrap_or_default())
+    }
+
+    /// Audit `collection` for rows sharing a `key`, which concurrent upserts
+    /// can leave behind. Read-only: only the `key` column of committed rows is
+    /// scanned, and a missing collection reports zero rows. An agent-scoped
+    /// handle audits only its agent's rows.
+    pub async fn verify(&self, collection: Option<String>) -> Result<VerifyReport, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let scope = self.scoped(None);
+
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        let mut total_rows = 0u64;
+        if let Some(table) = self.open_table_cached(&db, table_name).await? {
+            for batch in prefix_batches(&table, None, scope.as_deref(), None, 0, &["key"]).await? {
+                let Some(keys) = batch
+                    .column_by_name("key")
+                    .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                else {
+                    continue;
+                };
+                total_rows += keys.len() as u64;
+                for key in keys.iter().flatten() {
+                    *counts.entry(key.to_string()).or_default() += 1;
+                }
+            }
+        }
+
+        let mut duplicates: Vec<(String, u64)> = counts.into_iter().filter(|(_, n)| *n > 1).collect();
+        duplicates.sort();
+        Ok(VerifyReport {
+            total_rows,
+            duplicate_keys: duplicates.len() as u64,
+            duplicate_rows: duplicates.iter().map(|(_, n)| n - 1).sum(),
+            sample_keys: duplicates
+                .into_iter()
+                .take(VERIFY_SAMPLE_KEYS)
+                .map(|(k, _)| k)
+                .collect(),
+        })
+    }
+
+    /// Stream the Arrow IPC file at `path` into `collection` one record batch
+    /// at a time, so the file never has to fit in memory. The file schema is
+    /// checked once against the table (or, for a new table, the handle's
+    /// schema) before anything is written. With `upsert`, rows replace
+    /// existing rows with the same `key`; otherwise they are appended.
+    /// Each batch is its own commit: if one fails, the error names it and the
+    /// batches before it stay imported. `progress` is called with
+    /// (batches done, total batches). Returns the number of rows imported.
+    pub async fn import_arrow_file(
+        &self,
+        collection: Option<String>,
+        path: String,
+        upsert: bool,
+        progress: Option<Box<dyn ProgressReporter>>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("import_arrow_file")?;
+        let file = std::fs::File::open(&path).map_err(|e| LanceError::InsertError {
+            msg: format!("Cannot open {path}: {e}"),
+        })?;
+        let reader = FileReader::try_new(file, None).map_err(|e| LanceError::InsertError {
+            msg: format!("Cannot read Arrow IPC file {path}: {e}"),
+        })?;
+        let total = reader.num_batches() as u64;
+
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        let mut table = None;
+        let expected = if tables.contains(&table_name.to_string()) {
+            let t = self.open_table_unsafe(&db, table_name).await?;
+            let schema = t.schema().await.map_err(|e| LanceError::SchemaError {
+                msg: e.to_string(),
+            })?;
+            table = Some(t);
+            schema
+        } else {
+            Arc::new(self.new_table_schema())
+        };
+        check_import_schema(&reader.schema(), &expected)?;
+
+        let _written = TableWriteGuard::new(self, table_name);
+        let mut rows = 0u64;
+        for (i, batch) in reader.enumerate() {
+            let batch = batch.map_err(|e| LanceError::InsertError {
+                msg: format!("batch {i}: {e}"),
+            })?;
+            let n = batch.num_rows() as u64;
+            let schema = batch.schema();
+            let data = RecordBatchIterator::new(vec![Ok(batch)], schema);
+
+            let result = match &table {
+                None => db
+                    .create_table(table_name, data)
+                    .write_options(self.unsafe_write_options(table_name, WriteMode::Create))
+                    .execute()
+                    .await
+                    .map(|t| table = Some(t)),
+                Some(t) if upsert => {
+                    let mut merge = t.merge_insert(&["key"]);
+                    merge.when_matched_update_all(None).when_not_matched_insert_all();
+                    merge.execute(Box::new(data)).await.map(|_| ())
+                }
+                Some(t) => t
+                    .add(data)
+                    .write_options(self.unsafe_write_options(table_name, WriteMode::Append))
+                    .execute()
+                    .await
+                    .map(|_| ()),
+            };
+            result.map_err(|e| LanceError::InsertError {
+                msg: format!("batch {i}: {e}"),
+            })?;
+
+            rows += n;
+            if let Some(p) = &progress {
+                p.on_progress(i as u64 + 1, total);
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Write every row of `collection`, with all of its columns, to a Parquet
+    /// file at `out_path`, replacing any file already there. Rows are streamed
+    /// a batch at a time. Staged entries are flushed first; a missing
+    /// collection writes an empty file with the handle's schema. Returns the
+    /// number of rows written.
+    pub async fn export_parquet(
+        &self,
+        collection: Option<String>,
+        out_path: String,
+    ) -> Result<u64, LanceError> {
+        self.ensure_unscoped("export_parquet")?;
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+
+        let db = self.connect().await?;
+        let table = self.open_table_cached(&db, table_name).await?;
+        let schema = match &table {
+            Some(t) => t.schema().await.map_err(|e| LanceError::SchemaError {
+                msg: e.to_string(),
+            })?,
+            None => Arc::new(self.new_table_schema()),
+        };
+
+        let file = std::fs::File::create(&out_path).map_err(|e| LanceError::QueryError {
+            msg: format!("Cannot create {out_path}: {e}"),
+        })?;
+        let mut writer =
+            ArrowWriter::try_new(file, schema, None).map_err(|e| LanceError::QueryError {
+                msg: format!("Cannot write Parquet file {out_path}: {e}"),
+            })?;
+
+        let mut rows = 0u64;
+        if let Some(t) = table {
+            let mut stream = t.query().execute().await.map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+            while let Some(batch) = stream.next().await {
+                let batch = batch.map_err(|e| LanceError::QueryError {
+                    msg: e.to_string(),
+                })?;
+                rows += batch.num_rows() as u64;
+                writer.write(&batch).map_err(|e| LanceError::QueryError {
+                    msg: format!("Cannot write Parquet file {out_path}: {e}"),
+                })?;
+            }
+        }
+        writer.close().map_err(|e| LanceError::QueryError {
+            msg: format!("Cannot write Parquet file {out_path}: {e}"),
+        })?;
+        Ok(rows)
+    }
+
+    /// Load the rows of the Parquet file at `in_path` (as written by
+    /// `export_parquet`) into `collection` in a single commit. `Append`
+    /// upserts by key, as `import_json` does, except on an append-only handle,
+    /// where rows are added as they are. The file schema
+    /// must match the table's for `Append` to an existing collection, and the
+    /// handle's (including `embedding_dim`) otherwise; a mismatch is a
+    /// `SchemaError` and nothing is written. Rows are read
+    /// `DEFAULT_CHUNK_ROWS` at a time and `progress` is called with (rows
+    /// read, total rows) after each chunk. Returns the number of rows
+    /// imported.
+    pub async fn import_parquet(
+        &self,
+        collection: Option<String>,
+        in_path: String,
+        mode: ImportMode,
+        progress: Option<Box<dyn ProgressReporter>>,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("import_parquet")?;
+        let file = std::fs::File::open(&in_path).map_err(|e| LanceError::InsertError {
+            msg: format!("Cannot open {in_path}: {e}"),
+        })?;
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(file).map_err(|e| LanceError::InsertError {
+                msg: format!("Cannot read Parquet file {in_path}: {e}"),
+            })?;
+        let rows = builder.metadata().file_metadata().num_rows() as u64;
+        let reader = builder
+            .with_batch_size(DEFAULT_CHUNK_ROWS)
+            .build()
+            .map_err(|e| LanceError::InsertError {
+                msg: format!("Cannot read Parquet file {in_path}: {e}"),
+            })?;
+
+        let table_name = self.table_or_default(collection.as_deref());
+        if table_name == self.default_table && mode == ImportMode::Append {
+            self.flush().await?;
+        }
+        // Staged entries are only discarded once the overwrite has committed.
+        let staging = self
+            .staging
+            .as_ref()
+            .filter(|_| table_name == self.default_table && mode == ImportMode::Overwrite);
+        let _flushing = match staging {
+            Some(staging) => Some(staging.flush_lock.lock().await),
+            None => None,
+        };
+
+        let db = self.connect().await?;
+        let table = match mode {
+            ImportMode::Append => self.open_table_cached(&db, table_name).await?,
+            ImportMode::Overwrite => None,
+        };
+        let expected = match &table {
+            Some(t) => t.schema().await.map_err(|e| LanceError::SchemaError {
+                msg: e.to_string(),
+            })?,
+            None => Arc::new(self.new_table_schema()),
+        };
+        let schema = reader.schema();
+        check_import_schema(&schema, &expected)?;
+
+        let mut done = 0;
+        let reader = RecordBatchIterator::new(
+            reader.inspect(move |batch| {
+                if let (Ok(batch), Some(p)) = (batch, &progress) {
+                    done += batch.num_rows() as u64;
+                    p.on_progress(done, rows);
+                }
+            }),
+            schema,
+        );
+
+        let _written = TableWriteGuard::new(self, table_name);
+        let result = match table {
+            Some(t) if !self.append_only => {
+                let mut merge = t.merge_insert(&["key"]);
+                merge.when_matched_update_all(None).when_not_matched_insert_all();
+                merge.execute(Box::new(reader)).await.map(|_| ())
+            }
+            Some(t) => t
+                .add(reader)
+                .write_options(self.unsafe_write_options(table_name, WriteMode::Append))
+                .execute()
+                .await
+                .map(|_| ()),
+            None => db
+                .create_table(table_name, reader)
+                .mode(CreateTableMode::Overwrite)
+                .write_options(self.unsafe_write_options(table_name, WriteMode::Overwrite))
+                .execute()
+                .await
+                .map(|_| ()),
+        };
+        result.map_err(write_error)?;
+        if let Some(staging) = staging {
+            staging.pending.lock().unwrap().clear();
+        }
+        Ok(rows)
+    }
+
+    /// Every live row of `collection`, staged entries included, as a JSON
+    /// array of objects with `key`, `agent_id`, `text`, `embedding` (an array
+    /// of floats), `metadata` (the stored string or null), `created_at` and,
+    /// on tagged tables, `tags`, plus `embedding2` (null when unset) on
+    /// collections with a second vector column, and on handles opened with
+    /// extra columns a `columns` object of each set value by name. Meant for
+    /// debugging and small datasets; use
+    /// `export_parquet` for backups. A missing collection gives `[]`.
+    pub async fn export_json(&self, collection: Option<String>) -> Result<String, LanceError> {
+        let table_name = self.table_or_default(collection.as_deref());
+        let db = self.connect().await?;
+        let rows = self
+            .scan_with_staged(&db, table_name, None, false, true, false)
+            .await?;
+        let mut records = Vec::new();
+        for batch in &rows.batches {
+            for i in 0..batch.num_rows() {
+                records.push(json_record(batch, i, self.embedding_dtype, &self.extra_columns)?);
+            }
+        }
+        Ok(serde_json::Value::Array(records).to_string())
+    }
+
+    /// Load records in the format of `export_json` into `collection` with
+    /// `store_many`, so `Append` upserts by key. `columns` values are read
+    /// as the types of the handle's extra columns. `Overwrite` replaces the
+    /// collection's rows, and any of its staged entries, with the records in
+    /// a single commit once every record has passed `store_many`'s checks; a
+    /// bad record leaves the collection untouched. `Overwrite` is not
+    /// available on an agent-scoped handle. Returns the number of rows
+    /// written.
+    pub async fn import_json(
+        &self,
+        collection: Option<String>,
+        json: String,
+        mode: ImportMode,
+    ) -> Result<u64, LanceError> {
+        self.ensure_writable()?;
+        let value: serde_json::Value =
+            serde_json::from_str(&json).map_err(|e| LanceError::InsertError {
+                msg: format!("invalid JSON: {e}"),
+            })?;
+        let serde_json::Value::Array(records) = value else {
+            return Err(LanceError::InsertError {
+                msg: "expected a JSON array of records".into(),
+            });
+        };
+        let entries = records
+            .iter()
+            .enumerate()
+            .map(|(i, r)| entry_from_json(i, r, &self.extra_columns))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if mode == ImportMode::Append {
+            return self.store_many(entries, collection, None, None).await;
+        }
+        self.ensure_unscoped("import_json with ImportMode::Overwrite")?;
+        let entries = self.dedup(self.prepare_entries(entries)?);
+        let table_name = self.table_or_default(collection.as_deref());
+        let written = self.overwrite_entries(table_name, entries).await?;
+        self.evict_over_cap(table_name).await?;
+        self.spawn_auto_index(table_name);
+        Ok(written)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LanceDBManager — handles tracked by id
+// ---------------------------------------------------------------------------
+
+/// Opens `LanceDBHandle`s under app-chosen ids so several independent
+/// databases can be looked up, listed and closed together. The handles run
+/// on the shared runtime like any other.
+#[derive(uniffi::Object, Default)]
+pub struct LanceDBManager {
+    handles: Mutex<HashMap<String, Arc<LanceDBHandle>>>,
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl LanceDBManager {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Open the database at `db_path` (see `LanceDBHandle::open`) and track
+    /// it as `id`. Fails with `ConnectionFailed` when `id` is already open.
+    pub async fn open(
+        &self,
+        id: String,
+        db_path: String,
+        embedding_dim: i32,
+    ) -> Result<Arc<LanceDBHandle>, LanceError> {
+        let taken = || LanceError::ConnectionFailed {
+            msg: format!("database {id:?} is already open"),
+        };
+        if self.handles.lock().unwrap().contains_key(&id) {
+            return Err(taken());
+        }
+        let handle = LanceDBHandle::open(db_path, embedding_dim).await?;
+        let raced = {
+            let mut handles = self.handles.lock().unwrap();
+            let raced = handles.contains_key(&id);
+            if !raced {
+                handles.insert(id.clone(), handle.clone());
+            }
+            raced
+        };
+        if raced {
+            // Another `open` of the same id finished first.
+            let _ = handle.close().await;
+            return Err(taken());
+        }
+        Ok(handle)
+    }
+
+    /// The handle tracked as `id`, if any.
+    pub fn get(&self, id: String) -> Option<Arc<LanceDBHandle>> {
+        self.handles.lock().unwrap().get(&id).cloned()
+    }
+
+    /// Ids of the tracked handles, sorted.
+    pub fn ids(&self) -> Vec<String> {
+        let mut ids: Vec<_> = self.handles.lock().unwrap().keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Close the handle tracked as `id` (see `LanceDBHandle::close`) and stop
+    /// tracking it. Returns false when no handle has that id. A handle that
+    /// was already closed directly is just forgotten.
+    pub async fn close(&self, id: String) -> Result<bool, LanceError> {
+        let Some(handle) = self.handles.lock().unwrap().remove(&id) else {
+            return Ok(false);
+        };
+        if !handle.closed.load(Ordering::SeqCst) {
+            handle.close().await?;
+        }
+        Ok(true)
+    }
+
+    /// Close every tracked handle and stop tracking them. All are forgotten
+    /// even when some fail to close; the first failure is returned.
+    pub async fn close_all(&self) -> Result<(), LanceError> {
+        let handles: Vec<_> = self.handles.lock().unwrap().drain().map(|(_, h)| h).collect();
+        let mut result = Ok(());
+        for handle in handles {
+            if handle.closed.load(Ordering::SeqCst) {
+                continue;
+            }
+            if let Err(e) = handle.close().await {
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Private helpers (not exported via UniFFI)
+// ---------------------------------------------------------------------------
+
+impl LanceDBHandle {
+    async fn connect(&self) -> Result<lancedb::Connection, LanceError> {
+        let mut connection = self.connection.lock().await;
+        self.ensure_open()?;
+        if let Some(db) = connection.as_ref() {
+            return Ok(db.clone());
+        }
+
+        self.connects.fetch_add(1, Ordering::Relaxed);
+        let mut builder =
+            lancedb::connect(&self.db_path).read_consistency_interval(self.read_consistency);
+        if self.db_path.starts_with(MEMORY_SCHEME) {
+            builder = builder.session(memory_session(&self.db_path));
+        }
+        let connecting = builder.execute();
+        let connected = match self.connect_timeout {
+            Some(limit) => tokio::time::timeout(limit, connecting).await.map_err(|_| {
+                LanceError::ConnectionFailed {
+                    msg: format!("timeout after {} ms", limit.as_millis()),
+                }
+            })?,
+            None => connecting.await,
+        };
+        let db = connected.map_err(|e| LanceError::ConnectionFailed {
+            msg: e.to_string(),
+        })?;
+        *connection = Some(db.clone());
+        Ok(db)
+    }
+
+    /// Fails every mutating method of a handle opened read-only.
+    fn ensure_writable(&self) -> Result<(), LanceError> {
+        if self.read_only {
+            return Err(LanceError::TableError {
+                msg: "read-only handle".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// `TableError` when the handle is scoped to an agent (see `open_scoped`)
+    /// and `method` would reach other agents' rows.
+    fn ensure_unscoped(&self, method: &str) -> Result<(), LanceError> {
+        if self.agent_scope.is_some() {
+            return Err(LanceError::TableError {
+                msg: format!("{method} is not available on an agent-scoped handle"),
+            });
+        }
+        Ok(())
+    }
+
+    /// `QueryError` unless the handle stores `EmbeddingDtype::Binary` codes.
+    fn ensure_binary(&self) -> Result<(), LanceError> {
+        if self.embedding_dtype != EmbeddingDtype::Binary {
+            return Err(LanceError::QueryError {
+                msg: "binary codes need a handle opened with EmbeddingDtype::Binary".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// `filter` ANDed with the handle's agent scope, if any.
+    fn scoped(&self, filter: Option<&str>) -> Option<String> {
+        let Some(agent_id) = &self.agent_scope else {
+            return filter.map(String::from);
+        };
+        let scope = MetaFilter::AgentId {
+            agent_id: agent_id.clone(),
+        }
+        .to_sql();
+        Some(match filter {
+            Some(f) => format!("({f}) AND {scope}"),
+            None => scope,
+        })
+    }
+
+    /// Whether rows of `agent_id` are visible through this handle.
+    fn in_scope(&self, agent_id: &str) -> bool {
+        self.agent_scope.as_deref().is_none_or(|scope| scope == agent_id)
+    }
+
+    /// Predicate matching the entry stored under `key`, within the scope.
+    fn key_predicate(&self, key: &str) -> String {
+        let predicate = format!("key = {}", sql_quote_literal(key));
+        self.scoped(Some(&predicate)).unwrap_or(predicate)
+    }
+
+    fn ensure_open(&self) -> Result<(), LanceError> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(LanceError::ConnectionFailed {
+                msg: "handle is closed".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Open a table with UnsafeCommitHandler — avoids hardlink() syscall
+    /// that Android SELinux blocks for untrusted_app processes. Uses the
+    /// default handler instead when opened with `use_unsafe_commit` off.
+    async fn open_table_unsafe(
+        &self,
+        db: &lancedb::Connection,
+        name: &str,
+    ) -> Result<lancedb::Table, LanceError> {
+        let read_params = ReadParams {
+            commit_handler: self.commit_handler(),
+            ..Default::default()
+        };
+        db.open_table(name)
+            .lance_read_params(read_params)
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })
+    }
+
+    /// Shared read handle for `name` from `table_cache`, opening it on a miss.
+    /// Returns `None` when the table doesn't exist.
+    async fn open_table_cached(
+        &self,
+        db: &lancedb::Connection,
+        name: &str,
+    ) -> Result<Option<lancedb::Table>, LanceError> {
+        let generation = self.table_generation.load(Ordering::Acquire);
+        if let Some(table) = self.table_cache.lock().unwrap().get(name) {
+            return Ok(Some(table.clone()));
+        }
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&name.to_string()) {
+            return Ok(None);
+        }
+
+        let table = self.open_table_unsafe(db, name).await?;
+        let mut cache = self.table_cache.lock().unwrap();
+        if self.table_generation.load(Ordering::Acquire) == generation {
+            cache.insert(name.to_string(), table.clone());
+        }
+        Ok(Some(table))
+    }
+
+    /// `open_table_cached`, or with `version` a fresh (uncached) handle
+    /// checked out at that version. An unknown version is a `TableError`.
+    async fn open_table_at(
+        &self,
+        db: &lancedb::Connection,
+        name: &str,
+        version: Option<u64>,
+    ) -> Result<Option<lancedb::Table>, LanceError> {
+        let Some(version) = version else {
+            return self.open_table_cached(db, name).await;
+        };
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&name.to_string()) {
+            return Ok(None);
+        }
+
+        let table = self.open_table_unsafe(db, name).await?;
+        table
+            .checkout(version)
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: format!("cannot check out version {version}: {e}"),
+            })?;
+        Ok(Some(table))
+    }
+
+    fn evict_table(&self, name: &str) {
+        let mut cache = self.table_cache.lock().unwrap();
+        self.table_generation.fetch_add(1, Ordering::AcqRel);
+        cache.remove(name);
+    }
+
+    /// `collection`, or the handle's default collection when `None`.
+    fn table_or_default<'a>(&'a self, collection: Option<&'a str>) -> &'a str {
+        collection.unwrap_or(&self.default_table)
+    }
+
+    /// `UnsafeCommitHandler` unless `use_unsafe_commit` is off, in which case
+    /// `None` leaves the engine default in place.
+    fn commit_handler(&self) -> Option<Arc<dyn CommitHandler>> {
+        self.use_unsafe_commit
+            .then(|| Arc::new(UnsafeCommitHandler) as Arc<dyn CommitHandler>)
+    }
+
+    /// Write options with UnsafeCommitHandler (see `open_table_unsafe`), the
+    /// handle's storage version (which only takes effect on create) and
+    /// `table_name`'s preset, if any.
+    fn unsafe_write_options(&self, table_name: &str, mode: WriteMode) -> WriteOptions {
+        let mut params = WriteParams {
+            mode,
+            commit_handler: self.commit_handler(),
+            data_storage_version: self.storage_version,
+            ..Default::default()
+        };
+        if let Some(preset) = self.presets.get(table_name) {
+            if let Some(rows) = preset.max_rows_per_file {
+                params.max_rows_per_file = rows as usize;
+            }
+            if let Some(rows) = preset.max_rows_per_group {
+                params.max_rows_per_group = rows as usize;
+            }
+        }
+        WriteOptions {
+            lance_write_params: Some(params),
+        }
+    }
+
+    /// Set (`Some`) or clear (`None`) the tombstone of `key`, adding the
+    /// `deleted_at` column on the first soft delete.
+    async fn set_deleted_at(
+        &self,
+        key: &str,
+        collection: Option<&str>,
+        deleted_at: Option<i64>,
+    ) -> Result<bool, LanceError> {
+        self.ensure_writable()?;
+        let table_name = self.table_or_default(collection);
+        if table_name == self.default_table {
+            self.flush().await?;
+        }
+        let db = self.connect().await?;
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.contains(&table_name.to_string()) {
+            return Ok(false);
+        }
+
+        let table = self.open_table_unsafe(&db, table_name).await?;
+        let _written = TableWriteGuard::new(self, table_name);
+        if !has_deleted_at(&table).await? {
+            if deleted_at.is_none() {
+                return Ok(false);
+            }
+            table
+                .add_columns(
+                    NewColumnTransform::AllNulls(Arc::new(Schema::new(vec![deleted_at_field()]))),
+                    None,
+                )
+                .await
+                .map_err(|e| LanceError::SchemaError {
+                    msg: e.to_string(),
+                })?;
+        }
+        let key = self.key_predicate(key);
+        let (only_if, value) = match deleted_at {
+            Some(ms) => (format!("{key} AND deleted_at IS NULL"), ms.to_string()),
+            None => (format!("{key} AND deleted_at IS NOT NULL"), "NULL".to_string()),
+        };
+        let updated = table
+            .update()
+            .only_if(only_if)
+            .column("deleted_at", value)
+            .execute()
+            .await
+            .map_err(|e| LanceError::InsertError {
+                msg: e.to_string(),
+            })?;
+        Ok(updated.rows_updated > 0)
+    }
+
+    /// Stream every row of `from` into a newly created `to`.
+    async fn copy_table(
+        &self,
+        db: &lancedb::Connection,
+        from: &str,
+        to: &str,
+    ) -> Result<(), LanceError> {
+        if from == self.default_table {
+            self.flush().await?;
+        }
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        if !tables.iter().any(|t| t == from) {
+            return Err(LanceError::TableError {
+                msg: format!("collection {from:?} does not exist"),
+            });
+        }
+        if tables.iter().any(|t| t == to) {
+            return Err(LanceError::TableError {
+                msg: format!("collection {to:?} already exists"),
+            });
+        }
+
+        let table = self.open_table_unsafe(db, from).await?;
+        let source = table
+            .query()
+            .execute()
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+        let _written = TableWriteGuard::new(self, to);
+        db.create_table_streaming(to, source)
+            .write_options(self.unsafe_write_options(to, WriteMode::Create))
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+        Ok(())
+    }
+
+    /// `columns` of the rows of `options.collection` whose key starts with
+    /// `prefix` and that fall in the `options` time window, committed rows
+    /// first, with staged entries of the default collection replacing the
+    /// committed rows they supersede. The first `offset` rows are skipped and
+    /// up to `limit` committed rows are read after them (plus one per staged
+    /// entry); callers truncate the combined rows. With `order` every
+    /// matching row is read but only the first `offset + limit` in that order
+    /// are kept. With `version`, only that version's committed rows are read.
+    /// Soft-deleted rows are left out unless `include_deleted` is set.
+    async fn list_batches(
+        &self,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+        options: &ListOptions,
+        columns: &[&str],
+    ) -> Result<Vec<RecordBatch>, LanceError> {
+        let ListOptions {
+            offset,
+            version,
+            order,
+            include_deleted,
+            ..
+        } = *options;
+        let filter = with_meta_filters(
+            None,
+            &created_window(options.created_after, options.created_before),
+        );
+        let filter = self.scoped(filter.as_deref());
+        let filter = filter.as_deref();
+        let table_name = self.table_or_default(options.collection.as_deref());
+        let db = self.connect().await?;
+        let mut columns = columns.to_vec();
+        if order.is_some_and(|o| o != SortOrder::KeyAsc) && !columns.contains(&"created_at") {
+            columns.push("created_at");
+        }
+
+        let staged = if table_name == self.default_table && version.is_none() {
+            self.staged_view().await?
+        } else {
+            None
+        };
+        let extra = staged.as_ref().map_or(0, |v| v.keys.len());
+
+        // Staged rows are merged and ordered rows sorted in memory, so the
+        // offset can only be pushed down to the query when neither applies.
+        // The engine can't sort, so an ordered list reads every matching row
+        // but only keeps the first `offset + limit` in order as they stream in.
+        let offset = offset.unwrap_or(0) as usize;
+        let in_memory = staged.is_some() || order.is_some();
+        let (query_offset, skip) = if in_memory { (0, offset) } else { (offset, 0) };
+        let keep = limit.map(|l| l as usize + skip);
+        let query_limit = limit
+            .filter(|_| order.is_none())
+            .map(|l| l as usize + extra + skip);
+
+        let mut batches = Vec::new();
+        if let Some(table) = self.open_table_at(&db, table_name, version).await? {
+            let live = if include_deleted {
+                filter.map(String::from)
+            } else {
+                live_filter(&table, filter).await?
+            };
+            let mut rows =
+                prefix_stream(&table, prefix, live.as_deref(), query_limit, query_offset, &columns)
+                    .await?;
+            while let Some(batch) = rows.try_next().await.map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })? {
+                batches.push(match &staged {
+                    Some(view) => view.mask_superseded(&batch)?,
+                    None => batch,
+                });
+                if let (Some(order), Some(_)) = (order, keep) {
+                    batches = first_sorted(batches, order, keep)?;
+                }
+            }
+        }
+
+        if let Some(view) = staged {
+            let staged_batches = prefix_batches(&view.table, prefix, filter, None, 0, &columns).await;
+            view.discard().await;
+            batches.extend(staged_batches?);
+        }
+        if let Some(order) = order {
+            batches = first_sorted(batches, order, keep)?;
+        }
+        Ok(skip_rows(batches, skip))
+    }
+
+    /// Schema for collections this handle creates.
+    fn new_table_schema(&self) -> Schema {
+        make_schema(
+            self.embedding_dim,
+            self.enable_tags,
+            self.embedding_dtype,
+            self.embedding2_dim,
+            self.dedup_by_text,
+            &self.extra_columns,
+        )
+    }
+
+    /// Validate `entries` for `store_many` and turn them into rows, stamping
+    /// a missing `created_at` with the current time. The first invalid entry
+    /// fails the whole call.
+    fn prepare_entries(&self, entries: Vec<MemoryEntry>) -> Result<Vec<PendingEntry>, LanceError> {
+        let mut entries = entries;
+        for (i, e) in entries.iter_mut().enumerate() {
+            if e.embedding.len() != self.embedding_dim as usize {
+                return Err(LanceError::DimensionMismatch {
+                    expected: self.embedding_dim,
+                    actual: e.embedding.len() as i32,
+                    key: Some(e.key.clone()),
+                    index: Some(i as u32),
+                });
+            }
+            if let Some(j) = first_non_finite(&e.embedding) {
+                return Err(LanceError::InsertError {
+                    msg: format!("entry {i}: embedding[{j}] is not finite"),
+                });
+            }
+            if self.normalize && !l2_normalize(&mut e.embedding) {
+                return Err(LanceError::InsertError {
+                    msg: format!("entry {i}: cannot normalize a zero embedding"),
+                });
+            }
+            if e.tags.is_some() && !self.enable_tags {
+                return Err(LanceError::InsertError {
+                    msg: format!("entry {i}: tags require a handle opened with enable_tags"),
+                });
+            }
+            if let Some(ms) = e.created_at.filter(|ms| *ms < 0) {
+                return Err(LanceError::InsertError {
+                    msg: format!("entry {i}: created_at {ms} is negative"),
+                });
+            }
+            e.embedding2 = self.check_embedding2(e.embedding2.take(), &e.key, Some(i as u32))?;
+            self.check_columns(e.columns.as_deref().unwrap_or_default(), &format!("entry {i}: "))?;
+        }
+        let now = chrono_now_ms();
+        Ok(entries
+            .into_iter()
+            .map(|e| PendingEntry {
+                key: e.key,
+                agent_id: self.agent_scope.clone().unwrap_or(e.agent_id),
+                text: e.text,
+                embedding: e.embedding,
+                metadata: e.metadata,
+                created_at: e.created_at.unwrap_or(now),
+                tags: e.tags,
+                embedding2: e.embedding2,
+                columns: e.columns.unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Validate the `embedding2` of the entry for `key`, normalizing it when
+    /// the handle normalizes. `index` is the entry's position in a
+    /// `store_many` batch, and prefixes error messages.
+    fn check_embedding2(
+        &self,
+        embedding2: Option<Vec<f32>>,
+        key: &str,
+        index: Option<u32>,
+    ) -> Result<Option<Vec<f32>>, LanceError> {
+        let Some(mut embedding2) = embedding2 else {
+            return Ok(None);
+        };
+        let context = index.map(|i| format!("entry {i}: ")).unwrap_or_default();
+        let Some(dim2) = self.embedding2_dim else {
+            return Err(LanceError::InsertError {
+                msg: format!("{context}embedding2 requires a handle opened with embedding2_dim"),
+            });
+        };
+        if embedding2.len() != dim2 as usize {
+            return Err(LanceError::DimensionMismatch {
+                expected: dim2,
+                actual: embedding2.len() as i32,
+                key: Some(key.to_string()),
+                index,
+            });
+        }
+        if let Some(i) = first_non_finite(&embedding2) {
+            return Err(LanceError::InsertError {
+                msg: format!("{context}embedding2[{i}] is not finite"),
+            });
+        }
+        if self.normalize && !l2_normalize(&mut embedding2) {
+            return Err(LanceError::InsertError {
+                msg: format!("{context}cannot normalize a zero embedding2"),
+            });
+        }
+        Ok(Some(embedding2))
+    }
+
+    /// `InsertError` unless every value in `columns` names one of the
+    /// handle's extra columns and has its type. `context` prefixes error
+    /// messages.
+    fn check_columns(&self, columns: &[ColumnEntry], context: &str) -> Result<(), LanceError> {
+        for c in columns {
+            let Some(spec) = self.extra_columns.iter().find(|s| s.name == c.name) else {
+                return Err(LanceError::InsertError {
+                    msg: format!("{context}unknown column {:?}", c.name),
+                });
+            };
+            if c.value.column_type() != spec.column_type {
+                return Err(LanceError::InsertError {
+                    msg: format!(
+                        "{context}column {:?} is {:?}, got a {:?} value",
+                        c.name,
+                        spec.column_type,
+                        c.value.column_type()
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Dimension of the vector column `which`, or `QueryError` when the
+    /// handle has no such column.
+    fn column_dim(&self, which: EmbeddingColumn) -> Result<i32, LanceError> {
+        match which {
+            EmbeddingColumn::Primary => Ok(self.embedding_dim),
+            EmbeddingColumn::Secondary => self.embedding2_dim.ok_or_else(|| LanceError::QueryError {
+                msg: "searching embedding2 requires a handle opened with embedding2_dim".into(),
+            }),
+        }
+    }
+
+    fn make_batch(
+        &self,
+        schema: &Arc<Schema>,
+        entries: Vec<PendingEntry>,
+    ) -> Result<RecordBatch, LanceError> {
+        let n = entries.len();
+        let mut keys = Vec::with_capacity(n);
+        let mut agent_ids = Vec::with_capacity(n);
+        let mut texts = Vec::with_capacity(n);
+        let mut flat = Vec::with_capacity(n * self.embedding_dim as usize);
+        let mut metadatas = Vec::with_capacity(n);
+        let mut created_ats = Vec::with_capacity(n);
+        let mut tags = ListBuilder::new(StringBuilder::new());
+        let mut embeddings2 = Vec::with_capacity(n);
+        let mut extra = Vec::with_capacity(n);
+        for e in entries {
+            if let Some(i) = first_non_finite(&e.embedding) {
+                return Err(LanceError::InsertError {
+                    msg: format!("key {:?}: embedding[{i}] is not finite", e.key),
+                });
+            }
+            keys.push(e.key);
+            agent_ids.push(e.agent_id);
+            texts.push(e.text);
+            // Flatten embeddings into a single Vec<f32>
+            flat.extend(e.embedding);
+            metadatas.push(e.metadata);
+            created_ats.push(e.created_at);
+            tags.append_option(e.tags.map(|t| t.into_iter().map(Some).collect::<Vec<_>>()));
+            embeddings2.push(e.embedding2);
+            extra.push(e.columns);
+        }
+
+        let item = self.embedding_dtype.item_type();
+        let values = self.embedding_dtype.encode(Float32Array::from(flat))?;
+        let field = Arc::new(Field::new("item", item.clone(), true));
+        let embedding_array = FixedSizeListArray::new(field, self.embedding_dim, values, None);
+
+        let key_array = StringArray::from(keys);
+        let agent_id_array = StringArray::from(agent_ids);
+        let text_array = StringArray::from(texts);
+        let metadata_array = StringArray::from(metadatas);
+        let created_at_array = Int64Array::from(created_ats);
+
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(key_array),
+            Arc::new(agent_id_array),
+            Arc::new(text_array.clone()),
+            Arc::new(embedding_array),
+            Arc::new(metadata_array),
+            Arc::new(created_at_array),
+        ];
+        if schema.column_with_name("tags").is_some() {
+            columns.push(Arc::new(tags.finish()));
+        }
+        if let Some(dim2) = self.embedding2_dim.filter(|_| schema.column_with_name("embedding2").is_some()) {
+            // Null rows still take `dim2` slots in the child array.
+            let valid: Vec<bool> = embeddings2.iter().map(Option::is_some).collect();
+            let flat: Vec<f32> = embeddings2
+                .into_iter()
+                .flat_map(|e| e.unwrap_or_else(|| vec![0.0; dim2 as usize]))
+                .collect();
+            let values = self.embedding_dtype.encode(Float32Array::from(flat))?;
+            let field = Arc::new(Field::new("item", item.clone(), true));
+            columns.push(Arc::new(FixedSizeListArray::new(
+                field,
+                dim2,
+                values,
+                Some(NullBuffer::from(valid)),
+            )));
+        }
+        if schema.column_with_name("text_hash").is_some() {
+            let hashes = text_array.iter().map(|t| t.map(text_hash)).collect::<StringArray>();
+            columns.push(Arc::new(hashes));
+        }
+        for spec in &self.extra_columns {
+            if schema.column_with_name(&spec.name).is_none() {
+                continue;
+            }
+            // When a column repeats in one entry the last value wins.
+            let values = extra
+                .iter()
+                .map(|cols| cols.iter().rev().find(|c| c.name == spec.name).map(|c| &c.value));
+            columns.push(extra_column_array(spec, values));
+        }
+
+        RecordBatch::try_new(schema.clone(), columns)
+        .map_err(|e| LanceError::InsertError {
+            msg: format!("Failed to create record batch: {e}"),
+        })
+    }
+
+    /// `entries` as record batches of at most `chunk_size` rows each, each
+    /// converted only when it is read (see `EntryBatches`).
+    fn make_batches(
+        &self,
+        schema: &Arc<Schema>,
+        entries: Arc<Vec<PendingEntry>>,
+        chunk_size: usize,
+        null_deleted_at: bool,
+    ) -> Result<EntryBatches, LanceError> {
+        let handle = self.this.upgrade().ok_or_else(|| LanceError::ConnectionFailed {
+            msg: "handle was dropped".into(),
+        })?;
+        Ok(EntryBatches {
+            handle,
+            schema: schema.clone(),
+            entries,
+            chunk_size,
+            null_deleted_at,
+            next: Some(0),
+            failed: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// `write_chunked` with `DEFAULT_CHUNK_ROWS`.
+    async fn write_entries(
+        &self,
+        table_name: &str,
+        entries: Vec<PendingEntry>,
+    ) -> Result<WriteStats, LanceError> {
+        self.write_chunked(table_name, entries, DEFAULT_CHUNK_ROWS, None)
+            .await
+    }
+
+    /// Upsert `entries` into `table_name` with a single atomic merge-insert on
+    /// `key`, creating the table on first write. The rows are fed to the
+    /// engine as one stream of `chunk_size`-row batches, and `progress` hears
+    /// about each batch as the engine takes it.
+    /// Later entries win when the same key appears more than once.
+    async fn write_chunked(
+        &self,
+        table_name: &str,
+        entries: Vec<PendingEntry>,
+        chunk_size: usize,
+        progress: Option<Arc<dyn ProgressReporter>>,
+    ) -> Result<WriteStats, LanceError> {
+        let db = self.connect().await?;
+        let schema = Arc::new(self.new_table_schema());
+        let entries = self.dedup(entries);
+        let _serialised = self.write_lock.lock().await;
+        // Checked under the write lock so two stores through this handle
+        // can't both insert the same text.
+        let entries = if self.dedup_by_text {
+            let table = self.open_table_cached(&db, table_name).await?;
+            let entries = self.drop_repeated_texts(table.as_ref(), entries).await?;
+            if entries.is_empty() {
+                return Ok(WriteStats::default());
+            }
+            entries
+        } else {
+            entries
+        };
+        let all_inserted = WriteStats {
+            inserted: entries.len() as u64,
+            replaced: 0,
+        };
+        let entries = Arc::new(entries);
+        let total = all_inserted.inserted;
+        // Every attempt gets a fresh stream that converts the entries one
+        // chunk at a time as the engine reads them, so no attempt holds more
+        // than a chunk of converted rows. `failed` reports why a stream
+        // stopped early, which the engine only passes on as text.
+        let stream = |null_deleted_at: bool| {
+            let batches = self.make_batches(&schema, entries.clone(), chunk_size, null_deleted_at)?;
+            let failed = batches.failed.clone();
+            let schema = batches.schema();
+            let progress = progress.clone();
+            let mut done = 0;
+            let batches = batches.inspect(move |batch| {
+                if let Ok(batch) = batch {
+                    done += batch.num_rows() as u64;
+                    if let Some(p) = &progress {
+                        p.on_progress(done, total);
+                    }
+                }
+            });
+            Ok::<_, LanceError>((RecordBatchIterator::new(batches, schema), failed))
+        };
+        let conversion_error = |failed: &Arc<Mutex<Option<LanceError>>>| failed.lock().unwrap().take();
+        let _written = TableWriteGuard::new(self, table_name);
+
+        let tables = db
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        if !tables.contains(&table_name.to_string()) {
+            let (data, failed) = stream(false)?;
+            let created = db
+                .create_table(table_name, data)
+                .write_options(self.unsafe_write_options(table_name, WriteMode::Create))
+                .execute()
+                .await;
+            if let Some(e) = conversion_error(&failed) {
+                return Err(e);
+            }
+            match created {
+                Ok(_) => return Ok(all_inserted),
+                // Lost a race with another first write; upsert into its table.
+                Err(lancedb::Error::TableAlreadyExists { .. }) => {}
+                Err(e) if is_commit_conflict(&e) => {}
+                Err(e) => {
+                    return Err(LanceError::TableError {
+                        msg: e.to_string(),
+                    })
+                }
+            }
+        }
+
+        let table = match self.open_table_unsafe(&db, table_name).await {
+            Ok(table) => table,
+            Err(_) => {
+                // Table is corrupted (e.g. partial write) — drop and recreate
+                let _ = db.drop_table(table_name, &[]).await;
+                let (data, failed) = stream(false)?;
+                let created = db
+                    .create_table(table_name, data)
+                    .write_options(self.unsafe_write_options(table_name, WriteMode::Create))
+                    .execute()
+                    .await;
+                if let Some(e) = conversion_error(&failed) {
+                    return Err(e);
+                }
+                created.map_err(|e| LanceError::TableError {
+                    msg: e.to_string(),
+                })?;
+                return Ok(all_inserted);
+            }
+        };
+
+        if self.enable_tags {
+            ensure_column(&table, tags_field()).await?;
+        }
+        if let Some(dim2) = self.embedding2_dim {
+            ensure_column(&table, embedding2_field(dim2, self.embedding_dtype)).await?;
+        }
+        if self.dedup_by_text {
+            ensure_column(&table, text_hash_field()).await?;
+        }
+        for spec in &self.extra_columns {
+            ensure_column(&table, spec.field()).await?;
+        }
+        let null_deleted_at = has_deleted_at(&table).await?;
+        let mut attempt = 1;
+        loop {
+            let (data, failed) = stream(null_deleted_at)?;
+            let result = if self.append_only {
+                table
+                    .add(data)
+                    .write_options(self.unsafe_write_options(table_name, WriteMode::Append))
+                    .execute()
+                    .await
+                    .map(|_| all_inserted.clone())
+            } else {
+                let mut merge = table.merge_insert(&["key"]);
+                // A scoped handle must not overwrite another agent's key.
+                let same_agent = self
+                    .agent_scope
+                    .as_ref()
+                    .map(|_| "target.agent_id = source.agent_id".to_string());
+                merge.when_matched_update_all(same_agent).when_not_matched_insert_all();
+                merge.execute(Box::new(data)).await.map(|r| WriteStats {
+                    inserted: r.num_inserted_rows,
+                    replaced: r.num_updated_rows,
+                })
+            };
+            if let Some(e) = conversion_error(&failed) {
+                return Err(e);
+            }
+            match result {
+                Err(e) if is_commit_conflict(&e) && attempt < self.max_write_attempts => {
+                    let backoff = WRITE_RETRY_BACKOFF_MS << (attempt - 1).min(10);
+                    tokio::time::sleep(Duration::from_millis(backoff)).await;
+                    // Pick up the version that won before trying again.
+                    let _ = table.checkout_latest().await;
+                    attempt += 1;
+                }
+                result => return result.map_err(write_error),
+            }
+        }
+    }
+
+    /// Replace every row of `table_name` with `entries` in one overwrite
+    /// commit, creating the table if needed. Staged entries of the default
+    /// collection are discarded with the rows they would have joined.
+    async fn overwrite_entries(
+        &self,
+        table_name: &str,
+        entries: Vec<PendingEntry>,
+    ) -> Result<u64, LanceError> {
+        let db = self.connect().await?;
+        let entries = if self.dedup_by_text {
+            self.drop_repeated_texts(None, entries).await?
+        } else {
+            entries
+        };
+        let schema = Arc::new(self.new_table_schema());
+        let rows = entries.len() as u64;
+        let batches = self.make_batches(&schema, Arc::new(entries), DEFAULT_CHUNK_ROWS, false)?;
+        let failed = batches.failed.clone();
+        let batch_schema = batches.schema();
+
+        let staging = self.staging.as_ref().filter(|_| table_name == self.default_table);
+        let _flushing = match staging {
+            Some(staging) => Some(staging.flush_lock.lock().await),
+            None => None,
+        };
+        let _serialised = self.write_lock.lock().await;
+        let _written = TableWriteGuard::new(self, table_name);
+        let written = db
+            .create_table(table_name, RecordBatchIterator::new(batches, batch_schema))
+            .mode(CreateTableMode::Overwrite)
+            .write_options(self.unsafe_write_options(table_name, WriteMode::Overwrite))
+            .execute()
+            .await;
+        if let Some(e) = failed.lock().unwrap().take() {
+            return Err(e);
+        }
+        written.map_err(write_error)?;
+        if let Some(staging) = staging {
+            staging.pending.lock().unwrap().clear();
+        }
+        Ok(rows)
+    }
+
+    /// `entries` without those whose text an earlier entry, or a live row of
+    /// `table` under another key, already stores for the same agent (see
+    /// `OpenOptions::dedup_by_text`).
+    async fn drop_repeated_texts(
+        &self,
+        table: Option<&lancedb::Table>,
+        entries: Vec<PendingEntry>,
+    ) -> Result<Vec<PendingEntry>, LanceError> {
+        let hashes: Vec<String> = entries.iter().map(|e| text_hash(&e.text)).collect();
+        // (agent_id, text_hash) -> key of the row holding that text.
+        let mut stored = HashMap::new();
+        if let Some(table) = table {
+            let schema = table.schema().await.map_err(|e| LanceError::SchemaError {
+                msg: e.to_string(),
+            })?;
+            if schema.column_with_name("text_hash").is_some() {
+                let filter = format!(
+                    "text_hash IN ({})",
+                    sql_string_list(hashes.iter().map(String::as_str))
+                );
+                let live = live_filter(table, Some(&filter)).await?;
+                let batches =
+                    prefix_batches(table, None, live.as_deref(), None, 0, &["key", "agent_id", "text_hash"])
+                        .await?;
+                for batch in &batches {
+                    let keys = required_column::<StringArray>(batch, "key")?;
+                    let agents = required_column::<StringArray>(batch, "agent_id")?;
+                    let Some(row_hashes) = batch
+                        .column_by_name("text_hash")
+                        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                    else {
+                        continue;
+                    };
+                    for i in 0..batch.num_rows() {
+                        if let Some(hash) = nullable_string(Some(row_hashes), i) {
+                            stored
+                                .entry((agents.value(i).to_string(), hash))
+                                .or_insert_with(|| keys.value(i).to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(entries
+            .into_iter()
+            .zip(hashes)
+            .filter(|(e, hash)| {
+                let key = stored
+                    .entry((e.agent_id.clone(), hash.clone()))
+                    .or_insert_with(|| e.key.clone());
+                *key == e.key
+            })
+            .map(|(e, _)| e)
+            .collect())
+    }
+
+    /// Start building the vector index of `table_name` on `RUNTIME` when it
+    /// has reached `OpenOptions::auto_index_threshold` rows without one.
+    /// Int8 and binary embeddings have no vector index, so are never built.
+    /// The caller's write has already succeeded, so a failed build is only
+    /// logged; the next write tries again.
+    fn spawn_auto_index(&self, table_name: &str) {
+        let Some(threshold) = self.auto_index_threshold else {
+            return;
+        };
+        if matches!(self.embedding_dtype, EmbeddingDtype::Int8 { .. } | EmbeddingDtype::Binary) {
+            return;
+        }
+        if !self.auto_indexing.lock().unwrap().insert(table_name.to_string()) {
+            return;
+        }
+        let this = self.this.clone();
+        let table_name = table_name.to_string();
+        RUNTIME.spawn(async move {
+            let Some(handle) = this.upgrade() else {
+                return;
+            };
+            if let Err(e) = handle.auto_index(&table_name, threshold.max(PQ_MIN_ROWS)).await {
+                log::warn!("auto-index of {table_name} failed: {e}");
+            }
+            handle.auto_indexing.lock().unwrap().remove(&table_name);
+        });
+    }
+
+    async fn auto_index(&self, table_name: &str, threshold: u64) -> Result<(), LanceError> {
+        self.ensure_open()?;
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(());
+        };
+        if has_vector_index(&table, "embedding").await? {
+            return Ok(());
+        }
+        let rows = table
+            .count_rows(None)
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })? as u64;
+        if rows < threshold {
+            return Ok(());
+        }
+        self.create_index(Some(table_name.to_string()), None, None, None)
+            .await
+    }
+
+    /// Enforce `OpenOptions::max_rows` on `table_name` with one delete of
+    /// every row ordered (by `created_at`, then key) before the newest
+    /// `max_rows`. Only the rows being evicted are held while scanning.
+    async fn evict_over_cap(&self, table_name: &str) -> Result<(), LanceError> {
+        let Some(max_rows) = self.max_rows else {
+            return Ok(());
+        };
+        let db = self.connect().await?;
+        let Some(table) = self.open_table_cached(&db, table_name).await? else {
+            return Ok(());
+        };
+        let rows = table
+            .count_rows(None)
+            .await
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })? as u64;
+        if rows <= max_rows {
+            return Ok(());
+        }
+        let excess = (rows - max_rows) as usize;
+
+        // Only the `excess` oldest rows seen so far are kept while streaming.
+        let mut stream = prefix_stream(&table, None, None, None, 0, &["key", "created_at"]).await?;
+        let mut oldest = Vec::new();
+        while let Some(batch) = stream.try_next().await.map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })? {
+            oldest.push(batch);
+            oldest = first_sorted(oldest, SortOrder::CreatedAtAsc, Some(excess))?;
+        }
+        let Some(last) = oldest.last().filter(|b| b.num_rows() > 0) else {
+            return Ok(());
+        };
+        let i = last.num_rows() - 1;
+        let ms = required_column::<Int64Array>(last, "created_at")?.value(i);
+        let key = required_column::<StringArray>(last, "key")?.value(i);
+        let cutoff = format!(
+            "created_at < {ms} OR (created_at = {ms} AND key <= {})",
+            sql_quote_literal(key)
+        );
+
+        let _written = TableWriteGuard::new(self, table_name);
+        table
+            .delete(&cutoff)
+            .await
+            .map_err(|e| LanceError::DeleteError {
+                msg: e.to_string(),
+            })?;
+        Ok(())
+    }
+
+    /// `count` over `table_name`; `live` leaves out soft-deleted rows.
+    async fn count_where(
+        &self,
+        table_name: &str,
+        filter: Option<String>,
+        live: bool,
+    ) -> Result<u64, LanceError> {
+        let filter = self.scoped(filter.as_deref());
+        let db = self.connect().await?;
+        let count_rows = |table: lancedb::Table, filter: Option<String>| async move {
+            table
+                .count_rows(filter)
+                .await
+                .map(|n| n as u64)
+                .map_err(|e| LanceError::QueryError {
+                    msg: e.to_string(),
+                })
+        };
+
+        let table = self.open_table_cached(&db, table_name).await?;
+        let staged = if table_name == self.default_table {
+            self.staged_view().await?
+        } else {
+            None
+        };
+        let committed = match &table {
+            Some(t) if live => live_filter(t, filter.as_deref()).await?,
+            _ => filter.clone(),
+        };
+        let mut total = match &table {
+            Some(t) => count_rows(t.clone(), committed.clone()).await?,
+            None => 0,
+        };
+
+        if let Some(view) = staged {
+            // Committed rows a staged entry replaces are counted once, via the view.
+            let keys = sql_string_list(view.keys.iter().map(String::as_str));
+            let superseded = match &committed {
+                Some(f) => format!("({f}) AND key IN ({keys})"),
+                None => format!("key IN ({keys})"),
+            };
+            let counts = async {
+                let replaced = match &table {
+                    // Append-only views supersede nothing.
+                    Some(t) if !view.keys.is_empty() => count_rows(t.clone(), Some(superseded)).await?,
+                    _ => 0,
+                };
+                let pending = count_rows(view.table.clone(), filter.clone()).await?;
+                Ok::<_, LanceError>((replaced, pending))
+            }
+            .await;
+            view.discard().await;
+            let (replaced, pending) = counts?;
+            total = total.saturating_sub(replaced) + pending;
+        }
+
+        Ok(total)
+    }
+
+    /// `dedup_entries`, except in append-only mode where every entry is kept.
+    fn dedup(&self, entries: Vec<PendingEntry>) -> Vec<PendingEntry> {
+        if self.append_only {
+            entries
+        } else {
+            dedup_entries(entries)
+        }
+    }
+
+    /// Reject a `search` query vector of the wrong length for
+    /// `options.which` or with a non-finite component. `index` names the
+    /// query in `search_many`'s errors.
+    fn check_query(
+        &self,
+        query_vector: &[f32],
+        options: &SearchOptions,
+        index: Option<u32>,
+    ) -> Result<(), LanceError> {
+        let expected = self.column_dim(options.which.unwrap_or_default())?;
+        let dim = expected as usize;
+        let len_ok = if options.prefix_search {
+            (1..=dim).contains(&query_vector.len())
+        } else {
+            query_vector.len() == dim
+        };
+        if !len_ok {
+            return Err(LanceError::DimensionMismatch {
+                expected,
+                actual: query_vector.len() as i32,
+                key: None,
+                index,
+            });
+        }
+        if let Some(i) = first_non_finite(query_vector) {
+            let query = index.map(|n| format!("query {n}: ")).unwrap_or_default();
+            return Err(LanceError::QueryError {
+                msg: format!("{query}query_vector[{i}] is not finite"),
+            });
+        }
+        if options.prefix_search && options.version.is_some() {
+            return Err(LanceError::QueryError {
+                msg: "version is not supported with prefix_search".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The staged entries a search of `table_name` with `options` reads, or
+    /// `None` when it reads only committed rows. The caller discards it.
+    async fn search_view(
+        &self,
+        table_name: &str,
+        options: &SearchOptions,
+    ) -> Result<Option<StagedView>, LanceError> {
+        if options.committed_only || options.version.is_some() || table_name != self.default_table {
+            return Ok(None);
+        }
+        self.staged_view().await
+    }
+
+    /// `search_detailed` for a query vector that passed `check_query`,
+    /// merging `staged` rather than snapshotting the staged entries itself.
+    #[allow(clippy::too_many_arguments)]
+    async fn search_with_view(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        mut query_vector: Vec<f32>,
+        limit: u32,
+        filter: Option<String>,
+        options: &SearchOptions,
+        staged: Option<&StagedView>,
+    ) -> Result<SearchResponse, LanceError> {
+        let which = options.which.unwrap_or_default();
+        if self.normalize && !options.prefix_search && !l2_normalize(&mut query_vector) {
+            return Err(LanceError::QueryError {
+                msg: "cannot normalize a zero query_vector".into(),
+            });
+        }
+
+        let filter = with_meta_filters(filter, options.filters.as_deref().unwrap_or_default());
+        let filter = with_meta_filters(
+            filter,
+            &created_window(options.created_after, options.created_before),
+        );
+        let filter = self.with_tag_filter(filter, options)?;
+        // Rows stored without an `embedding2` can't match a search on it.
+        let filter = match (which, filter) {
+            (EmbeddingColumn::Primary, filter) => filter,
+            (EmbeddingColumn::Secondary, Some(f)) => Some(format!("({f}) AND embedding2 IS NOT NULL")),
+            (EmbeddingColumn::Secondary, None) => Some("embedding2 IS NOT NULL".into()),
+        };
+
+        let offset = options.offset.unwrap_or(0) as usize;
+        if options.prefix_search {
+            let mut results = self
+                .prefix_search(
+                    db,
+                    table_name,
+                    &query_vector,
+                    limit as usize + offset,
+                    filter.as_deref(),
+                    options,
+                    staged,
+                )
+                .await?;
+            results.drain(..offset.min(results.len()));
+            if let Some(min) = options.min_score {
+                results.retain(|r| r.score >= min);
+            }
+            return non_empty(
+                SearchResponse {
+                    results,
+                    approximate: false,
+                },
+                options,
+            );
+        }
+
+        let QueryRows {
+            batches,
+            merged,
+            version,
+            approximate,
+        } = self
+            .nearest_with_view(
+                db,
+                table_name,
+                query_vector,
+                limit as usize,
+                filter.as_deref(),
+                options,
+                staged,
+            )
+            .await?;
+
+        let mut results = Vec::new();
+        for batch in &batches {
+            let keys = batch
+                .column_by_name("key")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let texts = batch
+                .column_by_name("text")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let metas = batch
+                .column_by_name("metadata")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let distances = batch
+                .column_by_name("_distance")
+                .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
+            let row_ids = batch
+                .column_by_name("_rowid")
+                .and_then(|c| c.as_any().downcast_ref::<UInt64Array>());
+
+            let (keys, texts) = match (keys, texts) {
+                (Some(k), Some(t)) => (k, t),
+                _ => continue,
+            };
+            let agent_ids = required_column::<StringArray>(batch, "agent_id")?;
+            let created_ats = required_column::<Int64Array>(batch, "created_at")?;
+
+            for i in 0..batch.num_rows() {
+                let distance = distances.map(|d| d.value(i));
+                let score = distance.map(|d| self.score(d)).unwrap_or(0.0);
+                if options.min_score.is_some_and(|min| score < min) {
+                    continue;
+                }
+                let row_id = row_ids.map(|r| r.value(i));
+                let metadata = nullable_string(metas, i);
+                results.push(SearchResult {
+                    key: keys.value(i).to_string(),
+                    collection: table_name.to_string(),
+                    agent_id: agent_ids.value(i).to_string(),
+                    text: texts.value(i).to_string(),
+                    score,
+                    distance: distance.map(f64::from),
+                    meta: meta_pairs(metadata.as_deref()),
+                    metadata,
+                    created_at: created_ats.value(i),
+                    row_id,
+                    fragment_id: row_id.map(|r| r >> 32),
+                    dataset_version: row_id.and(version),
+                    embedding: options
+                        .include_embedding
+                        .then(|| embedding_at(batch, i, self.embedding_dtype))
+                        .flatten(),
+                });
+            }
+        }
+
+        if merged {
+            results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+            results.drain(..offset.min(results.len()));
+            results.truncate(limit as usize);
+        }
+
+        non_empty(
+            SearchResponse {
+                results,
+                approximate,
+            },
+            options,
+        )
+    }
+
+    /// AND the `has_tags` predicate from `options` onto `filter`.
+    fn with_tag_filter(
+        &self,
+        filter: Option<String>,
+        options: &SearchOptions,
+    ) -> Result<Option<String>, LanceError> {
+        let tags = match &options.has_tags {
+            Some(tags) if !tags.is_empty() => tags,
+            _ => return Ok(filter),
+        };
+        if !self.enable_tags {
+            return Err(LanceError::QueryError {
+                msg: "has_tags requires a handle opened with enable_tags".into(),
+            });
+        }
+
+        let list = sql_string_list(tags.iter().map(String::as_str));
+        let func = if options.match_any_tag {
+            "array_has_any"
+        } else {
+            "array_has_all"
+        };
+        let tag_filter = format!("{func}(tags, make_array({list}))");
+
+        Ok(Some(match filter {
+            Some(f) => format!("({f}) AND {tag_filter}"),
+            None => tag_filter,
+        }))
+    }
+
+    /// Materialise pending staged entries into a scratch in-memory table, so
+    /// reads can run exactly the same query (filter, limit, distance) against
+    /// the buffer as against the real table. Returns `None` when nothing is staged.
+    async fn staged_view(&self) -> Result<Option<StagedView>, LanceError> {
+        let Some(staging) = &self.staging else {
+            return Ok(None);
+        };
+        let entries = staging.snapshot();
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = Arc::new(self.new_table_schema());
+        let entries = self.dedup(entries);
+        // Appended rows never supersede committed ones.
+        let keys = if self.append_only {
+            HashSet::new()
+        } else {
+            entries.iter().map(|e| e.key.clone()).collect()
+        };
+        let batch = self.make_batch(&schema, entries)?;
+
+        // In-memory stores are shared process-wide, so every view gets its own table.
+        static NEXT_VIEW: AtomicU64 = AtomicU64::new(0);
+        let name = format!("staged_{}", NEXT_VIEW.fetch_add(1, Ordering::Relaxed));
+
+        let db = lancedb::connect("memory://staged")
+            .execute()
+            .await
+            .map_err(|e| LanceError::ConnectionFailed {
+                msg: e.to_string(),
+            })?;
+        let table = db
+            .create_table(&name, RecordBatchIterator::new(vec![Ok(batch)], schema))
+            .execute()
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: e.to_string(),
+            })?;
+
+        Ok(Some(StagedView { db, table, keys }))
+    }
+
+    /// Map an engine distance to `SearchResult::score` (see `DistanceMetric`).
+    fn score(&self, distance: f32) -> f64 {
+        match self.distance_metric {
+            Some(DistanceMetric::L2 | DistanceMetric::Hamming) => -(distance as f64),
+            _ => 1.0 - distance as f64,
+        }
+    }
+
+    /// The distance the engine would report for `a` and `b` under the
+    /// handle's metric.
+    fn prefix_distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        match self.distance_metric.unwrap_or(DistanceMetric::L2) {
+            DistanceMetric::L2 => a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum(),
+            DistanceMetric::Dot => 1.0 - dot,
+            DistanceMetric::Cosine => {
+                let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+                1.0 - dot / (norm(a) * norm(b))
+            }
+            DistanceMetric::Hamming => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| ((*x as u8) ^ (*y as u8)).count_ones() as f32)
+                .sum(),
+        }
+    }
+
+    /// Brute-force nearest neighbours comparing `query_vector` against the
+    /// first `query_vector.len()` dimensions of every stored embedding (in
+    /// the column chosen by `options.which`), using
+    /// the distance the engine would report for the handle's metric, with
+    /// `staged` merged in.
+    #[allow(clippy::too_many_arguments)]
+    async fn prefix_search(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+        options: &SearchOptions,
+        staged: Option<&StagedView>,
+    ) -> Result<Vec<SearchResult>, LanceError> {
+        let QueryRows {
+            batches, version, ..
+        } = self
+            .scan_with_view(
+                db,
+                table_name,
+                filter,
+                options.include_provenance,
+                staged,
+                options.include_deleted,
+            )
+            .await?;
+
+        let k = query_vector.len();
+        let column = options.which.unwrap_or_default().name();
+        let mut results = Vec::new();
+        for batch in &batches {
+            let keys = batch
+                .column_by_name("key")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let texts = batch
+                .column_by_name("text")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let metas = batch
+                .column_by_name("metadata")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let embeddings = batch
+                .column_by_name(column)
+                .and_then(|c| c.as_any().downcast_ref::<FixedSizeListArray>());
+            let row_ids = batch
+                .column_by_name("_rowid")
+                .and_then(|c| c.as_any().downcast_ref::<UInt64Array>());
+
+            let (keys, texts, embeddings) = match (keys, texts, embeddings) {
+                (Some(k), Some(t), Some(e)) => (k, t, e),
+                _ => continue,
+            };
+            let Some(values) = self.embedding_dtype.decode(embeddings.values()) else {
+                continue;
+            };
+            let agent_ids = required_column::<StringArray>(batch, "agent_id")?;
+            let created_ats = required_column::<Int64Array>(batch, "created_at")?;
+
+            for i in 0..batch.num_rows() {
+                let start = embeddings.value_offset(i) as usize;
+                let stored = &values.values()[start..start + k];
+                let distance = self.prefix_distance(stored, query_vector);
+                let row_id = row_ids.map(|r| r.value(i));
+                let metadata = nullable_string(metas, i);
+                results.push(SearchResult {
+                    key: keys.value(i).to_string(),
+                    collection: table_name.to_string(),
+                    agent_id: agent_ids.value(i).to_string(),
+                    text: texts.value(i).to_string(),
+                    score: self.score(distance),
+                    distance: Some(distance as f64),
+                    meta: meta_pairs(metadata.as_deref()),
+                    metadata,
+                    created_at: created_ats.value(i),
+                    row_id,
+                    fragment_id: row_id.map(|r| r >> 32),
+                    dataset_version: row_id.and(version),
+                    embedding: options
+                        .include_embedding
+                        .then(|| embedding_at(batch, i, self.embedding_dtype))
+                        .flatten(),
+                });
+            }
+        }
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// `nearest_with_staged` for int8 embeddings, which the engine can't
+    /// search: scans every matching row, appends its `_distance` to the
+    /// quantized query and keeps the best `limit` plus the offset.
+    #[allow(clippy::too_many_arguments)]
+    async fn quantized_nearest(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+        options: &SearchOptions,
+        staged: Option<&StagedView>,
+    ) -> Result<QueryRows, LanceError> {
+        if options.version.is_some() {
+            return Err(LanceError::QueryError {
+                msg: "version is not supported with int8 embeddings".into(),
+            });
+        }
+        let query_vector = self.embedding_dtype.round_trip(query_vector)?;
+        let column = options.which.unwrap_or_default().name();
+        let QueryRows {
+            batches, version, ..
+        } = self
+            .scan_with_view(
+                db,
+                table_name,
+                filter,
+                options.include_provenance,
+                staged,
+                options.include_deleted,
+            )
+            .await?;
+
+        let distances: Vec<Vec<f32>> = batches
+            .iter()
+            .map(|batch| {
+                (0..batch.num_rows())
+                    .map(|i| {
+                        vector_at(batch, column, i, self.embedding_dtype)
+                            .map_or(f32::MAX, |e| self.prefix_distance(&e, &query_vector))
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut ranked: Vec<(f32, usize, usize)> = distances
+            .iter()
+            .enumerate()
+            .flat_map(|(b, d)| d.iter().enumerate().map(move |(i, &d)| (d, b, i)))
+            .collect();
+        ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+        ranked.truncate(limit + options.offset.unwrap_or(0) as usize);
+        let mut keep: Vec<Vec<bool>> = batches.iter().map(|b| vec![false; b.num_rows()]).collect();
+        for (_, b, i) in ranked {
+            keep[b][i] = true;
+        }
+
+        let batches = batches
+            .iter()
+            .zip(distances)
+            .zip(keep)
+            .map(|((batch, distances), keep)| {
+                let mut fields = batch.schema().fields().to_vec();
+                fields.push(Arc::new(Field::new("_distance", DataType::Float32, true)));
+                let mut columns = batch.columns().to_vec();
+                columns.push(Arc::new(Float32Array::from(distances)));
+                let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+                filter_record_batch(&batch, &BooleanArray::from(keep))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| LanceError::QueryError {
+                msg: e.to_string(),
+            })?;
+        Ok(QueryRows {
+            batches,
+            merged: true,
+            version,
+            approximate: false,
+        })
+    }
+
+    /// All rows of `table_name` matching `filter`, merged with staged entries
+    /// when reading the default table and `include_staged` is set.
+    async fn scan_with_staged(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        filter: Option<&str>,
+        with_row_id: bool,
+        include_staged: bool,
+        include_deleted: bool,
+    ) -> Result<QueryRows, LanceError> {
+        let staged = if include_staged && table_name == self.default_table {
+            self.staged_view().await?
+        } else {
+            None
+        };
+        let rows = self
+            .scan_with_view(db, table_name, filter, with_row_id, staged.as_ref(), include_deleted)
+            .await;
+        if let Some(view) = staged {
+            view.discard().await;
+        }
+        rows
+    }
+
+    /// `scan_with_staged` merging the already-built `staged`.
+    async fn scan_with_view(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        filter: Option<&str>,
+        with_row_id: bool,
+        staged: Option<&StagedView>,
+        include_deleted: bool,
+    ) -> Result<QueryRows, LanceError> {
+        let filter = self.scoped(filter);
+        let filter = filter.as_deref();
+
+        let mut batches = Vec::new();
+        let mut version = None;
+        let approximate = false;
+        if let Some(table) = self.open_table_cached(db, table_name).await? {
+            if with_row_id {
+                version = Some(table.version().await.map_err(|e| LanceError::QueryError {
+                    msg: e.to_string(),
+                })?);
+            }
+            let live = if include_deleted {
+                filter.map(String::from)
+            } else {
+                live_filter(&table, filter).await?
+            };
+            batches = scan_batches(&table, live.as_deref(), with_row_id).await?;
+        }
+
+        let Some(view) = staged else {
+            return Ok(QueryRows {
+                batches,
+                merged: false,
+                version,
+                approximate,
+            });
+        };
+
+        let mut batches = batches
+            .iter()
+            .map(|b| view.mask_superseded(b))
+            .collect::<Result<Vec<_>, _>>()?;
+        batches.extend(scan_batches(&view.table, filter, false).await?);
+
+        Ok(QueryRows {
+            batches,
+            merged: true,
+            version,
+            approximate,
+        })
+    }
+
+    /// Nearest-neighbour batches from `table_name`, merged with any staged
+    /// entries when that is the default table and `options.committed_only`
+    /// is unset (see `QueryRows`).
+    async fn nearest_with_staged(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        query_vector: Vec<f32>,
+        limit: usize,
+        filter: Option<&str>,
+        options: &SearchOptions,
+    ) -> Result<QueryRows, LanceError> {
+        let staged = self.search_view(table_name, options).await?;
+        let rows = self
+            .nearest_with_view(db, table_name, query_vector, limit, filter, options, staged.as_ref())
+            .await;
+        if let Some(view) = staged {
+            view.discard().await;
+        }
+        rows
+    }
+
+    /// `nearest_with_staged` merging the already-built `staged`.
+    #[allow(clippy::too_many_arguments)]
+    async fn nearest_with_view(
+        &self,
+        db: &lancedb::Connection,
+        table_name: &str,
+        query_vector: Vec<f32>,
+        limit: usize,
+        filter: Option<&str>,
+        options: &SearchOptions,
+        staged: Option<&StagedView>,
+    ) -> Result<QueryRows, LanceError> {
+        let filter = self.scoped(filter);
+        let filter = filter.as_deref();
+        if let EmbeddingDtype::Int8 { .. } = self.embedding_dtype {
+            return self
+                .quantized_nearest(db, table_name, &query_vector, limit, filter, options, staged)
+                .await;
+        }
+        let query = self.embedding_dtype.encode(Float32Array::from(query_vector))?;
+        let column = options.which.unwrap_or_default().name();
+        // Over-fetch so rows superseded by a staged entry don't leave us short.
+        let extra = staged.as_ref().map_or(0, |v| v.keys.len());
+        // Merged rows are re-ranked in memory, where the caller skips the
+        // offset; otherwise the query applies it.
+        let offset = options.offset.unwrap_or(0) as usize;
+        let (query_offset, skip) = if staged.is_some() { (0, offset) } else { (offset, 0) };
+
+        let mut batches = Vec::new();
+        let mut version = None;
+        let mut approximate = false;
+        if let Some(table) = self.open_table_at(db, table_name, options.version).await? {
+            let indexed = has_vector_index(&table, column).await?;
+            approximate = indexed && !options.exact;
+            if options.include_provenance {
+                version = Some(table.version().await.map_err(|e| LanceError::QueryError {
+                    msg: e.to_string(),
+                })?);
+            }
+            let preset = self.presets.get(table_name);
+            let tuning = CollectionPreset {
+                nprobes: options.nprobes.or(preset.and_then(|p| p.nprobes)),
+                refine_factor: options.refine_factor.or(preset.and_then(|p| p.refine_factor)),
+                ..Default::default()
+            };
+            let live = if options.include_deleted {
+                filter.map(String::from)
+            } else {
+                live_filter(&table, filter).await?
+            };
+            batches = nearest_batches(
+                &table,
+                column,
+                query.clone(),
+                limit + extra + skip,
+                query_offset,
+                live.as_deref(),
+                options.include_provenance,
+                approximate.then_some(&tuning),
+                self.distance_metric,
+                indexed && options.exact,
+            )
+            .await?;
+        }
+
+        let Some(view) = staged else {
+            return Ok(QueryRows {
+                batches,
+                merged: false,
+                version,
+                approximate,
+            });
+        };
+
+        let mut batches = batches
+            .iter()
+            .map(|b| view.mask_superseded(b))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Scratch-table row ids mean nothing to the caller, so never ask for them.
+        let staged_batches = nearest_batches(
+            &view.table,
+            column,
+            query,
+            limit + skip,
+            0,
+            filter,
+            false,
+            None,
+            self.distance_metric,
+            false,
+        )
+        .await?;
+        batches.extend(staged_batches);
+
+        Ok(QueryRows {
+            batches,
+            merged: true,
+            version,
+            approximate,
+        })
+    }
+}
+
+impl Drop for LanceDBHandle {
+    fn drop(&mut self) {
+        // In-memory stores outlive the connection, so the handle that created
+        // one frees its tables here.
+        if !*self.owns_memory_store.get_mut() {
+            return;
+        }
+        if let Some(db) = self.connection.get_mut().take() {
+            RUNTIME.spawn(async move {
+                let _ = db.drop_all_tables(&[]).await;
+            });
+        }
+    }
+}
+
+/// Whether `e` is a commit that lost to a concurrent writer and may succeed
+/// if retried against the latest version.
+fn is_commit_conflict(e: &lancedb::Error) -> bool {
+    matches!(
+        e,
+        lancedb::Error::Lance {
+            source: lance::Error::CommitConflict { .. }
+                | lance::Error::RetryableCommitConflict { .. }
+                | lance::Error::TooMuchWriteContention { .. },
+        }
+    )
+}
+
+/// `InsertError` for a failed write, or `ConcurrencyConflict` when it lost
+/// to a concurrent writer.
+fn write_error(e: lancedb::Error) -> LanceError {
+    if is_commit_conflict(&e) {
+        LanceError::ConcurrencyConflict {
+            msg: e.to_string(),
+        }
+    } else {
+        LanceError::InsertError {
+            msg: e.to_string(),
+        }
+    }
+}
+
+/// Total size of the files under `dir`, recursively. Unreadable entries
+/// count as 0 since the result is only an estimate.
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Evicts a collection from `table_cache` when dropped, i.e. once the write
+/// it guards has finished (or failed), so later reads see the new version.
+struct TableWriteGuard<'a> {
+    handle: &'a LanceDBHandle,
+    name: &'a str,
+}
+
+impl<'a> TableWriteGuard<'a> {
+    fn new(handle: &'a LanceDBHandle, name: &'a str) -> Self {
+        Self { handle, name }
+    }
+}
+
+impl Drop for TableWriteGuard<'_> {
+    fn drop(&mut self) {
+        self.handle.evict_table(self.name);
+    }
+}
+
+/// Record batches of `entries`, `chunk_size` rows each, converted one at a
+/// time as the engine reads them (see `LanceDBHandle::make_batches`). Always
+/// yields at least one batch, which is empty when `entries` is. With
+/// `null_deleted_at` each batch also gets an all-null `deleted_at` column. A
+/// conversion error ends the stream and is kept in `failed`.
+struct EntryBatches {
+    handle: Arc<LanceDBHandle>,
+    schema: Arc<Schema>,
+    entries: Arc<Vec<PendingEntry>>,
+    chunk_size: usize,
+    null_deleted_at: bool,
+    /// First entry of the next batch; `None` once the stream has ended.
+    next: Option<usize>,
+    failed: Arc<Mutex<Option<LanceError>>>,
+}
+
+impl EntryBatches {
+    /// Schema of the batches this yields.
+    fn schema(&self) -> Arc<Schema> {
+        if !self.null_deleted_at {
+            return self.schema.clone();
+        }
+        let mut fields = self.schema.fields().to_vec();
+        fields.push(Arc::new(deleted_at_field()));
+        Arc::new(Schema::new(fields))
+    }
+}
+
+impl Iterator for EntryBatches {
+    type Item = Result<RecordBatch, arrow_schema::ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next?;
+        let end = (start + self.chunk_size).min(self.entries.len());
+        self.next = Some(end).filter(|&end| end < self.entries.len());
+        let chunk = self.entries[start..end].to_vec();
+        let batch = self.handle.make_batch(&self.schema, chunk);
+        let batch = if self.null_deleted_at {
+            batch.and_then(with_null_deleted_at)
+        } else {
+            batch
+        };
+        Some(batch.map_err(|e| {
+            let msg = e.to_string();
+            *self.failed.lock().unwrap() = Some(e);
+            self.next = None;
+            arrow_schema::ArrowError::ExternalError(msg.into())
+        }))
+    }
+}
+
+/// Raw output of `nearest_with_staged` / `scan_with_staged`.
+struct QueryRows {
+    batches: Vec<RecordBatch>,
+    /// Staged rows were merged in, so the caller must re-rank and truncate
+    /// to `limit` itself.
+    merged: bool,
+    /// Table version queried, when provenance was requested.
+    version: Option<u64>,
+    /// The table's vector index served the query, so ranking is approximate.
+    approximate: bool,
+}
+
+/// Scratch table holding a snapshot of staged entries (see `staged_view`).
+struct StagedView {
+    db: lancedb::Connection,
+    table: lancedb::Table,
+    keys: HashSet<String>,
+}
+
+impl StagedView {
+    /// Drop rows from a table batch whose key has a newer staged entry.
+    fn mask_superseded(&self, batch: &RecordBatch) -> Result<RecordBatch, LanceError> {
+        let Some(keys) = batch
+            .column_by_name("key")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        else {
+            return Ok(batch.clone());
+        };
+        let mask: BooleanArray = (0..keys.len())
+            .map(|i| Some(!self.keys.contains(keys.value(i))))
+            .collect();
+        filter_record_batch(batch, &mask).map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })
+    }
+
+    async fn discard(self) {
+        let _ = self.db.drop_table(self.table.name(), &[]).await;
+    }
+}
+
+/// `schema` with the `embedding` column resized to `dim`.
+fn with_embedding_dim(schema: &Schema, dim: i32) -> Arc<Schema> {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|f| match f.data_type() {
+            DataType::FixedSizeList(item, _) if f.name() == "embedding" => Field::new(
+                "embedding",
+                DataType::FixedSizeList(item.clone(), dim),
+                f.is_nullable(),
+            ),
+            _ => f.as_ref().clone(),
+        })
+        .collect();
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Replace the `embedding` column of `batch` with `reembed.embed(text)` for
+/// every row, stored as `dtype`. `offset` is the index of the batch's first
+/// row, for error messages.
+fn reembed_batch(
+    batch: &RecordBatch,
+    schema: &Arc<Schema>,
+    dim: i32,
+    dtype: EmbeddingDtype,
+    offset: u64,
+    reembed: &dyn Reembedder,
+) -> Result<RecordBatch, LanceError> {
+    let texts = batch
+        .column_by_name("text")
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(|| LanceError::SchemaError {
+            msg: "table has no text column to re-embed".to_string(),
+        })?;
+
+    let mut flat = Vec::with_capacity(texts.len() * dim as usize);
+    for i in 0..texts.len() {
+        let embedding = reembed.embed(texts.value(i).to_string());
+        if embedding.len() != dim as usize {
+            return Err(LanceError::InsertError {
+                msg: format!(
+                    "reembedder returned {} values for row {}, expected {dim}",
+                    embedding.len(),
+                    offset + i as u64
+                ),
+            });
+        }
+        flat.extend(embedding);
+    }
+
+    let item = match schema.field_with_name("embedding").map(|f| f.data_type()) {
+        Ok(DataType::FixedSizeList(item, _)) => item.clone(),
+        _ => Arc::new(Field::new("item", DataType::Float32, true)),
+    };
+    let values = dtype.encode(Float32Array::from(flat))?;
+    let embeddings: ArrayRef = Arc::new(FixedSizeListArray::new(item, dim, values, None));
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| {
+            if f.name() == "embedding" {
+                Ok(embeddings.clone())
+            } else {
+                batch
+                    .column_by_name(f.name())
+                    .cloned()
+                    .ok_or_else(|| LanceError::SchemaError {
+                        msg: format!("missing column {}", f.name()),
+                    })
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    RecordBatch::try_new(schema.clone(), columns).map_err(|e| LanceError::InsertError {
+        msg: format!("Failed to create record batch: {e}"),
+    })
+}
+
+/// Check that `filter` parses and only references existing columns by
+/// planning, but not running, a scan with it.
+async fn validate_filter(table: &lancedb::Table, filter: &str) -> Result<(), LanceError> {
+    table
+        .query()
+        .only_if(filter)
+        .explain_plan(false)
+        .await
+        .map(|_| ())
+        .map_err(|e| LanceError::QueryError {
+            msg: format!("invalid filter `{filter}`: {e}"),
+        })
+}
+
+fn schema_info(schema: &Schema) -> SchemaInfo {
+    let embedding_dim = match schema.field_with_name("embedding").map(|f| f.data_type()) {
+        Ok(DataType::FixedSizeList(_, dim)) => Some(*dim),
+        _ => None,
+    };
+    SchemaInfo {
+        fields: schema
+            .fields()
+            .iter()
+            .map(|f| FieldInfo {
+                name: f.name().clone(),
+                data_type: f.data_type().to_string(),
+                nullable: f.is_nullable(),
+            })
+            .collect(),
+        embedding_dim,
+    }
+}
+
+/// Every column of `expected` must be present in `actual` with the same type.
+fn check_import_schema(actual: &Schema, expected: &Schema) -> Result<(), LanceError> {
+    for field in expected.fields() {
+        match actual.field_with_name(field.name()) {
+            Ok(f) if f.data_type() == field.data_type() => {}
+            Ok(f) => {
+                return Err(LanceError::SchemaError {
+                    msg: format!(
+                        "column {} has type {}, expected {}",
+                        field.name(),
+                        f.data_type(),
+                        field.data_type()
+                    ),
+                })
+            }
+            Err(_) => {
+                return Err(LanceError::SchemaError {
+                    msg: format!("missing column {}", field.name()),
+                })
+            }
+        }
+    }
+    if actual.fields().len() != expected.fields().len() {
+        return Err(LanceError::SchemaError {
+            msg: format!(
+                "file has {} columns, table expects {}",
+                actual.fields().len(),
+                expected.fields().len()
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Apply `SearchOptions::error_on_empty` to a finished search.
+fn non_empty(response: SearchResponse, options: &SearchOptions) -> Result<SearchResponse, LanceError> {
+    if options.error_on_empty && response.results.is_empty() {
+        return Err(LanceError::NotFound {
+            msg: "search returned no results".into(),
+        });
+    }
+    Ok(response)
+}
+
+/// Apply `ListOptions::error_on_empty` to a finished `list`.
+fn non_empty_listing(empty: bool, options: &ListOptions) -> Result<(), LanceError> {
+    if options.error_on_empty && empty {
+        return Err(LanceError::NotFound {
+            msg: "list returned no entries".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Little-endian f32s packed in `bytes`; a trailing partial value is dropped.
+fn f32s_from_le_bytes(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect()
+}
+
+/// Index of the first NaN or infinite value, which would corrupt distance
+/// ordering if written or queried.
+fn first_non_finite(vector: &[f32]) -> Option<usize> {
+    vector.iter().position(|v| !v.is_finite())
+}
+
+/// Scale `vector` to unit L2 norm in place. Returns false, leaving it
+/// untouched, when the norm is zero.
+fn l2_normalize(vector: &mut [f32]) -> bool {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return false;
+    }
+    vector.iter_mut().for_each(|v| *v /= norm);
+    true
+}
+
+/// Sub-vector count for IVF_PQ: `dim / 16`, else `dim / 8`, whichever divides
+/// `dim` evenly, falling back to a single sub-vector.
+fn default_sub_vectors(dim: u32) -> u32 {
+    [dim / 16, dim / 8]
+        .into_iter()
+        .find(|&n| n > 0 && dim.is_multiple_of(n))
+        .unwrap_or(1)
+}
+
+/// Drop deleted rows from `table`'s indexes without retraining them (see
+/// `delete_where`).
+async fn maintain_indices(table: &lancedb::Table) -> Result<(), LanceError> {
+    let indexed = table
+        .list_indices()
+        .await
+        .map_err(|e| LanceError::TableError {
+            msg: e.to_string(),
+        })?;
+    if indexed.is_empty() {
+        return Ok(());
+    }
+
+    for action in [compaction(), OptimizeAction::Index(OptimizeOptions::default())] {
+        table
+            .optimize(action)
+            .await
+            .map_err(|e| LanceError::TableError {
+                msg: format!("rows deleted but index maintenance failed: {e}"),
+            })?;
+    }
+    Ok(())
+}
+
+/// Compaction that also rewrites every data file holding deleted rows.
+fn compaction() -> OptimizeAction {
+    OptimizeAction::Compact {
+        options: CompactionOptions {
+            materialize_deletions: true,
+            materialize_deletions_threshold: 0.0,
+            ..Default::default()
+        },
+        remap_options: None,
+    }
+}
+
+/// Whether `table` has an ANN index on the vector column `column`, which
+/// `nearest_to` then uses instead of a flat scan.
+async fn has_vector_index(table: &lancedb::Table, column: &str) -> Result<bool, LanceError> {
+    let indices = table
+        .list_indices()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })?;
+    Ok(indices
+        .iter()
+        .any(|i| i.columns.iter().any(|c| c == column)))
+}
+
+/// Whether `table` has a full-text index on `text` (see `search_text`).
+async fn has_text_index(table: &lancedb::Table) -> Result<bool, LanceError> {
+    let indices = table
+        .list_indices()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })?;
+    Ok(indices
+        .iter()
+        .any(|i| i.index_type == IndexType::FTS && i.columns.iter().any(|c| c == "text")))
+}
+
+/// The vector query `nearest_batches` runs, for planning without executing.
+#[allow(clippy::too_many_arguments)]
+fn nearest_query(
+    table: &lancedb::Table,
+    column: &str,
+    query_vector: ArrayRef,
+    limit: usize,
+    offset: usize,
+    filter: Option<&str>,
+    with_row_id: bool,
+    preset: Option<&CollectionPreset>,
+    metric: Option<DistanceMetric>,
+    bypass_index: bool,
+) -> Result<VectorQuery, LanceError> {
+    let mut query = table
+        .query()
+        .nearest_to(query_vector)
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })?
+        .column(column)
+        .limit(limit);
+
+    if offset > 0 {
+        query = query.offset(offset);
+    }
+
+    if let Some(metric) = metric {
+        query = query.distance_type(metric.distance_type());
+    }
+
+    if let Some(nprobes) = preset.and_then(|p| p.nprobes) {
+        query = query.nprobes(nprobes as usize);
+    }
+    if let Some(factor) = preset.and_then(|p| p.refine_factor) {
+        query = query.refine_factor(factor);
+    }
+    if bypass_index {
+        query = query.bypass_vector_index();
+    }
+
+    if let Some(f) = filter {
+        query = query.only_if(f);
+    }
+
+    if with_row_id {
+        query = query.with_row_id();
+    }
+    Ok(query)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn nearest_batches(
+    table: &lancedb::Table,
+    column: &str,
+    query_vector: ArrayRef,
+    limit: usize,
+    offset: usize,
+    filter: Option<&str>,
+    with_row_id: bool,
+    preset: Option<&CollectionPreset>,
+    metric: Option<DistanceMetric>,
+    bypass_index: bool,
+) -> Result<Vec<RecordBatch>, LanceError> {
+    let query = nearest_query(
+        table,
+        column,
+        query_vector,
+        limit,
+        offset,
+        filter,
+        with_row_id,
+        preset,
+        metric,
+        bypass_index,
+    )?;
+    let stream = query
+        .execute()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })?;
+
+    stream
+        .try_collect()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })
+}
+
+async fn scan_batches(
+    table: &lancedb::Table,
+    filter: Option<&str>,
+    with_row_id: bool,
+) -> Result<Vec<RecordBatch>, LanceError> {
+    let mut query = table.query();
+
+    if let Some(f) = filter {
+        query = query.only_if(f);
+    }
+
+    if with_row_id {
+        query = query.with_row_id();
+    }
+
+    let stream = query
+        .execute()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })?;
+
+    stream
+        .try_collect()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })
+}
+
+/// `columns` of the rows whose key starts with `prefix`.
+async fn prefix_batches(
+    table: &lancedb::Table,
+    prefix: Option<&str>,
+    filter: Option<&str>,
+    limit: Option<usize>,
+    offset: usize,
+    columns: &[&str],
+) -> Result<Vec<RecordBatch>, LanceError> {
+    prefix_stream(table, prefix, filter, limit, offset, columns)
+        .await?
+        .try_collect()
+        .await
+        .map_err(|e| LanceError::QueryError {
+            msg: e.to_string(),
+        })
+}
+
+/// `prefix_batches` as a stream, for callers that fold the rows as they come.
+async fn prefix_stream(
+    table: &lancedb::Table,
+    prefix: Option<&str>,
+    filter: Option<&str>,
+    limit: Option<usize>,
+    offset: usize,
+    columns: &[&str],
+) -> Result<SendableRecordBatchStream, LanceError> {
+    let mut query = table.query().select(lancedb::query::Select::Columns(
+        columns.iter().map(|c| c.to_string()).collect(),
+    ));
+
+    let prefix = prefix.map(key_prefix_sql);
+    let predicate = match (prefix, filter) {
+        (Some(p), Some(f)) => Some(format!("{p} AND ({f})")),
+        (p, f) => p.or_else(|| f.map(String::from)),
+    };
+    if let Some(predicate) = predicate {
+        query = query.only_if(predicate);
+    }
+
+    if let Some(lim) = limit {
+        query = query.limit(lim);
+    }
+    if offset > 0 {
+        query = query.offset(offset);
+    }
+
+    query.execute().await.map_err(|e| LanceError::QueryError {
+        msg: e.to_string(),
+    })
+}
+
+/// Row `i` of a batch holding `LIST_COLUMNS`, as a `SearchResult` with no
+/// score or provenance.
+fn stored_result(
+    batch: &RecordBatch,
+    i: usize,
+    collection: &str,
+) -> Result<SearchResult, LanceError> {
+    let column = |name| {
+        batch
+            .column_by_name(name)
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+    };
+    let metadata = nullable_string(column("metadata"), i);
+    Ok(SearchResult {
+        key: required_column::<StringArray>(batch, "key")?.value(i).to_string(),
+        collection: collection.to_string(),
+        agent_id: required_column::<StringArray>(batch, "agent_id")?
+            .value(i)
+            .to_string(),
+        text: nullable_string(column("text"), i).unwrap_or_default(),
+        score: 0.0,
+        distance: None,
+        meta: meta_pairs(metadata.as_deref()),
+        metadata,
+        created_at: required_column::<Int64Array>(batch, "created_at")?.value(i),
+        row_id: None,
+        fragment_id: None,
+        dataset_version: None,
+        embedding: None,
+    })
+}
+
+/// Row `i` of a stored batch as an `export_json` record.
+fn json_record(
+    batch: &RecordBatch,
+    i: usize,
+    dtype: EmbeddingDtype,
+    extra: &[ColumnSpec],
+) -> Result<serde_json::Value, LanceError> {
+    use serde_json::Value;
+
+    let column = |name| {
+        batch
+            .column_by_name(name)
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+    };
+    let mut record = serde_json::Map::new();
+    record.insert(
+        "key".into(),
+        required_column::<StringArray>(batch, "key")?.value(i).into(),
+    );
+    record.insert(
+        "agent_id".into(),
+        required_column::<StringArray>(batch, "agent_id")?.value(i).into(),
+    );
+    record.insert(
+        "text".into(),
+        nullable_string(column("text"), i).unwrap_or_default().into(),
+    );
+    record.insert(
+        "embedding".into(),
+        embedding_at(batch, i, dtype).unwrap_or_default().into(),
+    );
+    record.insert(
+        "metadata".into(),
+        nullable_string(column("metadata"), i).map_or(Value::Null, Value::String),
+    );
+    record.insert(
+        "created_at".into(),
+        required_column::<Int64Array>(batch, "created_at")?.value(i).into(),
+    );
+    if let Some(tags) = batch
+        .column_by_name("tags")
+        .and_then(|c| c.as_any().downcast_ref::<ListArray>())
+    {
+        let value = if tags.is_null(i) {
+            Value::Null
+        } else {
+            let values = tags.value(i);
+            let values = values
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| LanceError::SchemaError {
+                    msg: "tags column is not a list of strings".into(),
+                })?;
+            values.iter().flatten().collect::<Vec<_>>().into()
+        };
+        record.insert("tags".into(), value);
+    }
+    if batch.column_by_name("embedding2").is_some() {
+        record.insert(
+            "embedding2".into(),
+            vector_at(batch, "embedding2", i, dtype).map_or(Value::Null, Value::from),
+        );
+    }
+    let mut columns = serde_json::Map::new();
+    for spec in extra {
+        let Some(array) = batch.column_by_name(&spec.name) else {
+            continue;
+        };
+        if array.is_null(i) {
+            continue;
+        }
+        let any = array.as_any();
+        let value = match spec.column_type {
+            ColumnType::Utf8 => any.downcast_ref::<StringArray>().map(|a| a.value(i).into()),
+            ColumnType::Int64 => any.downcast_ref::<Int64Array>().map(|a| a.value(i).into()),
+            ColumnType::Float64 => any.downcast_ref::<Float64Array>().map(|a| a.value(i).into()),
+            ColumnType::Bool => any.downcast_ref::<BooleanArray>().map(|a| a.value(i).into()),
+        };
+        let value = value.ok_or_else(|| LanceError::SchemaError {
+            msg: format!("column {} is not {:?}", spec.name, spec.column_type),
+        })?;
+        columns.insert(spec.name.clone(), value);
+    }
+    if !extra.is_empty() {
+        record.insert("columns".into(), Value::Object(columns));
+    }
+    Ok(Value::Object(record))
+}
+
+/// Record `i` of an `import_json` array as a `MemoryEntry`.
+/// Values in `columns` are typed by the matching spec in `extra`.
+fn entry_from_json(
+    i: usize,
+    record: &serde_json::Value,
+    extra: &[ColumnSpec],
+) -> Result<MemoryEntry, LanceError> {
+    let invalid = |what: &str| LanceError::InsertError {
+        msg: format!("record {i}: {what}"),
+    };
+    let string = |name: &str| {
+        record
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .ok_or_else(|| invalid(&format!("missing string {name}")))
+    };
+    let floats = |name: &str, v: &serde_json::Value| {
+        v.as_array()
+            .and_then(|a| a.iter().map(|v| v.as_f64().map(|x| x as f32)).collect())
+            .ok_or_else(|| invalid(&format!("{name} must be an array of numbers")))
+    };
+    let embedding = floats(
+        "embedding",
+        record.get("embedding").ok_or_else(|| invalid("missing array embedding"))?,
+    )?;
+    let embedding2 = match record.get("embedding2") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(v) => Some(floats("embedding2", v)?),
+    };
+    let tags = match record.get("tags") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(v) => Some(
+            v.as_array()
+                .and_then(|a| a.iter().map(|t| t.as_str().map(String::from)).collect())
+                .ok_or_else(|| invalid("tags must be an array of strings"))?,
+        ),
+    };
+    let columns = match record.get("columns") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(v) => {
+            let object = v.as_object().ok_or_else(|| invalid("columns must be an object"))?;
+            let mut columns = Vec::new();
+            for (name, v) in object.iter().filter(|(_, v)| !v.is_null()) {
+                let column_type = extra
+                    .iter()
+                    .find(|spec| spec.name == *name)
+                    .map(|spec| spec.column_type)
+                    .ok_or_else(|| invalid(&format!("unknown column {name:?}")))?;
+                let value = match column_type {
+                    ColumnType::Utf8 => v.as_str().map(|value| ColumnValue::Utf8 { value: value.into() }),
+                    ColumnType::Int64 => v.as_i64().map(|value| ColumnValue::Int64 { value }),
+                    ColumnType::Float64 => v.as_f64().map(|value| ColumnValue::Float64 { value }),
+                    ColumnType::Bool => v.as_bool().map(|value| ColumnValue::Bool { value }),
+                };
+                let value = value
+                    .ok_or_else(|| invalid(&format!("column {name} must be {column_type:?}")))?;
+                columns.push(ColumnEntry { name: name.clone(), value });
+            }
+            Some(columns)
+        }
+    };
+    Ok(MemoryEntry {
+        key: string("key")?,
+        agent_id: string("agent_id")?,
+        text: string("text")?,
+        embedding,
+        metadata: record.get("metadata").and_then(|v| v.as_str()).map(String::from),
+        tags,
+        created_at: record.get("created_at").and_then(|v| v.as_i64()),
+        embedding2,
+        columns,
+    })
+}
+
+/// Row `i` of a batch's `embedding` column, if it was read, decoded from
+/// `dtype`.
+fn embedding_at(batch: &RecordBatch, i: usize, dtype: EmbeddingDtype) -> Option<Vec<f32>> {
+    vector_at(batch, "embedding", i, dtype)
+}
+
+/// Row `i` of the vector column `name`, or `None` when it wasn't read or the
+/// row leaves it null.
+fn vector_at(batch: &RecordBatch, name: &str, i: usize, dtype: EmbeddingDtype) -> Option<Vec<f32>> {
+    let vectors = batch
+        .column_by_name(name)?
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()?;
+    if vectors.is_null(i) {
+        return None;
+    }
+    Some(dtype.decode(&vectors.value(i))?.values().to_vec())
+}
+
+/// Predicate matching keys that start with `prefix`, character for
+/// character. Lance rewrites `starts_with` into a `LIKE` with `\` as the
+/// escape character, escaping `%` itself but not `_` or `\`, so those two are
+/// escaped here.
+fn key_prefix_sql(prefix: &str) -> String {
+    let mut pattern = String::with_capacity(prefix.len());
+    for c in prefix.chars() {
+        match c {
+            '\\' | '_' => pattern.extend(['\\', c]),
+            c => pattern.push(c),
+        }
+    }
+    format!("starts_with(key, {})", sql_quote_literal(&pattern))
+}
+
+/// `filter` ANDed with each of `filters`.
+fn with_meta_filters(filter: Option<String>, filters: &[MetaFilter]) -> Option<String> {
+    filters.iter().fold(filter, |acc, f| {
+        let sql = f.to_sql();
+        Some(match acc {
+            Some(acc) => format!("({acc}) AND {sql}"),
+            None => sql,
+        })
+    })
+}
+
+/// `MetaFilter`s bounding `created_at` to `[after, before]`; either end may
+/// be open.
+fn created_window(after: Option<i64>, before: Option<i64>) -> Vec<MetaFilter> {
+    let after = after.map(|ms| MetaFilter::CreatedSince { ms });
+    let before = before.map(|ms| MetaFilter::CreatedBefore { ms });
+    after.into_iter().chain(before).collect()
+}
+
+/// `batches` as a single batch sorted by `order`.
+fn sort_batches(batches: Vec<RecordBatch>, order: SortOrder) -> Result<Vec<RecordBatch>, LanceError> {
+    let Some(first) = batches.first() else {
+        return Ok(batches);
+    };
+    let to_query_error = |e: arrow_schema::ArrowError| LanceError::QueryError {
+        msg: e.to_string(),
+    };
+    let batch = concat_batches(&first.schema(), &batches).map_err(to_query_error)?;
+    let column = |name: &str, descending| -> Result<SortColumn, LanceError> {
+        Ok(SortColumn {
+            values: batch
+                .column_by_name(name)
+                .cloned()
+                .ok_or_else(|| LanceError::QueryError {
+                    msg: format!("result is missing column {name}"),
+                })?,
+            options: Some(SortOptions {
+                descending,
+                nulls_first: false,
+            }),
+        })
+    };
+    let columns = match order {
+        SortOrder::CreatedAtAsc => vec![column("created_at", false)?, column("key", false)?],
+        SortOrder::CreatedAtDesc => vec![column("created_at", true)?, column("key", false)?],
+        SortOrder::KeyAsc => vec![column("key", false)?],
+    };
+    let indices = lexsort_to_indices(&columns, None).map_err(to_query_error)?;
+    Ok(vec![take_record_batch(&batch, &indices).map_err(to_query_error)?])
+}
+
+/// The first `keep` rows of `batches` (all of them when `None`) in `order`.
+fn first_sorted(
+    batches: Vec<RecordBatch>,
+    order: SortOrder,
+    keep: Option<usize>,
+) -> Result<Vec<RecordBatch>, LanceError> {
+    let sorted = sort_batches(batches, order)?;
+    Ok(match keep {
+        Some(n) => sorted
+            .into_iter()
+            .map(|b| b.slice(0, n.min(b.num_rows())))
+            .collect(),
+        None => sorted,
+    })
+}
+
+/// `batches` without their first `n` rows.
+fn skip_rows(batches: Vec<RecordBatch>, mut n: usize) -> Vec<RecordBatch> {
+    let mut kept = Vec::with_capacity(batches.len());
+    for batch in batches {
+        if n >= batch.num_rows() {
+            n -= batch.num_rows();
+        } else {
+            kept.push(batch.slice(n, batch.num_rows() - n));
+            n = 0;
+        }
+    }
+    kept
+}
+
+/// `metadata` as pairs when it is a JSON object (see `SearchResult::meta`).
+fn meta_pairs(metadata: Option<&str>) -> Option<Vec<MetaPair>> {
+    let serde_json::Value::Object(map) = serde_json::from_str(metadata?).ok()? else {
+        return None;
+    };
+    Some(
+        map.into_iter()
+            .map(|(key, value)| MetaPair {
+                key,
+                value: match value {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                },
+            })
+            .collect(),
+    )
+}
+
+/// `s` as a single-quoted SQL string literal. Every value spliced into a
+/// predicate goes through here: quotes are doubled, and nothing else needs
+/// escaping since the parser takes backslashes literally.
+fn sql_quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// `'a', 'b', ...` with quotes escaped, for use inside `IN (...)`.
+fn sql_string_list<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    values.map(sql_quote_literal).collect::<Vec<_>>().join(", ")
+}
+
+/// A non-nullable schema column, downcast; a missing or mistyped column is a
+/// `QueryError` rather than something to skip.
+fn required_column<'a, T: Array + 'static>(
+    batch: &'a RecordBatch,
+    name: &str,
+) -> Result<&'a T, LanceError> {
+    batch
+        .column_by_name(name)
+        .and_then(|c| c.as_any().downcast_ref::<T>())
+        .ok_or_else(|| LanceError::QueryError {
+            msg: format!("result is missing column {name} or has the wrong type"),
+        })
+}
+
+fn nullable_string(col: Option<&StringArray>, i: usize) -> Option<String> {
+    col.filter(|c| !c.is_null(i)).map(|c| c.value(i).to_string())
+}
+
+/// Keep only the last entry for each key, preserving the order of those survivors.
+fn dedup_entries(entries: Vec<PendingEntry>) -> Vec<PendingEntry> {
+    let mut seen = HashSet::new();
+    let mut out: Vec<PendingEntry> = entries
+        .into_iter()
+        .rev()
+        .filter(|e| seen.insert(e.key.clone()))
+        .collect();
+    out.reverse();
+    out
+}
+
+/// Periodically flush staged entries until the handle is dropped.
+fn spawn_staging_flusher(handle: Weak<LanceDBHandle>, interval: Duration) {
+    RUNTIME.spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            let Some(handle) = handle.upgrade() else {
+                break;
+            };
+            if handle.ensure_open().is_err() {
+                break;
+            }
+            // Nobody to report to here; a failed flush leaves its entries
+            // pending for the next tick or an explicit flush().
+            let _ = handle.flush().await;
+        }
+    });
+}
+
+// ---------------------------------------------------------------------------
+// BM25 scoring helpers (pure Rust, no external deps)
+// ---------------------------------------------------------------------------
+
+/// `scores` rescaled to `[0, 1]`; all 1 when they are equal.
+fn min_max(scores: &[f64]) -> Vec<f64> {
+    let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = scores.iter().copied().fold(f64::INFINITY, f64::min);
+    scores
+        .iter()
+        .map(|s| if max > min { (s - min) / (max - min) } else { 1.0 })
+        .collect()
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn bm25_scores(docs: &[String], query: &str) -> Vec<f64> {
+    let k1 = 1.2_f64;
+    let b = 0.75_f64;
+    let query_terms = tokenize(query);
+    let doc_tokens: Vec<Vec<String>> = docs.iter().map(|d| tokenize(d)).collect();
+    let n = docs.len() as f64;
+    let avgdl = doc_tokens.iter().map(|t| t.len() as f64).sum::<f64>() / n.max(1.0);
+
+    doc_tokens
+        .iter()
+        .map(|tokens| {
+            let dl = tokens.len() as f64;
+            query_terms
+                .iter()
+                .map(|term| {
+                    let tf = tokens.iter().filter(|t| *t == term).count() as f64;
+                    let df = doc_tokens.iter().filter(|dt| dt.contains(term)).count() as f64;
+                    let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+                    idf * (tf * (k1 + 1.0)) / (tf + k1 * (1.0 - b + b * dl / avgdl))
+                })
+                .sum()
+        })
+        .collect()
+}
+
+fn chrono_now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_open_dimension_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("k", "text")]).await.unwrap();
+        handle.close().await.unwrap();
+
+        let err = LanceDBHandle::open(db_path.clone(), 8).await.err().unwrap();
+        assert!(matches!(&err, LanceError::SchemaError { msg } if msg.contains("4-dim")));
+        assert!(LanceDBHandle::open(db_path, 4).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_store_and_search() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store(
+                "color-blue".into(),
+                "main".into(),
+                "My favorite color is blue".into(),
+                vec![1.0, 0.0, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        handle
+            .store(
+                "color-red".into(),
+                "main".into(),
+                "I also like red".into(),
+                vec![0.9, 0.1, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        handle
+            .store(
+                "food-pizza".into(),
+                "main".into(),
+                "I love pizza".into(),
+                vec![0.0, 0.0, 1.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 2, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "color-blue");
+        assert_eq!(results[1].key, "color-red");
+        assert_eq!(results[0].distance, Some(0.0));
+        let d = results[1].distance.unwrap();
+        assert!((results[1].score - (1.0 - d)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_open_memory() {
+        let handle = LanceDBHandle::open_memory(4).await.unwrap();
+        let other = LanceDBHandle::open_memory(4).await.unwrap();
+
+        for (key, text, embedding) in [
+            ("color-blue", "My favorite color is blue", vec![1.0, 0.0, 0.0, 0.0]),
+            ("color-red", "I also like red", vec![0.9, 0.1, 0.0, 0.0]),
+            ("food-pizza", "I love pizza", vec![0.0, 0.0, 1.0, 0.0]),
+        ] {
+            handle
+                .store(key.into(), "main".into(), text.into(), embedding, None, None)
+                .await
+                .unwrap();
+        }
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 2, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "color-blue");
+        assert_eq!(results[1].key, "color-red");
+        assert_eq!(results[0].distance, Some(0.0));
+
+        // Each in-memory handle has its own database.
+        assert_eq!(handle.count(None, None).await.unwrap(), 3);
+        assert_eq!(other.count(None, None).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shared_memory_store() {
+        let owner = LanceDBHandle::open_memory(4).await.unwrap();
+        owner
+            .store("k".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        // A second handle on the same store doesn't free it when it goes away.
+        let shared = LanceDBHandle::open(owner.db_path.clone(), 4).await.unwrap();
+        assert_eq!(shared.count(None, None).await.unwrap(), 1);
+        shared.close().await.unwrap();
+        drop(shared);
+        assert_eq!(owner.count(None, None).await.unwrap(), 1);
+
+        // The owner does.
+        let path = owner.db_path.clone();
+        owner.close().await.unwrap();
+        let reopened = LanceDBHandle::open(path, 4).await.unwrap();
+        assert_eq!(reopened.count(None, None).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_safe_commit_handler() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            use_unsafe_commit: false,
+            ..Default::default()
+        };
+        assert!(OpenOptions::default().use_unsafe_commit);
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options)
+            .await
+            .unwrap();
+
+        for (key, embedding) in [("a", vec![1.0, 0.0, 0.0, 0.0]), ("b", vec![0.0, 1.0, 0.0, 0.0])] {
+            handle
+                .store(key.into(), "main".into(), "text".into(), embedding, None, None)
+                .await
+                .unwrap();
+        }
+        handle
+            .store("a".into(), "main".into(), "updated".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "a");
+        assert_eq!(results[0].text, "updated");
+    }
+
+    #[tokio::test]
+    async fn test_store_write_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let store = || {
+            handle.store("k".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+        };
+
+        let first = store().await.unwrap();
+        assert_eq!(first, WriteStats { inserted: 1, replaced: 0 });
+        let second = store().await.unwrap();
+        assert_eq!(second, WriteStats { inserted: 0, replaced: 1 });
+        let third = handle
+            .store("k2".into(), "main".into(), "text".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(third, WriteStats { inserted: 1, replaced: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_store_with_meta() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let pair = |key: &str, value: &str| MetaPair {
+            key: key.into(),
+            value: value.into(),
+        };
+        let meta = vec![pair("source", "chat"), pair("lang", "en"), pair("quote", "it's \"x\"")];
+        handle
+            .store_with_meta(
+                "k1".into(),
+                "main".into(),
+                "text".into(),
+                vec![1.0, 0.0, 0.0, 0.0],
+                meta,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let expected = vec![pair("lang", "en"), pair("quote", "it's \"x\""), pair("source", "chat")];
+        let got = handle.get("k1".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(got.meta, Some(expected.clone()));
+        let found = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(found[0].meta, Some(expected));
+
+        // Plain-string metadata that isn't a JSON object has no pairs.
+        handle
+            .store("k2".into(), "main".into(), "text".into(), vec![0.0, 1.0, 0.0, 0.0], Some("note".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(handle.get("k2".into(), None, false, None).await.unwrap().unwrap().meta, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(handle.search_text("cat".into(), 5, None, None).await.unwrap().is_empty());
+
+        let entries = [
+            ("k1", "the weather is sunny today"),
+            ("k2", "my cat sleeps on the sofa"),
+            ("k3", "stock prices fell sharply"),
+        ]
+        .iter()
+        .map(|(k, text)| entry(k, text))
+        .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let results = handle.search_text("cat".into(), 5, None, None).await.unwrap();
+        assert_eq!(results[0].key, "k2");
+        assert!(results[0].score > 0.0);
+        assert!(results.iter().all(|r| r.key != "k1" && r.key != "k3"));
+
+        // Rows written after the index was built are still found.
+        handle
+            .write_entries(DEFAULT_TABLE, vec![entry("k4", "a cat chased the dog")])
+            .await
+            .unwrap();
+        let results = handle
+            .search_text("cat".into(), 5, Some("key = 'k4'".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "k4");
+    }
+
+    #[tokio::test]
+    async fn test_search_hybrid() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let entries = [
+            ("both", "rust borrow checker", [1.0, 0.0, 0.0, 0.0]),
+            ("vector", "garbage collection", [0.9, 0.1, 0.0, 0.0]),
+            ("text", "the rust compiler", [0.0, 1.0, 0.0, 0.0]),
+            ("neither", "cooking pasta", [0.0, 0.0, 1.0, 0.0]),
+        ]
+        .iter()
+        .map(|(k, text, v)| PendingEntry {
+            embedding: v.to_vec(),
+            ..entry(k, text)
+        })
+        .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let query = vec![1.0, 0.0, 0.0, 0.0];
+        let keys = |results: Vec<SearchResult>| results.into_iter().map(|r| r.key).collect::<Vec<_>>();
+        let blended = handle
+            .search_hybrid(query.clone(), "rust".into(), 4, 0.5, None)
+            .await
+            .unwrap();
+        assert_eq!(blended[0].key, "both");
+        let ranked = keys(blended);
+        let pos = |k| ranked.iter().position(|r| r == k).unwrap();
+        assert!(pos("both") < pos("vector") && pos("both") < pos("text"));
+
+        let vector_only = handle
+            .search_hybrid(query.clone(), "rust".into(), 2, 1.0, None)
+            .await
+            .unwrap();
+        assert_eq!(keys(vector_only), ["both", "vector"]);
+
+        let err = handle
+            .search_hybrid(query, "rust".into(), 2, 1.5, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_bytes_api_matches_floats() {
+        let dir = tempfile::tempdir().unwrap();
+        let floats = LanceDBHandle::open(dir.path().join("f").to_str().unwrap().into(), 4)
+            .await
+            .unwrap();
+        let bytes = LanceDBHandle::open(dir.path().join("b").to_str().unwrap().into(), 4)
+            .await
+            .unwrap();
+        let to_bytes = |v: &[f32]| v.iter().flat_map(|f| f.to_le_bytes()).collect::<Vec<u8>>();
+
+        for (k, v) in [("a", [0.1, 0.2, 0.3, 0.4]), ("b", [-1.5, 0.0, 2.25, 1e-3])] {
+            floats
+                .store(k.into(), "main".into(), "t".into(), v.to_vec(), None, None)
+                .await
+                .unwrap();
+            bytes
+                .store_bytes(k.into(), "main".into(), "t".into(), to_bytes(&v), None, None)
+                .await
+                .unwrap();
+        }
+
+        let query = [0.2, 0.1, 0.0, 0.5];
+        let expected = floats.search(query.to_vec(), 5, None, None).await.unwrap();
+        let actual = bytes
+            .search_bytes(to_bytes(&query), 5, None, None)
+            .await
+            .unwrap();
+        assert_eq!(expected.len(), 2);
+        for (e, a) in expected.iter().zip(&actual) {
+            assert_eq!(e.key, a.key);
+            assert_eq!(e.score, a.score);
+        }
+
+        let err = bytes
+            .store_bytes("c".into(), "main".into(), "t".into(), vec![0; 15], None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+        let err = bytes
+            .store_bytes("c".into(), "main".into(), "t".into(), vec![0; 12], None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 4, actual: 3, .. }));
+        // Options pass through to `store`.
+        let options = StoreOptions { created_at: Some(1_000), ..Default::default() };
+        bytes
+            .store_bytes("d".into(), "main".into(), "t".into(), to_bytes(&[1.0; 4]), None, Some(options))
+            .await
+            .unwrap();
+        assert_eq!(bytes.get("d".into(), None, false, None).await.unwrap().unwrap().created_at, 1_000);
+        let err = bytes.search_bytes(vec![0; 12], 5, None, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 4, actual: 3, .. }));
+        let err = bytes.search_bytes(vec![0; 13], 5, None, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_search_min_score() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let entries = vec![
+            entry("relevant", "text"),
+            PendingEntry {
+                embedding: vec![-1.0, 0.0, 0.0, 0.0],
+                ..entry("irrelevant", "text")
+            },
+        ];
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let query = vec![1.0, 0.0, 0.0, 0.0];
+        assert_eq!(handle.search(query.clone(), 5, None, None).await.unwrap().len(), 2);
+        let options = SearchOptions {
+            min_score: Some(0.5),
+            ..Default::default()
+        };
+        let results = handle
+            .search(query, 5, None, Some(options))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "relevant");
+    }
+
+    #[tokio::test]
+    async fn test_search_many() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let entries = (0..4)
+            .map(|i| {
+                let mut embedding = vec![0.0; 4];
+                embedding[i] = 1.0;
+                PendingEntry {
+                    embedding,
+                    ..entry(&format!("k{i}"), "text")
+                }
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let queries = vec![vec![1.0, 0.5, 0.0, 0.0], vec![0.0, 0.0, 0.2, 1.0]];
+        let batched = handle
+            .search_many(queries.clone(), 3, None, None)
+            .await
+            .unwrap();
+        assert_eq!(batched.len(), 2);
+        for (query, results) in queries.into_iter().zip(&batched) {
+            let single = handle.search(query, 3, None, None).await.unwrap();
+            let keys = |r: &[SearchResult]| r.iter().map(|r| r.key.clone()).collect::<Vec<_>>();
+            assert_eq!(keys(results), keys(&single));
+        }
+        assert_eq!(batched[0][0].key, "k0");
+        assert_eq!(batched[1][0].key, "k3");
+
+        let err = handle
+            .search_many(vec![vec![1.0; 4], vec![1.0; 3]], 3, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 4, actual: 3, index: Some(1), .. }));
+
+        // Every query sees the same staged entries, which supersede committed rows.
+        let dir = tempfile::tempdir().unwrap();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("k0", "committed")]).await.unwrap();
+        for (key, text) in [("k0", "staged"), ("k1", "new")] {
+            handle
+                .store(key.into(), "main".into(), text.into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        let batched = handle
+            .search_many(vec![vec![1.0, 0.0, 0.0, 0.0], vec![0.0, 1.0, 0.0, 0.0]], 5, None, None)
+            .await
+            .unwrap();
+        for results in &batched {
+            let mut texts = results.iter().map(|r| r.text.as_str()).collect::<Vec<_>>();
+            texts.sort();
+            assert_eq!(texts, vec!["new", "staged"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(!handle.update_metadata("k".into(), None, None).await.unwrap());
+
+        handle
+            .store("it's".into(), "main".into(), "text".into(), vec![0.5, 0.0, 0.5, 0.0], Some("old".into()), None)
+            .await
+            .unwrap();
+        let before = handle.get("it's".into(), None, false, None).await.unwrap().unwrap();
+
+        let updated = handle
+            .update_metadata("it's".into(), Some("{\"note\":\"o'brien\"}".into()), None)
+            .await
+            .unwrap();
+        assert!(updated);
+        let after = handle.get("it's".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(after.metadata.as_deref(), Some("{\"note\":\"o'brien\"}"));
+        assert_eq!(after.text, before.text);
+        assert_eq!(after.created_at, before.created_at);
+        let nearest = handle
+            .search(vec![0.5, 0.0, 0.5, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(nearest[0].distance, Some(0.0));
+
+        assert!(handle.update_metadata("it's".into(), None, None).await.unwrap());
+        assert_eq!(handle.get("it's".into(), None, false, None).await.unwrap().unwrap().metadata, None);
+        assert!(!handle.update_metadata("missing".into(), None, None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_exists() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(!handle.exists("k".into(), None).await.unwrap());
+
+        handle.write_entries(DEFAULT_TABLE, vec![entry("it's", "text")]).await.unwrap();
+        assert!(handle.exists("it's".into(), None).await.unwrap());
+        assert!(!handle.exists("absent".into(), None).await.unwrap());
+        assert!(!handle.exists("it's".into(), Some("other".into())).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(handle.get("k1".into(), None, false, None).await.unwrap().is_none());
+
+        handle
+            .store("k1".into(), "main".into(), "v1".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("k1".into(), "main".into(), "v2".into(), vec![1.0, 0.0, 0.0, 0.0], Some("{\"n\":2}".into()), None)
+            .await
+            .unwrap();
+        handle
+            .store("it's".into(), "main".into(), "quoted".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let hit = handle.get("k1".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(hit.text, "v2");
+        assert_eq!(hit.metadata.as_deref(), Some("{\"n\":2}"));
+        assert_eq!(handle.get("it's".into(), None, false, None).await.unwrap().unwrap().text, "quoted");
+        assert!(handle.get("missing".into(), None, false, None).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_returns_agent_and_created_at() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .write_entries(DEFAULT_TABLE, vec![PendingEntry {
+                agent_id: "agent-7".into(),
+                created_at: 1_700_000_000_123,
+                ..entry("k1", "text")
+            }])
+            .await
+            .unwrap();
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].agent_id, "agent-7");
+        assert_eq!(results[0].created_at, 1_700_000_000_123);
+
+        let hit = handle.get("k1".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(hit.created_at, 1_700_000_000_123);
+        let hit = handle
+            .nearest(vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.agent_id, "agent-7");
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store(
+                "k1".into(),
+                "main".into(),
+                "text1".into(),
+                vec![1.0, 0.0, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let keys = handle.list(None, None, None).await.unwrap();
+        assert_eq!(keys.len(), 1);
+
+        handle.delete("k1".into(), None).await.unwrap();
+
+        let keys = handle.list(None, None, None).await.unwrap();
+        assert_eq!(keys.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_with_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        for (k, text) in [("proj:a", "A"), ("proj:b", "B"), ("other:c", "C")] {
+            handle
+                .store(
+                    k.into(),
+                    "main".into(),
+                    text.into(),
+                    vec![1.0, 0.0, 0.0, 0.0],
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let proj_keys = handle
+            .list(Some("proj:".into()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(proj_keys.len(), 2);
+
+        let all_keys = handle.list(None, None, None).await.unwrap();
+        assert_eq!(all_keys.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_offset() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        for i in 0..5 {
+            handle
+                .store(
+                    format!("k{i}"),
+                    "main".into(),
+                    "text".into(),
+                    vec![i as f32, 1.0, 0.0, 0.0],
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let all = handle.list(None, None, None).await.unwrap();
+        assert_eq!(all.len(), 5);
+        let page = handle.list(None, Some(2), Some(ListOptions { offset: Some(2), ..Default::default() })).await.unwrap();
+        assert_eq!(page, all[2..4]);
+
+        let query = vec![0.0, 1.0, 0.0, 0.0];
+        let ranked = handle.search(query.clone(), 5, None, None).await.unwrap();
+        let options = SearchOptions {
+            offset: Some(1),
+            ..Default::default()
+        };
+        let page = handle
+            .search(query, 2, None, Some(options))
+            .await
+            .unwrap();
+        let keys: Vec<_> = page.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, ["k1", "k2"]);
+        assert_eq!(page[0].key, ranked[1].key);
+    }
+
+    #[tokio::test]
+    async fn test_meta_filters() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let rows = [
+            ("proj:a", "o'brien", 1_000),
+            ("proj:b", "main", 2_000),
+            ("x:c", "main", 3_000),
+        ];
+        let entries = rows
+            .iter()
+            .map(|(k, agent, at)| PendingEntry {
+                agent_id: agent.to_string(),
+                created_at: *at,
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let keys = |filters: Vec<MetaFilter>, filter: Option<&str>| {
+            let handle = handle.clone();
+            let filter = filter.map(String::from);
+            async move {
+                let options = SearchOptions {
+                    filters: Some(filters),
+                    ..Default::default()
+                };
+                let mut keys: Vec<_> = handle
+                    .search(vec![1.0, 0.0, 0.0, 0.0], 10, filter, Some(options))
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| r.key)
+                    .collect();
+                keys.sort();
+                keys
+            }
+        };
+
+        let agent = MetaFilter::AgentId {
+            agent_id: "o'brien".into(),
+        };
+        assert_eq!(keys(vec![agent.clone()], None).await, ["proj:a"]);
+        let since = MetaFilter::CreatedSince { ms: 2_000 };
+        assert_eq!(keys(vec![since.clone()], None).await, ["proj:b", "x:c"]);
+        let prefix = MetaFilter::KeyPrefix {
+            prefix: "proj:".into(),
+        };
+        assert_eq!(keys(vec![prefix.clone()], None).await, ["proj:a", "proj:b"]);
+        assert_eq!(keys(vec![prefix.clone(), since.clone()], None).await, ["proj:b"]);
+        assert!(keys(vec![since], Some("agent_id = 'nobody'")).await.is_empty());
+
+        let sql = meta_filter_sql(vec![prefix, agent]);
+        assert_eq!(handle.count(None, Some(sql)).await.unwrap(), 1);
+        assert_eq!(meta_filter_sql(vec![]), "true");
+    }
+
+    #[tokio::test]
+    async fn test_created_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let entries = [("a", 1_000), ("b", 2_000), ("c", 3_000)]
+            .iter()
+            .map(|(k, at)| PendingEntry {
+                created_at: *at,
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let options = SearchOptions {
+            created_after: Some(1_500),
+            created_before: Some(3_000),
+            ..Default::default()
+        };
+        let mut keys: Vec<_> = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, Some(options))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.key)
+            .collect();
+        keys.sort();
+        assert_eq!(keys, ["b", "c"]);
+
+        let mut keys = handle
+            .list(None, None, Some(ListOptions { created_before: Some(2_000), ..Default::default() }))
+            .await
+            .unwrap();
+        keys.sort();
+        assert_eq!(keys, ["a", "b"]);
+        let keys = handle
+            .list(Some("b".into()), None, Some(ListOptions { created_after: Some(2_500), ..Default::default() }))
+            .await
+            .unwrap();
+        assert!(keys.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_store_created_at() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let store = |key: &str, created_at| {
+            handle.store(key.into(), "main".into(), "text".into(), vec![1.0; 4], None, Some(StoreOptions { created_at, ..Default::default() }))
+        };
+
+        store("old", Some(1_600_000_000_000)).await.unwrap();
+        let got = handle.get("old".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(got.created_at, 1_600_000_000_000);
+        let listed = handle.list_detailed(None, None, None).await.unwrap();
+        assert_eq!(listed[0].created_at, 1_600_000_000_000);
+
+        let err = store("bad", Some(-1)).await.unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+        let entry = MemoryEntry {
+            key: "imported".into(),
+            agent_id: "main".into(),
+            text: "text".into(),
+            embedding: vec![1.0; 4],
+            metadata: None,
+            tags: None,
+            created_at: Some(42),
+            embedding2: None,
+            columns: None,
+        };
+        handle.store_many(vec![entry], None, None, None).await.unwrap();
+        let got = handle.get("imported".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(got.created_at, 42);
+    }
+
+    #[tokio::test]
+    async fn test_get_many() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let entries = ["a", "b", "c", "d", "it's"].iter().map(|k| entry(k, k)).collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let keys = ["d", "missing", "it's", "a", "d"].map(String::from).to_vec();
+        let results = handle.get_many(keys, None, None).await.unwrap();
+        let got: Vec<_> = results.iter().map(|r| (r.key.as_str(), r.text.as_str())).collect();
+        assert_eq!(got, [("d", "d"), ("it's", "it's"), ("a", "a")]);
+        assert!(handle.get_many(vec![], None, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_include_embedding() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let v = vec![0.25, 0.5, 0.75, 1.0];
+        handle
+            .store("k".into(), "main".into(), "text".into(), v.clone(), None, None)
+            .await
+            .unwrap();
+
+        let plain = handle.search(v.clone(), 1, None, None).await.unwrap();
+        assert_eq!(plain[0].embedding, None);
+        let options = SearchOptions {
+            include_embedding: true,
+            ..Default::default()
+        };
+        let results = handle.search(v.clone(), 1, None, Some(options)).await.unwrap();
+        assert_eq!(results[0].embedding.as_deref(), Some(&v[..]));
+
+        let got = handle.get("k".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(got.embedding, Some(v));
+        let got = handle.get("k".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(got.embedding, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_multi() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for (collection, key, v) in [
+            ("facts", "f1", [1.0, 0.0, 0.0, 0.0]),
+            ("facts", "f2", [0.0, 1.0, 0.0, 0.0]),
+            ("conversations", "c1", [0.9, 0.1, 0.0, 0.0]),
+            ("conversations", "c2", [0.5, 0.5, 0.0, 0.0]),
+        ] {
+            handle
+                .store(key.into(), "main".into(), "text".into(), v.to_vec(), None, Some(StoreOptions { collection: Some(collection.into()), ..Default::default() }))
+                .await
+                .unwrap();
+        }
+
+        let collections = ["facts", "conversations", "missing", "facts"].map(String::from).to_vec();
+        let results = handle
+            .search_multi(collections, vec![1.0, 0.0, 0.0, 0.0], 3, None)
+            .await
+            .unwrap();
+        let got: Vec<_> = results.iter().map(|r| (r.key.as_str(), r.collection.as_str())).collect();
+        assert_eq!(got, [("f1", "facts"), ("c1", "conversations"), ("c2", "conversations")]);
+        assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[tokio::test]
+    async fn test_search_similar() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for (key, v) in [
+            ("a", [1.0, 0.0, 0.0, 0.0]),
+            ("b", [0.9, 0.1, 0.0, 0.0]),
+            ("c", [0.7, 0.3, 0.0, 0.0]),
+            ("d", [0.0, 0.0, 1.0, 0.0]),
+        ] {
+            handle
+                .store(key.into(), "main".into(), "text".into(), v.to_vec(), None, None)
+                .await
+                .unwrap();
+        }
+
+        let keys = |results: Vec<SearchResult>| results.into_iter().map(|r| r.key).collect::<Vec<_>>();
+        let similar = handle.search_similar("a".into(), 2, None, true, None).await.unwrap();
+        assert_eq!(keys(similar), ["b", "c"]);
+        let similar = handle.search_similar("a".into(), 2, None, false, None).await.unwrap();
+        assert_eq!(keys(similar), ["a", "b"]);
+
+        let err = handle.search_similar("missing".into(), 2, None, true, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("a", "1"), entry("b", "2")]).await.unwrap();
+        assert!(!handle.restore_key("a".into(), None).await.unwrap());
+
+        let search = |include_deleted| {
+            let options = SearchOptions {
+                include_deleted,
+                ..Default::default()
+            };
+            handle.search(vec![1.0, 0.0, 0.0, 0.0], 10, None, Some(options))
+        };
+        let list = |include_deleted| {
+            handle.list(None, None, Some(ListOptions { include_deleted, ..Default::default() }))
+        };
+
+        assert!(handle.soft_delete("a".into(), None).await.unwrap());
+        assert!(!handle.soft_delete("a".into(), None).await.unwrap());
+        assert_eq!(list(false).await.unwrap(), ["b"]);
+        assert_eq!(list(true).await.unwrap().len(), 2);
+        let hits = search(false).await.unwrap();
+        assert_eq!(hits.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), ["b"]);
+        assert_eq!(search(true).await.unwrap().len(), 2);
+
+        assert!(handle.restore_key("a".into(), None).await.unwrap());
+        assert_eq!(search(false).await.unwrap().len(), 2);
+
+        // Storing a soft-deleted key again brings it back too.
+        handle.soft_delete("b".into(), None).await.unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("b", "3")]).await.unwrap();
+        let mut keys = list(false).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_hides_reads() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle
+            .write_entries(DEFAULT_TABLE, vec![entry("a", "the cat sat"), entry("b", "a cat ran")])
+            .await
+            .unwrap();
+        handle.soft_delete("a".into(), None).await.unwrap();
+
+        let keys = |results: Vec<SearchResult>| results.into_iter().map(|r| r.key).collect::<Vec<_>>();
+        let text = handle.search_text("cat".into(), 10, None, None).await.unwrap();
+        assert_eq!(keys(text), ["b"]);
+        let hybrid = handle
+            .search_hybrid(vec![1.0, 0.0, 0.0, 0.0], "cat".into(), 10, 0.0, None)
+            .await
+            .unwrap();
+        assert_eq!(keys(hybrid), ["b"]);
+
+        assert!(handle.get("a".into(), None, false, None).await.unwrap().is_none());
+        assert!(!handle.exists("a".into(), None).await.unwrap());
+        assert!(handle.exists("b".into(), None).await.unwrap());
+        let many = handle.get_many(vec!["a".into(), "b".into()], None, None).await.unwrap();
+        assert_eq!(keys(many), ["b"]);
+        assert_eq!(handle.count(None, None).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let entries = [("b", 3_000), ("a", 1_000), ("d", 2_000), ("c", 2_000)]
+            .iter()
+            .map(|(k, at)| PendingEntry {
+                created_at: *at,
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let list = |limit, offset, order| {
+            handle.list(None, limit, Some(ListOptions { offset, order: Some(order), ..Default::default() }))
+        };
+        assert_eq!(list(None, None, SortOrder::CreatedAtDesc).await.unwrap(), ["b", "c", "d", "a"]);
+        assert_eq!(list(Some(2), None, SortOrder::CreatedAtDesc).await.unwrap(), ["b", "c"]);
+        assert_eq!(list(Some(2), Some(1), SortOrder::CreatedAtAsc).await.unwrap(), ["c", "d"]);
+        assert_eq!(list(None, None, SortOrder::KeyAsc).await.unwrap(), ["a", "b", "c", "d"]);
+
+        let recent = handle
+            .list_detailed(None, Some(1), Some(ListOptions { order: Some(SortOrder::CreatedAtDesc), ..Default::default() }))
+            .await
+            .unwrap();
+        assert_eq!((recent[0].key.as_str(), recent[0].created_at), ("b", 3_000));
+
+        // Rows spread over several fragments are still ordered as a whole.
+        for (k, at) in [("e", 500), ("f", 4_000), ("g", 2_500)] {
+            let entry = PendingEntry {
+                created_at: at,
+                ..entry(k, "text")
+            };
+            handle.write_entries(DEFAULT_TABLE, vec![entry]).await.unwrap();
+        }
+        assert_eq!(list(Some(3), Some(1), SortOrder::CreatedAtDesc).await.unwrap(), ["b", "g", "c"]);
+        assert_eq!(list(Some(2), None, SortOrder::CreatedAtAsc).await.unwrap(), ["e", "a"]);
+
+        // `list_detailed` takes the same options.
+        handle.soft_delete("c".into(), None).await.unwrap();
+        let detailed = |include_deleted| {
+            let options = ListOptions {
+                offset: Some(1),
+                created_after: Some(1_000),
+                created_before: Some(3_000),
+                order: Some(SortOrder::CreatedAtAsc),
+                include_deleted,
+                ..Default::default()
+            };
+            handle.list_detailed(None, Some(2), Some(options))
+        };
+        let keys = |results: Vec<SearchResult>| results.into_iter().map(|r| r.key).collect::<Vec<_>>();
+        assert_eq!(keys(detailed(false).await.unwrap()), ["d", "g"]);
+        assert_eq!(keys(detailed(true).await.unwrap()), ["c", "d"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(handle.list_agents(None).await.unwrap().is_empty());
+
+        let entries = [("k1", "planner"), ("k2", "main"), ("k3", "planner")]
+            .iter()
+            .map(|(k, agent)| PendingEntry {
+                agent_id: agent.to_string(),
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        assert_eq!(handle.list_agents(None).await.unwrap(), vec!["main", "planner"]);
+    }
+
+    #[tokio::test]
+    async fn test_counts_by_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        assert!(handle.counts_by_agent(None).await.unwrap().is_empty());
+        assert!(handle.counts_by_agent(Some("missing".into())).await.unwrap().is_empty());
+
+        let entries = [("k1", "planner"), ("k2", "main"), ("k3", "planner"), ("k4", "planner")]
+            .iter()
+            .map(|(k, agent)| PendingEntry {
+                agent_id: agent.to_string(),
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+        // A staged entry counts, and re-storing a key doesn't count it twice.
+        handle
+            .store("k5".into(), "main".into(), "t".into(), vec![1.0; 4], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("k1".into(), "planner".into(), "t".into(), vec![1.0; 4], None, None)
+            .await
+            .unwrap();
+
+        let count = |agent_id: &str, count| AgentCount {
+            agent_id: agent_id.into(),
+            count,
+        };
+        assert_eq!(
+            handle.counts_by_agent(None).await.unwrap(),
+            vec![count("main", 2), count("planner", 3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_detailed() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        for (k, text) in [("proj:a", "A"), ("proj:it's", "B"), ("other:c", "C")] {
+            handle
+                .store(
+                    k.into(),
+                    "main".into(),
+                    text.into(),
+                    vec![1.0, 0.0, 0.0, 0.0],
+                    Some(format!("{{\"k\":\"{k}\"}}")),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let mut results = handle
+            .list_detailed(Some("proj:".into()), None, None)
+            .await
+            .unwrap();
+        results.sort_by(|a, b| a.key.cmp(&b.key));
+        let texts: Vec<_> = results.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(texts, ["A", "B"]);
+        assert_eq!(results[1].metadata.as_deref(), Some("{\"k\":\"proj:it's\"}"));
+        assert_eq!(results[0].score, 0.0);
+
+        let escaped = handle
+            .list_detailed(Some("proj:it'".into()), Some(5), None)
+            .await
+            .unwrap();
+        assert_eq!(escaped.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store(
+                "k1".into(),
+                "main".into(),
+                "text".into(),
+                vec![1.0, 0.0, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        handle.clear(None).await.unwrap();
+
+        let keys = handle.list(None, None, None).await.unwrap();
+        assert_eq!(keys.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_upsert_same_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let store = |text: &'static str| {
+            handle.store("k1".into(), "main".into(), text.into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+        };
+
+        // Racing first writes, then racing updates of an existing row.
+        let (a, b) = tokio::join!(store("a"), store("b"));
+        a.unwrap();
+        b.unwrap();
+        let (c, d) = tokio::join!(store("c"), store("d"));
+        c.unwrap();
+        d.unwrap();
+
+        assert_eq!(handle.count(None, None).await.unwrap(), 1);
+        let text = handle.get("k1".into(), None, false, None).await.unwrap().unwrap().text;
+        assert!(text == "c" || text == "d");
+        assert_eq!(handle.verify(None).await.unwrap().duplicate_keys, 0);
+    }
+
+    #[tokio::test]
+    async fn test_named_collections() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let a = || Some("a".to_string());
+        let b = || Some("b".to_string());
+
+        handle
+            .store("k1".into(), "main".into(), "in a".into(), vec![1.0, 0.0, 0.0, 0.0], None, Some(StoreOptions { collection: a(), ..Default::default() }))
+            .await
+            .unwrap();
+        handle
+            .store("k1".into(), "main".into(), "in b".into(), vec![1.0, 0.0, 0.0, 0.0], None, Some(StoreOptions { collection: b(), ..Default::default() }))
+            .await
+            .unwrap();
+        handle
+            .store("k2".into(), "main".into(), "in b".into(), vec![0.0, 1.0, 0.0, 0.0], None, Some(StoreOptions { collection: b(), ..Default::default() }))
+            .await
+            .unwrap();
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, Some(SearchOptions { collection: a(), ..Default::default() }))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].text, "in a");
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, Some(SearchOptions { collection: b(), ..Default::default() }))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.text == "in b"));
+
+        // The default collection is untouched.
+        assert!(handle.list(None, None, None).await.unwrap().is_empty());
+        assert_eq!(handle.count(a(), None).await.unwrap(), 1);
+        assert_eq!(handle.count(b(), None).await.unwrap(), 2);
+
+        handle.delete("k1".into(), b()).await.unwrap();
+        assert_eq!(handle.list(None, None, Some(ListOptions { collection: b(), ..Default::default() })).await.unwrap(), vec!["k2"]);
+        assert_eq!(handle.get("k1".into(), a(), false, None).await.unwrap().unwrap().text, "in a");
+    }
+
+    #[tokio::test]
+    async fn test_upsert() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store(
+                "k1".into(),
+                "main".into(),
+                "original".into(),
+                vec![1.0, 0.0, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        handle
+            .store(
+                "k1".into(),
+                "main".into(),
+                "updated".into(),
+                vec![0.0, 1.0, 0.0, 0.0],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let keys = handle.list(None, None, None).await.unwrap();
+        assert_eq!(keys.len(), 1);
+
+        let results = handle
+            .search(vec![0.0, 1.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].text, "updated");
+    }
+
+    #[tokio::test]
+    async fn test_store_many() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let memory = |key: &str, text: &str, embedding: Vec<f32>| MemoryEntry {
+            key: key.into(),
+            agent_id: "main".into(),
+            text: text.into(),
+            embedding,
+            metadata: None,
+            tags: None,
+            created_at: None,
+            embedding2: None,
+            columns: None,
+        };
+
+        handle
+            .store("a".into(), "main".into(), "old".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let written = handle
+            .store_many(vec![
+                memory("a", "new", vec![1.0, 0.0, 0.0, 0.0]),
+                memory("b", "first", vec![0.0, 1.0, 0.0, 0.0]),
+                memory("b", "second", vec![0.0, 1.0, 0.0, 0.0]),
+            ], None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(written, 2);
+
+        let mut results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None)
+            .await
+            .unwrap();
+        results.sort_by(|x, y| x.key.cmp(&y.key));
+        let texts: Vec<_> = results.iter().map(|r| (r.key.as_str(), r.text.as_str())).collect();
+        assert_eq!(texts, vec![("a", "new"), ("b", "second")]);
+
+        // One bad embedding rejects the whole batch.
+        let err = handle
+            .store_many(vec![
+                memory("c", "ok", vec![0.0, 0.0, 1.0, 0.0]),
+                memory("d", "bad", vec![1.0]),
+            ], None, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { index: Some(1), .. }));
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_store_many_chunked() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let schema = Arc::new(make_schema(4, false, EmbeddingDtype::F32, None, false, &[]));
+        let entries = (0..5).map(|i| entry(&format!("k{i}"), "v")).collect();
+        let sizes: Vec<_> = handle
+            .make_batches(&schema, Arc::new(entries), 2, false)
+            .unwrap()
+            .map(|b| b.unwrap().num_rows())
+            .collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(handle.make_batches(&schema, Arc::new(Vec::new()), 2, false).unwrap().count(), 1);
+
+        let n = 5000;
+        let entries: Vec<_> = (0..n)
+            .map(|i| MemoryEntry {
+                key: format!("k{i}"),
+                agent_id: "main".into(),
+                text: format!("row {i}"),
+                embedding: vec![i as f32, 1.0, 0.0, 0.0],
+                metadata: None,
+                tags: None,
+                created_at: None,
+                embedding2: None,
+                columns: None,
+            })
+            .collect();
+        let written = handle.store_many(entries, None, Some(512), None).await.unwrap();
+        assert_eq!(written, n);
+        assert_eq!(handle.count(None, None).await.unwrap(), n);
+        let row = handle.get("k4999".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(row.embedding, Some(vec![4999.0, 1.0, 0.0, 0.0]));
+        assert_eq!(handle.list_versions(None).await.unwrap().len(), 1);
+
+        let err = handle.store_many(Vec::new(), None, Some(0), None).await.unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_staging_reads_and_flush() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+            .await
+            .unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "first".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("k2".into(), "main".into(), "second".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        // Nothing committed yet, but reads see the buffer.
+        let committed = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        assert!(committed.list(None, None, None).await.unwrap().is_empty());
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+
+        let results = handle
+            .search(vec![0.0, 1.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].key, "k2");
+
+        let filtered = handle
+            .search(vec![0.0, 1.0, 0.0, 0.0], 5, Some("key = 'k1'".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].key, "k1");
+
+        handle.flush().await.unwrap();
+        assert_eq!(committed.list(None, None, None).await.unwrap().len(), 2);
+
+        // A staged upsert shadows the committed row until it is flushed.
+        handle
+            .store("k1".into(), "main".into(), "updated".into(), vec![0.0, 0.0, 1.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+        let results = handle
+            .search(vec![0.0, 0.0, 1.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].text, "updated");
+
+        handle.flush().await.unwrap();
+        assert_eq!(committed.list(None, None, None).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_staging_flushes_after_max_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let options = OpenOptions {
+            staging: Some(StagingConfig {
+                max_entries: Some(3),
+                flush_interval_ms: None,
+            }),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+            .await
+            .unwrap();
+        let committed = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        for k in ["a", "b"] {
+            handle
+                .store(k.into(), "main".into(), k.into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        assert!(committed.list(None, None, None).await.unwrap().is_empty());
+
+        handle
+            .store("c".into(), "main".into(), "c".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(committed.list(None, None, None).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_staging_flushes_on_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let options = OpenOptions {
+            staging: Some(StagingConfig {
+                max_entries: None,
+                flush_interval_ms: Some(50),
+            }),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+            .await
+            .unwrap();
+        let committed = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let mut flushed = false;
+        for _ in 0..40 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if committed.list(None, None, None).await.unwrap().len() == 1 {
+                flushed = true;
+                break;
+            }
+        }
+        assert!(flushed, "staged entry was never flushed by the timer");
+    }
+
+    #[tokio::test]
+    async fn test_search_committed_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "committed".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle.flush().await.unwrap();
+        handle
+            .store("k1".into(), "main".into(), "staged".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("k2".into(), "main".into(), "staged".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.text == "staged"));
+
+        let committed = SearchOptions {
+            committed_only: true,
+            ..Default::default()
+        };
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, Some(committed.clone()))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "k1");
+        assert_eq!(results[0].text, "committed");
+
+        let prefix = SearchOptions {
+            prefix_search: true,
+            ..committed
+        };
+        let results = handle.search(vec![1.0], 10, None, Some(prefix)).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].text, "committed");
+
+        // get and get_many read the same committed rows.
+        let committed = Some(GetOptions { committed_only: true, ..Default::default() });
+        let got = handle.get("k1".into(), None, false, committed.clone()).await.unwrap().unwrap();
+        assert_eq!(got.text, "committed");
+        assert!(handle.get("k2".into(), None, false, committed.clone()).await.unwrap().is_none());
+        let many = handle.get_many(vec!["k1".into(), "k2".into()], None, committed).await.unwrap();
+        assert_eq!(many.iter().map(|r| r.text.as_str()).collect::<Vec<_>>(), vec!["committed"]);
+        assert_eq!(handle.get("k1".into(), None, false, None).await.unwrap().unwrap().text, "staged");
+    }
+
+    #[tokio::test]
+    async fn test_staging_delete_drops_pending_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options)
+            .await
+            .unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle.delete("k1".into(), None).await.unwrap();
+        handle.flush().await.unwrap();
+
+        assert!(handle.list(None, None, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_where() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        // Missing table is a no-op
+        assert_eq!(
+            handle
+                .delete_where("agent_id = 'old'".into(), None, false, false, false)
+                .await
+                .unwrap(),
+            0
+        );
+
+        for (k, agent) in [("k1", "old"), ("k2", "old"), ("k3", "main")] {
+            handle
+                .store(k.into(), agent.into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+
+        let would_delete = handle
+            .delete_where("agent_id = 'old'".into(), None, true, false, false)
+            .await
+            .unwrap();
+        assert_eq!(would_delete, 2);
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 3);
+
+        let deleted = handle
+            .delete_where("agent_id = 'old'".into(), None, false, false, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert_eq!(handle.list(None, None, None).await.unwrap(), vec!["k3".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_append_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            append_only: true,
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options)
+            .await
+            .unwrap();
+
+        for text in ["first", "second"] {
+            handle
+                .store(
+                    "event".into(),
+                    "main".into(),
+                    text.into(),
+                    vec![1.0, 0.0, 0.0, 0.0],
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
+        let mut texts: Vec<_> = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.text)
+            .collect();
+        texts.sort();
+        assert_eq!(texts, ["first", "second"]);
+
+        let options = OpenOptions {
+            append_only: true,
+            staging: Some(StagingConfig {
+                max_entries: Some(10),
+                flush_interval_ms: None,
+            }),
+            ..Default::default()
+        };
+        let staged = LanceDBHandle::open_with_options(dir.path().join("staged").to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        for text in ["first", "second"] {
+            staged
+                .store("event".into(), "main".into(), text.into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        staged.flush().await.unwrap();
+        staged
+            .store("event".into(), "main".into(), "third".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(staged.count(None, None).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert_eq!(handle.delete_by_agent("retired".into(), None).await.unwrap(), 0);
+
+        let entries = [("k1", "retired"), ("k2", "main"), ("k3", "retired")]
+            .iter()
+            .map(|(k, agent)| PendingEntry {
+                agent_id: agent.to_string(),
+                ..entry(k, "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        assert_eq!(handle.delete_by_agent("retired".into(), None).await.unwrap(), 2);
+        assert_eq!(handle.list(None, None, None).await.unwrap(), vec!["k2"]);
+        assert_eq!(handle.delete_by_agent("retired".into(), None).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_delete_many() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        assert_eq!(handle.delete_many(vec!["k1".into()], None).await.unwrap(), 0);
+        assert_eq!(handle.delete_many(vec!["k1".into()], Some("missing".into())).await.unwrap(), 0);
+
+        let entries = ["k1", "k2", "it's"].iter().map(|k| entry(k, "text")).collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+        // Staged entries are deleted too.
+        for key in ["k4", "k5"] {
+            handle
+                .store(key.into(), "main".into(), "t".into(), vec![1.0; 4], None, None)
+                .await
+                .unwrap();
+        }
+
+        let keys = vec!["k1".into(), "it's".into(), "k5".into(), "absent".into()];
+        assert_eq!(handle.delete_many(keys, None).await.unwrap(), 3);
+        let mut remaining = handle.list(None, None, None).await.unwrap();
+        remaining.sort();
+        assert_eq!(remaining, vec!["k2", "k4"]);
+        assert_eq!(handle.delete_many(Vec::new(), None).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_delete_where_maintain_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let entries = (0..300)
+            .map(|i| PendingEntry {
+                embedding: vec![i as f32, 1.0, 0.0, 0.0],
+                ..entry(&format!("k{i}"), "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        table
+            .create_index(
+                &["embedding"],
+                lancedb::index::Index::IvfFlat(
+                    lancedb::index::vector::IvfFlatIndexBuilder::default().num_partitions(2),
+                ),
+            )
+            .execute()
+            .await
+            .unwrap();
+
+        let deleted = handle
+            .delete_where("created_at >= 0 AND key < 'k2'".into(), None, false, false, true)
+            .await
+            .unwrap();
+        assert!(deleted > 0);
+
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let stats = table.index_stats("embedding_idx").await.unwrap().unwrap();
+        assert_eq!(stats.num_indexed_rows as u64, 300 - deleted);
+        assert_eq!(stats.num_unindexed_rows, 0);
+        assert_eq!(table.count_rows(None).await.unwrap() as u64, 300 - deleted);
+    }
+
+    #[tokio::test]
+    async fn test_delete_where_guards() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        for k in ["k1", "k2"] {
+            handle
+                .store(k.into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+
+        let err = handle
+            .delete_where("  ".into(), None, false, false, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DeleteError { .. }));
+
+        let err = handle
+            .delete_where("no_such_column = 1".into(), None, false, false, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+
+        let deleted = handle
+            .delete_where(String::new(), None, false, true, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert!(handle.list(None, None, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nearest() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        assert!(handle
+            .nearest(vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap()
+            .is_none());
+
+        handle
+            .store("x".into(), "main".into(), "x".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("y".into(), "main".into(), "y".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle.flush().await.unwrap();
+        // A closer staged entry beats the committed rows.
+        handle
+            .store("z".into(), "main".into(), "z".into(), vec![0.0, 0.9, 0.1, 0.0], Some("{}".into()), None)
+            .await
+            .unwrap();
+
+        let hit = handle
+            .nearest(vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.key, "x");
+        assert!((hit.score - 1.0).abs() < 1e-6);
+
+        let hit = handle
+            .nearest(vec![0.0, 0.9, 0.1, 0.0], None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.key, "z");
+        assert_eq!(hit.metadata.as_deref(), Some("{}"));
+
+        let hit = handle
+            .nearest(vec![0.0, 0.9, 0.1, 0.0], Some("key != 'z'".into()), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hit.key, "y");
+
+        assert!(handle
+            .nearest(vec![1.0, 0.0, 0.0, 0.0], None, Some("missing".into()))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_error_on_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let strict = SearchOptions {
+            error_on_empty: true,
+            ..Default::default()
+        };
+
+        let results = handle.search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None).await.unwrap();
+        assert!(results.is_empty());
+        let err = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, Some(strict.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, Some(strict.clone()))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        let err = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, Some("agent_id = 'x'".into()), Some(strict))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+
+        // get, get_many, list and list_detailed take the same flag.
+        let strict_get = Some(GetOptions { error_on_empty: true, ..Default::default() });
+        assert!(handle.get("k1".into(), None, false, strict_get.clone()).await.unwrap().is_some());
+        assert!(handle.get("k2".into(), None, false, None).await.unwrap().is_none());
+        let err = handle.get("k2".into(), None, false, strict_get.clone()).await.unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+        assert_eq!(handle.get_many(vec!["k1".into()], None, strict_get.clone()).await.unwrap().len(), 1);
+        let err = handle.get_many(vec!["k2".into()], None, strict_get).await.unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+
+        let strict_list = ListOptions { error_on_empty: true, ..Default::default() };
+        assert_eq!(handle.list(None, None, Some(strict_list.clone())).await.unwrap(), vec!["k1"]);
+        let err = handle.list(Some("x".into()), None, Some(strict_list.clone())).await.unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+        let err = handle.list_detailed(Some("x".into()), None, Some(strict_list)).await.unwrap_err();
+        assert!(matches!(err, LanceError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_search_provenance() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let plain = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert!(plain[0].row_id.is_none());
+        assert!(plain[0].dataset_version.is_none());
+
+        let options = SearchOptions {
+            include_provenance: true,
+            ..Default::default()
+        };
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 1, None, Some(options))
+            .await
+            .unwrap();
+        let row_id = results[0].row_id.expect("row_id requested");
+        assert_eq!(results[0].fragment_id, Some(row_id >> 32));
+        assert!(results[0].dataset_version.unwrap() >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_collection_presets() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let preset = CollectionPreset {
+            max_rows_per_file: Some(2),
+            nprobes: Some(4),
+            refine_factor: Some(2),
+            ..Default::default()
+        };
+        let options = OpenOptions {
+            collection_presets: Some(HashMap::from([(DEFAULT_TABLE.to_string(), preset)])),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+
+        let entries = (0..5).map(|i| entry(&format!("k{i}"), "text")).collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        // max_rows_per_file splits the single append into three fragments.
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let native = table.as_native().unwrap();
+        assert_eq!(native.manifest().await.unwrap().fragments.len(), 3);
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_storage_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let err = LanceDBHandle::open_with_options(
+            db_path.clone(),
+            4,
+            OpenOptions {
+                storage_version: Some("9.9".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .err()
+        .expect("unknown storage version must be rejected");
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+
+        let handle = LanceDBHandle::open_with_options(
+            db_path,
+            4,
+            OpenOptions {
+                storage_version: Some("2.0".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let manifest = table.as_native().unwrap().manifest().await.unwrap();
+        assert_eq!(manifest.data_storage_format.version, "2.0");
+    }
+
+    /// Maps "x" to [1, 0] and anything else to [0, 1].
+    struct TwoDimEmbedder;
+
+    impl Reembedder for TwoDimEmbedder {
+        fn embed(&self, text: String) -> Vec<f32> {
+            if text.contains('x') {
+                vec![1.0, 0.0]
+            } else {
+                vec![0.0, 1.0]
+            }
+        }
+    }
+
+    struct BrokenEmbedder;
+
+    impl Reembedder for BrokenEmbedder {
+        fn embed(&self, _text: String) -> Vec<f32> {
+            vec![1.0]
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingReporter(Arc<Mutex<Vec<(u64, u64)>>>);
+
+    impl ProgressReporter for RecordingReporter {
+        fn on_progress(&self, done: u64, total: u64) {
+            self.0.lock().unwrap().push((done, total));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrate_dimension() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        for (k, text) in [("k1", "has x"), ("k2", "plain"), ("k3", "also plain")] {
+            handle
+                .store(k.into(), "main".into(), text.into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+
+        let reporter = RecordingReporter::default();
+        let migrated = handle
+            .migrate_dimension(None, 2, Box::new(TwoDimEmbedder), Some(Box::new(reporter.clone())))
+            .await
+            .unwrap();
+        assert_eq!(migrated, 3);
+        assert_eq!(reporter.0.lock().unwrap().last(), Some(&(3, 3)));
+
+        let reopened = LanceDBHandle::open(db_path, 2).await.unwrap();
+        let results = reopened
+            .search(vec![1.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].key, "k1");
+        assert_eq!(reopened.list(None, None, None).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_dimension_failure_keeps_original() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        assert!(handle
+            .migrate_dimension(None, 2, Box::new(BrokenEmbedder), None)
+            .await
+            .is_err());
+
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].key, "k1");
+    }
+
+    #[tokio::test]
+    async fn test_distance_metric() {
+        // "near" is closest in L2; "aligned" points the same way as the query.
+        let query = vec![1.0, 0.0, 0.0, 0.0];
+        let rows = [
+            ("near", vec![0.8, 0.3, 0.0, 0.0]),
+            ("aligned", vec![5.0, 0.0, 0.0, 0.0]),
+        ];
+
+        let mut keys_by_metric = Vec::new();
+        for metric in [DistanceMetric::L2, DistanceMetric::Cosine] {
+            let dir = tempfile::tempdir().unwrap();
+            let db_path = dir.path().to_str().unwrap().to_string();
+            let options = OpenOptions {
+                distance_metric: Some(metric),
+                ..Default::default()
+            };
+            let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+            for (key, embedding) in &rows {
+                handle
+                    .store((*key).into(), "main".into(), "t".into(), embedding.clone(), None, None)
+                    .await
+                    .unwrap();
+            }
+
+            let results = handle.search(query.clone(), 2, None, None).await.unwrap();
+            assert!(results[0].score >= results[1].score);
+            for r in &results {
+                assert!(r.distance.is_some());
+            }
+            if metric == DistanceMetric::L2 {
+                // score = -|a - b|² = -(0.04 + 0.09)
+                assert!((results[0].score + 0.13).abs() < 1e-5);
+            } else {
+                assert!((results[0].score - 1.0).abs() < 1e-5);
+            }
+            keys_by_metric.push(results.into_iter().map(|r| r.key).collect::<Vec<_>>());
+        }
+
+        assert_eq!(keys_by_metric[0], vec!["near", "aligned"]);
+        assert_eq!(keys_by_metric[1], vec!["aligned", "near"]);
+    }
+
+    #[tokio::test]
+    async fn test_prefix_search() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle
+            .store("full".into(), "main".into(), "a".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("prefix".into(), "main".into(), "b".into(), vec![0.5, 0.5, 9.0, 9.0], None, None)
+            .await
+            .unwrap();
+
+        // Without the option a short vector is rejected.
+        let err = handle.search(vec![0.5, 0.5], 1, None, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 4, actual: 2, .. }));
+
+        let options = SearchOptions {
+            prefix_search: true,
+            ..Default::default()
+        };
+        let results = handle
+            .search(vec![0.5, 0.5], 2, None, Some(options.clone()))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "prefix");
+        assert!((results[0].score - 1.0).abs() < 1e-6);
+        // 0.5² + 0.5² for the first two dimensions of "full".
+        assert!((results[1].distance.unwrap() - 0.5).abs() < 1e-6);
+
+        let filtered = handle
+            .search(vec![0.5, 0.5], 2, Some("key = 'full'".into()), Some(options))
+            .await
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].key, "full");
+    }
+
+    #[tokio::test]
+    async fn test_tags() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let v = vec![1.0, 0.0, 0.0, 0.0];
+
+        // A table written before tags were enabled gains the column later.
+        let plain = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        plain
+            .store("old".into(), "main".into(), "old".into(), v.clone(), None, None)
+            .await
+            .unwrap();
+        assert!(plain
+            .store("x".into(), "main".into(), "x".into(), v.clone(), None, Some(StoreOptions { tags: Some(vec!["a".into()]), ..Default::default() }))
+            .await
+            .is_err());
+
+        let options = OpenOptions {
+            enable_tags: true,
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        let tags = |t: &[&str]| Some(t.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+        handle
+            .store("a".into(), "main".into(), "a".into(), v.clone(), None, Some(StoreOptions { tags: tags(&["work", "urgent"]), ..Default::default() }))
+            .await
+            .unwrap();
+        handle
+            .store("b".into(), "main".into(), "b".into(), v.clone(), None, Some(StoreOptions { tags: tags(&["work"]), ..Default::default() }))
+            .await
+            .unwrap();
+        handle.flush().await.unwrap();
+        // Left staged so the buffered path is filtered too.
+        handle
+            .store("c".into(), "main".into(), "c".into(), v.clone(), None, Some(StoreOptions { tags: tags(&["home"]), ..Default::default() }))
+            .await
+            .unwrap();
+
+        let keys = |results: Vec<SearchResult>| {
+            let mut keys: Vec<_> = results.into_iter().map(|r| r.key).collect();
+            keys.sort();
+            keys
+        };
+        let all = SearchOptions {
+            has_tags: tags(&["work", "urgent"]),
+            ..Default::default()
+        };
+        let results = handle.search(v.clone(), 10, None, Some(all)).await.unwrap();
+        assert_eq!(keys(results), vec!["a"]);
+
+        let any = SearchOptions {
+            has_tags: tags(&["urgent", "home"]),
+            match_any_tag: true,
+            ..Default::default()
+        };
+        let results = handle.search(v.clone(), 10, None, Some(any.clone())).await.unwrap();
+        assert_eq!(keys(results), vec!["a", "c"]);
+
+        let results = handle
+            .search(v.clone(), 10, Some("key != 'a'".into()), Some(any))
+            .await
+            .unwrap();
+        assert_eq!(keys(results), vec!["c"]);
+
+        let results = handle.search(v, 10, None, None).await.unwrap();
+        assert_eq!(keys(results), vec!["a", "b", "c", "old"]);
+    }
+
+    #[tokio::test]
+    async fn test_centroid() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(handle.centroid(None, None).await.is_err());
+
+        handle
+            .store("a".into(), "main".into(), "a".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("b".into(), "main".into(), "b".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("c".into(), "other".into(), "c".into(), vec![0.0, 0.0, 3.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let centroid = handle.centroid(None, None).await.unwrap();
+        let expected = [1.0 / 3.0, 1.0 / 3.0, 1.0, 0.0];
+        assert_eq!(centroid.len(), 4);
+        for (c, e) in centroid.iter().zip(expected) {
+            assert!((c - e).abs() < 1e-6);
+        }
+
+        let centroid = handle
+            .centroid(Some("agent_id = 'main'".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(centroid, vec![0.5, 0.5, 0.0, 0.0]);
+
+        assert!(handle
+            .centroid(Some("agent_id = 'nobody'".into()), None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connection_reused() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            let results = handle
+                .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None)
+                .await
+                .unwrap();
+            assert_eq!(results.len(), 1);
+        }
+        assert_eq!(handle.connects.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_write_error_classification() {
+        let location = snafu::Location::new(file!(), line!(), column!());
+        let conflict = lancedb::Error::Lance {
+            source: lance::Error::CommitConflict {
+                version: 2,
+                source: "concurrent transaction".into(),
+                location,
+            },
+        };
+        assert!(matches!(write_error(conflict), LanceError::ConcurrencyConflict { .. }));
+        let other = lancedb::Error::Lance {
+            source: lance::Error::invalid_input("bad batch", location),
+        };
+        assert!(matches!(write_error(other), LanceError::InsertError { .. }));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_stores() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let handle = handle.clone();
+                tokio::spawn(async move {
+                    handle
+                        .store(format!("k{i}"), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                        .await
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert_eq!(handle.count(None, None).await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_default_table_option() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            default_table: Some("notes".into()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0; 4], None, None)
+            .await
+            .unwrap();
+
+        let list = |collection: Option<&str>| {
+            handle.list(None, None, Some(ListOptions { collection: collection.map(String::from), ..Default::default() }))
+        };
+        assert_eq!(list(None).await.unwrap(), ["k1"]);
+        assert_eq!(list(Some("notes")).await.unwrap(), ["k1"]);
+        assert!(list(Some(DEFAULT_TABLE)).await.unwrap().is_empty());
+        let db = handle.connect().await.unwrap();
+        assert_eq!(db.table_names().execute().await.unwrap(), ["notes"]);
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        let health = handle.health_check().await.unwrap();
+        assert!(health.can_connect);
+        assert_eq!((health.table_count, health.db_path.as_str()), (0, db_path.as_str()));
+
+        handle.write_entries(DEFAULT_TABLE, vec![entry("a", "1")]).await.unwrap();
+        assert_eq!(handle.health_check().await.unwrap().table_count, 1);
+        handle.close().await.unwrap();
+        assert!(!handle.health_check().await.unwrap().can_connect);
+    }
+
+    #[tokio::test]
+    async fn test_close() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig {
+                max_entries: None,
+                flush_interval_ms: None,
+            }),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+            .await
+            .unwrap();
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        handle.close().await.unwrap();
+        let closed = |r: Result<(), LanceError>| matches!(r, Err(LanceError::ConnectionFailed { .. }));
+        let search = handle.search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None).await;
+        assert!(closed(search.map(|_| ())));
+        assert!(closed(
+            handle
+                .store("k2".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .map(|_| ())
+        ));
+        assert!(closed(handle.list(None, None, None).await.map(|_| ())));
+        assert!(closed(handle.close().await));
+
+        // The staged entry was flushed before the handle closed.
+        let reopened = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert_eq!(reopened.list(None, None, None).await.unwrap(), vec!["k1"]);
+    }
+
+    #[tokio::test]
+    async fn test_table_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let cached = |h: &LanceDBHandle| h.table_cache.lock().unwrap().contains_key(DEFAULT_TABLE);
+
+        // A missing table is never cached.
+        handle.search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None).await.unwrap();
+        assert!(!cached(&handle));
+
+        handle
+            .store("k1".into(), "main".into(), "one".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        let (a, b) = tokio::join!(
+            handle.search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None),
+            handle.search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None),
+        );
+        assert_eq!(a.unwrap().len(), 1);
+        assert_eq!(b.unwrap().len(), 1);
+        assert!(cached(&handle));
+
+        // Writes evict the cached table so the next read sees them.
+        handle
+            .store("k2".into(), "main".into(), "two".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert!(!cached(&handle));
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+        assert!(cached(&handle));
+
+        handle.delete("k1".into(), None).await.unwrap();
+        assert_eq!(handle.list(None, None, None).await.unwrap(), vec!["k2"]);
+
+        handle.clear(None).await.unwrap();
+        assert!(handle.list(None, None, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_table_cache_sees_other_handles() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let writer = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        let open_reader = |read_consistency_ms| {
+            let options = OpenOptions {
+                read_consistency_ms,
+                ..Default::default()
+            };
+            LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+        };
+        let reader = open_reader(0).await.unwrap();
+        let lagging = open_reader(60_000).await.unwrap();
+        let periodic = open_reader(100).await.unwrap();
+        writer
+            .store("k1".into(), "main".into(), "one".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(reader.count(None, None).await.unwrap(), 1);
+        assert!(reader.table_cache.lock().unwrap().contains_key(DEFAULT_TABLE));
+        assert_eq!(lagging.count(None, None).await.unwrap(), 1);
+        assert_eq!(periodic.count(None, None).await.unwrap(), 1);
+
+        // The reader's cached table still sees rows written after it opened.
+        writer
+            .store("k2".into(), "main".into(), "two".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        writer.delete("k1".into(), None).await.unwrap();
+        assert_eq!(reader.count(None, None).await.unwrap(), 1);
+        let results = reader.search(vec![0.0, 1.0, 0.0, 0.0], 10, None, None).await.unwrap();
+        assert_eq!(results.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), vec!["k2"]);
+        assert!(reader.get("k2".into(), None, false, None).await.unwrap().is_some());
+
+        // Other handles' commits show up once the interval has passed.
+        assert!(lagging.get("k2".into(), None, false, None).await.unwrap().is_none());
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(periodic.get("k2".into(), None, false, None).await.unwrap().is_some());
+        assert_eq!(periodic.count(None, None).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        // Nothing to index yet.
+        handle.create_index(None, None, None, None).await.unwrap();
+
+        let entries = (0..300)
+            .map(|i| PendingEntry {
+                embedding: vec![(i % 20) as f32, (i / 20) as f32, 0.0, 0.0],
+                ..entry(&format!("k{i}"), "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        handle.create_index(None, None, None, None).await.unwrap();
+        // Idempotent.
+        handle.create_index(None, Some(4), Some(2), None).await.unwrap();
+
+        handle
+            .write_entries(DEFAULT_TABLE, vec![entry("late", "text")])
+            .await
+            .unwrap();
+        let info = handle.index_info(None).await.unwrap();
+        assert_eq!(info.len(), 1);
+        assert_eq!(info[0].column, "embedding");
+        assert_eq!(info[0].index_type, "IVF_PQ");
+        assert_eq!((info[0].num_indexed_rows, info[0].num_unindexed_rows), (300, 1));
+        assert!(handle.index_info(Some("missing".into())).await.unwrap().is_empty());
+
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        assert_eq!(table.list_indices().await.unwrap().len(), 1);
+
+        let response = handle
+            .search_detailed(vec![7.0, 3.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert!(response.approximate);
+        assert_eq!(response.results[0].key, "k67");
+    }
+
+    #[tokio::test]
+    async fn test_auto_index_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            auto_index_threshold: Some(300),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        let batch = |range: std::ops::Range<i32>| {
+            range
+                .map(|i| MemoryEntry {
+                    key: format!("k{i}"),
+                    agent_id: "main".into(),
+                    text: "text".into(),
+                    embedding: vec![(i % 20) as f32, (i / 20) as f32, 1.0, 0.0],
+                    metadata: None,
+                    tags: None,
+                    created_at: None,
+                    embedding2: None,
+                    columns: None,
+                })
+                .collect()
+        };
+
+        handle.store_many(batch(0..299), None, None, None).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(handle.index_info(None).await.unwrap().is_empty());
+
+        handle.store_many(batch(299..310), None, None, None).await.unwrap();
+        let mut info = Vec::new();
+        for _ in 0..300 {
+            info = handle.index_info(None).await.unwrap();
+            if !info.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(info.len(), 1);
+        assert_eq!(info[0].column, "embedding");
+        assert_eq!(info[0].num_indexed_rows, 310);
+
+        // Int8 embeddings have no vector index to build.
+        let options = OpenOptions {
+            auto_index_threshold: Some(300),
+            embedding_dtype: Some(EmbeddingDtype::Int8 { max_abs: 20.0 }),
+            ..Default::default()
+        };
+        let int8 = LanceDBHandle::open_with_options(dir.path().join("int8").to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        int8.store_many(batch(0..310), None, None, None).await.unwrap();
+        assert!(int8.auto_indexing.lock().unwrap().is_empty());
+        assert!(int8.index_info(None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_drop_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        // Nothing to drop yet.
+        handle.drop_index(None, None).await.unwrap();
+
+        let entries = (0..300)
+            .map(|i| PendingEntry {
+                embedding: vec![(i % 20) as f32, (i / 20) as f32, 0.0, 0.0],
+                ..entry(&format!("k{i}"), "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+        handle.create_index(None, None, None, None).await.unwrap();
+        handle.create_scalar_index(None, "agent_id".into()).await.unwrap();
+
+        handle.drop_index(None, None).await.unwrap();
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let indices = table.list_indices().await.unwrap();
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].columns, ["agent_id"]);
+        // Idempotent.
+        handle.drop_index(None, None).await.unwrap();
+
+        let response = handle
+            .search_detailed(vec![7.0, 3.0, 0.0, 0.0], 1, None, None)
+            .await
+            .unwrap();
+        assert!(!response.approximate);
+        assert_eq!(response.results[0].key, "k67");
+
+        handle.drop_index(None, Some("agent_id".into())).await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        assert!(table.list_indices().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_non_finite_embedding_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let err = handle
+            .store(
+                "k".into(),
+                "agent".into(),
+                "text".into(),
+                vec![0.1, f32::NAN, 0.3, 0.4],
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(&err, LanceError::InsertError { msg } if msg.contains("embedding[1]")));
+        assert_eq!(handle.count(None, None).await.unwrap(), 0);
+
+        let err = handle
+            .search(vec![f32::NAN, 0.0, 0.0, 0.0], 5, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(&err, LanceError::QueryError { msg } if msg.contains("query_vector[0]")));
+    }
+
+    #[tokio::test]
+    async fn test_normalize_on_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            normalize: true,
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options)
+            .await
+            .unwrap();
+        let store = |embedding| {
+            handle.store(
+                "k".into(),
+                "agent".into(),
+                "text".into(),
+                embedding,
+                None,
+                None,
+            )
+        };
+        store(vec![3.0, 4.0, 0.0, 0.0]).await.unwrap();
+
+        let db = handle.connect().await.unwrap();
+        let table = db.open_table(DEFAULT_TABLE).execute().await.unwrap();
+        let batches = scan_batches(&table, None, false).await.unwrap();
+        let vectors = batches[0]
+            .column_by_name("embedding")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap()
+            .value(0);
+        let stored = vectors.as_any().downcast_ref::<Float32Array>().unwrap();
+        let norm = stored.values().iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+        assert!((stored.value(0) - 0.6).abs() < 1e-6);
+
+        let err = store(vec![0.0; 4]).await.unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+        let err = handle
+            .search(vec![0.0; 4], 1, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_search_index_tuning() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let entries = (0..300)
+            .map(|i| PendingEntry {
+                embedding: vec![(i % 20) as f32, (i / 20) as f32, 0.0, 0.0],
+                ..entry(&format!("k{i}"), "text")
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let tuned = |nprobes| SearchOptions {
+            nprobes: Some(nprobes),
+            refine_factor: Some(4),
+            ..Default::default()
+        };
+        let query = vec![7.0, 3.0, 0.0, 0.0];
+        // Ignored without an index.
+        let flat = handle
+            .search(query.clone(), 3, None, Some(tuned(8)))
+            .await
+            .unwrap();
+        assert_eq!(flat[0].key, "k67");
+
+        handle.create_index(None, Some(4), Some(2), None).await.unwrap();
+        for nprobes in [1, 4] {
+            let results = handle
+                .search(query.clone(), 3, None, Some(tuned(nprobes)))
+                .await
+                .unwrap();
+            assert_eq!(results[0].key, "k67");
+            assert_eq!(results.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_default_sub_vectors() {
+        assert_eq!(default_sub_vectors(1536), 96);
+        assert_eq!(default_sub_vectors(8), 1);
+        assert_eq!(default_sub_vectors(48), 3);
+        assert_eq!(default_sub_vectors(4), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_detailed_approximate() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let entries = (0..300)
+            .map(|i| PendingEntry {
+                key: format!("k{i}"),
+                agent_id: "main".into(),
+                text: format!("text {i}"),
+                embedding: vec![i as f32, 1.0, 0.0, 0.0],
+                metadata: None,
+                created_at: 0,
+                tags: None,
+                embedding2: None,
+                columns: Vec::new(),
+            })
+            .collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        let query = vec![5.0, 1.0, 0.0, 0.0];
+        let response = handle.search_detailed(query.clone(), 3, None, None).await.unwrap();
+        assert!(!response.approximate);
+        assert_eq!(response.results[0].key, "k5");
+
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        table
+            .create_index(
+                &["embedding"],
+                lancedb::index::Index::IvfFlat(
+                    lancedb::index::vector::IvfFlatIndexBuilder::default().num_partitions(2),
+                ),
+            )
+            .execute()
+            .await
+            .unwrap();
+        handle.evict_table(DEFAULT_TABLE);
+
+        let response = handle.search_detailed(query, 3, None, None).await.unwrap();
+        assert!(response.approximate);
+        assert_eq!(response.results.len(), 3);
+
+        let exact = SearchOptions {
+            exact: true,
+            ..Default::default()
+        };
+        let query = vec![5.2, 1.0, 0.0, 0.0];
+        let response = handle.search_detailed(query, 3, None, Some(exact)).await.unwrap();
+        assert!(!response.approximate);
+        let keys: Vec<_> = response.results.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, ["k5", "k6", "k4"]);
+    }
+
+    fn write_ipc_file(path: &std::path::Path, handle: &LanceDBHandle, batches: Vec<Vec<PendingEntry>>) {
+        let schema = Arc::new(handle.new_table_schema());
+        let file = std::fs::File::create(path).unwrap();
+        let mut writer = arrow_ipc::writer::FileWriter::try_new(file, &schema).unwrap();
+        for entries in batches {
+            writer.write(&handle.make_batch(&schema, entries).unwrap()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn entry(key: &str, text: &str) -> PendingEntry {
+        PendingEntry {
+            key: key.into(),
+            agent_id: "main".into(),
+            text: text.into(),
+            embedding: vec![1.0, 0.0, 0.0, 0.0],
+            metadata: None,
+            created_at: 0,
+            tags: None,
+            embedding2: None,
+            columns: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_arrow_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db").to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+
+        let path = dir.path().join("import.arrow");
+        write_ipc_file(
+            &path,
+            &handle,
+            vec![vec![entry("a", "a1"), entry("b", "b1")], vec![entry("c", "c1")]],
+        );
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let imported = handle
+            .import_arrow_file(
+                None,
+                path.to_str().unwrap().into(),
+                false,
+                Some(Box::new(RecordingReporter(calls.clone()))),
+            )
+            .await
+            .unwrap();
+        assert_eq!(imported, 3);
+        assert_eq!(*calls.lock().unwrap(), vec![(1, 2), (2, 2)]);
+
+        write_ipc_file(&path, &handle, vec![vec![entry("a", "a2"), entry("d", "d1")]]);
+        let imported = handle
+            .import_arrow_file(None, path.to_str().unwrap().into(), true, None)
+            .await
+            .unwrap();
+        assert_eq!(imported, 2);
+
+        let mut keys = handle.list(None, None, None).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["a", "b", "c", "d"]);
+        let results = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 10, Some("key = 'a'".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].text, "a2");
+    }
+
+    #[tokio::test]
+    async fn test_import_arrow_file_schema_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db").to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        // Embeddings of the wrong width are rejected before anything is written.
+        let wide_path = dir.path().join("wide_db").to_str().unwrap().to_string();
+        let wide = LanceDBHandle::open(wide_path, 8).await.unwrap();
+        let path = dir.path().join("wide.arrow");
+        let mut e = entry("x", "x");
+        e.embedding = vec![0.0; 8];
+        write_ipc_file(&path, &wide, vec![vec![e]]);
+
+        let err = handle
+            .import_arrow_file(None, path.to_str().unwrap().into(), false, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+        assert_eq!(handle.list(None, None, None).await.unwrap(), vec!["k1"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_parquet() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db").to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for (key, v) in [("a", 1.0), ("b", 2.0), ("c", 3.0)] {
+            handle
+                .store(key.into(), "main".into(), format!("text {key}"), vec![v, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+
+        let path = dir.path().join("export.parquet");
+        let exported = handle
+            .export_parquet(None, path.to_str().unwrap().into())
+            .await
+            .unwrap();
+        assert_eq!(exported, 3);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+        let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        for column in ["key", "agent_id", "text", "embedding", "metadata", "created_at"] {
+            assert!(batch.column_by_name(column).is_some(), "missing {column}");
+        }
+        let keys = batch.column_by_name("key").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
+        let i = (0..3).find(|&i| keys.value(i) == "b").unwrap();
+        let texts = batch.column_by_name("text").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(texts.value(i), "text b");
+        assert_eq!(embedding_at(&batch, i, EmbeddingDtype::F32), Some(vec![2.0, 0.0, 0.0, 0.0]));
+
+        // A missing collection exports an empty file.
+        let exported = handle
+            .export_parquet(Some("none".into()), path.to_str().unwrap().into())
+            .await
+            .unwrap();
+        assert_eq!(exported, 0);
+    }
+
+    #[tokio::test]
+    async fn test_import_parquet() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db").to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for (key, v) in [("a", 1.0), ("b", 2.0)] {
+            handle
+                .store(key.into(), "main".into(), format!("text {key}"), vec![v, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        let path = dir.path().join("dump.parquet");
+        let path = path.to_str().unwrap().to_string();
+        handle.export_parquet(None, path.clone()).await.unwrap();
+
+        handle.clear(None).await.unwrap();
+        let imported = handle.import_parquet(None, path.clone(), ImportMode::Append, None).await.unwrap();
+        assert_eq!(imported, 2);
+        let entry = handle.get("b".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(entry.text, "text b");
+        assert_eq!(entry.embedding, Some(vec![2.0, 0.0, 0.0, 0.0]));
+
+        // Appending the same rows again upserts them rather than duplicating keys.
+        handle
+            .store("a".into(), "main".into(), "changed".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle.import_parquet(None, path.clone(), ImportMode::Append, None).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
+        let entry = handle.get("a".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(entry.text, "text a");
+        handle.import_parquet(None, path.clone(), ImportMode::Overwrite, None).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
+
+        // A dump with a different embedding width is rejected.
+        let wide_path = dir.path().join("wide_db").to_str().unwrap().to_string();
+        let wide = LanceDBHandle::open(wide_path, 8).await.unwrap();
+        let err = wide.import_parquet(None, path.clone(), ImportMode::Append, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+        assert_eq!(wide.count(None, None).await.unwrap(), 0);
+
+        // A rejected overwrite keeps the staged entries it would have replaced.
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let staged_path = dir.path().join("staged_db").to_str().unwrap().to_string();
+        let staged = LanceDBHandle::open_with_options(staged_path, 8, options).await.unwrap();
+        staged
+            .store("s".into(), "main".into(), "t".into(), vec![1.0; 8], None, None)
+            .await
+            .unwrap();
+        let err = staged.import_parquet(None, path, ImportMode::Overwrite, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+        assert!(staged.exists("s".into(), None).await.unwrap());
+        staged.flush().await.unwrap();
+        assert_eq!(staged.count(None, None).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_json_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for (key, v) in [("a", 0.1), ("b", 0.2), ("c", 0.3)] {
+            handle
+                .store(
+                    key.into(),
+                    "main".into(),
+                    format!("text {key}"),
+                    vec![v, 1.0 / 3.0, 0.0, 0.0],
+                    Some(format!(r#"{{"source":"{key}"}}"#)),
+                    Some(StoreOptions { created_at: Some(1000), ..Default::default() }),
+                )
+                .await
+                .unwrap();
+        }
+
+        let json = handle.export_json(None).await.unwrap();
+        let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(records.as_array().unwrap().len(), 3);
+
+        handle.clear(None).await.unwrap();
+        assert_eq!(handle.import_json(None, json.clone(), ImportMode::Append).await.unwrap(), 3);
+        let entry = handle.get("b".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(entry.text, "text b");
+        assert_eq!(entry.metadata.as_deref(), Some(r#"{"source":"b"}"#));
+        assert_eq!(entry.created_at, 1000);
+        assert_eq!(entry.embedding, Some(vec![0.2, 1.0 / 3.0, 0.0, 0.0]));
+        assert_eq!(handle.export_json(None).await.unwrap().len(), json.len());
+
+        handle
+            .store("d".into(), "main".into(), "d".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        let mut bad = records.clone();
+        bad[2]["created_at"] = serde_json::json!(-1);
+        let err = handle
+            .import_json(None, bad.to_string(), ImportMode::Overwrite)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+        assert_eq!(handle.count(None, None).await.unwrap(), 4);
+
+        handle.import_json(None, json, ImportMode::Overwrite).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 3);
+
+        let err = handle
+            .import_json(None, "{}".into(), ImportMode::Append)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_describe() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(matches!(handle.describe(None).await, Err(LanceError::TableError { .. })));
+        handle
+            .store("k1".into(), "main".into(), "text".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        let info = handle.describe(None).await.unwrap();
+        assert_eq!(info.embedding_dim, Some(4));
+        let expected: Vec<FieldInfo> = make_schema(4, false, EmbeddingDtype::F32, None, false, &[])
+            .fields()
+            .iter()
+            .map(|f| FieldInfo {
+                name: f.name().clone(),
+                data_type: f.data_type().to_string(),
+                nullable: f.is_nullable(),
+            })
+            .collect();
+        assert_eq!(info.fields, expected);
+        let names: Vec<String> = info.fields.iter().map(|f| f.name.clone()).collect();
+        assert_eq!(names, ["key", "agent_id", "text", "embedding", "metadata", "created_at"]);
+    }
+
+    #[test]
+    fn test_sql_quote_literal() {
+        assert_eq!(sql_quote_literal("plain"), "'plain'");
+        assert_eq!(sql_quote_literal(""), "''");
+        assert_eq!(sql_quote_literal("it's"), "'it''s'");
+        assert_eq!(sql_quote_literal("''"), "''''''");
+        assert_eq!(sql_quote_literal("x' OR '1'='1"), "'x'' OR ''1''=''1'");
+        assert_eq!(sql_quote_literal(r"a\'b"), r"'a\''b'");
+        assert_eq!(sql_quote_literal("héllo 日本 🦀"), "'héllo 日本 🦀'");
+        assert_eq!(sql_string_list(["a", "b'c"].into_iter()), "'a', 'b''c'");
+    }
+
+    #[tokio::test]
+    async fn test_quoted_keys_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let tricky = ["it's", "x' OR '1'='1", "''", "日本'語"];
+        for key in tricky {
+            handle
+                .store(key.into(), "o'brien".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        handle
+            .store("other".into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        for key in tricky {
+            assert!(handle.exists(key.into(), None).await.unwrap(), "{key:?}");
+            assert!(handle.get(key.into(), None, false, None).await.unwrap().is_some());
+        }
+        let found = handle
+            .get_many(tricky.iter().map(|k| k.to_string()).collect(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), tricky.len());
+        let filter = meta_filter_sql(vec![MetaFilter::AgentId { agent_id: "o'brien".into() }]);
+        assert_eq!(handle.count(None, Some(filter)).await.unwrap(), 4);
+
+        handle.delete("x' OR '1'='1".into(), None).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_list_prefix_special_characters() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for key in ["a'b%c", "a'b%cd", "a'bxc", r"a\b", r"a\\b", "ab", "a_b", "a\"b"] {
+            handle
+                .store(key.into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+
+        let cases: [(&str, &[&str]); 7] = [
+            ("a'b%c", &["a'b%c", "a'b%cd"]),
+            ("a'b%", &["a'b%c", "a'b%cd"]),
+            ("%", &[]),
+            (r"a\", &[r"a\\b", r"a\b"]),
+            (r"a\\", &[r"a\\b"]),
+            ("a_", &["a_b"]),
+            ("a\"", &["a\"b"]),
+        ];
+        for (prefix, expected) in cases {
+            let mut keys = handle
+                .list(Some(prefix.into()), None, None)
+                .await
+                .unwrap();
+            keys.sort();
+            assert_eq!(keys, expected, "prefix {prefix:?}");
+        }
+        let filter = meta_filter_sql(vec![MetaFilter::KeyPrefix { prefix: "a_".into() }]);
+        assert_eq!(handle.count(None, Some(filter)).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_scalar_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.create_scalar_index(None, "key".into()).await.unwrap();
+        let entries = (0..50).map(|i| entry(&format!("k{i}"), &format!("v{i}"))).collect();
+        handle.write_entries(DEFAULT_TABLE, entries).await.unwrap();
+
+        handle.create_scalar_index(None, "key".into()).await.unwrap();
+        handle.create_scalar_index(None, "key".into()).await.unwrap();
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let indices = table.list_indices().await.unwrap();
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].index_type, IndexType::BTree);
+
+        let got = handle.get("k17".into(), None, false, None).await.unwrap().unwrap();
+        assert_eq!(got.text, "v17");
+        handle.write_entries(DEFAULT_TABLE, vec![entry("k17", "new"), entry("k50", "v50")]).await.unwrap();
+        assert_eq!(handle.get("k17".into(), None, false, None).await.unwrap().unwrap().text, "new");
+        assert!(handle.exists("k50".into(), None).await.unwrap());
+        handle.delete("k3".into(), None).await.unwrap();
+        assert!(!handle.exists("k3".into(), None).await.unwrap());
+        assert_eq!(handle.count(None, None).await.unwrap(), 50);
+
+        for column in ["embedding", "missing"] {
+            let err = handle.create_scalar_index(None, column.into()).await.unwrap_err();
+            assert!(matches!(err, LanceError::SchemaError { .. }), "{column}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_touch() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        handle
+            .store_many(
+                vec![MemoryEntry {
+                    key: "old".into(),
+                    agent_id: "main".into(),
+                    text: "text".into(),
+                    embedding: vec![1.0, 0.0, 0.0, 0.0],
+                    metadata: None,
+                    tags: None,
+                    created_at: Some(1000),
+                    embedding2: None,
+                    columns: None,
+                }],
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        handle
+            .store("staged".into(), "main".into(), "text".into(), vec![0.0, 1.0, 0.0, 0.0], None, Some(StoreOptions { created_at: Some(1000), ..Default::default() }))
+            .await
+            .unwrap();
+
+        for key in ["old", "staged"] {
+            assert!(handle.touch(key.into(), None).await.unwrap());
+            let got = handle.get(key.into(), None, true, None).await.unwrap().unwrap();
+            assert!(got.created_at > 1000, "{key}");
+            assert_eq!(got.text, "text");
+        }
+        assert!(!handle.touch("missing".into(), None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_max_rows_evicts_oldest() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            max_rows: Some(2),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
+        for (key, ms) in [("b", 2000), ("a", 1000), ("c", 3000)] {
+            handle
+                .store(key.into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, Some(StoreOptions { created_at: Some(ms), ..Default::default() }))
+                .await
+                .unwrap();
+        }
+        let mut keys = handle.list(None, None, None).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["b", "c"]);
+
+        // Ties on created_at are broken by key, so the cap is exact.
+        let entries = ["x", "y", "z"]
+            .iter()
+            .map(|k| MemoryEntry {
+                key: k.to_string(),
+                agent_id: "main".into(),
+                text: "t".into(),
+                embedding: vec![1.0, 0.0, 0.0, 0.0],
+                metadata: None,
+                tags: None,
+                created_at: Some(5000),
+                embedding2: None,
+                columns: None,
             })
             .collect();
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-        scored.truncate(limit as usize);
+        handle.store_many(entries, None, None, None).await.unwrap();
+        let mut keys = handle.list(None, None, None).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["y", "z"]);
 
-        let results = scored
-            .into_iter()
-            .map(|(i, rrf_score)| HybridSearchResult {
-                key: candidates[i].key.clone(),
-                text: candidates[i].text.clone(),
-                vector_rank: vector_ranks[i],
-                text_rank: text_ranks[i],
-                rrf_score,
-                metadata: candidates[i].metadata.clone(),
+        // Only the rows over the cap are evicted.
+        let dir = tempfile::tempdir().unwrap();
+        let options = OpenOptions {
+            max_rows: Some(10),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        let entries = (0..11)
+            .map(|i| MemoryEntry {
+                key: format!("k{i:02}"),
+                agent_id: "main".into(),
+                text: "t".into(),
+                embedding: vec![1.0, 0.0, 0.0, 0.0],
+                metadata: None,
+                tags: None,
+                created_at: Some(1000 + i),
+                embedding2: None,
+                columns: None,
             })
             .collect();
+        handle.store_many(entries, None, None, None).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 10);
+        assert!(!handle.exists("k00".into(), None).await.unwrap());
+        assert!(handle.exists("k01".into(), None).await.unwrap());
+        handle
+            .store("new".into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 10);
+        assert!(!handle.exists("k01".into(), None).await.unwrap());
+        assert!(handle.exists("new".into(), None).await.unwrap());
+
+        // The cap spans agents, so a scoped handle can't enforce it.
+        let options = OpenOptions {
+            max_rows: Some(10),
+            agent_scope: Some("main".into()),
+            ..Default::default()
+        };
+        let err = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, LanceError::SchemaError { .. }), "{err:?}");
+    }
 
-        Ok(results)
+    #[tokio::test]
+    async fn test_search_paged() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        for i in 0..7 {
+            let agent = if i % 2 == 0 { "even" } else { "odd" };
+            handle
+                .store(format!("k{i}"), agent.into(), "t".into(), vec![i as f32, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+        }
+        handle.soft_delete("k6".into(), None).await.unwrap();
+
+        let even = Some("agent_id = 'even'".to_string());
+        let page = handle
+            .search_paged(vec![0.0, 0.0, 0.0, 0.0], 2, 0, even.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(page.total_matching, 3);
+        let keys: Vec<_> = page.results.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["k0", "k2"]);
+
+        let page = handle
+            .search_paged(vec![0.0, 0.0, 0.0, 0.0], 2, 2, even, None)
+            .await
+            .unwrap();
+        assert_eq!(page.total_matching, 3);
+        assert_eq!(page.results.len(), 1);
+        assert_eq!(page.results[0].key, "k4");
+
+        let page = handle
+            .search_paged(vec![0.0, 0.0, 0.0, 0.0], 10, 0, None, None)
+            .await
+            .unwrap();
+        assert_eq!(page.total_matching, 6);
     }
-}
 
-// ---------------------------------------------------------------------------
-// Private helpers (not exported via UniFFI)
-// ---------------------------------------------------------------------------
+    #[tokio::test]
+    async fn test_connect_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        for connect_timeout_ms in [5000, 0] {
+            let options = OpenOptions {
+                connect_timeout_ms,
+                ..Default::default()
+            };
+            let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options).await.unwrap();
+            handle
+                .store("k".into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .unwrap();
+            let results = handle.search(vec![1.0, 0.0, 0.0, 0.0], 1, None, None).await.unwrap();
+            assert_eq!(results[0].key, "k");
+        }
+    }
 
-impl LanceDBHandle {
-    async fn connect(&self) -> Result<lancedb::Connection, LanceError> {
-        lancedb::connect(&self.db_path)
-            .execute()
+    #[tokio::test]
+    async fn test_read_only_handle() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let writer = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        writer
+            .store("k".into(), "main".into(), "hello world".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
             .await
-            .map_err(|e| LanceError::ConnectionFailed {
-                msg: e.to_string(),
+            .unwrap();
+
+        let reader = LanceDBHandle::open_read_only(db_path, 4).await.unwrap();
+        let read_only = |r: Result<(), LanceError>| {
+            matches!(r, Err(LanceError::TableError { msg }) if msg == "read-only handle")
+        };
+        assert!(read_only(
+            reader
+                .store("x".into(), "main".into(), "t".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+                .await
+                .map(|_| ())
+        ));
+        assert!(read_only(reader.delete("k".into(), None).await));
+        assert!(read_only(reader.clear(None).await));
+        assert!(read_only(reader.soft_delete("k".into(), None).await.map(|_| ())));
+        assert!(read_only(reader.create_index(None, None, None, None).await));
+
+        let results = reader.search(vec![1.0, 0.0, 0.0, 0.0], 5, None, None).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(reader.exists("k".into(), None).await.unwrap());
+        assert_eq!(writer.count(None, None).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db").to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let entries: Vec<_> = (0..2500)
+            .map(|i| MemoryEntry {
+                key: format!("k{i}"),
+                agent_id: "main".into(),
+                text: "t".into(),
+                embedding: vec![i as f32, (i % 7) as f32, 1.0, 0.0],
+                metadata: None,
+                tags: None,
+                created_at: None,
+                embedding2: None,
+                columns: None,
             })
+            .collect();
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let reporter = || Some(Box::new(RecordingReporter(calls.clone())) as Box<dyn ProgressReporter>);
+        handle.store_many(entries, None, Some(1000), reporter()).await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec![(1000, 2500), (2000, 2500), (2500, 2500)]);
+
+        let path = dir.path().join("dump.parquet").to_str().unwrap().to_string();
+        handle.export_parquet(None, path.clone()).await.unwrap();
+        calls.lock().unwrap().clear();
+        handle
+            .import_parquet(Some("copy".into()), path, ImportMode::Overwrite, reporter())
+            .await
+            .unwrap();
+        let seen = calls.lock().unwrap().clone();
+        assert!(seen.len() > 1);
+        assert!(seen.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(seen.last(), Some(&(2500, 2500)));
+
+        calls.lock().unwrap().clear();
+        handle.create_index(None, Some(2), Some(2), reporter()).await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 1), (1, 1)]);
     }
 
-    /// Open a table with UnsafeCommitHandler — avoids hardlink() syscall
-    /// that Android SELinux blocks for untrusted_app processes.
-    async fn open_table_unsafe(
-        &self,
-        db: &lancedb::Connection,
-        name: &str,
-    ) -> Result<lancedb::Table, LanceError> {
-        let read_params = ReadParams {
-            commit_handler: Some(Arc::new(UnsafeCommitHandler)),
+    #[tokio::test]
+    async fn test_f16_embeddings() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            embedding_dtype: Some(EmbeddingDtype::F16),
             ..Default::default()
         };
-        db.open_table(name)
-            .lance_read_params(read_params)
-            .execute()
-            .await
-            .map_err(|e| LanceError::TableError {
-                msg: e.to_string(),
-            })
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options.clone()).await.unwrap();
+        let vectors = [
+            ("a", vec![1.0, 0.0, 0.0, 0.0]),
+            ("b", vec![0.9, 0.1, 0.0, 0.0]),
+            ("c", vec![0.5, 0.5, 0.1, 0.0]),
+            ("d", vec![0.0, 0.2, 0.9, 0.3]),
+        ];
+        for (key, v) in &vectors {
+            handle
+                .store(key.to_string(), "main".into(), "t".into(), v.clone(), None, None)
+                .await
+                .unwrap();
+        }
+
+        let info = handle.describe(None).await.unwrap();
+        let embedding = info.fields.iter().find(|f| f.name == "embedding").unwrap();
+        assert!(embedding.data_type.contains("Float16"), "{}", embedding.data_type);
+
+        let results = handle.search(vec![1.0, 0.05, 0.0, 0.0], 4, None, None).await.unwrap();
+        let keys: Vec<_> = results.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b", "c", "d"]);
+
+        let got = handle.get("c".into(), None, true, None).await.unwrap().unwrap().embedding.unwrap();
+        for (g, want) in got.iter().zip(&vectors[2].1) {
+            assert!((g - want).abs() < 1e-3, "{g} vs {want}");
+        }
+
+        // The stored type is checked when reopening.
+        drop(handle);
+        let err = LanceDBHandle::open(db_path.clone(), 4).await.err().unwrap();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+        assert!(LanceDBHandle::open_with_options(db_path, 4, options).await.is_ok());
     }
 
-    fn unsafe_write_options(mode: WriteMode) -> WriteOptions {
-        WriteOptions {
-            lance_write_params: Some(WriteParams {
-                mode,
-                commit_handler: Some(Arc::new(UnsafeCommitHandler)),
-                ..Default::default()
-            }),
+    #[tokio::test]
+    async fn test_int8_embeddings() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let dtype = EmbeddingDtype::Int8 { max_abs: 1.0 };
+        let options = OpenOptions {
+            embedding_dtype: Some(dtype),
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path.clone(), 4, options).await.unwrap();
+        let vectors = [
+            ("a", vec![1.0, 0.0, 0.0, 0.0]),
+            ("b", vec![0.7, 0.3, 0.0, 0.0]),
+            ("c", vec![0.3, 0.6, 0.2, 0.0]),
+            ("d", vec![-0.1, 0.2, 0.9, 0.4]),
+        ];
+        for (key, v) in &vectors[..3] {
+            handle
+                .store(key.to_string(), "main".into(), "t".into(), v.clone(), None, None)
+                .await
+                .unwrap();
         }
+        handle.flush().await.unwrap();
+        // One row stays staged, so both sources are ranked together.
+        let (key, v) = &vectors[3];
+        handle
+            .store(key.to_string(), "main".into(), "t".into(), v.clone(), None, None)
+            .await
+            .unwrap();
+
+        let info = handle.describe(None).await.unwrap();
+        let embedding = info.fields.iter().find(|f| f.name == "embedding").unwrap();
+        assert!(embedding.data_type.contains("Int8"), "{}", embedding.data_type);
+
+        let scale = 1.0 / 127.0;
+        for (key, want) in &vectors {
+            let got = handle.get(key.to_string(), None, true, None).await.unwrap().unwrap().embedding.unwrap();
+            for (g, w) in got.iter().zip(want) {
+                assert!((g - w).abs() <= scale / 2.0 + 1e-6, "{key}: {g} vs {w}");
+            }
+        }
+
+        let results = handle.search(vec![1.0, 0.05, 0.0, 0.0], 4, None, None).await.unwrap();
+        let keys: Vec<_> = results.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b", "c", "d"]);
+        let page = handle
+            .search(
+                vec![1.0, 0.05, 0.0, 0.0],
+                2,
+                None,
+                Some(SearchOptions {
+                    offset: Some(1),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let keys: Vec<_> = page.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["b", "c"]);
+        let best = handle.nearest(vec![0.0, 0.2, 1.0, 0.4], None, None).await.unwrap().unwrap();
+        assert_eq!(best.key, "d");
+
+        // The scale is part of the collection, so another one can't reopen it.
+        handle.flush().await.unwrap();
+        let reopen = |max_abs| {
+            let options = OpenOptions {
+                embedding_dtype: Some(EmbeddingDtype::Int8 { max_abs }),
+                ..Default::default()
+            };
+            LanceDBHandle::open_with_options(db_path.clone(), 4, options)
+        };
+        assert!(matches!(reopen(2.0).await.err().unwrap(), LanceError::SchemaError { .. }));
+        assert_eq!(reopen(1.0).await.unwrap().count(None, None).await.unwrap(), 4);
+
+        let dir = tempfile::tempdir().unwrap();
+        let options = OpenOptions {
+            embedding_dtype: Some(EmbeddingDtype::Int8 { max_abs: 0.0 }),
+            ..Default::default()
+        };
+        let err = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
     }
 
-    fn make_batch(
-        &self,
-        schema: &Arc<Schema>,
-        keys: Vec<String>,
-        agent_ids: Vec<String>,
-        texts: Vec<String>,
-        embeddings: Vec<Vec<f32>>,
-        metadatas: Vec<Option<String>>,
-        created_ats: Vec<i64>,
-    ) -> Result<RecordBatch, LanceError> {
-        // Flatten embeddings into a single Vec<f32>
-        let flat: Vec<f32> = embeddings.iter().flatten().copied().collect();
-        let values = Float32Array::from(flat);
+    #[tokio::test]
+    async fn test_secondary_embedding() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = OpenOptions {
+            embedding2_dim: Some(2),
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        let rows = [
+            ("a", vec![1.0, 0.0, 0.0, 0.0], Some(vec![0.0, 1.0])),
+            ("b", vec![0.0, 1.0, 0.0, 0.0], Some(vec![1.0, 0.0])),
+            ("c", vec![0.9, 0.1, 0.0, 0.0], None),
+        ];
+        for (key, embedding, embedding2) in &rows[..2] {
+            handle
+                .store(key.to_string(), "main".into(), "t".into(), embedding.clone(), None, Some(StoreOptions { embedding2: embedding2.clone(), ..Default::default() }))
+                .await
+                .unwrap();
+        }
+        handle.flush().await.unwrap();
+        // The last row stays staged.
+        let (key, embedding, embedding2) = &rows[2];
+        handle
+            .store(key.to_string(), "main".into(), "t".into(), embedding.clone(), None, Some(StoreOptions { embedding2: embedding2.clone(), ..Default::default() }))
+            .await
+            .unwrap();
 
-        let field = Arc::new(Field::new("item", DataType::Float32, true));
-        let embedding_array =
-            FixedSizeListArray::new(field, self.embedding_dim, Arc::new(values), None);
+        let search = |query: Vec<f32>, which| {
+            let handle = handle.clone();
+            async move {
+                let options = SearchOptions {
+                    which,
+                    ..Default::default()
+                };
+                let results = handle.search(query, 3, None, Some(options)).await.unwrap();
+                results.into_iter().map(|r| r.key).collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(search(vec![1.0, 0.0, 0.0, 0.0], None).await, vec!["a", "c", "b"]);
+        assert_eq!(search(vec![1.0, 0.0], Some(EmbeddingColumn::Secondary)).await, vec!["b", "a"]);
+        assert_eq!(search(vec![0.0, 1.0], Some(EmbeddingColumn::Secondary)).await, vec!["a", "b"]);
 
-        let key_array = StringArray::from(keys);
-        let agent_id_array = StringArray::from(agent_ids);
-        let text_array = StringArray::from(texts);
-        let metadata_array = StringArray::from(
-            metadatas
-                .into_iter()
-                .collect::<Vec<Option<String>>>(),
+        let secondary = SearchOptions {
+            which: Some(EmbeddingColumn::Secondary),
+            ..Default::default()
+        };
+        let err = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 3, None, Some(secondary.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 2, actual: 4, .. }));
+        let err = handle
+            .store("d".into(), "main".into(), "t".into(), vec![1.0; 4], None, Some(StoreOptions { embedding2: Some(vec![1.0; 3]), ..Default::default() }))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 2, actual: 3, .. }));
+        let entries = ["ok", "bad"]
+            .iter()
+            .map(|k| MemoryEntry {
+                key: k.to_string(),
+                agent_id: "main".into(),
+                text: "t".into(),
+                embedding: vec![1.0; 4],
+                metadata: None,
+                tags: None,
+                created_at: None,
+                embedding2: Some(if *k == "ok" { vec![1.0; 2] } else { vec![1.0; 3] }),
+                columns: None,
+            })
+            .collect();
+        let err = handle.store_many(entries, None, None, None).await.unwrap_err();
+        assert!(
+            matches!(&err, LanceError::DimensionMismatch { expected: 2, actual: 3, key: Some(k), index: Some(1) } if k == "bad"),
+            "{err:?}"
         );
-        let created_at_array = Int64Array::from(created_ats);
 
-        RecordBatch::try_new(
-            schema.clone(),
-            vec![
-                Arc::new(key_array),
-                Arc::new(agent_id_array),
-                Arc::new(text_array),
-                Arc::new(embedding_array),
-                Arc::new(metadata_array),
-                Arc::new(created_at_array),
-            ],
-        )
-        .map_err(|e| LanceError::InsertError {
-            msg: format!("Failed to create record batch: {e}"),
-        })
+        let plain = LanceDBHandle::open(tempfile::tempdir().unwrap().path().to_str().unwrap().into(), 4)
+            .await
+            .unwrap();
+        let err = plain
+            .store("d".into(), "main".into(), "t".into(), vec![1.0; 4], None, Some(StoreOptions { embedding2: Some(vec![1.0; 2]), ..Default::default() }))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+        let err = plain.search(vec![1.0, 0.0], 3, None, Some(secondary)).await.unwrap_err();
+        assert!(matches!(err, LanceError::QueryError { .. }));
     }
-}
 
-// ---------------------------------------------------------------------------
-// BM25 scoring helpers (pure Rust, no external deps)
-// ---------------------------------------------------------------------------
+    #[tokio::test]
+    async fn test_dedup_by_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = OpenOptions {
+            dedup_by_text: true,
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(dir.path().to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        let store = |key: &str, agent_id: &str, text: &str| {
+            let handle = handle.clone();
+            let (key, agent_id, text) = (key.to_string(), agent_id.to_string(), text.to_string());
+            async move {
+                handle
+                    .store(key, agent_id, text, vec![1.0, 0.0, 0.0, 0.0], None, None)
+                    .await
+                    .unwrap()
+            }
+        };
 
-fn tokenize(text: &str) -> Vec<String> {
-    text.to_lowercase()
-        .split(|c: char| !c.is_alphanumeric())
-        .filter(|s| !s.is_empty())
-        .map(String::from)
-        .collect()
-}
+        assert_eq!(store("a", "main", "same text").await.inserted, 1);
+        assert_eq!(store("b", "main", "same text").await, WriteStats::default());
+        // Another agent may store the same text, and a key may be rewritten.
+        assert_eq!(store("c", "other", "same text").await.inserted, 1);
+        assert_eq!(store("a", "main", "same text").await.replaced, 1);
+        let mut keys = handle.list(None, None, None).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["a", "c"]);
+
+        let entries = ["d", "e"]
+            .iter()
+            .map(|k| MemoryEntry {
+                key: k.to_string(),
+                agent_id: "main".into(),
+                text: "batch text".into(),
+                embedding: vec![1.0, 0.0, 0.0, 0.0],
+                metadata: None,
+                tags: None,
+                created_at: None,
+                embedding2: None,
+                columns: None,
+            })
+            .collect();
+        assert_eq!(handle.store_many(entries, None, None, None).await.unwrap(), 1);
+        assert_eq!(handle.count(None, None).await.unwrap(), 3);
+        assert!(handle.exists("d".into(), None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_extra_columns() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        // A row from before the column existed reads as null.
+        let plain = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
+        plain
+            .store("old".into(), "main".into(), "t".into(), vec![1.0; 4], None, None)
+            .await
+            .unwrap();
+        drop(plain);
+
+        let importance = |value| {
+            Some(vec![ColumnEntry {
+                name: "importance".into(),
+                value: ColumnValue::Int64 { value },
+            }])
+        };
+        let spec = ColumnSpec {
+            name: "importance".into(),
+            column_type: ColumnType::Int64,
+        };
+        let handle = LanceDBHandle::open_with_columns(db_path, 4, vec![spec.clone()]).await.unwrap();
+        for (key, value) in [("low", 1), ("mid", 3), ("high", 5)] {
+            handle
+                .store(key.into(), "main".into(), "t".into(), vec![1.0; 4], None, Some(StoreOptions { columns: importance(value), ..Default::default() }))
+                .await
+                .unwrap();
+        }
 
-fn bm25_scores(docs: &[String], query: &str) -> Vec<f64> {
-    let k1 = 1.2_f64;
-    let b = 0.75_f64;
-    let query_terms = tokenize(query);
-    let doc_tokens: Vec<Vec<String>> = docs.iter().map(|d| tokenize(d)).collect();
-    let n = docs.len() as f64;
-    let avgdl = doc_tokens.iter().map(|t| t.len() as f64).sum::<f64>() / n.max(1.0);
+        let info = handle.describe(None).await.unwrap();
+        assert!(info.fields.iter().any(|f| f.name == "importance" && f.data_type == "Int64"));
+        assert_eq!(handle.count(None, Some("importance >= 3".into())).await.unwrap(), 2);
+        assert_eq!(handle.count(None, Some("importance IS NULL".into())).await.unwrap(), 1);
+        let results = handle
+            .search(vec![1.0; 4], 10, Some("importance > 4".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(results.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), vec!["high"]);
+
+        let wrong_type = Some(vec![ColumnEntry {
+            name: "importance".into(),
+            value: ColumnValue::Utf8 { value: "high".into() },
+        }]);
+        let unknown = Some(vec![ColumnEntry {
+            name: "source_url".into(),
+            value: ColumnValue::Utf8 { value: "https://example.com".into() },
+        }]);
+        for columns in [wrong_type, unknown] {
+            let err = handle
+                .store("x".into(), "main".into(), "t".into(), vec![1.0; 4], None, Some(StoreOptions { columns, ..Default::default() }))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, LanceError::InsertError { .. }), "{err:?}");
+        }
 
-    doc_tokens
-        .iter()
-        .map(|tokens| {
-            let dl = tokens.len() as f64;
-            query_terms
-                .iter()
-                .map(|term| {
-                    let tf = tokens.iter().filter(|t| *t == term).count() as f64;
-                    let df = doc_tokens.iter().filter(|dt| dt.contains(term)).count() as f64;
-                    let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
-                    idf * (tf * (k1 + 1.0)) / (tf + k1 * (1.0 - b + b * dl / avgdl))
-                })
-                .sum()
-        })
-        .collect()
-}
+        let json = handle.export_json(None).await.unwrap();
+        let mut records: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let high = records.as_array().unwrap().iter().find(|r| r["key"] == "high").unwrap();
+        assert_eq!(high["columns"], serde_json::json!({ "importance": 5 }));
+        handle.clear(None).await.unwrap();
+        assert_eq!(handle.import_json(None, json, ImportMode::Append).await.unwrap(), 4);
+        assert_eq!(handle.count(None, Some("importance >= 3".into())).await.unwrap(), 2);
+        assert_eq!(handle.count(None, Some("importance IS NULL".into())).await.unwrap(), 1);
+        records[0]["columns"] = serde_json::json!({ "importance": "high" });
+        let err = handle
+            .import_json(None, records.to_string(), ImportMode::Append)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::InsertError { .. }), "{err:?}");
 
-fn chrono_now_ms() -> i64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as i64
-}
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["text", "bad-name", "importance"] {
+            let columns = vec![spec.clone(), ColumnSpec { name: name.into(), ..spec.clone() }];
+            let err = LanceDBHandle::open_with_columns(dir.path().to_str().unwrap().into(), 4, columns)
+                .await
+                .err()
+                .unwrap();
+            assert!(matches!(err, LanceError::SchemaError { .. }), "{name}: {err:?}");
+        }
+    }
 
-// ---------------------------------------------------------------------------
-// Tests
-// ---------------------------------------------------------------------------
+    #[test]
+    fn test_lancedb_version() {
+        let version = lancedb_version();
+        let parts: Vec<(&str, &str)> = version
+            .split(' ')
+            .map(|part| part.split_once('/').unwrap())
+            .collect();
+        let names: Vec<_> = parts.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["lancedb-ffi", "lancedb", "lance"]);
+        for (name, v) in parts {
+            let numbers: Vec<_> = v.split('.').map(str::parse::<u32>).collect();
+            assert!(numbers.len() >= 2 && numbers.iter().all(Result::is_ok), "{name}/{v}");
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn test_manager() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+        let manager = LanceDBManager::new();
+        let notes = manager.open("notes".into(), path("notes"), 4).await.unwrap();
+        let chat = manager.open("chat".into(), path("chat"), 2).await.unwrap();
+        let err = manager.open("notes".into(), path("other"), 4).await.err().unwrap();
+        assert!(matches!(err, LanceError::ConnectionFailed { .. }));
+        assert_eq!(manager.ids(), vec!["chat", "notes"]);
+
+        notes
+            .store("n".into(), "main".into(), "note".into(), vec![1.0; 4], None, None)
+            .await
+            .unwrap();
+        let chat_again = manager.get("chat".into()).unwrap();
+        chat_again
+            .store("c".into(), "main".into(), "chat".into(), vec![1.0; 2], None, None)
+            .await
+            .unwrap();
+        assert_eq!(notes.count(None, None).await.unwrap(), 1);
+        assert_eq!(chat.count(None, None).await.unwrap(), 1);
+        assert!(manager.get("missing".into()).is_none());
+
+        assert!(manager.close("chat".into()).await.unwrap());
+        assert!(!manager.close("chat".into()).await.unwrap());
+        assert!(chat.count(None, None).await.is_err());
+        manager.open("chat".into(), path("chat"), 2).await.unwrap();
+
+        manager.close_all().await.unwrap();
+        assert!(manager.ids().is_empty());
+        assert!(notes.count(None, None).await.is_err());
+    }
 
     #[tokio::test]
-    async fn test_store_and_search() {
+    async fn test_explain_search() {
         let dir = tempfile::tempdir().unwrap();
         let db_path = dir.path().to_str().unwrap().to_string();
-
         let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let err = handle.explain_search(vec![1.0; 4], 5, None, None).await.err().unwrap();
+        assert!(matches!(err, LanceError::NotFound { .. }));
 
         handle
-            .store(
-                "color-blue".into(),
-                "main".into(),
-                "My favorite color is blue".into(),
-                vec![1.0, 0.0, 0.0, 0.0],
-                None,
-            )
+            .store("a".into(), "main".into(), "a".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        let plan = handle
+            .explain_search(vec![1.0, 0.0, 0.0, 0.0], 5, Some("agent_id = 'main'".into()), None)
             .await
             .unwrap();
+        assert!(plan.contains("KNN"), "{plan}");
+        assert!(plan.contains("agent_id"), "{plan}");
+
+        let err = handle.explain_search(vec![1.0; 3], 5, None, None).await.err().unwrap();
+        assert!(matches!(err, LanceError::DimensionMismatch { expected: 4, actual: 3, .. }));
+    }
 
+    #[tokio::test]
+    async fn test_update_embeddings() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
         handle
-            .store(
-                "color-red".into(),
-                "main".into(),
-                "I also like red".into(),
-                vec![0.9, 0.1, 0.0, 0.0],
-                None,
-            )
+            .store("a".into(), "main".into(), "apple".into(), vec![1.0, 0.0, 0.0, 0.0], Some("{\"n\":1}".into()), None)
             .await
             .unwrap();
-
         handle
-            .store(
-                "food-pizza".into(),
-                "main".into(),
-                "I love pizza".into(),
-                vec![0.0, 0.0, 1.0, 0.0],
+            .store("b".into(), "main".into(), "banana".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        let query = vec![0.0, 0.0, 1.0, 0.0];
+
+        let err = handle
+            .update_embeddings(vec![KeyEmbedding { key: "a".into(), embedding: vec![1.0; 3] }], None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            LanceError::DimensionMismatch { expected: 4, actual: 3, key: Some(k), .. } if k == "a"
+        ));
+
+        let updated = handle
+            .update_embeddings(
+                vec![
+                    KeyEmbedding { key: "b".into(), embedding: vec![0.0, 0.0, 1.0, 0.0] },
+                    KeyEmbedding { key: "a".into(), embedding: vec![0.0, 0.0, 0.0, 1.0] },
+                    KeyEmbedding { key: "missing".into(), embedding: vec![1.0; 4] },
+                ],
                 None,
             )
             .await
             .unwrap();
+        assert_eq!(updated, 2);
+        let results = handle.search(query, 2, None, None).await.unwrap();
+        assert_eq!(results.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), vec!["b", "a"]);
+        assert!(results[0].distance.unwrap() < 1e-6);
+
+        let a = handle.get("a".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(a.text, "apple");
+        assert_eq!(a.metadata.as_deref(), Some("{\"n\":1}"));
+        assert_eq!(a.embedding, Some(vec![0.0, 0.0, 0.0, 1.0]));
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
+
+        let options = OpenOptions {
+            normalize: true,
+            ..Default::default()
+        };
+        let normalized = LanceDBHandle::open_with_options(dir.path().join("normalized").to_str().unwrap().into(), 4, options)
+            .await
+            .unwrap();
+        normalized
+            .store("a".into(), "main".into(), "apple".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        normalized
+            .update_embeddings(vec![KeyEmbedding { key: "a".into(), embedding: vec![3.0, 4.0, 0.0, 0.0] }], None)
+            .await
+            .unwrap();
+        let a = normalized.get("a".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(a.embedding, Some(vec![0.6, 0.8, 0.0, 0.0]));
+    }
+
+    #[tokio::test]
+    async fn test_binary_hamming() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let err = LanceDBHandle::open_with_options(db_path.clone(), 2, OpenOptions {
+            embedding_dtype: Some(EmbeddingDtype::Binary),
+            ..Default::default()
+        })
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+
+        let options = OpenOptions {
+            embedding_dtype: Some(EmbeddingDtype::Binary),
+            distance_metric: Some(DistanceMetric::Hamming),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 2, options).await.unwrap();
+        for (key, code) in [("near", [0b1111_0000, 0x00]), ("far", [0x0F, 0xFF]), ("mid", [0b1100_0000, 0x03])] {
+            handle
+                .store_binary(key.into(), "main".into(), key.into(), code.to_vec(), None, None)
+                .await
+                .unwrap();
+        }
 
         let results = handle
-            .search(vec![1.0, 0.0, 0.0, 0.0], 2, None)
+            .search_binary(vec![0b1111_0001, 0x00], 3, None, None)
+            .await
+            .unwrap();
+        assert_eq!(results.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), vec!["near", "mid", "far"]);
+        assert_eq!(results.iter().map(|r| r.score).collect::<Vec<_>>(), vec![-1.0, -5.0, -15.0]);
+
+        let near = handle.get("near".into(), None, true, None).await.unwrap().unwrap();
+        assert_eq!(near.embedding, Some(vec![240.0, 0.0]));
+        let err = handle.create_index(None, None, None, None).await.unwrap_err();
+        assert!(matches!(err, LanceError::SchemaError { .. }));
+        let err = handle
+            .store("bad".into(), "main".into(), "bad".into(), vec![0.5, 1.0], None, None)
             .await
+            .err()
             .unwrap();
+        assert!(matches!(err, LanceError::InsertError { .. }));
+    }
 
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].key, "color-blue");
-        assert_eq!(results[1].key, "color-red");
+    #[tokio::test]
+    async fn test_open_scoped() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let alice = LanceDBHandle::open_scoped(db_path.clone(), 4, "alice".into()).await.unwrap();
+        let bob = LanceDBHandle::open_scoped(db_path.clone(), 4, "bob".into()).await.unwrap();
+
+        alice
+            .store("shared".into(), "alice".into(), "a".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        alice
+            .store("a2".into(), "alice".into(), "a2".into(), vec![0.0, 1.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        // The agent_id argument is ignored, and Alice's "shared" is untouched.
+        bob.store("shared".into(), "alice".into(), "b".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        bob.store("b1".into(), "alice".into(), "b1".into(), vec![0.0, 0.0, 1.0, 0.0], None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(alice.count(None, None).await.unwrap(), 2);
+        assert_eq!(bob.count(None, None).await.unwrap(), 1);
+        assert_eq!(alice.get("shared".into(), None, false, None).await.unwrap().unwrap().text, "a");
+        assert!(bob.get("shared".into(), None, false, None).await.unwrap().is_none());
+        assert!(!bob.exists("a2".into(), None).await.unwrap());
+        let mut keys = alice.list(None, None, None).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["a2", "shared"]);
+        let found = bob.search(vec![1.0, 0.0, 0.0, 0.0], 10, None, None).await.unwrap();
+        assert_eq!(found.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(), vec!["b1"]);
+
+        bob.delete("a2".into(), None).await.unwrap();
+        assert!(alice.exists("a2".into(), None).await.unwrap());
+        let err = bob.clear_all().await.err().unwrap();
+        assert!(matches!(err, LanceError::TableError { .. }));
+        bob.clear(None).await.unwrap();
+        assert_eq!(bob.count(None, None).await.unwrap(), 0);
+        assert_eq!(alice.count(None, None).await.unwrap(), 2);
+
+        let all = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert_eq!(all.count(None, None).await.unwrap(), 2);
     }
 
     #[tokio::test]
-    async fn test_delete() {
+    async fn test_count() {
         let dir = tempfile::tempdir().unwrap();
         let db_path = dir.path().to_str().unwrap().to_string();
+        let options = OpenOptions {
+            staging: Some(StagingConfig::default()),
+            ..Default::default()
+        };
+        let handle = LanceDBHandle::open_with_options(db_path, 4, options).await.unwrap();
 
-        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 0);
+        assert_eq!(handle.count(Some("missing".into()), None).await.unwrap(), 0);
 
         handle
-            .store(
-                "k1".into(),
-                "main".into(),
-                "text1".into(),
-                vec![1.0, 0.0, 0.0, 0.0],
-                None,
-            )
+            .write_entries(DEFAULT_TABLE, vec![
+                entry("a", "a"),
+                entry("b", "b"),
+                PendingEntry {
+                    agent_id: "other".into(),
+                    ..entry("c", "c")
+                },
+            ])
             .await
             .unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 3);
+        let main = Some("agent_id = 'main'".to_string());
+        assert_eq!(handle.count(None, main.clone()).await.unwrap(), 2);
 
-        let keys = handle.list(None, None).await.unwrap();
-        assert_eq!(keys.len(), 1);
-
-        handle.delete("k1".into()).await.unwrap();
+        // A staged update of "c" moves it into the filter; "d" is new.
+        handle
+            .store("c".into(), "main".into(), "c2".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        handle
+            .store("d".into(), "main".into(), "d".into(), vec![1.0, 0.0, 0.0, 0.0], None, None)
+            .await
+            .unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 4);
+        assert_eq!(handle.count(None, main.clone()).await.unwrap(), 4);
 
-        let keys = handle.list(None, None).await.unwrap();
-        assert_eq!(keys.len(), 0);
+        handle.flush().await.unwrap();
+        assert_eq!(handle.count(None, main).await.unwrap(), 4);
     }
 
     #[tokio::test]
-    async fn test_list_with_prefix() {
+    async fn test_optimize() {
         let dir = tempfile::tempdir().unwrap();
         let db_path = dir.path().to_str().unwrap().to_string();
-
         let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.optimize(None).await.unwrap();
 
-        for (k, text) in [("proj:a", "A"), ("proj:b", "B"), ("other:c", "C")] {
+        for i in 0..10 {
             handle
-                .store(
-                    k.into(),
-                    "main".into(),
-                    text.into(),
-                    vec![1.0, 0.0, 0.0, 0.0],
-                    None,
-                )
+                .store("k".into(), "main".into(), format!("v{i}"), vec![1.0, 0.0, 0.0, 0.0], None, None)
                 .await
                 .unwrap();
         }
+        handle.optimize(None).await.unwrap();
+        handle.optimize(None).await.unwrap();
+
+        assert_eq!(handle.count(None, None).await.unwrap(), 1);
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        let stats = table.stats().await.unwrap();
+        assert_eq!(stats.fragment_stats.num_fragments, 1);
+        assert_eq!(handle.get("k".into(), None, false, None).await.unwrap().unwrap().text, "v9");
+    }
 
-        let proj_keys = handle.list(Some("proj:".into()), None).await.unwrap();
-        assert_eq!(proj_keys.len(), 2);
+    #[tokio::test]
+    async fn test_versions_time_travel() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert!(handle.list_versions(None).await.unwrap().is_empty());
 
-        let all_keys = handle.list(None, None).await.unwrap();
-        assert_eq!(all_keys.len(), 3);
+        handle.write_entries(DEFAULT_TABLE, vec![entry("k", "old")]).await.unwrap();
+        handle
+            .write_entries(DEFAULT_TABLE, vec![entry("k", "new"), entry("k2", "added")])
+            .await
+            .unwrap();
+
+        let versions = handle.list_versions(None).await.unwrap();
+        assert_eq!(versions.len(), 2);
+        assert!(versions[0].version < versions[1].version);
+        assert!(versions[0].timestamp_ms <= versions[1].timestamp_ms);
+
+        let at = |version| SearchOptions {
+            version: Some(version),
+            ..Default::default()
+        };
+        let old = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, Some(at(versions[0].version)))
+            .await
+            .unwrap();
+        assert_eq!(old.len(), 1);
+        assert_eq!(old[0].text, "old");
+        let keys = handle
+            .list(None, None, Some(ListOptions { version: Some(versions[0].version), ..Default::default() }))
+            .await
+            .unwrap();
+        assert_eq!(keys, vec!["k"]);
+        assert_eq!(handle.list(None, None, None).await.unwrap().len(), 2);
+
+        let err = handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, Some(at(99)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LanceError::TableError { .. }));
     }
 
     #[tokio::test]
-    async fn test_clear() {
+    async fn test_restore() {
         let dir = tempfile::tempdir().unwrap();
         let db_path = dir.path().to_str().unwrap().to_string();
-
         let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let err = handle.restore(None, 1).await.unwrap_err();
+        assert!(matches!(err, LanceError::TableError { .. }));
 
+        handle.write_entries(DEFAULT_TABLE, vec![entry("k", "v1")]).await.unwrap();
+        let v1 = handle.list_versions(None).await.unwrap()[0].version;
         handle
-            .store(
-                "k1".into(),
-                "main".into(),
-                "text".into(),
-                vec![1.0, 0.0, 0.0, 0.0],
-                None,
-            )
+            .write_entries(DEFAULT_TABLE, vec![entry("k", "v2"), entry("k2", "v2")])
             .await
             .unwrap();
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
 
-        handle.clear(None).await.unwrap();
+        handle.restore(None, v1).await.unwrap();
+        assert_eq!(handle.list(None, None, None).await.unwrap(), vec!["k"]);
+        assert_eq!(handle.get("k".into(), None, false, None).await.unwrap().unwrap().text, "v1");
+        assert_eq!(handle.list_versions(None).await.unwrap().len(), 3);
 
-        let keys = handle.list(None, None).await.unwrap();
-        assert_eq!(keys.len(), 0);
+        let err = handle.restore(None, 99).await.unwrap_err();
+        assert!(matches!(err, LanceError::TableError { .. }));
     }
 
     #[tokio::test]
-    async fn test_upsert() {
+    async fn test_clear_all() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.clear_all().await.unwrap();
+
+        handle.write_entries(DEFAULT_TABLE, vec![entry("a", "1")]).await.unwrap();
+        handle.write_entries("notes", vec![entry("b", "2")]).await.unwrap();
+        handle.clear_all().await.unwrap();
+
+        let db = handle.connect().await.unwrap();
+        assert!(db.table_names().execute().await.unwrap().is_empty());
+        assert_eq!(handle.count(None, None).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_copy_collection() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("a", "1"), entry("b", "2")]).await.unwrap();
+
+        handle.copy_collection(DEFAULT_TABLE.into(), "copy".into()).await.unwrap();
+        let copied = handle.get("b".into(), Some("copy".into()), false, None).await.unwrap().unwrap();
+        assert_eq!(copied.text, "2");
+        assert_eq!(handle.count(None, None).await.unwrap(), 2);
+        assert_eq!(handle.count(Some("copy".into()), None).await.unwrap(), 2);
+
+        let err = handle.copy_collection(DEFAULT_TABLE.into(), "copy".into()).await.unwrap_err();
+        assert!(matches!(err, LanceError::TableError { .. }));
+        let err = handle.copy_collection("missing".into(), "other".into()).await.unwrap_err();
+        assert!(matches!(err, LanceError::TableError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_rename_collection() {
         let dir = tempfile::tempdir().unwrap();
         let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        handle.write_entries(DEFAULT_TABLE, vec![entry("a", "1"), entry("b", "2")]).await.unwrap();
+
+        handle.rename_collection(DEFAULT_TABLE.into(), "memories_v2".into()).await.unwrap();
+        let db = handle.connect().await.unwrap();
+        assert_eq!(db.table_names().execute().await.unwrap(), ["memories_v2"]);
+        assert_eq!(handle.count(None, None).await.unwrap(), 0);
+        let renamed = handle.get("a".into(), Some("memories_v2".into()), false, None).await.unwrap().unwrap();
+        assert_eq!(renamed.text, "1");
+    }
 
+    #[tokio::test]
+    async fn test_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
         let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        let empty = handle.stats(None).await.unwrap();
+        assert_eq!((empty.num_rows, empty.num_versions, empty.bytes), (0, 0, 0));
 
         handle
-            .store(
-                "k1".into(),
-                "main".into(),
-                "original".into(),
-                vec![1.0, 0.0, 0.0, 0.0],
-                None,
-            )
+            .write_entries(DEFAULT_TABLE, vec![entry("a", "1"), entry("b", "2"), entry("c", "3")])
             .await
             .unwrap();
+        let stats = handle.stats(None).await.unwrap();
+        assert_eq!(stats.num_rows, 3);
+        assert_eq!(stats.num_versions, handle.list_versions(None).await.unwrap().len() as u64);
+        assert!(stats.latest_version >= 1);
+        assert!(stats.bytes > 0);
+    }
 
-        handle
-            .store(
-                "k1".into(),
-                "main".into(),
-                "updated".into(),
-                vec![0.0, 1.0, 0.0, 0.0],
-                None,
-            )
+    #[tokio::test]
+    async fn test_cleanup_old_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path, 4).await.unwrap();
+        assert_eq!(handle.cleanup_old_versions(None, 0).await.unwrap().versions_removed, 0);
+
+        for i in 0..4 {
+            handle
+                .write_entries(DEFAULT_TABLE, vec![entry(&format!("k{i}"), "v")])
+                .await
+                .unwrap();
+        }
+        let before = handle.list_versions(None).await.unwrap();
+        assert!(before.len() >= 4);
+
+        let stats = handle.cleanup_old_versions(None, 0).await.unwrap();
+        assert_eq!(stats.versions_removed, before.len() as u64 - 1);
+        let after = handle.list_versions(None).await.unwrap();
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].version, before.last().unwrap().version);
+        assert_eq!(handle.count(None, None).await.unwrap(), 4);
+        let pruned = SearchOptions {
+            version: Some(before[0].version),
+            ..Default::default()
+        };
+        assert!(handle
+            .search(vec![1.0, 0.0, 0.0, 0.0], 5, None, Some(pruned))
             .await
-            .unwrap();
+            .is_err());
+    }
 
-        let keys = handle.list(None, None).await.unwrap();
-        assert_eq!(keys.len(), 1);
+    #[tokio::test]
+    async fn test_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().to_str().unwrap().to_string();
+        let handle = LanceDBHandle::open(db_path.clone(), 4).await.unwrap();
 
-        let results = handle
-            .search(vec![0.0, 1.0, 0.0, 0.0], 1, None)
+        let report = handle.verify(None).await.unwrap();
+        assert_eq!(report.total_rows, 0);
+        assert_eq!(report.duplicate_keys, 0);
+
+        handle
+            .write_entries(DEFAULT_TABLE, vec![entry("a", "a"), entry("b", "b")])
             .await
             .unwrap();
-        assert_eq!(results[0].text, "updated");
+        let report = handle.verify(None).await.unwrap();
+        assert_eq!(report.total_rows, 2);
+        assert_eq!(report.duplicate_keys, 0);
+        assert!(report.sample_keys.is_empty());
+
+        // Append behind the upsert path to simulate a lost race.
+        let schema = Arc::new(make_schema(4, false, EmbeddingDtype::F32, None, false, &[]));
+        let batch = handle
+            .make_batch(&schema, vec![entry("a", "a2"), entry("a", "a3"), entry("b", "b2")])
+            .unwrap();
+        let db = handle.connect().await.unwrap();
+        let table = handle.open_table_unsafe(&db, DEFAULT_TABLE).await.unwrap();
+        table
+            .add(RecordBatchIterator::new(vec![Ok(batch)], schema))
+            .execute()
+            .await
+            .unwrap();
+        handle.evict_table(DEFAULT_TABLE);
+
+        let report = handle.verify(None).await.unwrap();
+        assert_eq!(report.total_rows, 5);
+        assert_eq!(report.duplicate_keys, 2);
+        assert_eq!(report.duplicate_rows, 3);
+        assert_eq!(report.sample_keys, vec!["a", "b"]);
+
+        // A scoped handle doesn't see other agents' keys.
+        let other = LanceDBHandle::open_scoped(db_path, 4, "other".into()).await.unwrap();
+        let report = other.verify(None).await.unwrap();
+        assert_eq!(report.total_rows, 0);
+        assert!(report.sample_keys.is_empty());
     }
 
     #[test]